@@ -17,6 +17,12 @@ pub enum AcseError {
 
     #[error("ACSE Error: {}", .0)]
     InternalError(String),
+
+    #[error("ACSE Association Rejected - Result: {:?}, Diagnostic: {:?}", .0, .1)]
+    Rejected(AssociateResult, AssociateSourceDiagnostic, Option<Vec<u8>>),
+
+    #[error("ACSE Association Aborted - Source: {:?}, Diagnostic: {:?}", .0, .1)]
+    Aborted(AbortSource, Option<AbortDiagnostic>, Option<Vec<u8>>),
 }
 
 // Only BER encoding is supported.
@@ -35,13 +41,22 @@ pub struct AcseRequestInformation {
     pub calling_ap_invocation_identifier: Option<Vec<u8>>, // Integer
     pub calling_ae_invocation_identifier: Option<Vec<u8>>, // Integer
 
+    pub sender_acse_requirements: Option<AcseRequirements>,
+    pub mechanism_name: Option<Oid<'static>>,
+    pub calling_authentication_value: Option<AuthenticationValue>,
+
     pub implementation_information: Option<String>,
 }
 
+/// The MMS application context name from ISO 9506-2. Requests use this by default and it is the only name that listeners accept by default.
+pub fn mms_application_context_name() -> Oid<'static> {
+    Oid::from(&[1, 0, 9506, 2, 3]).expect("Failed to generate constant OID. This is a bug.").to_owned()
+}
+
 impl Default for AcseRequestInformation {
     fn default() -> Self {
         Self {
-            application_context_name: Oid::from(&[1, 2, 3, 4, 5]).expect("Failed to generate constant OID. This is a bug.").to_owned(),
+            application_context_name: mms_application_context_name(),
             called_ap_title: Default::default(),
             called_ae_qualifier: Default::default(),
             called_ap_invocation_identifier: Default::default(),
@@ -50,6 +65,9 @@ impl Default for AcseRequestInformation {
             calling_ae_qualifier: Default::default(),
             calling_ap_invocation_identifier: Default::default(),
             calling_ae_invocation_identifier: Default::default(),
+            sender_acse_requirements: Default::default(),
+            mechanism_name: Default::default(),
+            calling_authentication_value: Default::default(),
             implementation_information: Default::default(),
         }
     }
@@ -67,6 +85,10 @@ pub struct AcseResponseInformation {
     pub responding_ap_invocation_identifier: Option<Vec<u8>>, // Integer
     pub responding_ae_invocation_identifier: Option<Vec<u8>>, // Integer
 
+    pub responder_acse_requirements: Option<AcseRequirements>,
+    pub mechanism_name: Option<Oid<'static>>,
+    pub responding_authentication_value: Option<AuthenticationValue>,
+
     pub implementation_information: Option<String>,
 }
 
@@ -113,22 +135,126 @@ pub enum AssociateSourceDiagnosticProviderCategory {
     Unknown(Vec<u8>), // Integer
 }
 
+/// The ACSE functional units requested by the sender or accepted by the responder.
+#[derive(Clone, PartialEq, Eq, Debug, Default)]
+pub struct AcseRequirements {
+    pub authentication: bool,
+    pub application_context_negotiation: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AuthenticationValue {
+    CharString(String),
+    BitString(Vec<u8>),
+    /// The single ASN.1 type encoding of an EXTERNAL. Only the encoded value is kept.
+    External(Vec<u8>),
+}
+
+/// An attribute of a relative distinguished name.
+#[derive(Clone, PartialEq, Debug)]
+pub struct AttributeTypeAndValue {
+    pub attribute_type: Oid<'static>,
+    pub value: Vec<u8>, // BER encoded attribute value including its tag
+}
+
+/// A set of attributes that name one level of a directory name.
+pub type RelativeDistinguishedName = Vec<AttributeTypeAndValue>;
+
 #[derive(Clone, PartialEq, Debug)]
 pub enum ApTitle {
-    // Form1(...), Not supporting as this library does not support DN.
+    /// A directory name made up of a sequence of relative distinguished names.
+    Form1(Vec<RelativeDistinguishedName>),
     Form2(Oid<'static>),
 }
 
 #[derive(Clone, PartialEq, Debug)]
 pub enum AeQualifier {
-    // Form1(...), Not supporting as this library does not support DN.
+    Form1(RelativeDistinguishedName),
     Form2(Vec<u8>), // Integer
 }
 
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ReleaseRequestReason {
+    Normal,
+    Urgent,
+    UserDefined,
+    Unknown(Vec<u8>), // Integer
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum ReleaseResponseReason {
+    Normal,
+    NotFinished,
+    UserDefined,
+    Unknown(Vec<u8>), // Integer
+}
+
+/// A negative release is carried on the session not finished and leaves the association open. It needs the negotiated release session functional unit.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ReleaseResult {
+    Affirmative,
+    Negative,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AbortSource {
+    ServiceUser,
+    ServiceProvider,
+    Unknown(Vec<u8>), // Integer
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AbortDiagnostic {
+    NoReasonGiven,
+    ProtocolError,
+    AuthenticationMechanismNameNotRecognized,
+    AuthenticationMechanismNameRequired,
+    AuthenticationFailure,
+    AuthenticationRequired,
+    Unknown(Vec<u8>), // Enumerated
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum AcseRecvResult {
     Closed,
     Data(Vec<u8>),
+    /// The remote side requested the release of the association. This must be answered with a release response on the writer.
+    ReleaseRequest(Option<ReleaseRequestReason>, Option<Vec<u8>>),
+    /// The remote side answered the release request. The reader and writer may be dropped if it was affirmative.
+    ReleaseResponse(ReleaseResult, Option<ReleaseResponseReason>, Option<Vec<u8>>),
+}
+
+/// A presentation context proposed by the ACSE user. Context [1] is reserved for ACSE and is always proposed alongside these.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AcsePresentationContext {
+    pub identifier: Vec<u8>, // Odd Integer
+    pub abstract_syntax_name: Oid<'static>,
+}
+
+/// The responder's answer to a proposed presentation context. Data can only be exchanged on accepted contexts.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub enum AcsePresentationContextResult {
+    Accepted,
+    UserRejection,
+    AbstractSyntaxNotSupported,
+}
+
+/// User data carried on a presentation context.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct AcseContextData {
+    pub presentation_context_identifier: Vec<u8>, // Integer
+    pub data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AcseContextRecvResult {
+    Closed,
+    /// The data received in one presentation data transfer. Each value is tagged with the presentation context it arrived on.
+    Data(Vec<AcseContextData>),
+    /// The remote side requested the release of the association. This must be answered with a release response on the writer.
+    ReleaseRequest(Option<ReleaseRequestReason>, Vec<AcseContextData>),
+    /// The remote side answered the release request. The reader and writer may be dropped if it was affirmative.
+    ReleaseResponse(ReleaseResult, Option<ReleaseResponseReason>, Vec<AcseContextData>),
 }
 
 pub trait OsiSingleValueAcseInitiator: Send {
@@ -137,10 +263,19 @@ pub trait OsiSingleValueAcseInitiator: Send {
 
 pub trait OsiSingleValueAcseListener: Send {
     fn responder(self) -> impl std::future::Future<Output = Result<(impl OsiSingleValueAcseResponder, Vec<u8>), AcseError>> + Send;
+
+    /// Aborts the association before it is accepted. The peer receives an aborted error on initiate.
+    fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
 }
 
 pub trait OsiSingleValueAcseResponder: Send {
     fn accept(self, user_data: Vec<u8>) -> impl std::future::Future<Output = Result<impl OsiSingleValueAcseConnection, AcseError>> + Send;
+
+    /// Refuses the association with a rejected result. The peer receives a rejected error on initiate.
+    fn reject(self, result: AssociateResult, diagnostic: AssociateSourceDiagnostic, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Aborts the association before it is accepted. The peer receives an aborted error on initiate.
+    fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
 }
 
 pub trait OsiSingleValueAcseConnection: Send {
@@ -153,4 +288,65 @@ pub trait OsiSingleValueAcseReader: Send {
 
 pub trait OsiSingleValueAcseWriter: Send {
     fn send(&mut self, input: &mut VecDeque<Vec<u8>>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Requests the release of the association. A release response should be received on the reader before dropping it.
+    /// The writer remains usable as the peer may refuse the release.
+    fn release(&mut self, reason: Option<ReleaseRequestReason>, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Answers a release request received on the reader. The reader and writer may be dropped after an affirmative response.
+    /// A negative response keeps the association open and must use the not finished reason, if any.
+    fn release_response(&mut self, result: ReleaseResult, reason: Option<ReleaseResponseReason>, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Abruptly ends the association. Any data in transit may be lost. The peer receives an aborted error on its reader.
+    fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+}
+
+/// An association that can carry several abstract syntaxes. Rejection and abort errors hold the first user data value regardless of its presentation context.
+pub trait OsiAcseInitiator: Send {
+    fn initiate(
+        self,
+        presentation_contexts: Vec<AcsePresentationContext>,
+        user_data: Vec<AcseContextData>,
+    ) -> impl std::future::Future<Output = Result<(impl OsiAcseConnection, AcseResponseInformation, Vec<AcseContextData>), AcseError>> + Send;
+}
+
+pub trait OsiAcseListener: Send {
+    fn responder(self) -> impl std::future::Future<Output = Result<(impl OsiAcseResponder, Vec<AcseContextData>), AcseError>> + Send;
+
+    /// Aborts the association before it is accepted. The peer receives an aborted error on initiate.
+    fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Vec<AcseContextData>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+}
+
+pub trait OsiAcseResponder: Send {
+    fn accept(self, user_data: Vec<AcseContextData>) -> impl std::future::Future<Output = Result<impl OsiAcseConnection, AcseError>> + Send;
+
+    /// Refuses the association with a rejected result. The peer receives a rejected error on initiate.
+    fn reject(self, result: AssociateResult, diagnostic: AssociateSourceDiagnostic, user_data: Vec<AcseContextData>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Aborts the association before it is accepted. The peer receives an aborted error on initiate.
+    fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Vec<AcseContextData>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+}
+
+pub trait OsiAcseConnection: Send {
+    fn split(self) -> impl std::future::Future<Output = Result<(impl OsiAcseReader, impl OsiAcseWriter), AcseError>> + Send;
+}
+
+pub trait OsiAcseReader: Send {
+    fn recv(&mut self) -> impl std::future::Future<Output = Result<AcseContextRecvResult, AcseError>> + Send;
+}
+
+pub trait OsiAcseWriter: Send {
+    /// Sends each item in its own presentation data transfer on the presentation context it names.
+    fn send(&mut self, input: &mut VecDeque<AcseContextData>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Requests the release of the association. A release response should be received on the reader before dropping it.
+    /// The writer remains usable as the peer may refuse the release.
+    fn release(&mut self, reason: Option<ReleaseRequestReason>, user_data: Vec<AcseContextData>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Answers a release request received on the reader. The reader and writer may be dropped after an affirmative response.
+    /// A negative response keeps the association open and must use the not finished reason, if any.
+    fn release_response(&mut self, result: ReleaseResult, reason: Option<ReleaseResponseReason>, user_data: Vec<AcseContextData>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
+
+    /// Abruptly ends the association. Any data in transit may be lost. The peer receives an aborted error on its reader.
+    fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Vec<AcseContextData>) -> impl std::future::Future<Output = Result<(), AcseError>> + Send;
 }
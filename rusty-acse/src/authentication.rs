@@ -0,0 +1,49 @@
+use der_parser::Oid;
+
+use crate::{AcseError, AcseRequestInformation, AssociateSourceDiagnosticUserCategory, AuthenticationValue};
+
+/// Validates the authentication on an association request before it is accepted by the listener.
+/// An error is sent to the peer as the diagnostic of a permanent rejection.
+pub trait AcseAuthenticator: Send + Sync {
+    fn authenticate(&self, request: &AcseRequestInformation) -> Result<(), AssociateSourceDiagnosticUserCategory>;
+}
+
+/// Fills in the authentication fields of an association request when it is initiated.
+pub trait AcseCredentials: Send + Sync {
+    fn apply(&self, request: &mut AcseRequestInformation) -> Result<(), AcseError>;
+}
+
+/// Simple password authentication using the password-1 mechanism and a character string authentication value.
+pub struct PasswordAuthenticator {
+    password: String,
+}
+
+impl PasswordAuthenticator {
+    pub fn new(password: impl Into<String>) -> Self {
+        Self { password: password.into() }
+    }
+
+    /// The password-1 mechanism name (2.2.3.1).
+    pub fn mechanism_name() -> Oid<'static> {
+        Oid::from(&[2, 2, 3, 1]).expect("Failed to generate constant OID. This is a bug.").to_owned()
+    }
+}
+
+impl AcseAuthenticator for PasswordAuthenticator {
+    fn authenticate(&self, request: &AcseRequestInformation) -> Result<(), AssociateSourceDiagnosticUserCategory> {
+        match &request.sender_acse_requirements {
+            Some(requirements) if requirements.authentication => (),
+            _ => return Err(AssociateSourceDiagnosticUserCategory::AuthenticationRequired),
+        }
+        match &request.mechanism_name {
+            Some(mechanism_name) if mechanism_name == &Self::mechanism_name() => (),
+            Some(_) => return Err(AssociateSourceDiagnosticUserCategory::AuthenticationMechanismNameNotRecognized),
+            None => return Err(AssociateSourceDiagnosticUserCategory::AuthenticationMechanismNameRequired),
+        }
+        match &request.calling_authentication_value {
+            Some(AuthenticationValue::CharString(password)) if password == &self.password => Ok(()),
+            Some(_) => Err(AssociateSourceDiagnosticUserCategory::AuthenticationFailure),
+            None => Err(AssociateSourceDiagnosticUserCategory::AuthenticationRequired),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use chrono::{DateTime, NaiveDateTime, Utc};
+use der_parser::{Oid, ber::parse_ber_any};
+use ring::{
+    rand::SystemRandom,
+    signature::{self, EcdsaKeyPair, Ed25519KeyPair, RsaKeyPair, UnparsedPublicKey},
+};
+use tracing::warn;
+use x509_parser::{
+    certificate::X509Certificate,
+    oid_registry::{OID_EC_P256, OID_KEY_TYPE_EC_PUBLIC_KEY, OID_NIST_EC_P384, OID_PKCS1_RSAENCRYPTION, OID_SIG_ED25519},
+    prelude::FromDer,
+};
+
+use crate::{AcseAuthenticator, AcseCredentials, AcseError, AcseRequestInformation, AcseRequirements, AssociateSourceDiagnosticUserCategory, AuthenticationValue, messages::parsers::to_acse_error};
+
+// GeneralizedTime in UTC with millisecond precision.
+const TOKEN_TIME_FORMAT: &str = "%Y%m%d%H%M%S%.3fZ";
+const TOKEN_TIME_PARSE_FORMAT: &str = "%Y%m%d%H%M%S%.fZ";
+
+/// The IEC 62351-4 certificate based mechanism name used when one is not configured.
+pub fn iec62351_mechanism_name() -> Oid<'static> {
+    Oid::from(&[1, 0, 62351, 4, 1, 1]).expect("Failed to generate constant OID. This is a bug.").to_owned()
+}
+
+/// IEC 62351-4 A-profile credentials for the initiator.
+///
+/// A signed time stamp token is generated each time an association is initiated. The token holds the certificate, the time and a signature over the time.
+/// ECDSA P-256/P-384, Ed25519 and RSA (PKCS#1 SHA-256) keys are supported.
+pub struct Iec62351Credentials {
+    certificate: Vec<u8>, // DER encoded X.509 certificate
+    private_key: Vec<u8>, // DER encoded PKCS#8 private key
+    mechanism_name: Oid<'static>,
+}
+
+impl Iec62351Credentials {
+    pub fn new(certificate: Vec<u8>, private_key: Vec<u8>) -> Result<Self, AcseError> {
+        let credentials = Self { certificate, private_key, mechanism_name: iec62351_mechanism_name() };
+        // Fail early on a bad key rather than on the first association.
+        credentials.sign(b"")?;
+        Ok(credentials)
+    }
+
+    pub fn with_mechanism_name(mut self, mechanism_name: Oid<'static>) -> Self {
+        self.mechanism_name = mechanism_name;
+        self
+    }
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, AcseError> {
+        let (_, certificate) = X509Certificate::from_der(&self.certificate).map_err(to_acse_error("Failed to parse IEC 62351 certificate"))?;
+        let rng = SystemRandom::new();
+        let signature = match KeyAlgorithm::from_certificate(&certificate)? {
+            KeyAlgorithm::EcdsaP256 => EcdsaKeyPair::from_pkcs8(&signature::ECDSA_P256_SHA256_ASN1_SIGNING, &self.private_key, &rng)
+                .map_err(to_acse_error("Failed to load IEC 62351 private key"))?
+                .sign(&rng, message)
+                .map_err(to_acse_error("Failed to sign IEC 62351 token"))?
+                .as_ref()
+                .to_vec(),
+            KeyAlgorithm::EcdsaP384 => EcdsaKeyPair::from_pkcs8(&signature::ECDSA_P384_SHA384_ASN1_SIGNING, &self.private_key, &rng)
+                .map_err(to_acse_error("Failed to load IEC 62351 private key"))?
+                .sign(&rng, message)
+                .map_err(to_acse_error("Failed to sign IEC 62351 token"))?
+                .as_ref()
+                .to_vec(),
+            KeyAlgorithm::Ed25519 => Ed25519KeyPair::from_pkcs8_maybe_unchecked(&self.private_key).map_err(to_acse_error("Failed to load IEC 62351 private key"))?.sign(message).as_ref().to_vec(),
+            KeyAlgorithm::Rsa => {
+                let key_pair = RsaKeyPair::from_pkcs8(&self.private_key).map_err(to_acse_error("Failed to load IEC 62351 private key"))?;
+                let mut signature = vec![0; key_pair.public().modulus_len()];
+                key_pair.sign(&signature::RSA_PKCS1_SHA256, &rng, message, &mut signature).map_err(to_acse_error("Failed to sign IEC 62351 token"))?;
+                signature
+            }
+        };
+        Ok(signature)
+    }
+}
+
+impl AcseCredentials for Iec62351Credentials {
+    fn apply(&self, request: &mut AcseRequestInformation) -> Result<(), AcseError> {
+        let time = Utc::now().format(TOKEN_TIME_FORMAT).to_string();
+        let signature = self.sign(time.as_bytes())?;
+
+        let mut requirements = request.sender_acse_requirements.clone().unwrap_or_default();
+        requirements.authentication = true;
+        request.sender_acse_requirements = Some(requirements);
+        request.mechanism_name = Some(self.mechanism_name.clone());
+        request.calling_authentication_value = Some(AuthenticationValue::External(serialise_token(&self.certificate, time.as_bytes(), &signature)?));
+        Ok(())
+    }
+}
+
+/// IEC 62351-4 A-profile peer authentication for the listener.
+///
+/// The token certificate must be in the trust store or be issued by a CA certificate in the trust store, and both must be valid now.
+/// The token time must be within the replay window of the current time and a certificate is only accepted once for each token time.
+/// Replays are recognised by the signed content rather than the signature as an ECDSA signature can be re-encoded without the private key.
+/// Clones share the record of seen tokens so a single authenticator should be cloned for each listener.
+#[derive(Clone)]
+pub struct Iec62351Authenticator {
+    trust_store: Arc<Vec<Vec<u8>>>, // DER encoded X.509 certificates
+    replay_window: Duration,
+    mechanism_name: Oid<'static>,
+    seen_tokens: Arc<Mutex<VecDeque<SeenToken>>>,
+}
+
+struct SeenToken {
+    time: DateTime<Utc>,
+    issuer: Vec<u8>, // DER encoded certificate issuer name
+    serial: Vec<u8>,
+}
+
+impl Iec62351Authenticator {
+    pub fn new(trust_store: Vec<Vec<u8>>, replay_window: Duration) -> Result<Self, AcseError> {
+        for certificate in &trust_store {
+            X509Certificate::from_der(certificate).map_err(to_acse_error("Failed to parse IEC 62351 trusted certificate"))?;
+        }
+        Ok(Self { trust_store: Arc::new(trust_store), replay_window, mechanism_name: iec62351_mechanism_name(), seen_tokens: Arc::new(Mutex::new(VecDeque::new())) })
+    }
+
+    pub fn with_mechanism_name(mut self, mechanism_name: Oid<'static>) -> Self {
+        self.mechanism_name = mechanism_name;
+        self
+    }
+
+    fn verify_token(&self, token: &[u8]) -> Result<(), AcseError> {
+        let Token { certificate, time, signature } = parse_token(token)?;
+        let (_, certificate) = X509Certificate::from_der(&certificate).map_err(to_acse_error("Failed to parse IEC 62351 token certificate"))?;
+
+        if !certificate.validity().is_valid() {
+            return Err(AcseError::ProtocolError("IEC 62351 token certificate is not valid at this time".into()));
+        }
+        if !self.is_trusted(&certificate) {
+            return Err(AcseError::ProtocolError(format!("IEC 62351 token certificate is not trusted: {}", certificate.subject())));
+        }
+
+        let public_key = certificate.public_key();
+        let algorithm: &dyn signature::VerificationAlgorithm = match KeyAlgorithm::from_certificate(&certificate)? {
+            KeyAlgorithm::EcdsaP256 => &signature::ECDSA_P256_SHA256_ASN1,
+            KeyAlgorithm::EcdsaP384 => &signature::ECDSA_P384_SHA384_ASN1,
+            KeyAlgorithm::Ed25519 => &signature::ED25519,
+            KeyAlgorithm::Rsa => &signature::RSA_PKCS1_2048_8192_SHA256,
+        };
+        UnparsedPublicKey::new(algorithm, &public_key.subject_public_key.data).verify(&time, &signature).map_err(to_acse_error("IEC 62351 token signature is not valid"))?;
+
+        let time_text = std::str::from_utf8(&time).map_err(to_acse_error("Failed to parse IEC 62351 token time"))?;
+        let time = NaiveDateTime::parse_from_str(time_text, TOKEN_TIME_PARSE_FORMAT).map_err(to_acse_error("Failed to parse IEC 62351 token time"))?.and_utc();
+        let now = Utc::now();
+        let replay_window = chrono::Duration::from_std(self.replay_window).map_err(to_acse_error("Invalid IEC 62351 replay window"))?;
+        if (now - time).abs() > replay_window {
+            return Err(AcseError::ProtocolError(format!("IEC 62351 token time {} is outside of the replay window", time_text)));
+        }
+
+        let mut seen_tokens = self.seen_tokens.lock().map_err(|e| AcseError::InternalError(format!("IEC 62351 replay protection is unavailable: {e}")))?;
+        while seen_tokens.front().is_some_and(|seen_token| now - seen_token.time > replay_window) {
+            seen_tokens.pop_front();
+        }
+        let issuer = certificate.issuer().as_raw();
+        let serial = certificate.raw_serial();
+        if seen_tokens.iter().any(|seen_token| seen_token.time == time && seen_token.issuer == issuer && seen_token.serial == serial) {
+            return Err(AcseError::ProtocolError("IEC 62351 token has already been used".into()));
+        }
+        seen_tokens.push_back(SeenToken { time, issuer: issuer.to_vec(), serial: serial.to_vec() });
+        Ok(())
+    }
+
+    fn is_trusted(&self, certificate: &X509Certificate) -> bool {
+        self.trust_store.iter().any(|trusted_data| {
+            if trusted_data.as_slice() == certificate.as_ref() {
+                return true;
+            }
+            match X509Certificate::from_der(trusted_data) {
+                Ok((_, trusted)) => is_certificate_authority(&trusted) && trusted.subject() == certificate.issuer() && certificate.verify_signature(Some(trusted.public_key())).is_ok(),
+                Err(_) => false,
+            }
+        })
+    }
+}
+
+/// Only a currently valid certificate marked as a CA by its basic constraints can issue token certificates.
+fn is_certificate_authority(certificate: &X509Certificate) -> bool {
+    certificate.validity().is_valid() && matches!(certificate.basic_constraints(), Ok(Some(basic_constraints)) if basic_constraints.value.ca)
+}
+
+impl AcseAuthenticator for Iec62351Authenticator {
+    fn authenticate(&self, request: &AcseRequestInformation) -> Result<(), AssociateSourceDiagnosticUserCategory> {
+        match &request.sender_acse_requirements {
+            Some(AcseRequirements { authentication: true, .. }) => (),
+            _ => return Err(AssociateSourceDiagnosticUserCategory::AuthenticationRequired),
+        }
+        match &request.mechanism_name {
+            Some(mechanism_name) if mechanism_name == &self.mechanism_name => (),
+            Some(_) => return Err(AssociateSourceDiagnosticUserCategory::AuthenticationMechanismNameNotRecognized),
+            None => return Err(AssociateSourceDiagnosticUserCategory::AuthenticationMechanismNameRequired),
+        }
+        match &request.calling_authentication_value {
+            Some(AuthenticationValue::External(token)) => self.verify_token(token).map_err(|e| {
+                warn!("IEC 62351 authentication failed: {e}");
+                AssociateSourceDiagnosticUserCategory::AuthenticationFailure
+            }),
+            Some(_) => Err(AssociateSourceDiagnosticUserCategory::AuthenticationFailure),
+            None => Err(AssociateSourceDiagnosticUserCategory::AuthenticationRequired),
+        }
+    }
+}
+
+enum KeyAlgorithm {
+    EcdsaP256,
+    EcdsaP384,
+    Ed25519,
+    Rsa,
+}
+
+impl KeyAlgorithm {
+    fn from_certificate(certificate: &X509Certificate) -> Result<Self, AcseError> {
+        let algorithm = &certificate.public_key().algorithm;
+        if algorithm.algorithm == OID_KEY_TYPE_EC_PUBLIC_KEY {
+            match algorithm.parameters.as_ref().and_then(|x| x.as_oid().ok()) {
+                Some(curve) if curve == OID_EC_P256 => Ok(KeyAlgorithm::EcdsaP256),
+                Some(curve) if curve == OID_NIST_EC_P384 => Ok(KeyAlgorithm::EcdsaP384),
+                curve => Err(AcseError::ProtocolError(format!("Unsupported IEC 62351 elliptic curve: {:?}", curve))),
+            }
+        } else if algorithm.algorithm == OID_SIG_ED25519 {
+            Ok(KeyAlgorithm::Ed25519)
+        } else if algorithm.algorithm == OID_PKCS1_RSAENCRYPTION {
+            Ok(KeyAlgorithm::Rsa)
+        } else {
+            Err(AcseError::ProtocolError(format!("Unsupported IEC 62351 key algorithm: {}", algorithm.algorithm)))
+        }
+    }
+}
+
+/// Serialises the certificate based token. The certificate is implicitly tagged so its universal sequence tag is replaced.
+///
+/// ```text
+/// certificate-based [0] IMPLICIT SEQUENCE {
+///     authentication-Certificate [0] IMPLICIT Certificate,
+///     time [1] IMPLICIT GeneralizedTime,
+///     signature [2] IMPLICIT OCTET STRING
+/// }
+/// ```
+fn serialise_token(certificate: &[u8], time: &[u8], signature: &[u8]) -> Result<Vec<u8>, AcseError> {
+    let mut tagged_certificate = certificate.to_vec();
+    match tagged_certificate.first_mut() {
+        Some(tag) if *tag == 0x30 => *tag = 0xa0,
+        _ => return Err(AcseError::InternalError("IEC 62351 certificate is not a DER sequence".into())),
+    }
+    let content = [tagged_certificate, serialise_tlv(0x81, time), serialise_tlv(0x82, signature)].concat();
+    Ok(serialise_tlv(0xa0, &content))
+}
+
+fn serialise_tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let length = match content.len() {
+        length if length < 0x80 => vec![length as u8],
+        length => {
+            let length_bytes: Vec<u8> = length.to_be_bytes().into_iter().skip_while(|x| *x == 0).collect();
+            [vec![0x80 | length_bytes.len() as u8], length_bytes].concat()
+        }
+    };
+    [vec![tag], length, content.to_vec()].concat()
+}
+
+struct Token {
+    certificate: Vec<u8>, // DER encoded X.509 certificate
+    time: Vec<u8>,
+    signature: Vec<u8>,
+}
+
+fn parse_token(token: &[u8]) -> Result<Token, AcseError> {
+    let (_, token) = parse_ber_any(token).map_err(to_acse_error("Failed to parse IEC 62351 token"))?;
+    if token.header.raw_tag() != Some(&[0xa0]) {
+        return Err(AcseError::ProtocolError(format!("Unsupported IEC 62351 token: {:?}", token.header.raw_tag())));
+    }
+
+    let mut certificate = None;
+    let mut time = None;
+    let mut signature = None;
+    let mut remaining = token.data;
+    while !remaining.is_empty() {
+        let (rest, part) = parse_ber_any(remaining).map_err(to_acse_error("Failed to parse IEC 62351 token"))?;
+        match part.header.raw_tag() {
+            Some(&[0xa0]) => {
+                let mut certificate_data = remaining[..remaining.len() - rest.len()].to_vec();
+                certificate_data[0] = 0x30;
+                certificate = Some(certificate_data);
+            }
+            Some(&[0x81]) => time = Some(part.data.to_vec()),
+            Some(&[0x82]) => signature = Some(part.data.to_vec()),
+            x => warn!("Unexpected tag in IEC 62351 token: {:?}", x),
+        }
+        remaining = rest;
+    }
+
+    match (certificate, time, signature) {
+        (Some(certificate), Some(time), Some(signature)) => Ok(Token { certificate, time, signature }),
+        _ => Err(AcseError::ProtocolError("IEC 62351 token is missing the certificate, time or signature".into())),
+    }
+}
@@ -1,26 +1,41 @@
 pub(crate) mod api;
+pub(crate) mod authentication;
+pub(crate) mod iec62351;
 pub(crate) mod messages;
 pub(crate) mod service;
 
 pub use api::*;
+pub use authentication::*;
+pub use iec62351::*;
 use rusty_copp::{RustyCoppInitiatorIsoStack, RustyCoppReaderIsoStack, RustyCoppResponderIsoStack, RustyCoppWriterIsoStack};
 pub use service::*;
 
-pub type RustyOsiSingleValueAcseReaderIsoStack<R> = RustyOsiSingleValueAcseReader<RustyCoppReaderIsoStack<R>>;
+pub type RustyOsiSingleValueAcseReaderIsoStack<R, W> = RustyOsiSingleValueAcseReader<RustyCoppReaderIsoStack<R, W>>;
 pub type RustyOsiSingleValueAcseWriterIsoStack<W> = RustyOsiSingleValueAcseWriter<RustyCoppWriterIsoStack<W>>;
-pub type RustyOsiSingleValueAcseInitiatorIsoStack<R, W> = RustyOsiSingleValueAcseInitiator<RustyCoppInitiatorIsoStack<R, W>, RustyCoppReaderIsoStack<R>, RustyCoppWriterIsoStack<W>>;
-pub type RustyOsiSingleValueAcseListenerIsoStack<R, W> = RustyOsiSingleValueAcseListener<RustyCoppResponderIsoStack<R, W>, RustyCoppReaderIsoStack<R>, RustyCoppWriterIsoStack<W>>;
-pub type RustyOsiSingleValueAcseResponderIsoStack<R, W> = RustyOsiSingleValueAcseResponder<RustyCoppResponderIsoStack<R, W>, RustyCoppReaderIsoStack<R>, RustyCoppWriterIsoStack<W>>;
-pub type RustyOsiSingleValueAcseConnectionIsoStack<R, W> = RustyAcseConnection<RustyCoppReaderIsoStack<R>, RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiSingleValueAcseInitiatorIsoStack<R, W> = RustyOsiSingleValueAcseInitiator<RustyCoppInitiatorIsoStack<R, W>, RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiSingleValueAcseListenerIsoStack<R, W> = RustyOsiSingleValueAcseListener<RustyCoppResponderIsoStack<R, W>, RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiSingleValueAcseResponderIsoStack<R, W> = RustyOsiSingleValueAcseResponder<RustyCoppResponderIsoStack<R, W>, RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiSingleValueAcseConnectionIsoStack<R, W> = RustyAcseConnection<RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
+
+pub type RustyOsiAcseReaderIsoStack<R, W> = RustyOsiAcseReader<RustyCoppReaderIsoStack<R, W>>;
+pub type RustyOsiAcseWriterIsoStack<W> = RustyOsiAcseWriter<RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiAcseInitiatorIsoStack<R, W> = RustyOsiAcseInitiator<RustyCoppInitiatorIsoStack<R, W>, RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiAcseListenerIsoStack<R, W> = RustyOsiAcseListener<RustyCoppResponderIsoStack<R, W>, RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiAcseResponderIsoStack<R, W> = RustyOsiAcseResponder<RustyCoppResponderIsoStack<R, W>, RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
+pub type RustyOsiAcseConnectionIsoStack<R, W> = RustyOsiAcseConnection<RustyCoppReaderIsoStack<R, W>, RustyCoppWriterIsoStack<W>>;
 
 #[cfg(test)]
 mod tests {
+    use anyhow::anyhow;
     use der_parser::num_bigint::BigInt;
-    use std::{collections::VecDeque, time::Duration};
+    use std::{collections::VecDeque, net::SocketAddr, ops::Range, time::Duration};
 
     use der_parser::Oid;
-    use rusty_copp::{CoppError, RustyCoppListener};
-    use rusty_cosp::{CospConnectionParameters, CospProtocolInformation, RustyCospAcceptor, RustyCospInitiator, RustyCospReader, RustyCospResponder, RustyCospWriter};
+    use rusty_copp::{
+        CoppError, CoppInitiator, CoppListener, PresentationContext, PresentationContextResult, PresentationContextResultCause, PresentationContextResultProviderReason, PresentationContextResultProviderReasonValue,
+        PresentationContextResultType, PresentationContextType, PresentationDataValueList, PresentationDataValues, RustyCoppListener, UserData,
+    };
+    use rusty_cosp::{CospConnectionParameters, CospFunctionalUnits, CospProtocolInformation, RustyCospAcceptor, RustyCospInitiator, RustyCospReader, RustyCospResponder, RustyCospWriter};
     use rusty_cotp::{CotpProtocolInformation, CotpResponder, RustyCotpConnection, RustyCotpReader, RustyCotpResponder, RustyCotpWriter};
     use rusty_tpkt::{TcpTpktConnection, TcpTpktReader, TcpTpktServer, TcpTpktWriter};
     use tokio::join;
@@ -33,7 +48,7 @@ mod tests {
     async fn it_should_create_connection() -> Result<(), anyhow::Error> {
         let (client, server) = create_acse_connection_pair_with_options(
             AcseRequestInformation {
-                application_context_name: Oid::from(&[1, 0, 9506, 2, 1])?,
+                application_context_name: mms_application_context_name(),
                 called_ap_title: Some(ApTitle::Form2(Oid::from(&[1, 2, 3, 4, 5])?)),
                 called_ae_qualifier: Some(AeQualifier::Form2(vec![100])),
                 called_ap_invocation_identifier: Some(vec![101]),
@@ -42,16 +57,22 @@ mod tests {
                 calling_ae_qualifier: Some(AeQualifier::Form2(BigInt::from(200u32).to_signed_bytes_be())),
                 calling_ap_invocation_identifier: Some(BigInt::from(201u32).to_signed_bytes_be()),
                 calling_ae_invocation_identifier: Some(BigInt::from(202u32).to_signed_bytes_be()),
+                sender_acse_requirements: Some(AcseRequirements { authentication: true, application_context_negotiation: false }),
+                mechanism_name: Some(PasswordAuthenticator::mechanism_name()),
+                calling_authentication_value: Some(AuthenticationValue::CharString("Password".into())),
                 implementation_information: Some("This Guy".into()),
             },
             AcseResponseInformation {
                 associate_result: AssociateResult::Accepted,
                 associate_source_diagnostic: AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::Null),
-                application_context_name: Oid::from(&[1, 0, 9506, 2, 1])?,
+                application_context_name: mms_application_context_name(),
                 responding_ap_title: Some(ApTitle::Form2(Oid::from(&[1, 2, 3, 4, 5])?)),
                 responding_ae_qualifier: Some(AeQualifier::Form2(vec![100])),
                 responding_ap_invocation_identifier: Some(vec![101]),
                 responding_ae_invocation_identifier: Some(vec![102]),
+                responder_acse_requirements: Some(AcseRequirements { authentication: true, application_context_negotiation: true }),
+                mechanism_name: Some(Oid::from(&[1, 2, 3])?),
+                responding_authentication_value: Some(AuthenticationValue::BitString(vec![0x01, 0x02])),
                 implementation_information: Some("This Other Guy".into()),
             },
             vec![0xa8, 0x00],
@@ -70,37 +91,602 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_create_connection_with_form1_titles() -> Result<(), anyhow::Error> {
+        let country = AttributeTypeAndValue { attribute_type: Oid::from(&[2, 5, 4, 6])?, value: [&[0x13, 0x02][..], b"DE"].concat() };
+        let organisation = AttributeTypeAndValue { attribute_type: Oid::from(&[2, 5, 4, 10])?, value: [&[0x13, 0x07][..], b"Partner"].concat() };
+        let common_name = AttributeTypeAndValue { attribute_type: Oid::from(&[2, 5, 4, 3])?, value: [&[0x0c, 0x06][..], b"Client"].concat() };
+
+        create_acse_connection_pair_with_options(
+            AcseRequestInformation {
+                called_ap_title: Some(ApTitle::Form1(vec![vec![country.clone()], vec![organisation.clone()]])),
+                called_ae_qualifier: Some(AeQualifier::Form1(vec![common_name.clone()])),
+                calling_ap_title: Some(ApTitle::Form1(vec![vec![country, organisation]])),
+                calling_ae_qualifier: Some(AeQualifier::Form2(vec![100])),
+                ..Default::default()
+            },
+            AcseResponseInformation { responding_ap_title: Some(ApTitle::Form1(vec![])), responding_ae_qualifier: Some(AeQualifier::Form1(vec![common_name])), ..default_response()? },
+            vec![0xa8, 0x00],
+            vec![0xa9, 0x00],
+        )
+        .await?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_release_the_association() -> Result<(), anyhow::Error> {
+        let (client, server) = create_acse_connection_pair_with_options(AcseRequestInformation::default(), default_response()?, vec![0xa8, 0x00], vec![0xa9, 0x00]).await?;
+
+        let (mut client_reader, mut client_writer) = client.split().await?;
+        let (mut server_reader, mut server_writer) = server.split().await?;
+
+        client_writer.release(Some(ReleaseRequestReason::Normal), Some(vec![0x8b, 0x00])).await?;
+        assert_eq!(AcseRecvResult::ReleaseRequest(Some(ReleaseRequestReason::Normal), Some(vec![0x8b, 0x00])), server_reader.recv().await?);
+        server_writer.release_response(ReleaseResult::Affirmative, Some(ReleaseResponseReason::Normal), Some(vec![0x8c, 0x00])).await?;
+        assert_eq!(AcseRecvResult::ReleaseResponse(ReleaseResult::Affirmative, Some(ReleaseResponseReason::Normal), Some(vec![0x8c, 0x00])), client_reader.recv().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_answer_a_release_negatively() -> Result<(), anyhow::Error> {
+        let (client, server) = create_acse_connection_pair_with_options(AcseRequestInformation::default(), default_response()?, vec![0xa8, 0x00], vec![0xa9, 0x00]).await?;
+
+        let (mut client_reader, mut client_writer) = client.split().await?;
+        let (mut server_reader, mut server_writer) = server.split().await?;
+
+        client_writer.release(None, None).await?;
+        assert_eq!(AcseRecvResult::ReleaseRequest(None, None), server_reader.recv().await?);
+        match server_writer.release_response(ReleaseResult::Negative, Some(ReleaseResponseReason::Normal), None).await {
+            Err(AcseError::InternalError(_)) => (),
+            x => panic!("Expected an internal error for a negative release response with a normal reason but got {:?}", x),
+        }
+        server_writer.release_response(ReleaseResult::Negative, None, Some(vec![0x8c, 0x00])).await?;
+        assert_eq!(AcseRecvResult::ReleaseResponse(ReleaseResult::Negative, Some(ReleaseResponseReason::NotFinished), Some(vec![0x8c, 0x00])), client_reader.recv().await?);
+
+        // The association stays open after a refused release.
+        client_writer.send(&mut VecDeque::from(vec![vec![0x30, 0x00]])).await?;
+        assert_eq!(AcseRecvResult::Data(vec![0x30, 0x00]), server_reader.recv().await?);
+        server_writer.send(&mut VecDeque::from(vec![vec![0x30, 0x01]])).await?;
+        assert_eq!(AcseRecvResult::Data(vec![0x30, 0x01]), client_reader.recv().await?);
+
+        client_writer.release(None, None).await?;
+        assert_eq!(AcseRecvResult::ReleaseRequest(None, None), server_reader.recv().await?);
+        server_writer.release_response(ReleaseResult::Affirmative, None, None).await?;
+        assert_eq!(AcseRecvResult::ReleaseResponse(ReleaseResult::Affirmative, None, None), client_reader.recv().await?);
+        match server_writer.send(&mut VecDeque::from(vec![vec![0x30, 0x02]])).await {
+            Err(AcseError::ProtocolError(_)) => (),
+            x => panic!("Expected a protocol error when sending after the release but got {:?}", x),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_abort_the_association() -> Result<(), anyhow::Error> {
+        let (client, server) = create_acse_connection_pair_with_options(AcseRequestInformation::default(), default_response()?, vec![0xa8, 0x00], vec![0xa9, 0x00]).await?;
+
+        let (_client_reader, client_writer) = client.split().await?;
+        let (mut server_reader, _server_writer) = server.split().await?;
+
+        client_writer.abort(Some(AbortDiagnostic::ProtocolError), Some(vec![0x8d, 0x00])).await?;
+        match server_reader.recv().await {
+            Err(AcseError::Aborted(AbortSource::ServiceUser, Some(AbortDiagnostic::ProtocolError), Some(user_data))) => assert_eq!(user_data, vec![0x8d, 0x00]),
+            x => panic!("Expected an abort but got {:?}", x),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_abort_the_association_before_it_is_accepted() -> Result<(), anyhow::Error> {
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let acse_client = create_acse_initiator(test_address, AcseRequestInformation::default(), None).await?;
+            Ok::<_, anyhow::Error>(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await.map(|_| ()))
+        };
+        let server_path = async {
+            let (acse_listener, _) = create_acse_listener(test_address, None, None).await?;
+            acse_listener.abort(Some(AbortDiagnostic::AuthenticationRequired), None).await?;
+            Ok::<_, anyhow::Error>(())
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        server_result?;
+        match client_result? {
+            Err(AcseError::Aborted(AbortSource::ServiceUser, Some(AbortDiagnostic::AuthenticationRequired), None)) => (),
+            x => panic!("Expected an abort but got {:?}", x),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_reject_the_association() -> Result<(), anyhow::Error> {
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let acse_client = create_acse_initiator(test_address, AcseRequestInformation::default(), None).await?;
+            Ok::<_, anyhow::Error>(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await.map(|_| ()))
+        };
+        let server_path = async {
+            let (acse_listener, _) = create_acse_listener(test_address, Some(default_response()?), None).await?;
+            let (acse_responder, _) = acse_listener.responder().await?;
+            acse_responder.reject(AssociateResult::RejectedPermanent, AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::CallingApTitleNotRecognized), Some(vec![0xa9, 0x00])).await?;
+            Ok::<_, anyhow::Error>(())
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        server_result?;
+        match client_result? {
+            Err(AcseError::Rejected(AssociateResult::RejectedPermanent, AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::CallingApTitleNotRecognized), Some(user_data))) => assert_eq!(user_data, vec![0xa9, 0x00]),
+            x => panic!("Expected a rejection but got {:?}", x),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_keep_the_negotiated_context_results_on_a_rejection() -> Result<(), anyhow::Error> {
+        let ber = Oid::from(&[2, 1, 1])?;
+        let presentation_contexts = vec![
+            PresentationContext { identifier: vec![3], abstract_syntax_name: Oid::from(&[1, 0, 9506, 2, 1])?, transfer_syntax_name_list: vec![ber.clone()] },
+            PresentationContext { identifier: vec![5], abstract_syntax_name: Oid::from(&[1, 2, 3, 4])?, transfer_syntax_name_list: vec![Oid::from(&[1, 2, 3])?] },
+        ];
+        let accepted = PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: Some(ber), provider_reason: None };
+        let rejected = PresentationContextResult {
+            result: PresentationContextResultCause::ProviderRejection,
+            transfer_syntax_name: None,
+            provider_reason: Some(PresentationContextResultProviderReason::Value(PresentationContextResultProviderReasonValue::ProposedAbstrctSyntaxNotSupported)),
+        };
+        assert_eq!(rejected_context_results(presentation_contexts, None).await?, vec![accepted.clone(), accepted, rejected]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_answer_each_context_with_the_user_result() -> Result<(), anyhow::Error> {
+        let ber = Oid::from(&[2, 1, 1])?;
+        let presentation_contexts = vec![
+            PresentationContext { identifier: vec![3], abstract_syntax_name: Oid::from(&[1, 0, 9506, 2, 1])?, transfer_syntax_name_list: vec![ber.clone()] },
+            PresentationContext { identifier: vec![5], abstract_syntax_name: Oid::from(&[1, 2, 3, 4])?, transfer_syntax_name_list: vec![ber.clone()] },
+            PresentationContext { identifier: vec![7], abstract_syntax_name: Oid::from(&[1, 2, 3, 5])?, transfer_syntax_name_list: vec![ber.clone()] },
+        ];
+        let user_results = vec![AcsePresentationContextResult::Accepted, AcsePresentationContextResult::UserRejection, AcsePresentationContextResult::AbstractSyntaxNotSupported];
+        let accepted = PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: Some(ber), provider_reason: None };
+        let user_rejected = PresentationContextResult { result: PresentationContextResultCause::UserRejection, transfer_syntax_name: None, provider_reason: None };
+        let not_supported = PresentationContextResult {
+            result: PresentationContextResultCause::ProviderRejection,
+            transfer_syntax_name: None,
+            provider_reason: Some(PresentationContextResultProviderReason::Value(PresentationContextResultProviderReasonValue::AbstrctSyntaxNotSupported)),
+        };
+        assert_eq!(rejected_context_results(presentation_contexts, Some(user_results)).await?, vec![accepted.clone(), accepted, user_rejected, not_supported]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_reject_an_unsupported_application_context() -> Result<(), anyhow::Error> {
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let acse_client = create_acse_initiator(test_address, AcseRequestInformation { application_context_name: Oid::from(&[1, 2, 3, 4])?, ..Default::default() }, None).await?;
+            Ok::<_, anyhow::Error>(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await.map(|_| ()))
+        };
+        let server_path = async {
+            let (acse_listener, _) = create_acse_listener(test_address, None, None).await?;
+            Ok::<_, anyhow::Error>(acse_listener.responder().await.map(|_| ()))
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        let expected_diagnostic = AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::ApplicationContextNameNotSupported);
+        match server_result? {
+            Err(AcseError::Rejected(AssociateResult::RejectedPermanent, diagnostic, None)) => assert_eq!(diagnostic, expected_diagnostic),
+            x => panic!("Expected a rejection but got {:?}", x),
+        }
+        match client_result? {
+            Err(AcseError::Rejected(AssociateResult::RejectedPermanent, diagnostic, None)) => assert_eq!(diagnostic, expected_diagnostic),
+            x => panic!("Expected a rejection but got {:?}", x),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_accept_a_configured_application_context() -> Result<(), anyhow::Error> {
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let acse_client = create_acse_initiator(test_address, AcseRequestInformation { application_context_name: Oid::from(&[1, 2, 3, 4])?, ..Default::default() }, None).await?;
+            Ok::<_, anyhow::Error>(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await?)
+        };
+        let server_path = async {
+            let (mut acse_listener, _) = RustyOsiSingleValueAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_listener(test_address).await?).await?;
+            acse_listener.set_application_context_names(vec![mms_application_context_name(), Oid::from(&[1, 2, 3, 4])?]);
+            acse_listener.set_response(Some(default_response()?));
+            let (acse_responder, _) = acse_listener.responder().await?;
+            Ok::<_, anyhow::Error>(acse_responder.accept(vec![0xa9, 0x00]).await?)
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        server_result?;
+        let (_, _, accept_data) = client_result?;
+        assert_eq!(accept_data, vec![0xa9, 0x00]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_accept_a_valid_password() -> Result<(), anyhow::Error> {
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let acse_client = create_acse_initiator(test_address, password_request(Some(AuthenticationValue::CharString("Password".into()))), None).await?;
+            let (_, _, received_accept_data) = acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await?;
+            Ok::<_, anyhow::Error>(received_accept_data)
+        };
+        let server_path = async {
+            let (acse_listener, _) = create_acse_listener(test_address, Some(default_response()?), Some(Box::new(PasswordAuthenticator::new("Password")))).await?;
+            let (acse_responder, _) = acse_listener.responder().await?;
+            acse_responder.accept(vec![0xa9, 0x00]).await?;
+            Ok::<_, anyhow::Error>(())
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        server_result?;
+        assert_eq!(client_result?, vec![0xa9, 0x00]);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_reject_an_invalid_password() -> Result<(), anyhow::Error> {
+        for (authentication_value, expected_diagnostic) in [
+            (Some(AuthenticationValue::CharString("Wrong".into())), AssociateSourceDiagnosticUserCategory::AuthenticationFailure),
+            (None, AssociateSourceDiagnosticUserCategory::AuthenticationRequired),
+        ] {
+            let test_address = random_test_address()?;
+
+            let client_path = async {
+                tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+                let acse_client = create_acse_initiator(test_address, password_request(authentication_value), None).await?;
+                Ok::<_, anyhow::Error>(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await.map(|_| ()))
+            };
+            let server_path = async {
+                let (acse_listener, _) = create_acse_listener(test_address, Some(default_response()?), Some(Box::new(PasswordAuthenticator::new("Password")))).await?;
+                Ok::<_, anyhow::Error>(acse_listener.responder().await.map(|_| ()))
+            };
+
+            let (client_result, server_result) = join!(client_path, server_path);
+            match server_result? {
+                Err(AcseError::Rejected(AssociateResult::RejectedPermanent, AssociateSourceDiagnostic::User(diagnostic), None)) => assert_eq!(diagnostic, expected_diagnostic),
+                x => panic!("Expected a rejection but got {:?}", x),
+            }
+            match client_result? {
+                Err(AcseError::Rejected(AssociateResult::RejectedPermanent, AssociateSourceDiagnostic::User(diagnostic), None)) => assert_eq!(diagnostic, expected_diagnostic),
+                x => panic!("Expected a rejection but got {:?}", x),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_authenticate_with_an_iec62351_token() -> Result<(), anyhow::Error> {
+        let (ca_certificate, ca_key) = create_ca_certificate("Test CA")?;
+        let (client_certificate, client_key) = create_client_certificate("Test Client", &ca_certificate, &ca_key)?;
+        let authenticator = Iec62351Authenticator::new(vec![ca_certificate.der().to_vec()], Duration::from_secs(60))?;
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let credentials = Iec62351Credentials::new(client_certificate.der().to_vec(), client_key.serialize_der())?;
+            let acse_client = create_acse_initiator(test_address, AcseRequestInformation::default(), Some(Box::new(credentials))).await?;
+            let (_, _, received_accept_data) = acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await?;
+            Ok::<_, anyhow::Error>(received_accept_data)
+        };
+        let server_path = async {
+            let (acse_listener, received_request_information) = create_acse_listener(test_address, Some(default_response()?), Some(Box::new(authenticator.clone()))).await?;
+            let (acse_responder, _) = acse_listener.responder().await?;
+            acse_responder.accept(vec![0xa9, 0x00]).await?;
+            Ok::<_, anyhow::Error>(received_request_information)
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        let received_request_information = server_result?;
+        assert_eq!(client_result?, vec![0xa9, 0x00]);
+
+        // The same token must not be accepted twice, even with the signature re-encoded.
+        assert_eq!(authenticator.authenticate(&received_request_information), Err(AssociateSourceDiagnosticUserCategory::AuthenticationFailure));
+        let token = match &received_request_information.calling_authentication_value {
+            Some(AuthenticationValue::External(token)) => token,
+            x => panic!("Expected an IEC 62351 token but got {:?}", x),
+        };
+        let replayed_request_information = AcseRequestInformation { calling_authentication_value: Some(AuthenticationValue::External(with_high_s_signature(token)?)), ..received_request_information.clone() };
+        assert_ne!(received_request_information, replayed_request_information);
+        assert_eq!(authenticator.authenticate(&replayed_request_information), Err(AssociateSourceDiagnosticUserCategory::AuthenticationFailure));
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_reject_an_untrusted_iec62351_token() -> Result<(), anyhow::Error> {
+        let (ca_certificate, ca_key) = create_ca_certificate("Test CA")?;
+        let (other_ca_certificate, _) = create_ca_certificate("Other CA")?;
+        let (client_certificate, client_key) = create_client_certificate("Test Client", &ca_certificate, &ca_key)?;
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let credentials = Iec62351Credentials::new(client_certificate.der().to_vec(), client_key.serialize_der())?;
+            let acse_client = create_acse_initiator(test_address, AcseRequestInformation::default(), Some(Box::new(credentials))).await?;
+            Ok::<_, anyhow::Error>(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await.map(|_| ()))
+        };
+        let server_path = async {
+            let authenticator = Iec62351Authenticator::new(vec![other_ca_certificate.der().to_vec()], Duration::from_secs(60))?;
+            let (acse_listener, _) = create_acse_listener(test_address, Some(default_response()?), Some(Box::new(authenticator))).await?;
+            Ok::<_, anyhow::Error>(acse_listener.responder().await.map(|_| ()))
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        server_result?.expect_err("Expected the listener to reject the association");
+        match client_result? {
+            Err(AcseError::Rejected(AssociateResult::RejectedPermanent, AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::AuthenticationFailure), None)) => (),
+            x => panic!("Expected a rejection but got {:?}", x),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_reject_an_iec62351_token_from_an_invalid_issuer() -> Result<(), anyhow::Error> {
+        for (is_ca, not_after) in [(rcgen::IsCa::ExplicitNoCa, (2999, 1, 1)), (rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained), (2001, 1, 1))] {
+            let (issuer_certificate, issuer_key) = create_issuer_certificate("Test Issuer", is_ca, not_after)?;
+            let (client_certificate, client_key) = create_client_certificate("Test Client", &issuer_certificate, &issuer_key)?;
+            let test_address = random_test_address()?;
+
+            let client_path = async {
+                tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+                let credentials = Iec62351Credentials::new(client_certificate.der().to_vec(), client_key.serialize_der())?;
+                let acse_client = create_acse_initiator(test_address, AcseRequestInformation::default(), Some(Box::new(credentials))).await?;
+                Ok::<_, anyhow::Error>(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1])?, vec![0xa8, 0x00]).await.map(|_| ()))
+            };
+            let server_path = async {
+                let authenticator = Iec62351Authenticator::new(vec![issuer_certificate.der().to_vec()], Duration::from_secs(60))?;
+                let (acse_listener, _) = create_acse_listener(test_address, Some(default_response()?), Some(Box::new(authenticator))).await?;
+                Ok::<_, anyhow::Error>(acse_listener.responder().await.map(|_| ()))
+            };
+
+            let (client_result, server_result) = join!(client_path, server_path);
+            server_result?.expect_err("Expected the listener to reject the association");
+            match client_result? {
+                Err(AcseError::Rejected(AssociateResult::RejectedPermanent, AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::AuthenticationFailure), None)) => (),
+                x => panic!("Expected a rejection but got {:?}", x),
+            }
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_exchange_data_on_several_contexts() -> Result<(), anyhow::Error> {
+        let test_address = random_test_address()?;
+        let presentation_contexts = vec![
+            AcsePresentationContext { identifier: vec![3], abstract_syntax_name: Oid::from(&[1, 0, 9506, 2, 1])? },
+            AcsePresentationContext { identifier: vec![5], abstract_syntax_name: Oid::from(&[1, 2, 3, 4])? },
+        ];
+        let connect_data = vec![
+            AcseContextData { presentation_context_identifier: vec![3], data: vec![0xa8, 0x00] },
+            AcseContextData { presentation_context_identifier: vec![5], data: vec![0x30, 0x00] },
+        ];
+        let accept_data = vec![AcseContextData { presentation_context_identifier: vec![5], data: vec![0x31, 0x00] }];
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let acse_client = RustyOsiAcseInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_initiator(test_address).await?, AcseRequestInformation::default());
+            Ok(acse_client.initiate(presentation_contexts.clone(), connect_data.clone()).await?)
+        };
+        let server_path = async {
+            let (mut acse_listener, _) = RustyOsiAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_listener(test_address).await?).await?;
+            assert_eq!(acse_listener.presentation_contexts(), presentation_contexts.as_slice());
+            acse_listener.set_response(Some(default_response()?));
+            let (acse_responder, received_connect_data) = acse_listener.responder().await?;
+            Ok((acse_responder.accept(accept_data.clone()).await?, received_connect_data))
+        };
+        let (client, server): (Result<_, anyhow::Error>, Result<_, anyhow::Error>) = join!(client_path, server_path);
+        let (client, _, received_accept_data) = client?;
+        let (server, received_connect_data) = server?;
+        assert_eq!(connect_data, received_connect_data);
+        assert_eq!(accept_data, received_accept_data);
+
+        let (mut client_reader, mut client_writer) = client.split().await?;
+        let (mut server_reader, mut server_writer) = server.split().await?;
+
+        client_writer
+            .send(&mut VecDeque::from(vec![
+                AcseContextData { presentation_context_identifier: vec![5], data: vec![0x30, 0x01] },
+                AcseContextData { presentation_context_identifier: vec![3], data: vec![0xa0, 0x00] },
+            ]))
+            .await?;
+        assert_eq!(AcseContextRecvResult::Data(vec![AcseContextData { presentation_context_identifier: vec![5], data: vec![0x30, 0x01] }]), server_reader.recv().await?);
+        assert_eq!(AcseContextRecvResult::Data(vec![AcseContextData { presentation_context_identifier: vec![3], data: vec![0xa0, 0x00] }]), server_reader.recv().await?);
+
+        match server_writer.send(&mut VecDeque::from(vec![AcseContextData { presentation_context_identifier: vec![7], data: vec![0x30, 0x00] }])).await {
+            Err(AcseError::InternalError(_)) => (),
+            x => panic!("Expected an internal error for an unknown context but got {:?}", x),
+        }
+
+        client_writer.release(Some(ReleaseRequestReason::Normal), vec![AcseContextData { presentation_context_identifier: vec![5], data: vec![0x30, 0x02] }]).await?;
+        assert_eq!(AcseContextRecvResult::ReleaseRequest(Some(ReleaseRequestReason::Normal), vec![AcseContextData { presentation_context_identifier: vec![5], data: vec![0x30, 0x02] }]), server_reader.recv().await?);
+        server_writer.release_response(ReleaseResult::Affirmative, None, vec![]).await?;
+        assert_eq!(AcseContextRecvResult::ReleaseResponse(ReleaseResult::Affirmative, None, vec![]), client_reader.recv().await?);
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_only_exchange_data_on_accepted_contexts() -> Result<(), anyhow::Error> {
+        let test_address = random_test_address()?;
+        let presentation_contexts = vec![
+            AcsePresentationContext { identifier: vec![3], abstract_syntax_name: Oid::from(&[1, 0, 9506, 2, 1])? },
+            AcsePresentationContext { identifier: vec![5], abstract_syntax_name: Oid::from(&[1, 2, 3, 4])? },
+            AcsePresentationContext { identifier: vec![7], abstract_syntax_name: Oid::from(&[1, 2, 3, 5])? },
+        ];
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let acse_client = RustyOsiAcseInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_initiator(test_address).await?, AcseRequestInformation::default());
+            Ok(acse_client.initiate(presentation_contexts.clone(), vec![]).await?)
+        };
+        let server_path = async {
+            let (mut acse_listener, _) = RustyOsiAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_listener(test_address).await?).await?;
+            acse_listener.set_response(Some(default_response()?));
+            acse_listener.set_presentation_context_results(vec![AcsePresentationContextResult::Accepted, AcsePresentationContextResult::UserRejection, AcsePresentationContextResult::AbstractSyntaxNotSupported]);
+            let (acse_responder, _) = acse_listener.responder().await?;
+            Ok(acse_responder.accept(vec![]).await?)
+        };
+        let (client, server): (Result<_, anyhow::Error>, Result<_, anyhow::Error>) = join!(client_path, server_path);
+        let (client, _, _) = client?;
+        let (_, mut client_writer) = client.split().await?;
+        let (mut server_reader, mut server_writer) = server?.split().await?;
+
+        for identifier in [vec![5], vec![7]] {
+            match server_writer.send(&mut VecDeque::from(vec![AcseContextData { presentation_context_identifier: identifier, data: vec![0x30, 0x00] }])).await {
+                Err(AcseError::InternalError(_)) => (),
+                x => panic!("Expected an internal error for a rejected context but got {:?}", x),
+            }
+        }
+        client_writer.send(&mut VecDeque::from(vec![AcseContextData { presentation_context_identifier: vec![3], data: vec![0xa0, 0x00] }])).await?;
+        assert_eq!(AcseContextRecvResult::Data(vec![AcseContextData { presentation_context_identifier: vec![3], data: vec![0xa0, 0x00] }]), server_reader.recv().await?);
+        client_writer.send(&mut VecDeque::from(vec![AcseContextData { presentation_context_identifier: vec![5], data: vec![0x30, 0x00] }])).await?;
+        match server_reader.recv().await {
+            Err(AcseError::ProtocolError(_)) => (),
+            x => panic!("Expected a protocol error for data on a rejected context but got {:?}", x),
+        }
+
+        Ok(())
+    }
+
+    fn create_ca_certificate(name: &str) -> Result<(rcgen::Certificate, rcgen::KeyPair), anyhow::Error> {
+        let key = rcgen::KeyPair::generate()?;
+        let mut params = rcgen::CertificateParams::new(vec![])?;
+        params.is_ca = rcgen::IsCa::Ca(rcgen::BasicConstraints::Unconstrained);
+        params.distinguished_name.push(rcgen::DnType::CommonName, name);
+        Ok((params.self_signed(&key)?, key))
+    }
+
+    fn create_issuer_certificate(name: &str, is_ca: rcgen::IsCa, not_after: (i32, u8, u8)) -> Result<(rcgen::Certificate, rcgen::KeyPair), anyhow::Error> {
+        let key = rcgen::KeyPair::generate()?;
+        let mut params = rcgen::CertificateParams::new(vec![])?;
+        params.is_ca = is_ca;
+        params.not_before = rcgen::date_time_ymd(2000, 1, 1);
+        params.not_after = rcgen::date_time_ymd(not_after.0, not_after.1, not_after.2);
+        params.distinguished_name.push(rcgen::DnType::CommonName, name);
+        Ok((params.self_signed(&key)?, key))
+    }
+
+    /// Replaces the ECDSA P-256 signature of a token with the equally valid (r, n - s) signature.
+    fn with_high_s_signature(token: &[u8]) -> Result<Vec<u8>, anyhow::Error> {
+        let order = BigInt::parse_bytes(b"FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632551", 16).ok_or_else(|| anyhow!("Failed to parse the P-256 order"))?;
+        let (_, token) = der_parser::ber::parse_ber_any(token)?;
+        let mut content = vec![];
+        let mut remaining = token.data;
+        while !remaining.is_empty() {
+            let (rest, part) = der_parser::ber::parse_ber_any(remaining)?;
+            match part.header.raw_tag() {
+                Some(&[0x82]) => {
+                    let (_, signature) = der_parser::parse_ber(part.data)?;
+                    let [r, s] = signature.as_sequence()?.as_slice() else { return Err(anyhow!("Expected an ECDSA signature")) };
+                    let integers = [tlv(0x02, &r.as_bigint()?.to_signed_bytes_be()), tlv(0x02, &(&order - s.as_bigint()?).to_signed_bytes_be())].concat();
+                    content.extend(tlv(0x82, &tlv(0x30, &integers)));
+                }
+                _ => content.extend(&remaining[..remaining.len() - rest.len()]),
+            }
+            remaining = rest;
+        }
+        Ok(tlv(0xa0, &content))
+    }
+
+    fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+        let length = match content.len() {
+            length if length < 0x80 => vec![length as u8],
+            length if length < 0x100 => vec![0x81, length as u8],
+            length => vec![0x82, (length >> 8) as u8, length as u8],
+        };
+        [vec![tag], length, content.to_vec()].concat()
+    }
+
+    fn create_client_certificate(name: &str, ca_certificate: &rcgen::Certificate, ca_key: &rcgen::KeyPair) -> Result<(rcgen::Certificate, rcgen::KeyPair), anyhow::Error> {
+        let key = rcgen::KeyPair::generate()?;
+        let mut params = rcgen::CertificateParams::new(vec![])?;
+        params.distinguished_name.push(rcgen::DnType::CommonName, name);
+        Ok((params.signed_by(&key, ca_certificate, ca_key)?, key))
+    }
+
+    fn password_request(authentication_value: Option<AuthenticationValue>) -> AcseRequestInformation {
+        AcseRequestInformation {
+            sender_acse_requirements: Some(AcseRequirements { authentication: true, application_context_negotiation: false }),
+            mechanism_name: Some(PasswordAuthenticator::mechanism_name()),
+            calling_authentication_value: authentication_value,
+            ..Default::default()
+        }
+    }
+
+    fn default_response() -> Result<AcseResponseInformation, anyhow::Error> {
+        Ok(AcseResponseInformation {
+            associate_result: AssociateResult::Accepted,
+            associate_source_diagnostic: AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::Null),
+            application_context_name: mms_application_context_name(),
+            responding_ap_title: None,
+            responding_ae_qualifier: None,
+            responding_ap_invocation_identifier: None,
+            responding_ae_invocation_identifier: None,
+            responder_acse_requirements: None,
+            mechanism_name: None,
+            responding_authentication_value: None,
+            implementation_information: None,
+        })
+    }
+
     async fn create_acse_connection_pair_with_options(
         reqeust_options: AcseRequestInformation,
         response_options: AcseResponseInformation,
         connect_data: Vec<u8>,
         accept_data: Vec<u8>,
     ) -> Result<(impl OsiSingleValueAcseConnection, impl OsiSingleValueAcseConnection), anyhow::Error> {
-        // let test_address = format!("127.0.0.1:{}", rand::random_range::<u16, Range<u16>>(20000..30000)).parse()?;
-        let test_address = "127.0.0.1:10002".parse()?;
-
-        let connect_information = CotpProtocolInformation::initiator(None, None);
+        let test_address = random_test_address()?;
 
         let client_path = async {
             tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
-            let tpkt_client = TcpTpktConnection::connect(test_address).await?;
-            let cotp_client = RustyCotpConnection::<TcpTpktReader, TcpTpktWriter>::initiate(tpkt_client, connect_information.clone(), Default::default()).await?;
-            let cosp_client = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, CospProtocolInformation::new(None, None), Default::default()).await?;
-            let copp_client = RustyCoppInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_client, Default::default());
-            let acse_client = RustyOsiSingleValueAcseInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(copp_client, reqeust_options.clone());
+            let acse_client = create_acse_initiator(test_address, reqeust_options.clone(), None).await?;
             Ok(acse_client.initiate(Oid::from(&[1, 0, 9506, 2, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?, connect_data.clone()).await?)
         };
         let server_path = async {
-            let tpkt_server = TcpTpktServer::listen(test_address).await?;
-            let tpkt_connection = tpkt_server.accept().await?;
-            let (cotp_server, initiator_info) = RustyCotpResponder::<TcpTpktReader, TcpTpktWriter>::new(tpkt_connection, Default::default()).await?;
-            let cotp_connection = cotp_server.accept(initiator_info.responder()).await?;
-            let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_connection, CospConnectionParameters::default()).await?;
-            let (copp_listener, _) =
-                RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(cosp_listener)
-                    .await?;
-            let (mut acse_listener, received_request_information) = RustyOsiSingleValueAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(copp_listener).await?;
-            acse_listener.set_response(Some(response_options.clone()));
+            let (acse_listener, received_request_information) = create_acse_listener(test_address, Some(response_options.clone()), None).await?;
             let (acse_responder, received_connect_data) = acse_listener.responder().await?;
 
             Ok((acse_responder.accept(accept_data.clone()).await?, received_request_information, received_connect_data))
@@ -117,4 +703,87 @@ mod tests {
 
         Ok((copp_client, copp_server))
     }
+
+    /// Proposes the contexts alongside the ACSE context and returns the results the listener sends when it rejects the association.
+    async fn rejected_context_results(presentation_contexts: Vec<PresentationContext>, user_results: Option<Vec<AcsePresentationContextResult>>) -> Result<Vec<PresentationContextResult>, anyhow::Error> {
+        let test_address = random_test_address()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let copp_client = create_copp_initiator(test_address).await?;
+            let acse_context = PresentationContext { identifier: vec![1], abstract_syntax_name: Oid::from(&[2, 2, 1, 0, 1])?, transfer_syntax_name_list: vec![Oid::from(&[2, 1, 1])?] };
+            let request = UserData::FullyEncoded(vec![PresentationDataValueList {
+                transfer_syntax_name: None,
+                presentation_context_identifier: vec![1],
+                presentation_data_values: PresentationDataValues::SingleAsn1Type(AcseRequestInformation::default().serialise(&None)?),
+            }]);
+            Ok::<_, anyhow::Error>(copp_client.initiate(PresentationContextType::ContextDefinitionList([vec![acse_context], presentation_contexts].concat()), Some(request)).await.map(|_| ()))
+        };
+        let server_path = async {
+            let (mut acse_listener, _) = RustyOsiAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_listener(test_address).await?).await?;
+            acse_listener.set_response(Some(default_response()?));
+            if let Some(user_results) = user_results {
+                acse_listener.set_presentation_context_results(user_results);
+            }
+            let (acse_responder, _) = acse_listener.responder().await?;
+            acse_responder.reject(AssociateResult::RejectedTransient, AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::NoReasonGiven), vec![]).await?;
+            Ok::<_, anyhow::Error>(())
+        };
+
+        let (client_result, server_result) = join!(client_path, server_path);
+        server_result?;
+        match client_result? {
+            Err(CoppError::Rejected(None, PresentationContextResultType::ContextDefinitionList(results), Some(_))) => Ok(results),
+            x => panic!("Expected a presentation rejection but got {:?}", x),
+        }
+    }
+
+    fn random_test_address() -> Result<SocketAddr, anyhow::Error> {
+        Ok(format!("127.0.0.1:{}", rand::random_range::<u16, Range<u16>>(20000..30000)).parse()?)
+    }
+
+    async fn create_acse_initiator(test_address: SocketAddr, request_options: AcseRequestInformation, credentials: Option<Box<dyn AcseCredentials>>) -> Result<impl OsiSingleValueAcseInitiator, anyhow::Error> {
+        let mut acse_client = RustyOsiSingleValueAcseInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_initiator(test_address).await?, request_options);
+        acse_client.set_credentials(credentials);
+        Ok(acse_client)
+    }
+
+    async fn create_acse_listener(
+        test_address: SocketAddr,
+        response_options: Option<AcseResponseInformation>,
+        authenticator: Option<Box<dyn AcseAuthenticator>>,
+    ) -> Result<(impl OsiSingleValueAcseListener, AcseRequestInformation), anyhow::Error> {
+        let (mut acse_listener, received_request_information) = RustyOsiSingleValueAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(create_copp_listener(test_address).await?).await?;
+        acse_listener.set_response(response_options);
+        acse_listener.set_authenticator(authenticator);
+        Ok((acse_listener, received_request_information))
+    }
+
+    async fn create_copp_initiator(test_address: SocketAddr) -> Result<impl CoppInitiator, anyhow::Error> {
+        let connect_information = CotpProtocolInformation::initiator(None, None);
+        let tpkt_client = TcpTpktConnection::connect(test_address).await?;
+        let cotp_client = RustyCotpConnection::<TcpTpktReader, TcpTpktWriter>::initiate(tpkt_client, connect_information, Default::default()).await?;
+        let cosp_client = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, CospProtocolInformation::new(None, None), cosp_parameters()).await?;
+        Ok(RustyCoppInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_client, Default::default(), Default::default()))
+    }
+
+    async fn create_copp_listener(test_address: SocketAddr) -> Result<impl CoppListener, anyhow::Error> {
+        let tpkt_server = TcpTpktServer::listen(test_address).await?;
+        let tpkt_connection = tpkt_server.accept().await?;
+        let (cotp_server, initiator_info) = RustyCotpResponder::<TcpTpktReader, TcpTpktWriter>::new(tpkt_connection, Default::default()).await?;
+        let cotp_connection = cotp_server.accept(initiator_info.responder()).await?;
+        let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_connection, cosp_parameters()).await?;
+        let (copp_listener, _) =
+            RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(
+                cosp_listener,
+                Default::default(),
+            )
+            .await?;
+        Ok(copp_listener)
+    }
+
+    /// Negotiates the release session functional unit so a release can be refused.
+    fn cosp_parameters() -> CospConnectionParameters {
+        CospConnectionParameters { functional_units: CospFunctionalUnits { negotiated_release: true, ..Default::default() }, ..Default::default() }
+    }
 }
@@ -1,6 +1,7 @@
 use std::fmt::Debug;
 
 use der_parser::{
+    Oid,
     asn1_rs::Any,
     ber::{BerObjectContent, BitStringObject, parse_ber_any, parse_ber_content, parse_ber_integer, parse_ber_oid},
     der::{Class, Tag},
@@ -9,13 +10,16 @@ use der_parser::{
 };
 use tracing::warn;
 
-use crate::{AcseError, AcseRequestInformation, AcseResponseInformation, AeQualifier, ApTitle, AssociateResult, AssociateSourceDiagnostic};
+use crate::{
+    AbortDiagnostic, AbortSource, AcseError, AcseRequestInformation, AcseRequirements, AcseResponseInformation, AeQualifier, ApTitle, AssociateResult, AssociateSourceDiagnostic, AttributeTypeAndValue, AuthenticationValue,
+    RelativeDistinguishedName, ReleaseRequestReason, ReleaseResponseReason,
+};
 
 pub(crate) fn to_acse_error<T: Debug>(message: &str) -> impl FnOnce(T) -> AcseError {
     move |error| AcseError::ProtocolError(format!("{}: {:?}", message, error))
 }
 
-pub(crate) fn process_request(data: &[u8]) -> Result<(AcseRequestInformation, Vec<u8>), AcseError> {
+pub(crate) fn process_request(data: &[u8]) -> Result<(AcseRequestInformation, Vec<External>), AcseError> {
     let mut version = BitStringObject { data: &[0x80] }; // Default protocol version 1
     let mut application_context_name = None;
     let mut called_ap_title = None;
@@ -26,8 +30,11 @@ pub(crate) fn process_request(data: &[u8]) -> Result<(AcseRequestInformation, Ve
     let mut calling_ae_qualifier = None;
     let mut calling_ap_invocation_identifier = None;
     let mut calling_ae_invocation_identifier = None;
+    let mut sender_acse_requirements = None;
+    let mut mechanism_name = None;
+    let mut calling_authentication_value = None;
     let mut implementation_information = None;
-    let mut payload_user_data = None;
+    let mut payload_user_data = vec![];
 
     let (_, pdu) = parse_ber_any(&data).map_err(to_acse_error("Failed to parse ACSE Request"))?;
     pdu.header.assert_class(Class::Application).map_err(to_acse_error("Expected ACSE Request was not found"))?;
@@ -50,29 +57,12 @@ pub(crate) fn process_request(data: &[u8]) -> Result<(AcseRequestInformation, Ve
             Some(&[168]) => calling_ap_invocation_identifier = Some(process_integer(pdu_part.data, "Failed to parse Calling AP Invocation Identifier in ACSE Request")?),
             Some(&[169]) => calling_ae_invocation_identifier = Some(process_integer(pdu_part.data, "Failed to parse Calling AE Invocation Identifier in ACSE Request")?),
 
+            Some(&[138]) => sender_acse_requirements = Some(process_acse_requirements(pdu_part, "Failed to parse Sender ACSE Requirements in ACSE Request")?),
+            Some(&[139]) => mechanism_name = Some(process_oid_context(pdu_part, "Failed to parse Mechanism Name in ACSE Request")?),
+            Some(&[172]) => calling_authentication_value = Some(process_authentication_value(pdu_part.data, "Failed to parse Calling Authentication Value in ACSE Request")?),
+
             Some(&[157]) => implementation_information = Some(process_graphical_string(pdu_part, "Failed to parse Implementation Information in ACSE Request")?),
-            Some(&[190]) => {
-                for user_data_part in process_constructed_data(pdu_part.data).map_err(to_acse_error("Failed to deconstruct UserInformation on ACSE Request".into()))? {
-                    match user_data_part.header.raw_tag() {
-                        Some(&[40]) => {
-                            for single_value_part in process_constructed_data(user_data_part.data).map_err(to_acse_error("Failed to deconstruct Single Value part in ACSE Request"))? {
-                                let mut context_id = None;
-                                match single_value_part.header.raw_tag() {
-                                    Some(&[2]) => context_id = Some(process_integer_context(single_value_part, "Failed to parse context id from Single Value part in ACSE Request")?),
-                                    Some(&[160]) => payload_user_data = Some(single_value_part.data.to_vec()),
-                                    x => warn!("Unknown tag in ACSE Request User Data Single Value part: {:?}", x),
-                                }
-                                if let Some(cid) = context_id
-                                    && cid != &[3]
-                                {
-                                    return Err(AcseError::ProtocolError(format!("Incorrect context id found for User Data in ACSE Request: Expected [3] but got {:?}", cid)));
-                                }
-                            }
-                        }
-                        x => warn!("Unknown tag in ACSE Request User Data: {:?}", x),
-                    };
-                }
-            }
+            Some(&[190]) => payload_user_data = process_user_information(pdu_part.data, "ACSE Request")?,
             x => warn!("Unexpected tag in ACSE Request: {:?}", x),
         }
     }
@@ -80,10 +70,6 @@ pub(crate) fn process_request(data: &[u8]) -> Result<(AcseRequestInformation, Ve
     if !version.is_set(0) {
         return Err(AcseError::ProtocolError("Unsupported ACSE version requested".into()));
     }
-    let payload_user_data = match payload_user_data {
-        Some(payload_user_data) => payload_user_data,
-        None => return Err(AcseError::ProtocolError("No User Data found on ACSE Request".into())),
-    };
 
     Ok((
         AcseRequestInformation {
@@ -100,13 +86,16 @@ pub(crate) fn process_request(data: &[u8]) -> Result<(AcseRequestInformation, Ve
             calling_ae_qualifier,
             calling_ap_invocation_identifier,
             calling_ae_invocation_identifier,
+            sender_acse_requirements,
+            mechanism_name,
+            calling_authentication_value,
             implementation_information,
         },
         payload_user_data,
     ))
 }
 
-pub(crate) fn process_response(data: &[u8]) -> Result<(AcseResponseInformation, Vec<u8>), AcseError> {
+pub(crate) fn process_response(data: &[u8]) -> Result<(AcseResponseInformation, Vec<External>), AcseError> {
     let mut version = BitStringObject { data: &[0x80] }; // Default protocol version 1
     let mut response_results = None;
     let mut response_diagnostics = None;
@@ -115,8 +104,11 @@ pub(crate) fn process_response(data: &[u8]) -> Result<(AcseResponseInformation,
     let mut responding_ae_qualifier = None;
     let mut responding_ap_invocation_identifier = None;
     let mut responding_ae_invocation_identifier = None;
+    let mut responder_acse_requirements = None;
+    let mut mechanism_name = None;
+    let mut responding_authentication_value = None;
     let mut implementation_information = None;
-    let mut payload_user_data = None;
+    let mut payload_user_data = vec![];
 
     let (_, pdu) = parse_ber_any(&data).map_err(to_acse_error("Failed to parse ACSE Response"))?;
     pdu.header.assert_class(Class::Application).map_err(to_acse_error("Expected ACSE Response was not found"))?;
@@ -136,29 +128,12 @@ pub(crate) fn process_response(data: &[u8]) -> Result<(AcseResponseInformation,
             Some(&[166]) => responding_ap_invocation_identifier = Some(process_integer(pdu_part.data, "Failed to parse Called AP Invocation Identifier in ACSE Response")?),
             Some(&[167]) => responding_ae_invocation_identifier = Some(process_integer(pdu_part.data, "Failed to parse Called AE Invocation Identifier in ACSE Response")?),
 
+            Some(&[136]) => responder_acse_requirements = Some(process_acse_requirements(pdu_part, "Failed to parse Responder ACSE Requirements in ACSE Response")?),
+            Some(&[137]) => mechanism_name = Some(process_oid_context(pdu_part, "Failed to parse Mechanism Name in ACSE Response")?),
+            Some(&[170]) => responding_authentication_value = Some(process_authentication_value(pdu_part.data, "Failed to parse Responding Authentication Value in ACSE Response")?),
+
             Some(&[157]) => implementation_information = Some(process_graphical_string(pdu_part, "Failed to parse Implementation Information in ACSE Response")?),
-            Some(&[190]) => {
-                for user_data_part in process_constructed_data(pdu_part.data).map_err(to_acse_error("Failed to deconstruct UserInformation on ACSE Response".into()))? {
-                    match user_data_part.header.raw_tag() {
-                        Some(&[40]) => {
-                            for single_value_part in process_constructed_data(user_data_part.data).map_err(to_acse_error("Failed to deconstruct Single Value part in ACSE Response"))? {
-                                let mut context_id = None;
-                                match single_value_part.header.raw_tag() {
-                                    Some(&[2]) => context_id = Some(process_integer_context(single_value_part, "Failed to parse context id from Single Value part in ACSE Response")?),
-                                    Some(&[160]) => payload_user_data = Some(single_value_part.data.to_vec()),
-                                    x => warn!("Unknown tag in ACSE Response User Data Single Value part: {:?}", x),
-                                }
-                                if let Some(cid) = context_id
-                                    && cid != &[3]
-                                {
-                                    return Err(AcseError::ProtocolError(format!("Incorrect context id found for User Data in ACSE Response: Expected [3] but got {:?}", cid)));
-                                }
-                            }
-                        }
-                        x => warn!("Unknown tag in ACSE Response User Data: {:?}", x),
-                    };
-                }
-            }
+            Some(&[190]) => payload_user_data = process_user_information(pdu_part.data, "ACSE Response")?,
             x => warn!("Unexpected tag in ACSE Response: {:?}", x),
         }
     }
@@ -166,10 +141,6 @@ pub(crate) fn process_response(data: &[u8]) -> Result<(AcseResponseInformation,
     if !version.is_set(0) {
         return Err(AcseError::ProtocolError("Unsupported ACSE version requested".into()));
     }
-    let payload_user_data = match payload_user_data {
-        Some(payload_user_data) => payload_user_data,
-        None => return Err(AcseError::ProtocolError("No User Data found on ACSE Request".into())),
-    };
 
     Ok((
         AcseResponseInformation {
@@ -184,12 +155,141 @@ pub(crate) fn process_response(data: &[u8]) -> Result<(AcseResponseInformation,
             responding_ae_qualifier,
             responding_ap_invocation_identifier,
             responding_ae_invocation_identifier,
+            responder_acse_requirements,
+            mechanism_name,
+            responding_authentication_value,
             implementation_information,
         },
         payload_user_data,
     ))
 }
 
+pub(crate) fn process_release_request(data: &[u8]) -> Result<(Option<ReleaseRequestReason>, Vec<External>), AcseError> {
+    let mut reason = None;
+    let mut payload_user_data = vec![];
+
+    let (_, pdu) = parse_ber_any(data).map_err(to_acse_error("Failed to parse ACSE Release Request"))?;
+    pdu.header.assert_class(Class::Application).map_err(to_acse_error("Expected ACSE Release Request was not found"))?;
+    pdu.header.assert_tag(Tag::from(2)).map_err(to_acse_error("Expected ACSE Release Request was not found"))?;
+    pdu.header.assert_constructed().map_err(to_acse_error("Expected ACSE Release Request was not found"))?;
+    let pdu_parts = process_constructed_data(pdu.data).map_err(to_acse_error("Could not deconstruct ACSE Release Request"))?;
+
+    for pdu_part in pdu_parts {
+        match pdu_part.header.raw_tag() {
+            Some(&[128]) => {
+                reason = Some(match process_integer_context(pdu_part, "Failed to parse Reason in ACSE Release Request")?.as_slice() {
+                    [0] => ReleaseRequestReason::Normal,
+                    [1] => ReleaseRequestReason::Urgent,
+                    [30] => ReleaseRequestReason::UserDefined,
+                    x => ReleaseRequestReason::Unknown(x.to_vec()),
+                })
+            }
+            Some(&[190]) => payload_user_data = process_user_information(pdu_part.data, "ACSE Release Request")?,
+            x => warn!("Unexpected tag in ACSE Release Request: {:?}", x),
+        }
+    }
+    Ok((reason, payload_user_data))
+}
+
+pub(crate) fn process_release_response(data: &[u8]) -> Result<(Option<ReleaseResponseReason>, Vec<External>), AcseError> {
+    let mut reason = None;
+    let mut payload_user_data = vec![];
+
+    let (_, pdu) = parse_ber_any(data).map_err(to_acse_error("Failed to parse ACSE Release Response"))?;
+    pdu.header.assert_class(Class::Application).map_err(to_acse_error("Expected ACSE Release Response was not found"))?;
+    pdu.header.assert_tag(Tag::from(3)).map_err(to_acse_error("Expected ACSE Release Response was not found"))?;
+    pdu.header.assert_constructed().map_err(to_acse_error("Expected ACSE Release Response was not found"))?;
+    let pdu_parts = process_constructed_data(pdu.data).map_err(to_acse_error("Could not deconstruct ACSE Release Response"))?;
+
+    for pdu_part in pdu_parts {
+        match pdu_part.header.raw_tag() {
+            Some(&[128]) => {
+                reason = Some(match process_integer_context(pdu_part, "Failed to parse Reason in ACSE Release Response")?.as_slice() {
+                    [0] => ReleaseResponseReason::Normal,
+                    [1] => ReleaseResponseReason::NotFinished,
+                    [30] => ReleaseResponseReason::UserDefined,
+                    x => ReleaseResponseReason::Unknown(x.to_vec()),
+                })
+            }
+            Some(&[190]) => payload_user_data = process_user_information(pdu_part.data, "ACSE Release Response")?,
+            x => warn!("Unexpected tag in ACSE Release Response: {:?}", x),
+        }
+    }
+    Ok((reason, payload_user_data))
+}
+
+pub(crate) fn process_abort(data: &[u8]) -> Result<(AbortSource, Option<AbortDiagnostic>, Vec<External>), AcseError> {
+    let mut source = None;
+    let mut diagnostic = None;
+    let mut payload_user_data = vec![];
+
+    let (_, pdu) = parse_ber_any(data).map_err(to_acse_error("Failed to parse ACSE Abort"))?;
+    pdu.header.assert_class(Class::Application).map_err(to_acse_error("Expected ACSE Abort was not found"))?;
+    pdu.header.assert_tag(Tag::from(4)).map_err(to_acse_error("Expected ACSE Abort was not found"))?;
+    pdu.header.assert_constructed().map_err(to_acse_error("Expected ACSE Abort was not found"))?;
+    let pdu_parts = process_constructed_data(pdu.data).map_err(to_acse_error("Could not deconstruct ACSE Abort"))?;
+
+    for pdu_part in pdu_parts {
+        match pdu_part.header.raw_tag() {
+            Some(&[128]) => {
+                source = Some(match process_integer_context(pdu_part, "Failed to parse Source in ACSE Abort")?.as_slice() {
+                    [0] => AbortSource::ServiceUser,
+                    [1] => AbortSource::ServiceProvider,
+                    x => AbortSource::Unknown(x.to_vec()),
+                })
+            }
+            Some(&[129]) => {
+                diagnostic = Some(match process_integer_context(pdu_part, "Failed to parse Diagnostic in ACSE Abort")?.as_slice() {
+                    [1] => AbortDiagnostic::NoReasonGiven,
+                    [2] => AbortDiagnostic::ProtocolError,
+                    [3] => AbortDiagnostic::AuthenticationMechanismNameNotRecognized,
+                    [4] => AbortDiagnostic::AuthenticationMechanismNameRequired,
+                    [5] => AbortDiagnostic::AuthenticationFailure,
+                    [6] => AbortDiagnostic::AuthenticationRequired,
+                    x => AbortDiagnostic::Unknown(x.to_vec()),
+                })
+            }
+            Some(&[190]) => payload_user_data = process_user_information(pdu_part.data, "ACSE Abort")?,
+            x => warn!("Unexpected tag in ACSE Abort: {:?}", x),
+        }
+    }
+    match source {
+        Some(source) => Ok((source, diagnostic, payload_user_data)),
+        None => Err(AcseError::ProtocolError("No Source was found on ACSE Abort".into())),
+    }
+}
+
+/// An EXTERNAL from the user information of an ACSE APDU. The indirect reference is the presentation context that the data belongs to.
+pub(crate) struct External {
+    pub(crate) indirect_reference: Option<Vec<u8>>,
+    pub(crate) data: Vec<u8>,
+}
+
+pub(crate) fn process_user_information(data: &[u8], pdu_name: &str) -> Result<Vec<External>, AcseError> {
+    let mut externals = vec![];
+    for user_data_part in process_constructed_data(data).map_err(to_acse_error(&format!("Failed to deconstruct UserInformation on {pdu_name}")))? {
+        match user_data_part.header.raw_tag() {
+            Some(&[40]) => {
+                let mut indirect_reference = None;
+                let mut payload_user_data = None;
+                for single_value_part in process_constructed_data(user_data_part.data).map_err(to_acse_error(&format!("Failed to deconstruct Single Value part in {pdu_name}")))? {
+                    match single_value_part.header.raw_tag() {
+                        Some(&[2]) => indirect_reference = Some(process_integer_context(single_value_part, &format!("Failed to parse context id from Single Value part in {pdu_name}"))?),
+                        Some(&[160]) => payload_user_data = Some(single_value_part.data.to_vec()),
+                        x => warn!("Unknown tag in {pdu_name} User Data Single Value part: {:?}", x),
+                    }
+                }
+                match payload_user_data {
+                    Some(data) => externals.push(External { indirect_reference, data }),
+                    None => return Err(AcseError::ProtocolError(format!("No Single Value was found in the User Data of {pdu_name}"))),
+                }
+            }
+            x => warn!("Unknown tag in {pdu_name} User Data: {:?}", x),
+        };
+    }
+    Ok(externals)
+}
+
 pub(crate) fn process_constructed_data<'a>(data: &'a [u8]) -> Result<Vec<Any<'a>>, BerError> {
     let mut remaining = data;
     let mut results = vec![];
@@ -229,6 +329,39 @@ pub(crate) fn process_integer_context<'a>(npm_object: Any<'a>, error_message: &s
     }
 }
 
+pub(crate) fn process_oid_context<'a>(npm_object: Any<'a>, error_message: &str) -> Result<Oid<'static>, AcseError> {
+    let (_, inner_object) = parse_ber_content(Tag::Oid)(npm_object.data, &npm_object.header, npm_object.data.len()).map_err(to_acse_error(error_message))?;
+
+    match inner_object {
+        BerObjectContent::OID(value) => Ok(value.to_owned()),
+        _ => Err(AcseError::ProtocolError(error_message.into())),
+    }
+}
+
+pub(crate) fn process_acse_requirements<'a>(npm_object: Any<'a>, error_message: &str) -> Result<AcseRequirements, AcseError> {
+    let requirements = process_bitstring(npm_object, error_message)?;
+    Ok(AcseRequirements { authentication: requirements.is_set(0), application_context_negotiation: requirements.is_set(1) })
+}
+
+pub(crate) fn process_authentication_value(data: &[u8], error_message: &str) -> Result<AuthenticationValue, AcseError> {
+    let (_, value) = parse_ber_any(data).map_err(to_acse_error(error_message))?;
+
+    match value.header.raw_tag() {
+        Some(&[128]) => Ok(AuthenticationValue::CharString(process_graphical_string(value, error_message)?)),
+        Some(&[129]) => Ok(AuthenticationValue::BitString(process_bitstring(value, error_message)?.data.to_vec())),
+        Some(&[162]) => {
+            for external_part in process_constructed_data(value.data).map_err(to_acse_error(error_message))? {
+                match external_part.header.raw_tag() {
+                    Some(&[160]) => return Ok(AuthenticationValue::External(external_part.data.to_vec())),
+                    x => warn!("Unsupported tag in external authentication value: {:?}", x),
+                }
+            }
+            Err(AcseError::ProtocolError(format!("{error_message}: No single ASN.1 type was found in the external authentication value")))
+        }
+        x => Err(AcseError::ProtocolError(format!("{error_message}: Unsupported authentication value tag {:?}", x))),
+    }
+}
+
 pub(crate) fn process_graphical_string<'a>(npm_object: Any<'a>, error_message: &str) -> Result<String, AcseError> {
     let (_, inner_object) = parse_ber_content(Tag::GeneralString)(npm_object.data, &npm_object.header, npm_object.data.len()).map_err(to_acse_error(error_message))?;
 
@@ -239,8 +372,13 @@ pub(crate) fn process_graphical_string<'a>(npm_object: Any<'a>, error_message: &
 }
 
 pub(crate) fn process_ap_title<'a>(npm_object: Any<'a>, error_message: &str) -> Result<ApTitle, AcseError> {
-    let (_, ap_title) = parse_ber(npm_object.data).map_err(to_acse_error(error_message))?;
+    let (_, ap_title) = parse_ber_any(npm_object.data).map_err(to_acse_error(error_message))?;
+    if ap_title.header.tag() == Tag::Sequence {
+        let name = process_constructed_data(ap_title.data).map_err(to_acse_error(error_message))?;
+        return Ok(ApTitle::Form1(name.into_iter().map(|x| process_relative_distinguished_name(x, error_message)).collect::<Result<Vec<RelativeDistinguishedName>, AcseError>>()?));
+    }
 
+    let (_, ap_title) = parse_ber(npm_object.data).map_err(to_acse_error(error_message))?;
     match (ap_title.header.tag(), ap_title.content) {
         (Tag::Oid, BerObjectContent::OID(oid)) => Ok(ApTitle::Form2(oid.to_owned())),
         (_, _) => Err(AcseError::ProtocolError(error_message.into())),
@@ -248,14 +386,36 @@ pub(crate) fn process_ap_title<'a>(npm_object: Any<'a>, error_message: &str) ->
 }
 
 pub(crate) fn process_ae_qualifier<'a>(npm_object: Any<'a>, error_message: &str) -> Result<AeQualifier, AcseError> {
-    let (_, ap_title) = parse_ber(npm_object.data).map_err(to_acse_error(error_message))?;
+    let (_, ae_qualifier) = parse_ber_any(npm_object.data).map_err(to_acse_error(error_message))?;
+    if ae_qualifier.header.tag() == Tag::Set {
+        return Ok(AeQualifier::Form1(process_relative_distinguished_name(ae_qualifier, error_message)?));
+    }
 
-    match (ap_title.header.tag(), ap_title.content) {
+    let (_, ae_qualifier) = parse_ber(npm_object.data).map_err(to_acse_error(error_message))?;
+    match (ae_qualifier.header.tag(), ae_qualifier.content) {
         (Tag::Integer, BerObjectContent::Integer(qualifier)) => Ok(AeQualifier::Form2(qualifier.to_owned())),
         (_, _) => Err(AcseError::ProtocolError(error_message.into())),
     }
 }
 
+pub(crate) fn process_relative_distinguished_name<'a>(npm_object: Any<'a>, error_message: &str) -> Result<RelativeDistinguishedName, AcseError> {
+    if npm_object.header.tag() != Tag::Set {
+        return Err(AcseError::ProtocolError(format!("{error_message}: Expected a relative distinguished name but found {:?}", npm_object.header.tag())));
+    }
+
+    let mut relative_distinguished_name = vec![];
+    for attribute in process_constructed_data(npm_object.data).map_err(to_acse_error(error_message))? {
+        if attribute.header.tag() != Tag::Sequence {
+            return Err(AcseError::ProtocolError(format!("{error_message}: Expected an attribute but found {:?}", attribute.header.tag())));
+        }
+        // The value is whatever remains after the attribute type.
+        let (value, attribute_type) = parse_ber_oid(attribute.data).map_err(to_acse_error(error_message))?;
+        let attribute_type = attribute_type.as_oid_val().map_err(to_acse_error(error_message))?.to_owned();
+        relative_distinguished_name.push(AttributeTypeAndValue { attribute_type, value: value.to_vec() });
+    }
+    Ok(relative_distinguished_name)
+}
+
 pub(crate) fn process_response_result<'a>(data: &[u8], error_message: &str) -> Result<AssociateResult, AcseError> {
     let (_, inner_object) = parse_ber_integer(data).map_err(to_acse_error(error_message))?;
 
@@ -290,8 +450,9 @@ pub(crate) fn process_response_diagnostics<'a>(data: &[u8], error_message: &str)
         (Some(&[161]), x) if x == vec![14] => Ok(AssociateSourceDiagnostic::User(crate::AssociateSourceDiagnosticUserCategory::AuthenticationRequired)),
         (Some(&[161]), x) => Ok(AssociateSourceDiagnostic::User(crate::AssociateSourceDiagnosticUserCategory::Unknown(x))),
         (Some(&[162]), x) if x == vec![0] => Ok(AssociateSourceDiagnostic::Provider(crate::AssociateSourceDiagnosticProviderCategory::Null)),
-        (Some(&[162]), x) if x == vec![0] => Ok(AssociateSourceDiagnostic::Provider(crate::AssociateSourceDiagnosticProviderCategory::NoReasonGiven)),
-        (Some(&[162]), x) if x == vec![0] => Ok(AssociateSourceDiagnostic::Provider(crate::AssociateSourceDiagnosticProviderCategory::NoCommonAcseVersion)),
+        (Some(&[162]), x) if x == vec![1] => Ok(AssociateSourceDiagnostic::Provider(crate::AssociateSourceDiagnosticProviderCategory::NoReasonGiven)),
+        (Some(&[162]), x) if x == vec![2] => Ok(AssociateSourceDiagnostic::Provider(crate::AssociateSourceDiagnosticProviderCategory::NoCommonAcseVersion)),
+        (Some(&[162]), x) => Ok(AssociateSourceDiagnostic::Provider(crate::AssociateSourceDiagnosticProviderCategory::Unknown(x))),
         (Some(x), _) => Ok(AssociateSourceDiagnostic::Unknown(x.to_vec())),
         _ => Err(AcseError::ProtocolError(error_message.into())),
     }
@@ -4,96 +4,210 @@ use der_parser::{
     Oid,
     ber::{BerObject, BerObjectContent, BitStringObject},
     der::{Class, Header, Tag},
+    parse_ber,
 };
 use rusty_copp::CoppConnection;
-use rusty_copp::{CoppError, CoppInitiator, CoppListener, CoppReader, CoppResponder, CoppWriter, PresentationContext, PresentationContextType, PresentationDataValueList, PresentationDataValues, UserData};
+use rusty_copp::{
+    CoppError, CoppInitiator, CoppListener, CoppReader, CoppResponder, CoppWriter, PresentationContext, PresentationContextResult, PresentationContextResultCause, PresentationContextResultProviderReason,
+    PresentationContextResultProviderReasonValue, PresentationContextResultType, PresentationContextType, PresentationDataValueList, PresentationDataValues, UserData, default_context_results,
+};
+use tracing::warn;
 
 use crate::{
-    AcseError, AcseRecvResult, AcseRequestInformation, AcseResponseInformation, AeQualifier, ApTitle, AssociateResult, AssociateSourceDiagnostic, AssociateSourceDiagnosticProviderCategory, AssociateSourceDiagnosticUserCategory,
-    OsiSingleValueAcseConnection, OsiSingleValueAcseInitiator, OsiSingleValueAcseListener, OsiSingleValueAcseReader, OsiSingleValueAcseResponder, OsiSingleValueAcseWriter,
-    messages::parsers::{process_request, process_response, to_acse_error},
+    AbortDiagnostic, AcseAuthenticator, AcseContextData, AcseContextRecvResult, AcseCredentials, AcseError, AcsePresentationContext, AcsePresentationContextResult, AcseRecvResult, AcseRequestInformation, AcseRequirements,
+    AcseResponseInformation, AeQualifier, ApTitle, AssociateResult, AssociateSourceDiagnostic, AssociateSourceDiagnosticProviderCategory, AssociateSourceDiagnosticUserCategory, AuthenticationValue, OsiAcseConnection, OsiAcseInitiator,
+    OsiAcseListener, OsiAcseReader, OsiAcseResponder, OsiAcseWriter, OsiSingleValueAcseConnection, OsiSingleValueAcseInitiator, OsiSingleValueAcseListener, OsiSingleValueAcseReader, OsiSingleValueAcseResponder, OsiSingleValueAcseWriter,
+    RelativeDistinguishedName, ReleaseRequestReason, ReleaseResponseReason, ReleaseResult,
+    messages::parsers::{External, process_abort, process_release_request, process_release_response, process_request, process_response, to_acse_error},
+    mms_application_context_name,
 };
 
+/// The presentation context that carries the ACSE APDUs.
+const ACSE_CONTEXT_IDENTIFIER: &[u8] = &[1];
+/// The presentation context that carries the user data of a single value association.
+const SINGLE_VALUE_CONTEXT_IDENTIFIER: &[u8] = &[3];
+
 pub struct RustyOsiSingleValueAcseInitiator<T: CoppInitiator, R: CoppReader, W: CoppWriter> {
     copp_initiator: T,
     copp_reader: PhantomData<R>,
     copp_writer: PhantomData<W>,
     options: AcseRequestInformation,
+    credentials: Option<Box<dyn AcseCredentials>>,
 }
 
 impl<T: CoppInitiator, R: CoppReader, W: CoppWriter> RustyOsiSingleValueAcseInitiator<T, R, W> {
     pub fn new(copp_initiator: impl CoppInitiator, options: AcseRequestInformation) -> RustyOsiSingleValueAcseInitiator<impl CoppInitiator, impl CoppReader, impl CoppWriter> {
-        RustyOsiSingleValueAcseInitiator { copp_initiator, copp_reader: PhantomData::<R>, copp_writer: PhantomData::<W>, options }
+        RustyOsiSingleValueAcseInitiator { copp_initiator, copp_reader: PhantomData::<R>, copp_writer: PhantomData::<W>, options, credentials: None }
+    }
+
+    /// Sets the credentials that fill in the authentication fields of the request when it is initiated.
+    pub fn set_credentials(&mut self, credentials: Option<Box<dyn AcseCredentials>>) {
+        self.credentials = credentials;
     }
 }
 
 impl<T: CoppInitiator, R: CoppReader, W: CoppWriter> OsiSingleValueAcseInitiator for RustyOsiSingleValueAcseInitiator<T, R, W> {
     async fn initiate(self, abstract_syntax_name: Oid<'static>, user_data: Vec<u8>) -> Result<(impl OsiSingleValueAcseConnection, AcseResponseInformation, Vec<u8>), AcseError> {
-        let init_result = self
-            .copp_initiator
-            .initiate(
-                PresentationContextType::ContextDefinitionList(vec![
-                    // ACSE
-                    PresentationContext {
-                        identifier: vec![1],
-                        abstract_syntax_name: Oid::from(&[2, 2, 1, 0, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?,
-                        transfer_syntax_name_list: vec![Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?],
-                    },
-                    // Requested BER Encoded Protocol
-                    PresentationContext { identifier: vec![3], abstract_syntax_name: abstract_syntax_name, transfer_syntax_name_list: vec![Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?] },
-                ]),
-                Some(UserData::FullyEncoded(vec![PresentationDataValueList {
-                    transfer_syntax_name: None,
-                    presentation_context_identifier: vec![0x01],
-                    presentation_data_values: PresentationDataValues::SingleAsn1Type(self.options.serialise(&Some(user_data))?),
-                }])),
-            )
-            .await?;
-        let (copp_connection, received_user_data) = match init_result {
-            rusty_copp::CoppInitResult::Success(x, user_data) => (x, user_data),
-            rusty_copp::CoppInitResult::AbortUser(_) => todo!(),
-            rusty_copp::CoppInitResult::AbortProvider(_) => todo!(),
-            x => {
-                let payload_type: &'static str = x.into();
-                return Err(AcseError::ProtocolError(format!("Unexpected payload during connect: {payload_type}")));
-            }
-        };
-
-        let (copp_reader, copp_writer) = copp_connection.split().await?;
-        let (acse_response, acse_response_data) = match received_user_data {
-            Some(UserData::FullyEncoded(pdvs)) => {
-                if pdvs.len() > 1 {
-                    return Err(AcseError::ProtocolError(format!("Expecting a single PDV on ACSE Response but found {}", pdvs.len())));
-                }
-                match pdvs.first() {
-                    Some(pdv) => {
-                        if pdv.presentation_context_identifier != vec![1] {
-                            return Err(AcseError::ProtocolError(format!("Expecting a context id of [1] on ACSE Response but found {:?}", pdv.presentation_context_identifier)));
-                        }
-                        match &pdv.presentation_data_values {
-                            PresentationDataValues::SingleAsn1Type(response_user_data) => process_response(response_user_data)?,
-                        }
-                    }
-                    None => return Err(AcseError::ProtocolError("No PDV was found on ACSE Response".into())),
-                }
-            }
-            None => return Err(AcseError::ProtocolError("No user data was found on ACSE Response".into())),
+        let presentation_contexts = vec![AcsePresentationContext { identifier: SINGLE_VALUE_CONTEXT_IDENTIFIER.to_vec(), abstract_syntax_name }];
+        let (copp_reader, copp_writer, acse_response, acse_response_data) = associate(self.copp_initiator, self.options, self.credentials, presentation_contexts, single_value_context_data(Some(user_data))).await?;
+        let acse_response_data = match single_value_data(acse_response_data, "ACSE Response")? {
+            Some(acse_response_data) => acse_response_data,
+            None => return Err(AcseError::ProtocolError("No User Data found on ACSE Response".into())),
         };
         Ok((RustyAcseConnection { copp_reader, copp_writer }, acse_response, acse_response_data))
     }
 }
 
-pub struct RustyOsiSingleValueAcseListener<T: CoppResponder, R: CoppReader, W: CoppWriter> {
-    copp_responder: T,
+pub struct RustyOsiAcseInitiator<T: CoppInitiator, R: CoppReader, W: CoppWriter> {
+    copp_initiator: T,
     copp_reader: PhantomData<R>,
     copp_writer: PhantomData<W>,
-    response: Option<AcseResponseInformation>,
+    options: AcseRequestInformation,
+    credentials: Option<Box<dyn AcseCredentials>>,
+}
+
+impl<T: CoppInitiator, R: CoppReader, W: CoppWriter> RustyOsiAcseInitiator<T, R, W> {
+    pub fn new(copp_initiator: impl CoppInitiator, options: AcseRequestInformation) -> RustyOsiAcseInitiator<impl CoppInitiator, impl CoppReader, impl CoppWriter> {
+        RustyOsiAcseInitiator { copp_initiator, copp_reader: PhantomData::<R>, copp_writer: PhantomData::<W>, options, credentials: None }
+    }
+
+    /// Sets the credentials that fill in the authentication fields of the request when it is initiated.
+    pub fn set_credentials(&mut self, credentials: Option<Box<dyn AcseCredentials>>) {
+        self.credentials = credentials;
+    }
+}
+
+impl<T: CoppInitiator, R: CoppReader, W: CoppWriter> OsiAcseInitiator for RustyOsiAcseInitiator<T, R, W> {
+    async fn initiate(self, presentation_contexts: Vec<AcsePresentationContext>, user_data: Vec<AcseContextData>) -> Result<(impl OsiAcseConnection, AcseResponseInformation, Vec<AcseContextData>), AcseError> {
+        let context_identifiers = context_identifiers(&presentation_contexts);
+        let (copp_reader, copp_writer, acse_response, acse_response_data) = associate(self.copp_initiator, self.options, self.credentials, presentation_contexts, user_data).await?;
+        Ok((RustyOsiAcseConnection { copp_reader, copp_writer, presentation_contexts: context_identifiers }, acse_response, acse_response_data))
+    }
+}
+
+/// Proposes the ACSE context followed by the user contexts and waits for the association to be accepted.
+async fn associate(
+    copp_initiator: impl CoppInitiator,
+    mut options: AcseRequestInformation,
+    credentials: Option<Box<dyn AcseCredentials>>,
+    presentation_contexts: Vec<AcsePresentationContext>,
+    user_data: Vec<AcseContextData>,
+) -> Result<(impl CoppReader, impl CoppWriter, AcseResponseInformation, Vec<AcseContextData>), AcseError> {
+    let context_identifiers = context_identifiers(&presentation_contexts);
+    for (index, identifier) in context_identifiers.iter().enumerate() {
+        // The initiator allocates the odd identifiers.
+        if identifier.last().is_none_or(|x| x % 2 == 0) || identifier == ACSE_CONTEXT_IDENTIFIER {
+            return Err(AcseError::InternalError(format!("Presentation context identifier {:?} is not an odd identifier other than [1]", identifier)));
+        }
+        if context_identifiers[..index].contains(identifier) {
+            return Err(AcseError::InternalError(format!("Presentation context identifier {:?} was proposed more than once", identifier)));
+        }
+    }
+    check_context_data(&user_data, &context_identifiers)?;
+    if let Some(credentials) = credentials {
+        credentials.apply(&mut options)?;
+    }
+
+    let transfer_syntax_name = Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?;
+    let init_result = copp_initiator
+        .initiate(
+            PresentationContextType::ContextDefinitionList(
+                // ACSE
+                [PresentationContext {
+                    identifier: ACSE_CONTEXT_IDENTIFIER.to_vec(),
+                    abstract_syntax_name: Oid::from(&[2, 2, 1, 0, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?,
+                    transfer_syntax_name_list: vec![transfer_syntax_name.clone()],
+                }]
+                .into_iter()
+                // Requested BER Encoded Protocols
+                .chain(presentation_contexts.iter().map(|context| PresentationContext {
+                    identifier: context.identifier.clone(),
+                    abstract_syntax_name: context.abstract_syntax_name.clone(),
+                    transfer_syntax_name_list: vec![transfer_syntax_name.clone()],
+                }))
+                .collect(),
+            ),
+            Some(acse_pdv(options.serialise_with_contexts(&user_data)?)),
+        )
+        .await
+        .map_err(from_copp_error(&context_identifiers))?;
+    let (copp_connection, received_user_data) = match init_result {
+        rusty_copp::CoppInitResult::Success(x, user_data) => (x, user_data),
+        x => {
+            let payload_type: &'static str = x.into();
+            return Err(AcseError::ProtocolError(format!("Unexpected payload during connect: {payload_type}")));
+        }
+    };
+
+    let (copp_reader, copp_writer) = copp_connection.split().await?;
+    let (acse_response, acse_response_data) = process_response(&acse_user_data(received_user_data, "ACSE Response")?)?;
+    let acse_response_data = context_user_data(acse_response_data, &context_identifiers, "ACSE Response")?;
+    if acse_response.associate_result != AssociateResult::Accepted {
+        return Err(AcseError::Rejected(acse_response.associate_result, acse_response.associate_source_diagnostic, acse_response_data.into_iter().next().map(|x| x.data)));
+    }
+    Ok((copp_reader, copp_writer, acse_response, acse_response_data))
+}
+
+pub struct RustyOsiSingleValueAcseListener<T: CoppResponder, R: CoppReader, W: CoppWriter> {
+    listener: RustyOsiAcseListener<T, R, W>,
     acse_user_data: Vec<u8>,
 }
 
 impl<T: CoppResponder, R: CoppReader, W: CoppWriter> RustyOsiSingleValueAcseListener<T, R, W> {
     pub async fn new(copp_listener: impl CoppListener) -> Result<(RustyOsiSingleValueAcseListener<impl CoppResponder, impl CoppReader, impl CoppWriter>, AcseRequestInformation), AcseError> {
-        let (copp_responder, _, copp_options) = copp_listener.accept().await?;
+        let (listener, request) = RustyOsiAcseListener::<T, R, W>::new(copp_listener).await?;
+        let acse_user_data = match single_value_data(listener.user_data.clone(), "ACSE Request")? {
+            Some(acse_user_data) => acse_user_data,
+            None => return Err(AcseError::ProtocolError("No User Data found on ACSE Request".into())),
+        };
+        Ok((RustyOsiSingleValueAcseListener { listener, acse_user_data }, request))
+    }
+
+    pub fn set_response(&mut self, response: Option<AcseResponseInformation>) {
+        self.listener.set_response(response);
+    }
+
+    /// Sets the authenticator that validates the request when the responder is created.
+    pub fn set_authenticator(&mut self, authenticator: Option<Box<dyn AcseAuthenticator>>) {
+        self.listener.set_authenticator(authenticator);
+    }
+
+    /// Sets the application context names that are accepted when the responder is created. This is only the MMS context by default. An empty list accepts any name.
+    pub fn set_application_context_names(&mut self, application_context_names: Vec<Oid<'static>>) {
+        self.listener.set_application_context_names(application_context_names);
+    }
+}
+
+impl<T: CoppResponder, R: CoppReader, W: CoppWriter> OsiSingleValueAcseListener for RustyOsiSingleValueAcseListener<T, R, W> {
+    /// Fails with a rejected error if the application context name is not supported or the authenticator refuses the request. The rejection has already been sent to the peer in this case.
+    async fn responder(self) -> Result<(impl OsiSingleValueAcseResponder, Vec<u8>), AcseError> {
+        let (responder, _) = self.listener.validated_responder().await?;
+        Ok((RustyOsiSingleValueAcseResponder { responder }, self.acse_user_data))
+    }
+
+    async fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Option<Vec<u8>>) -> Result<(), AcseError> {
+        self.listener.abort(diagnostic, single_value_context_data(user_data)).await
+    }
+}
+
+pub struct RustyOsiAcseListener<T: CoppResponder, R: CoppReader, W: CoppWriter> {
+    copp_responder: T,
+    copp_reader: PhantomData<R>,
+    copp_writer: PhantomData<W>,
+    request: AcseRequestInformation,
+    response: Option<AcseResponseInformation>,
+    authenticator: Option<Box<dyn AcseAuthenticator>>,
+    application_context_names: Vec<Oid<'static>>,
+    presentation_contexts: Vec<AcsePresentationContext>,
+    presentation_context_results: Vec<AcsePresentationContextResult>,
+    // The full proposal, including the ACSE context, that the per-context results are negotiated from.
+    presentation_context: PresentationContextType,
+    user_data: Vec<AcseContextData>,
+}
+
+impl<T: CoppResponder, R: CoppReader, W: CoppWriter> RustyOsiAcseListener<T, R, W> {
+    pub async fn new(copp_listener: impl CoppListener) -> Result<(RustyOsiAcseListener<impl CoppResponder, impl CoppReader, impl CoppWriter>, AcseRequestInformation), AcseError> {
+        let (copp_responder, presentation_context, copp_options) = copp_listener.accept().await?;
         let copp_presentation_data_list = match copp_options {
             Some(UserData::FullyEncoded(x)) => x,
             None => return Err(AcseError::ProtocolError("COPP did not provide and data in the initiate payload".into())),
@@ -110,56 +224,241 @@ impl<T: CoppResponder, R: CoppReader, W: CoppWriter> RustyOsiSingleValueAcseList
             Some(x) => return Err(AcseError::ProtocolError(format!("Unsupported transfer syntax: {}", x))),
             None => (),
         }
-        if copp_presentation_data.presentation_context_identifier != &[1] {
+        if copp_presentation_data.presentation_context_identifier != ACSE_CONTEXT_IDENTIFIER {
             return Err(AcseError::ProtocolError(format!("Unexpected presentation contact id on COPP ACES Payload: Expecting &[1] but found {:?}", copp_presentation_data.presentation_context_identifier)));
         }
+        let PresentationContextType::ContextDefinitionList(proposed_contexts) = &presentation_context;
+        let presentation_contexts: Vec<AcsePresentationContext> = proposed_contexts
+            .iter()
+            .filter(|context| context.identifier != ACSE_CONTEXT_IDENTIFIER)
+            .map(|context| AcsePresentationContext { identifier: context.identifier.clone(), abstract_syntax_name: context.abstract_syntax_name.clone() })
+            .collect();
         let (request, acse_user_data) = match &copp_presentation_data.presentation_data_values {
             PresentationDataValues::SingleAsn1Type(data) => process_request(data)?,
         };
-        Ok((RustyOsiSingleValueAcseListener { copp_responder, copp_reader: PhantomData::<R>, copp_writer: PhantomData::<W>, response: None, acse_user_data }, request))
+        let user_data = context_user_data(acse_user_data, &context_identifiers(&presentation_contexts), "ACSE Request")?;
+        Ok((
+            RustyOsiAcseListener {
+                copp_responder,
+                copp_reader: PhantomData::<R>,
+                copp_writer: PhantomData::<W>,
+                request: request.clone(),
+                response: None,
+                authenticator: None,
+                application_context_names: vec![mms_application_context_name()],
+                presentation_context_results: vec![AcsePresentationContextResult::Accepted; presentation_contexts.len()],
+                presentation_contexts,
+                presentation_context,
+                user_data,
+            },
+            request,
+        ))
+    }
+
+    /// The presentation contexts proposed by the initiator, other than the ACSE context. These are accepted with the association unless they are rejected with the presentation context results.
+    pub fn presentation_contexts(&self) -> &[AcsePresentationContext] {
+        &self.presentation_contexts
+    }
+
+    /// Sets the result for each of the proposed presentation contexts, in the same order. A context that does not offer the BER transfer syntax is always rejected.
+    pub fn set_presentation_context_results(&mut self, presentation_context_results: Vec<AcsePresentationContextResult>) {
+        self.presentation_context_results = presentation_context_results;
     }
 
     pub fn set_response(&mut self, response: Option<AcseResponseInformation>) {
         self.response = response;
     }
-}
 
-impl<T: CoppResponder, R: CoppReader, W: CoppWriter> OsiSingleValueAcseListener for RustyOsiSingleValueAcseListener<T, R, W> {
-    async fn responder(self) -> Result<(impl OsiSingleValueAcseResponder, Vec<u8>), AcseError> {
+    /// Sets the authenticator that validates the request when the responder is created.
+    pub fn set_authenticator(&mut self, authenticator: Option<Box<dyn AcseAuthenticator>>) {
+        self.authenticator = authenticator;
+    }
+
+    /// Sets the application context names that are accepted when the responder is created. This is only the MMS context by default. An empty list accepts any name.
+    pub fn set_application_context_names(&mut self, application_context_names: Vec<Oid<'static>>) {
+        self.application_context_names = application_context_names;
+    }
+
+    /// Checks the application context name and then the authenticator. A failed check is answered with a permanent rejection.
+    async fn validated_responder(self) -> Result<(RustyOsiAcseResponder<T, R, W>, Vec<AcseContextData>), AcseError> {
+        let (accepted_contexts, context_results) = self.negotiated_context_results()?;
+        let application_context_supported = self.application_context_names.is_empty() || self.application_context_names.contains(&self.request.application_context_name);
+        let diagnostic = match &self.authenticator {
+            _ if !application_context_supported => Some(AssociateSourceDiagnosticUserCategory::ApplicationContextNameNotSupported),
+            Some(authenticator) => authenticator.authenticate(&self.request).err(),
+            None => None,
+        };
+        if let Some(diagnostic) = diagnostic {
+            // A response to an unsupported context names a context that is supported.
+            let application_context_name = match self.application_context_names.first() {
+                Some(application_context_name) if !application_context_supported => application_context_name.clone(),
+                _ => self.request.application_context_name.clone(),
+            };
+            let response = self.response.unwrap_or_else(|| AcseResponseInformation {
+                application_context_name,
+                associate_result: AssociateResult::RejectedPermanent,
+                associate_source_diagnostic: AssociateSourceDiagnostic::User(diagnostic.clone()),
+                responding_ap_title: None,
+                responding_ae_qualifier: None,
+                responding_ap_invocation_identifier: None,
+                responding_ae_invocation_identifier: None,
+                responder_acse_requirements: None,
+                mechanism_name: None,
+                responding_authentication_value: None,
+                implementation_information: None,
+            });
+            let diagnostic = AssociateSourceDiagnostic::User(diagnostic);
+            RustyOsiAcseResponder::<T, R, W>::negotiated(self.copp_responder, response, accepted_contexts, context_results).reject(AssociateResult::RejectedPermanent, diagnostic.clone(), vec![]).await?;
+            return Err(AcseError::Rejected(AssociateResult::RejectedPermanent, diagnostic, None));
+        }
         match self.response {
-            Some(response) => Ok((RustyOsiSingleValueAcseResponder::<T, R, W>::new(self.copp_responder, response), self.acse_user_data)),
+            Some(response) => Ok((RustyOsiAcseResponder::<T, R, W>::negotiated(self.copp_responder, response, accepted_contexts, context_results), self.user_data)),
             None => Err(AcseError::ProtocolError("No ACSE response information was provided".into())),
         }
     }
+
+    /// Applies the user's results to the contexts the provider can accept. Returns the accepted user contexts and the result for every proposed context.
+    fn negotiated_context_results(&self) -> Result<(Vec<Vec<u8>>, PresentationContextResultType), AcseError> {
+        if self.presentation_context_results.len() != self.presentation_contexts.len() {
+            return Err(AcseError::InternalError(format!("Expected {} presentation context results but found {}", self.presentation_contexts.len(), self.presentation_context_results.len())));
+        }
+        let PresentationContextType::ContextDefinitionList(proposed_contexts) = &self.presentation_context;
+        let PresentationContextResultType::ContextDefinitionList(provider_results) = default_context_results(&self.presentation_context)?;
+        let mut user_results = self.presentation_context_results.iter();
+        let mut accepted_contexts = vec![];
+        let mut context_results = vec![];
+        for (context, provider_result) in proposed_contexts.iter().zip(provider_results) {
+            if context.identifier == ACSE_CONTEXT_IDENTIFIER {
+                context_results.push(provider_result);
+                continue;
+            }
+            let context_result = match (provider_result.result, user_results.next()) {
+                (PresentationContextResultCause::Acceptance, Some(AcsePresentationContextResult::UserRejection)) => {
+                    PresentationContextResult { result: PresentationContextResultCause::UserRejection, transfer_syntax_name: None, provider_reason: None }
+                }
+                (PresentationContextResultCause::Acceptance, Some(AcsePresentationContextResult::AbstractSyntaxNotSupported)) => PresentationContextResult {
+                    result: PresentationContextResultCause::ProviderRejection,
+                    transfer_syntax_name: None,
+                    provider_reason: Some(PresentationContextResultProviderReason::Value(PresentationContextResultProviderReasonValue::AbstrctSyntaxNotSupported)),
+                },
+                (PresentationContextResultCause::Acceptance, _) => {
+                    accepted_contexts.push(context.identifier.clone());
+                    PresentationContextResult { result: PresentationContextResultCause::Acceptance, ..provider_result }
+                }
+                (result, _) => PresentationContextResult { result, ..provider_result },
+            };
+            context_results.push(context_result);
+        }
+        Ok((accepted_contexts, PresentationContextResultType::ContextDefinitionList(context_results)))
+    }
+}
+
+impl<T: CoppResponder, R: CoppReader, W: CoppWriter> OsiAcseListener for RustyOsiAcseListener<T, R, W> {
+    /// Fails with a rejected error if the application context name is not supported or the authenticator refuses the request. The rejection has already been sent to the peer in this case.
+    async fn responder(self) -> Result<(impl OsiAcseResponder, Vec<AcseContextData>), AcseError> {
+        self.validated_responder().await
+    }
+
+    async fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Vec<AcseContextData>) -> Result<(), AcseError> {
+        check_context_data(&user_data, &context_identifiers(&self.presentation_contexts))?;
+        self.copp_responder.user_abort(None, Some(acse_pdv(serialise_abort(diagnostic, &user_data)?))).await?;
+        Ok(())
+    }
 }
 
 pub struct RustyOsiSingleValueAcseResponder<T: CoppResponder, R: CoppReader, W: CoppWriter> {
-    copp_responder: T,
-    copp_reader: PhantomData<R>,
-    copp_writer: PhantomData<W>,
-    response: AcseResponseInformation,
+    responder: RustyOsiAcseResponder<T, R, W>,
 }
 
 impl<T: CoppResponder, R: CoppReader, W: CoppWriter> RustyOsiSingleValueAcseResponder<T, R, W> {
     pub fn new(copp_responder: T, response: AcseResponseInformation) -> Self {
-        RustyOsiSingleValueAcseResponder { copp_responder, copp_reader: PhantomData, copp_writer: PhantomData, response }
+        RustyOsiSingleValueAcseResponder { responder: RustyOsiAcseResponder::new(copp_responder, response, vec![]) }
     }
 }
 
 impl<T: CoppResponder, R: CoppReader, W: CoppWriter> OsiSingleValueAcseResponder for RustyOsiSingleValueAcseResponder<T, R, W> {
     async fn accept(self, user_data: Vec<u8>) -> Result<impl OsiSingleValueAcseConnection, AcseError> {
-        let acse_data = self.response.serialise(&Some(user_data))?;
-        let copp_connection = self
-            .copp_responder
-            .complete_connection(Some(UserData::FullyEncoded(vec![PresentationDataValueList {
-                transfer_syntax_name: None,
-                presentation_context_identifier: vec![1],
-                presentation_data_values: PresentationDataValues::SingleAsn1Type(acse_data),
-            }])))
-            .await?;
-        let (copp_reader, copp_writer) = copp_connection.split().await?;
+        let (copp_reader, copp_writer) = self.responder.complete(single_value_context_data(Some(user_data))).await?;
         Ok(RustyAcseConnection { copp_reader, copp_writer })
     }
+
+    async fn reject(self, result: AssociateResult, diagnostic: AssociateSourceDiagnostic, user_data: Option<Vec<u8>>) -> Result<(), AcseError> {
+        self.responder.reject(result, diagnostic, single_value_context_data(user_data)).await
+    }
+
+    async fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Option<Vec<u8>>) -> Result<(), AcseError> {
+        self.responder.abort(diagnostic, single_value_context_data(user_data)).await
+    }
+}
+
+pub struct RustyOsiAcseResponder<T: CoppResponder, R: CoppReader, W: CoppWriter> {
+    copp_responder: T,
+    copp_reader: PhantomData<R>,
+    copp_writer: PhantomData<W>,
+    response: AcseResponseInformation,
+    presentation_contexts: Vec<Vec<u8>>,
+    // The per-context results negotiated from the CP PPDU.
+    context_results: Option<PresentationContextResultType>,
+}
+
+impl<T: CoppResponder, R: CoppReader, W: CoppWriter> RustyOsiAcseResponder<T, R, W> {
+    /// Creates a responder for the user contexts proposed by the initiator. A single value association is assumed if none are given.
+    pub fn new(copp_responder: T, response: AcseResponseInformation, presentation_contexts: Vec<AcsePresentationContext>) -> Self {
+        let presentation_contexts = match presentation_contexts.as_slice() {
+            [] => vec![SINGLE_VALUE_CONTEXT_IDENTIFIER.to_vec()],
+            _ => context_identifiers(&presentation_contexts),
+        };
+        RustyOsiAcseResponder { copp_responder, copp_reader: PhantomData, copp_writer: PhantomData, response, presentation_contexts, context_results: None }
+    }
+
+    /// Creates a responder for the contexts accepted by the negotiation. Data is only routed on these.
+    fn negotiated(copp_responder: T, response: AcseResponseInformation, accepted_contexts: Vec<Vec<u8>>, context_results: PresentationContextResultType) -> Self {
+        RustyOsiAcseResponder { copp_responder, copp_reader: PhantomData, copp_writer: PhantomData, response, presentation_contexts: accepted_contexts, context_results: Some(context_results) }
+    }
+
+    /// The negotiated results. Without a negotiation the ACSE context and the user contexts are accepted.
+    fn context_results(&self) -> Result<PresentationContextResultType, AcseError> {
+        if let Some(context_results) = &self.context_results {
+            return Ok(context_results.clone());
+        }
+        let transfer_syntax_name = Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?;
+        Ok(PresentationContextResultType::ContextDefinitionList(
+            (0..=self.presentation_contexts.len()).map(|_| PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: Some(transfer_syntax_name.clone()), provider_reason: None }).collect(),
+        ))
+    }
+
+    async fn complete(self, user_data: Vec<AcseContextData>) -> Result<(impl CoppReader, impl CoppWriter), AcseError> {
+        check_context_data(&user_data, &self.presentation_contexts)?;
+        let acse_data = self.response.serialise_with_contexts(&user_data)?;
+        let context_results = self.context_results()?;
+        Ok(self.copp_responder.complete_connection_with_results(context_results, Some(acse_pdv(acse_data))).await?.split().await?)
+    }
+}
+
+impl<T: CoppResponder, R: CoppReader, W: CoppWriter> OsiAcseResponder for RustyOsiAcseResponder<T, R, W> {
+    async fn accept(self, user_data: Vec<AcseContextData>) -> Result<impl OsiAcseConnection, AcseError> {
+        let presentation_contexts = self.presentation_contexts.clone();
+        let (copp_reader, copp_writer) = self.complete(user_data).await?;
+        Ok(RustyOsiAcseConnection { copp_reader, copp_writer, presentation_contexts })
+    }
+
+    async fn reject(self, result: AssociateResult, diagnostic: AssociateSourceDiagnostic, user_data: Vec<AcseContextData>) -> Result<(), AcseError> {
+        if result == AssociateResult::Accepted {
+            return Err(AcseError::InternalError("An ACSE rejection cannot use the accepted result".into()));
+        }
+        check_context_data(&user_data, &self.presentation_contexts)?;
+        // It is the ACSE user rejecting the association so the presentation contexts keep the results they were negotiated with.
+        let contexts = self.context_results()?;
+        let acse_data = AcseResponseInformation { associate_result: result, associate_source_diagnostic: diagnostic, ..self.response }.serialise_with_contexts(&user_data)?;
+        self.copp_responder.reject(contexts, None, Some(acse_pdv(acse_data))).await?;
+        Ok(())
+    }
+
+    async fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Vec<AcseContextData>) -> Result<(), AcseError> {
+        check_context_data(&user_data, &self.presentation_contexts)?;
+        self.copp_responder.user_abort(None, Some(acse_pdv(serialise_abort(diagnostic, &user_data)?))).await?;
+        Ok(())
+    }
 }
 
 pub struct RustyAcseConnection<R: CoppReader, W: CoppWriter> {
@@ -173,97 +472,431 @@ impl<R: CoppReader, W: CoppWriter> OsiSingleValueAcseConnection for RustyAcseCon
     }
 }
 
-pub struct RustyOsiSingleValueAcseReader<R: CoppReader> {
+pub struct RustyOsiAcseConnection<R: CoppReader, W: CoppWriter> {
     copp_reader: R,
+    copp_writer: W,
+    presentation_contexts: Vec<Vec<u8>>,
+}
+
+impl<R: CoppReader, W: CoppWriter> OsiAcseConnection for RustyOsiAcseConnection<R, W> {
+    async fn split(self) -> Result<(impl OsiAcseReader, impl OsiAcseWriter), AcseError> {
+        Ok((RustyOsiAcseReader { copp_reader: self.copp_reader, presentation_contexts: self.presentation_contexts.clone() }, RustyOsiAcseWriter::new(self.copp_writer, self.presentation_contexts)))
+    }
+}
+
+pub struct RustyOsiSingleValueAcseReader<R: CoppReader> {
+    reader: RustyOsiAcseReader<R>,
 }
 
 impl<R: CoppReader> RustyOsiSingleValueAcseReader<R> {
     pub fn new(copp_reader: R) -> Self {
-        Self { copp_reader }
+        Self { reader: RustyOsiAcseReader { copp_reader, presentation_contexts: vec![SINGLE_VALUE_CONTEXT_IDENTIFIER.to_vec()] } }
     }
 }
 
 impl<R: CoppReader> OsiSingleValueAcseReader for RustyOsiSingleValueAcseReader<R> {
     async fn recv(&mut self) -> Result<AcseRecvResult, AcseError> {
-        let copp_recv_result = self.copp_reader.recv().await?;
+        match self.reader.recv().await? {
+            AcseContextRecvResult::Closed => Ok(AcseRecvResult::Closed),
+            AcseContextRecvResult::Data(values) => match <[AcseContextData; 1]>::try_from(values) {
+                Ok([value]) => Ok(AcseRecvResult::Data(value.data)),
+                Err(values) => Err(AcseError::ProtocolError(format!("Expected one PDV value on ACSE read but found {}", values.len()))),
+            },
+            AcseContextRecvResult::ReleaseRequest(reason, user_data) => Ok(AcseRecvResult::ReleaseRequest(reason, single_value_data(user_data, "ACSE Release Request")?)),
+            AcseContextRecvResult::ReleaseResponse(result, reason, user_data) => Ok(AcseRecvResult::ReleaseResponse(result, reason, single_value_data(user_data, "ACSE Release Response")?)),
+        }
+    }
+}
+
+pub struct RustyOsiAcseReader<R: CoppReader> {
+    copp_reader: R,
+    presentation_contexts: Vec<Vec<u8>>,
+}
+
+impl<R: CoppReader> OsiAcseReader for RustyOsiAcseReader<R> {
+    async fn recv(&mut self) -> Result<AcseContextRecvResult, AcseError> {
+        let copp_recv_result = self.copp_reader.recv().await.map_err(from_copp_error(&self.presentation_contexts))?;
         match copp_recv_result {
-            rusty_copp::CoppRecvResult::Closed => return Ok(AcseRecvResult::Closed),
+            rusty_copp::CoppRecvResult::Closed => Ok(AcseContextRecvResult::Closed),
             rusty_copp::CoppRecvResult::Data(user_data) => match user_data {
                 UserData::FullyEncoded(presentation_data_value_lists) => {
-                    if presentation_data_value_lists.len() > 1 {
-                        return Err(AcseError::ProtocolError(format!("Expected one PDV value on ACSE read but found {}", presentation_data_value_lists.len())));
-                    }
-                    match presentation_data_value_lists.first() {
-                        Some(x) => {
-                            if x.presentation_context_identifier != vec![3] {
-                                return Err(AcseError::ProtocolError(format!("Expected a context id of 3 on ACSE read but was {:?}", x.presentation_context_identifier)));
-                            }
-                            match &x.presentation_data_values {
-                                PresentationDataValues::SingleAsn1Type(data) => return Ok(AcseRecvResult::Data(data.to_vec())),
-                            }
-                        }
-                        None => return Err(AcseError::ProtocolError("Expected one PDV value on ACSE read but did not find any".into())),
+                    if presentation_data_value_lists.is_empty() {
+                        return Err(AcseError::ProtocolError("Expected one PDV value on ACSE read but did not find any".into()));
                     }
+                    let values: Vec<AcseContextData> = presentation_data_value_lists
+                        .into_iter()
+                        .map(|x| match x.presentation_data_values {
+                            PresentationDataValues::SingleAsn1Type(data) => AcseContextData { presentation_context_identifier: x.presentation_context_identifier, data },
+                        })
+                        .collect();
+                    check_context_data(&values, &self.presentation_contexts).map_err(|e| AcseError::ProtocolError(format!("Unexpected PDV value on ACSE read: {e}")))?;
+                    Ok(AcseContextRecvResult::Data(values))
                 }
             },
-            rusty_copp::CoppRecvResult::Finish(_) => todo!(),
-            rusty_copp::CoppRecvResult::Disconnect(_) => todo!(),
-            rusty_copp::CoppRecvResult::AbortUser(_) => todo!(),
-            rusty_copp::CoppRecvResult::AbortProvider(_) => todo!(),
+            rusty_copp::CoppRecvResult::Finish(user_data) => {
+                let (reason, release_user_data) = process_release_request(&acse_user_data(user_data, "ACSE Release Request")?)?;
+                Ok(AcseContextRecvResult::ReleaseRequest(reason, context_user_data(release_user_data, &self.presentation_contexts, "ACSE Release Request")?))
+            }
+            rusty_copp::CoppRecvResult::Disconnect(user_data) => {
+                let (reason, release_user_data) = process_release_response(&acse_user_data(user_data, "ACSE Release Response")?)?;
+                Ok(AcseContextRecvResult::ReleaseResponse(ReleaseResult::Affirmative, reason, context_user_data(release_user_data, &self.presentation_contexts, "ACSE Release Response")?))
+            }
+            rusty_copp::CoppRecvResult::NotFinished(user_data) => {
+                let (reason, release_user_data) = process_release_response(&acse_user_data(user_data, "ACSE Release Response")?)?;
+                Ok(AcseContextRecvResult::ReleaseResponse(ReleaseResult::Negative, reason, context_user_data(release_user_data, &self.presentation_contexts, "ACSE Release Response")?))
+            }
+            // The optional presentation services are not negotiated by ACSE.
+            x => return Err(AcseError::ProtocolError(format!("Unexpected presentation service on ACSE read: {}", <rusty_copp::CoppRecvResult as Into<&'static str>>::into(x)))),
         }
     }
 }
 
 pub struct RustyOsiSingleValueAcseWriter<W: CoppWriter> {
-    copp_writer: W,
-    buffer: VecDeque<UserData>,
+    writer: RustyOsiAcseWriter<W>,
 }
 
 impl<W: CoppWriter> RustyOsiSingleValueAcseWriter<W> {
     pub fn new(copp_writer: W) -> Self {
-        Self { copp_writer, buffer: VecDeque::new() }
+        Self { writer: RustyOsiAcseWriter::new(copp_writer, vec![SINGLE_VALUE_CONTEXT_IDENTIFIER.to_vec()]) }
     }
 }
 
 impl<W: CoppWriter> OsiSingleValueAcseWriter for RustyOsiSingleValueAcseWriter<W> {
     async fn send(&mut self, input: &mut VecDeque<Vec<u8>>) -> Result<(), AcseError> {
+        let mut values = input.drain(..).flat_map(|data| single_value_context_data(Some(data))).collect();
+        self.writer.send(&mut values).await
+    }
+
+    async fn release(&mut self, reason: Option<ReleaseRequestReason>, user_data: Option<Vec<u8>>) -> Result<(), AcseError> {
+        self.writer.release(reason, single_value_context_data(user_data)).await
+    }
+
+    async fn release_response(&mut self, result: ReleaseResult, reason: Option<ReleaseResponseReason>, user_data: Option<Vec<u8>>) -> Result<(), AcseError> {
+        self.writer.release_response(result, reason, single_value_context_data(user_data)).await
+    }
+
+    async fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Option<Vec<u8>>) -> Result<(), AcseError> {
+        self.writer.abort(diagnostic, single_value_context_data(user_data)).await
+    }
+}
+
+pub struct RustyOsiAcseWriter<W: CoppWriter> {
+    // Taken once an affirmative release response has been sent.
+    copp_writer: Option<W>,
+    buffer: VecDeque<UserData>,
+    presentation_contexts: Vec<Vec<u8>>,
+}
+
+impl<W: CoppWriter> RustyOsiAcseWriter<W> {
+    fn new(copp_writer: W, presentation_contexts: Vec<Vec<u8>>) -> Self {
+        Self { copp_writer: Some(copp_writer), buffer: VecDeque::new(), presentation_contexts }
+    }
+
+    fn copp_writer(&mut self) -> Result<&mut W, AcseError> {
+        self.copp_writer.as_mut().ok_or_else(association_released)
+    }
+}
+
+/// The error raised when the writer is used after the association was released.
+fn association_released() -> AcseError {
+    AcseError::ProtocolError("The association has already been released.".into())
+}
+
+impl<W: CoppWriter> OsiAcseWriter for RustyOsiAcseWriter<W> {
+    async fn send(&mut self, input: &mut VecDeque<AcseContextData>) -> Result<(), AcseError> {
+        check_context_data(input.make_contiguous(), &self.presentation_contexts)?;
         while let Some(data_item) = input.pop_front() {
             self.buffer.push_back(UserData::FullyEncoded(vec![PresentationDataValueList {
                 transfer_syntax_name: None,
-                presentation_context_identifier: vec![3],
-                presentation_data_values: PresentationDataValues::SingleAsn1Type(data_item),
+                presentation_context_identifier: data_item.presentation_context_identifier,
+                presentation_data_values: PresentationDataValues::SingleAsn1Type(data_item.data),
             }]));
         }
 
+        let copp_writer = self.copp_writer.as_mut().ok_or_else(association_released)?;
         while !self.buffer.is_empty() {
-            self.copp_writer.send(&mut self.buffer).await?
+            copp_writer.send(&mut self.buffer).await?
         }
 
         // Perform one more to ensure lower levels are also flushed even if this layer is complete.
-        self.copp_writer.send(&mut self.buffer).await?;
+        copp_writer.send(&mut self.buffer).await?;
+        Ok(())
+    }
+
+    async fn release(&mut self, reason: Option<ReleaseRequestReason>, user_data: Vec<AcseContextData>) -> Result<(), AcseError> {
+        check_context_data(&user_data, &self.presentation_contexts)?;
+        let reason = reason.map(|reason| match reason {
+            ReleaseRequestReason::Normal => vec![0],
+            ReleaseRequestReason::Urgent => vec![1],
+            ReleaseRequestReason::UserDefined => vec![30],
+            ReleaseRequestReason::Unknown(x) => x,
+        });
+        let release_data = serialise_apdu(2, &[reason], &user_data)?;
+        self.copp_writer()?.finish(Some(acse_pdv(release_data))).await?;
+        Ok(())
+    }
+
+    async fn release_response(&mut self, result: ReleaseResult, reason: Option<ReleaseResponseReason>, user_data: Vec<AcseContextData>) -> Result<(), AcseError> {
+        check_context_data(&user_data, &self.presentation_contexts)?;
+        let reason = match (result, reason) {
+            (ReleaseResult::Negative, None | Some(ReleaseResponseReason::NotFinished)) => Some(vec![1]),
+            (ReleaseResult::Negative, Some(x)) => return Err(AcseError::InternalError(format!("A negative release response must use the not finished reason but was {:?}", x))),
+            (ReleaseResult::Affirmative, Some(ReleaseResponseReason::NotFinished)) => return Err(AcseError::InternalError("An affirmative release response cannot use the not finished reason".into())),
+            (ReleaseResult::Affirmative, reason) => reason.map(|reason| match reason {
+                ReleaseResponseReason::Normal => vec![0],
+                ReleaseResponseReason::NotFinished => vec![1],
+                ReleaseResponseReason::UserDefined => vec![30],
+                ReleaseResponseReason::Unknown(x) => x,
+            }),
+        };
+        let release_data = serialise_apdu(3, &[reason], &user_data)?;
+        // A refused release is carried on the session not finished and the association stays open.
+        match result {
+            ReleaseResult::Affirmative => self.copp_writer.take().ok_or_else(association_released)?.disconnect(Some(acse_pdv(release_data))).await?,
+            ReleaseResult::Negative => self.copp_writer()?.not_finished(Some(acse_pdv(release_data))).await?,
+        }
+        Ok(())
+    }
+
+    async fn abort(self, diagnostic: Option<AbortDiagnostic>, user_data: Vec<AcseContextData>) -> Result<(), AcseError> {
+        check_context_data(&user_data, &self.presentation_contexts)?;
+        self.copp_writer.ok_or_else(association_released)?.user_abort(None, Some(acse_pdv(serialise_abort(diagnostic, &user_data)?))).await?;
         Ok(())
     }
 }
 
-impl AcseRequestInformation {
-    pub fn serialise(&self, user_data: &Option<Vec<u8>>) -> Result<Vec<u8>, AcseError> {
-        // There is a bug that prevents creating a tag with a value of 30. Instead we create the header from a raw tag.
-        // https://github.com/rusticata/der-parser/issues/89
-        let user_data_structure = user_data
-            .iter()
-            .map(|v| {
-                BerObject::from_header_and_content(
-                    Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)),
-                    der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
+/// Wraps an ACSE APDU in the ACSE presentation context.
+fn acse_pdv(data: Vec<u8>) -> UserData {
+    UserData::FullyEncoded(vec![PresentationDataValueList { transfer_syntax_name: None, presentation_context_identifier: ACSE_CONTEXT_IDENTIFIER.to_vec(), presentation_data_values: PresentationDataValues::SingleAsn1Type(data) }])
+}
+
+/// Extracts an ACSE APDU from the ACSE presentation context.
+fn acse_user_data(user_data: Option<UserData>, pdu_name: &str) -> Result<Vec<u8>, AcseError> {
+    let pdvs = match user_data {
+        Some(UserData::FullyEncoded(pdvs)) => pdvs,
+        None => return Err(AcseError::ProtocolError(format!("No user data was found on {pdu_name}"))),
+    };
+    match pdvs.as_slice() {
+        [pdv] if pdv.presentation_context_identifier == ACSE_CONTEXT_IDENTIFIER => match &pdv.presentation_data_values {
+            PresentationDataValues::SingleAsn1Type(data) => Ok(data.to_vec()),
+        },
+        [pdv] => Err(AcseError::ProtocolError(format!("Expecting a context id of [1] on {pdu_name} but found {:?}", pdv.presentation_context_identifier))),
+        _ => Err(AcseError::ProtocolError(format!("Expecting a single PDV on {pdu_name} but found {}", pdvs.len()))),
+    }
+}
+
+fn context_identifiers(presentation_contexts: &[AcsePresentationContext]) -> Vec<Vec<u8>> {
+    presentation_contexts.iter().map(|context| context.identifier.clone()).collect()
+}
+
+/// Checks that all of the user data is on one of the given presentation contexts.
+fn check_context_data(user_data: &[AcseContextData], presentation_contexts: &[Vec<u8>]) -> Result<(), AcseError> {
+    match user_data.iter().find(|value| !presentation_contexts.contains(&value.presentation_context_identifier)) {
+        Some(value) => Err(AcseError::InternalError(format!("Presentation context {:?} is not one of the negotiated contexts {:?}", value.presentation_context_identifier, presentation_contexts))),
+        None => Ok(()),
+    }
+}
+
+/// Binds the user information of an ACSE APDU to the presentation contexts. A value without an indirect reference can only belong to the sole user context.
+fn context_user_data(externals: Vec<External>, presentation_contexts: &[Vec<u8>], pdu_name: &str) -> Result<Vec<AcseContextData>, AcseError> {
+    externals
+        .into_iter()
+        .map(|external| {
+            let presentation_context_identifier = match (external.indirect_reference, presentation_contexts) {
+                (Some(identifier), _) if presentation_contexts.contains(&identifier) => identifier,
+                (Some(identifier), _) => return Err(AcseError::ProtocolError(format!("Incorrect context id found for User Data in {pdu_name}: Expected one of {:?} but got {:?}", presentation_contexts, identifier))),
+                (None, [identifier]) => identifier.clone(),
+                (None, _) => return Err(AcseError::ProtocolError(format!("No context id was found for User Data in {pdu_name}"))),
+            };
+            Ok(AcseContextData { presentation_context_identifier, data: external.data })
+        })
+        .collect()
+}
+
+fn single_value_context_data(user_data: Option<Vec<u8>>) -> Vec<AcseContextData> {
+    user_data.into_iter().map(|data| AcseContextData { presentation_context_identifier: SINGLE_VALUE_CONTEXT_IDENTIFIER.to_vec(), data }).collect()
+}
+
+/// Takes the user data of a single value association. This is at most one value which must be on context [3].
+fn single_value_data(user_data: Vec<AcseContextData>, pdu_name: &str) -> Result<Option<Vec<u8>>, AcseError> {
+    let mut user_data = user_data.into_iter();
+    match (user_data.next(), user_data.next()) {
+        (None, _) => Ok(None),
+        (Some(value), None) if value.presentation_context_identifier == SINGLE_VALUE_CONTEXT_IDENTIFIER => Ok(Some(value.data)),
+        (Some(value), None) => Err(AcseError::ProtocolError(format!("Incorrect context id found for User Data in {pdu_name}: Expected [3] but got {:?}", value.presentation_context_identifier))),
+        (Some(_), Some(_)) => Err(AcseError::ProtocolError(format!("Expecting a single User Data value on {pdu_name}"))),
+    }
+}
+
+/// Converts a presentation rejection carrying an AARE APDU or a presentation user abort carrying an ABRT APDU into the ACSE equivalent. Other errors are passed through.
+fn from_copp_error(presentation_contexts: &[Vec<u8>]) -> impl FnOnce(CoppError) -> AcseError {
+    // The ACSE error only has room for one value so the first is kept.
+    let first_value = |externals: Vec<External>, pdu_name: &str| context_user_data(externals, presentation_contexts, pdu_name).map(|user_data| user_data.into_iter().next().map(|value| value.data));
+    move |error| match error {
+        CoppError::Rejected(provider_reason, context_definition_result_list, Some(user_data)) => {
+            match acse_user_data(Some(user_data.clone()), "ACSE Response").and_then(|data| process_response(&data)).and_then(|(response, externals)| Ok((response, first_value(externals, "ACSE Response")?))) {
+                Ok((response, response_user_data)) => AcseError::Rejected(response.associate_result, response.associate_source_diagnostic, response_user_data),
+                Err(e) => {
+                    warn!("Failed to parse ACSE Response on presentation rejection: {}", e);
+                    AcseError::ProtocolStackError(CoppError::Rejected(provider_reason, context_definition_result_list, Some(user_data)))
+                }
+            }
+        }
+        CoppError::UserAborted(presentation_contexts, Some(user_data)) => {
+            match acse_user_data(Some(user_data.clone()), "ACSE Abort").and_then(|data| process_abort(&data)).and_then(|(source, diagnostic, externals)| Ok((source, diagnostic, first_value(externals, "ACSE Abort")?))) {
+                Ok((source, diagnostic, abort_user_data)) => AcseError::Aborted(source, diagnostic, abort_user_data),
+                Err(e) => {
+                    warn!("Failed to parse ACSE Abort on presentation user abort: {}", e);
+                    AcseError::ProtocolStackError(CoppError::UserAborted(presentation_contexts, Some(user_data)))
+                }
+            }
+        }
+        e => AcseError::ProtocolStackError(e),
+    }
+}
+
+/// Serialises an ABRT APDU. Aborts requested by this library always have a source of ACSE service user.
+fn serialise_abort(diagnostic: Option<AbortDiagnostic>, user_data: &[AcseContextData]) -> Result<Vec<u8>, AcseError> {
+    let diagnostic = diagnostic.map(|diagnostic| match diagnostic {
+        AbortDiagnostic::NoReasonGiven => vec![1],
+        AbortDiagnostic::ProtocolError => vec![2],
+        AbortDiagnostic::AuthenticationMechanismNameNotRecognized => vec![3],
+        AbortDiagnostic::AuthenticationMechanismNameRequired => vec![4],
+        AbortDiagnostic::AuthenticationFailure => vec![5],
+        AbortDiagnostic::AuthenticationRequired => vec![6],
+        AbortDiagnostic::Unknown(x) => x,
+    });
+    serialise_apdu(4, &[Some(vec![0]), diagnostic], user_data)
+}
+
+/// Serialises the user information as one EXTERNAL for each value. The indirect reference of each is its presentation context.
+///
+/// There is a bug that prevents creating a tag with a value of 30. Instead this is tagged [0] and the caller must patch the first byte to 0xbe.
+/// https://github.com/rusticata/der-parser/issues/89
+fn serialise_user_information(user_data: &[AcseContextData]) -> Option<BerObject<'_>> {
+    if user_data.is_empty() {
+        return None;
+    }
+    Some(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)),
+        der_parser::ber::BerObjectContent::Sequence(
+            user_data
+                .iter()
+                .map(|value| {
+                    BerObject::from_header_and_content(
                         Header::new(Class::Universal, true, Tag::from(8), der_parser::ber::Length::Definite(0)),
                         der_parser::ber::BerObjectContent::Sequence(vec![
-                            BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)), BerObjectContent::Integer(&[3])),
-                            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)), BerObjectContent::OctetString(v)),
+                            BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)), BerObjectContent::Integer(&value.presentation_context_identifier)),
+                            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)), BerObjectContent::OctetString(&value.data)),
                         ]),
-                    )]),
-                )
+                    )
+                })
+                .collect(),
+        ),
+    ))
+}
+
+/// Serialises an RLRQ (tag 2), RLRE (tag 3) or ABRT (tag 4) APDU. These hold implicitly tagged integers numbered from [0] followed by the user information.
+fn serialise_apdu(tag: u32, fields: &[Option<Vec<u8>>], user_data: &[AcseContextData]) -> Result<Vec<u8>, AcseError> {
+    let user_data_structure = serialise_user_information(user_data);
+    let user_data_length = match &user_data_structure {
+        Some(x) => x.to_vec().map_err(to_acse_error("Failed to serialise ACSE User Data"))?.len(),
+        None => 0,
+    };
+
+    let payload = BerObject::from_header_and_content(
+        Header::new(Class::Application, true, Tag::from(tag), der_parser::ber::Length::Definite(0)),
+        der_parser::ber::BerObjectContent::Sequence(
+            fields
+                .iter()
+                .enumerate()
+                .map(|(index, field)| {
+                    field.as_ref().map(|value| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(index as u32), der_parser::ber::Length::Definite(0)), BerObjectContent::Integer(value)))
+                })
+                // User Information
+                .chain([user_data_structure])
+                .flatten()
+                .collect(),
+        ),
+    );
+    let mut data = payload.to_vec().map_err(to_acse_error("Failed to serialise ACSE APDU"))?;
+    let tl = data.len();
+    if user_data_length > 0 {
+        data[tl - user_data_length] = 0xbe;
+    }
+    Ok(data)
+}
+
+/// Serialises an explicitly tagged AP title.
+fn serialise_ap_title(tag: u32, ap_title: &ApTitle) -> Result<BerObject<'_>, AcseError> {
+    let ap_title = match ap_title {
+        ApTitle::Form1(name) => BerObject::from_seq(name.iter().map(serialise_relative_distinguished_name).collect::<Result<Vec<BerObject>, AcseError>>()?),
+        ApTitle::Form2(oid) => BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Oid, der_parser::ber::Length::Definite(0)), BerObjectContent::OID(oid.to_owned())),
+    };
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(tag), der_parser::ber::Length::Definite(0)), der_parser::ber::BerObjectContent::Sequence(vec![ap_title])))
+}
+
+/// Serialises an explicitly tagged AE qualifier.
+fn serialise_ae_qualifier(tag: u32, ae_qualifier: &AeQualifier) -> Result<BerObject<'_>, AcseError> {
+    let ae_qualifier = match ae_qualifier {
+        AeQualifier::Form1(relative_distinguished_name) => serialise_relative_distinguished_name(relative_distinguished_name)?,
+        AeQualifier::Form2(value) => BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)), BerObjectContent::Integer(value)),
+    };
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(tag), der_parser::ber::Length::Definite(0)), der_parser::ber::BerObjectContent::Sequence(vec![ae_qualifier])))
+}
+
+fn serialise_relative_distinguished_name(relative_distinguished_name: &RelativeDistinguishedName) -> Result<BerObject<'_>, AcseError> {
+    Ok(BerObject::from_set(
+        relative_distinguished_name
+            .iter()
+            .map(|attribute| {
+                let (_, value) = parse_ber(&attribute.value).map_err(to_acse_error("Failed to serialise attribute value in relative distinguished name"))?;
+                Ok(BerObject::from_seq(vec![BerObject::from_obj(BerObjectContent::OID(attribute.attribute_type.clone())), value]))
             })
-            .last();
+            .collect::<Result<Vec<BerObject>, AcseError>>()?,
+    ))
+}
+
+/// Serialises the implicitly tagged ACSE requirements bit string.
+fn serialise_acse_requirements(tag: u32, requirements: &AcseRequirements) -> BerObject<'static> {
+    let data: &'static [u8] = match (requirements.authentication, requirements.application_context_negotiation) {
+        (false, false) => &[0x00],
+        (true, false) => &[0x80],
+        (false, true) => &[0x40],
+        (true, true) => &[0xc0],
+    };
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), der_parser::ber::Length::Definite(0)), BerObjectContent::BitString(6, BitStringObject { data }))
+}
+
+/// Serialises the explicitly tagged authentication value choice.
+fn serialise_authentication_value(tag: u32, value: &AuthenticationValue) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), der_parser::ber::Length::Definite(0)),
+        BerObjectContent::Sequence(vec![match value {
+            AuthenticationValue::CharString(value) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), der_parser::ber::Length::Definite(0)), BerObjectContent::GraphicString(value)),
+            AuthenticationValue::BitString(value) => {
+                BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), der_parser::ber::Length::Definite(0)), BerObjectContent::BitString(0, BitStringObject { data: value }))
+            }
+            AuthenticationValue::External(value) => BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(2), der_parser::ber::Length::Definite(0)),
+                BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
+                    Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)),
+                    BerObjectContent::OctetString(value),
+                )]),
+            ),
+        }]),
+    )
+}
+
+impl AcseRequestInformation {
+    /// Serialises the APDU with the user data of a single value association, which is carried on context [3].
+    pub fn serialise(&self, user_data: &Option<Vec<u8>>) -> Result<Vec<u8>, AcseError> {
+        self.serialise_with_contexts(&single_value_context_data(user_data.clone()))
+    }
+
+    /// Serialises the APDU with user data on any number of presentation contexts.
+    pub fn serialise_with_contexts(&self, user_data: &[AcseContextData]) -> Result<Vec<u8>, AcseError> {
+        let user_data_structure = serialise_user_information(user_data);
         let user_data_length = match &user_data_structure {
             Some(x) => x.to_vec().map_err(to_acse_error("Failed to serialise ACSE Request User Data"))?.len(),
             None => 0,
@@ -284,35 +917,9 @@ impl AcseRequestInformation {
                         )]),
                     )),
                     // Called AP Title
-                    self.called_ap_title
-                        .iter()
-                        .map(|ap_title| {
-                            BerObject::from_header_and_content(
-                                Header::new(Class::ContextSpecific, true, Tag::from(2), der_parser::ber::Length::Definite(0)),
-                                der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
-                                    Header::new(Class::Universal, false, Tag::Oid, der_parser::ber::Length::Definite(0)),
-                                    BerObjectContent::OID(match ap_title {
-                                        ApTitle::Form2(oid) => oid.to_owned(),
-                                    }),
-                                )]),
-                            )
-                        })
-                        .last(),
+                    self.called_ap_title.as_ref().map(|ap_title| serialise_ap_title(2, ap_title)).transpose()?,
                     // Called AE Qualifier
-                    self.called_ae_qualifier
-                        .iter()
-                        .map(|ae_qualifier| {
-                            BerObject::from_header_and_content(
-                                Header::new(Class::ContextSpecific, true, Tag::from(3), der_parser::ber::Length::Definite(0)),
-                                der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
-                                    Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)),
-                                    BerObjectContent::Integer(match ae_qualifier {
-                                        AeQualifier::Form2(value) => value,
-                                    }),
-                                )]),
-                            )
-                        })
-                        .last(),
+                    self.called_ae_qualifier.as_ref().map(|ae_qualifier| serialise_ae_qualifier(3, ae_qualifier)).transpose()?,
                     // Called AP InvocationIdentifier
                     self.called_ap_invocation_identifier
                         .iter()
@@ -340,35 +947,9 @@ impl AcseRequestInformation {
                         })
                         .last(),
                     // Calling AP Title
-                    self.calling_ap_title
-                        .iter()
-                        .map(|ap_title| {
-                            BerObject::from_header_and_content(
-                                Header::new(Class::ContextSpecific, true, Tag::from(6), der_parser::ber::Length::Definite(0)),
-                                der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
-                                    Header::new(Class::Universal, false, Tag::Oid, der_parser::ber::Length::Definite(0)),
-                                    BerObjectContent::OID(match ap_title {
-                                        ApTitle::Form2(oid) => oid.to_owned(),
-                                    }),
-                                )]),
-                            )
-                        })
-                        .last(),
+                    self.calling_ap_title.as_ref().map(|ap_title| serialise_ap_title(6, ap_title)).transpose()?,
                     // Calling AE Qualifier
-                    self.calling_ae_qualifier
-                        .iter()
-                        .map(|ae_qualifier| {
-                            BerObject::from_header_and_content(
-                                Header::new(Class::ContextSpecific, true, Tag::from(7), der_parser::ber::Length::Definite(0)),
-                                der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
-                                    Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)),
-                                    BerObjectContent::Integer(match ae_qualifier {
-                                        AeQualifier::Form2(value) => value,
-                                    }),
-                                )]),
-                            )
-                        })
-                        .last(),
+                    self.calling_ae_qualifier.as_ref().map(|ae_qualifier| serialise_ae_qualifier(7, ae_qualifier)).transpose()?,
                     // Calling AP InvocationIdentifier
                     self.calling_ap_invocation_identifier
                         .iter()
@@ -395,6 +976,14 @@ impl AcseRequestInformation {
                             )
                         })
                         .last(),
+                    // Sender ACSE Requirements
+                    self.sender_acse_requirements.as_ref().map(|requirements| serialise_acse_requirements(10, requirements)),
+                    // Mechanism Name
+                    self.mechanism_name
+                        .as_ref()
+                        .map(|mechanism_name| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(11), der_parser::ber::Length::Definite(0)), BerObjectContent::OID(mechanism_name.clone()))),
+                    // Calling Authentication Value
+                    self.calling_authentication_value.as_ref().map(|value| serialise_authentication_value(12, value)),
                     // Implementation Information
                     self.implementation_information
                         .iter()
@@ -420,24 +1009,14 @@ impl AcseRequestInformation {
 }
 
 impl AcseResponseInformation {
+    /// Serialises the APDU with the user data of a single value association, which is carried on context [3].
     pub fn serialise(&self, user_data: &Option<Vec<u8>>) -> Result<Vec<u8>, AcseError> {
-        // There is a bug that prevents creating a tag with a value of 30. Instead we create the header from a raw tag.
-        // https://github.com/rusticata/der-parser/issues/89
-        let user_data_structure = user_data
-            .iter()
-            .map(|v| {
-                BerObject::from_header_and_content(
-                    Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)),
-                    der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
-                        Header::new(Class::Universal, true, Tag::from(8), der_parser::ber::Length::Definite(0)),
-                        der_parser::ber::BerObjectContent::Sequence(vec![
-                            BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)), BerObjectContent::Integer(&[3])),
-                            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)), BerObjectContent::OctetString(v)),
-                        ]),
-                    )]),
-                )
-            })
-            .last();
+        self.serialise_with_contexts(&single_value_context_data(user_data.clone()))
+    }
+
+    /// Serialises the APDU with user data on any number of presentation contexts.
+    pub fn serialise_with_contexts(&self, user_data: &[AcseContextData]) -> Result<Vec<u8>, AcseError> {
+        let user_data_structure = serialise_user_information(user_data);
         let user_data_length = match &user_data_structure {
             Some(x) => x.to_vec().map_err(to_acse_error("Failed to serialise ACSE Request User Data"))?.len(),
             None => 0,
@@ -498,7 +1077,7 @@ impl AcseResponseInformation {
                                 )]),
                             ),
                             AssociateSourceDiagnostic::Provider(category) => BerObject::from_header_and_content(
-                                Header::new(Class::ContextSpecific, true, Tag::from(1), der_parser::ber::Length::Definite(0)),
+                                Header::new(Class::ContextSpecific, true, Tag::from(2), der_parser::ber::Length::Definite(0)),
                                 der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
                                     Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)),
                                     der_parser::ber::BerObjectContent::Integer(match category {
@@ -513,35 +1092,9 @@ impl AcseResponseInformation {
                         }]),
                     )),
                     // Called AP Title
-                    self.responding_ap_title
-                        .iter()
-                        .map(|ap_title| {
-                            BerObject::from_header_and_content(
-                                Header::new(Class::ContextSpecific, true, Tag::from(4), der_parser::ber::Length::Definite(0)),
-                                der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
-                                    Header::new(Class::Universal, false, Tag::Oid, der_parser::ber::Length::Definite(0)),
-                                    BerObjectContent::OID(match ap_title {
-                                        ApTitle::Form2(oid) => oid.to_owned(),
-                                    }),
-                                )]),
-                            )
-                        })
-                        .last(),
+                    self.responding_ap_title.as_ref().map(|ap_title| serialise_ap_title(4, ap_title)).transpose()?,
                     // Called AE Qualifier
-                    self.responding_ae_qualifier
-                        .iter()
-                        .map(|ae_qualifier| {
-                            BerObject::from_header_and_content(
-                                Header::new(Class::ContextSpecific, true, Tag::from(5), der_parser::ber::Length::Definite(0)),
-                                der_parser::ber::BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
-                                    Header::new(Class::Universal, false, Tag::Integer, der_parser::ber::Length::Definite(0)),
-                                    BerObjectContent::Integer(match ae_qualifier {
-                                        AeQualifier::Form2(value) => value,
-                                    }),
-                                )]),
-                            )
-                        })
-                        .last(),
+                    self.responding_ae_qualifier.as_ref().map(|ae_qualifier| serialise_ae_qualifier(5, ae_qualifier)).transpose()?,
                     // Called AP InvocationIdentifier
                     self.responding_ap_invocation_identifier
                         .iter()
@@ -568,6 +1121,14 @@ impl AcseResponseInformation {
                             )
                         })
                         .last(),
+                    // Responder ACSE Requirements
+                    self.responder_acse_requirements.as_ref().map(|requirements| serialise_acse_requirements(8, requirements)),
+                    // Mechanism Name
+                    self.mechanism_name
+                        .as_ref()
+                        .map(|mechanism_name| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(9), der_parser::ber::Length::Definite(0)), BerObjectContent::OID(mechanism_name.clone()))),
+                    // Responding Authentication Value
+                    self.responding_authentication_value.as_ref().map(|value| serialise_authentication_value(10, value)),
                     // Implementation Information
                     self.implementation_information
                         .iter()
@@ -3,8 +3,8 @@ use std::{collections::VecDeque, net::SocketAddr};
 use anyhow::anyhow;
 use der_parser::oid;
 use rusty_copp::{
-    CoppConnection, CoppConnectionInformation, CoppInitResult, CoppInitiator, CoppReader, CoppResponder, CoppWriter, PresentationContextType, PresentationDataValueList, PresentationDataValues, RustyCoppInitiatorIsoStack,
-    RustyCoppResponder, UserData,
+    CoppConnection, CoppConnectionInformation, CoppConnectionParameters, CoppInitResult, CoppInitiator, CoppReader, CoppResponder, CoppWriter, PresentationContextType, PresentationDataValueList, PresentationDataValues,
+    RustyCoppInitiatorIsoStack, RustyCoppResponder, UserData,
 };
 use rusty_cosp::{CospAcceptor, CospProtocolInformation, RustyCospAcceptorIsoStack, RustyCospInitiatorIsoStack};
 use rusty_cosp::{RustyCospReaderIsoStack, RustyCospWriterIsoStack};
@@ -51,7 +51,7 @@ async fn example_server(address: SocketAddr) -> Result<(), anyhow::Error> {
     let (cosp_responder, connect_data) = cosp_acceptor.accept().await?;
 
     // Using the cosp responder, create a copp connection.
-    let copp_responder = RustyCoppResponder::<_, RustyCospReaderIsoStack<TcpTpktReader>, RustyCospWriterIsoStack<TcpTpktWriter>>::new(cosp_responder, CoppConnectionInformation::default());
+    let copp_responder = RustyCoppResponder::<_, RustyCospReaderIsoStack<TcpTpktReader>, RustyCospWriterIsoStack<TcpTpktWriter>>::new(cosp_responder, CoppConnectionInformation::default(), CoppConnectionParameters::default());
     let copp_connection = copp_responder.complete_connection(Some(UserData::FullyEncoded(vec![]))).await?;
 
     // Split the connection into read and write halves. This is often done for easy multi-tasking.
@@ -101,7 +101,7 @@ async fn example_client(address: SocketAddr) -> Result<(), anyhow::Error> {
     // Upgrade the connection to a COSP connection. Here will will signal our identity and the expected identity of the remote side.
     let cosp_initiator = RustyCospInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cotp_connection, CospProtocolInformation::new(Some(vec![1]), Some(vec![2])), Default::default()).await?;
 
-    let copp_initiator = RustyCoppInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_initiator, CoppConnectionInformation::default());
+    let copp_initiator = RustyCoppInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_initiator, CoppConnectionInformation::default(), CoppConnectionParameters::default());
     let copp_connection = copp_initiator.initiate(PresentationContextType::ContextDefinitionList(vec![]), Some(UserData::FullyEncoded(vec![]))).await?;
 
     let (copp_connection, user_data) = match copp_connection {
@@ -2,6 +2,7 @@ use std::collections::VecDeque;
 
 use der_parser::Oid;
 use rusty_cosp::CospError;
+pub use rusty_cosp::ResynchronizeType;
 use strum::IntoStaticStr;
 use thiserror::Error;
 
@@ -35,7 +36,7 @@ pub enum CoppError {
     /// Indicated a connection was aborted. The connection should be dropped.
     /// This may occur during any read operation.
     #[error("COPP Provider Abort")]
-    ProviderAborted(Option<ProviderReason>, Option<EventIdentifier>),
+    ProviderAborted(Option<AbortReason>, Option<EventIdentifier>),
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -88,7 +89,55 @@ impl From<&ProviderReason> for Vec<u8> {
     }
 }
 
-#[derive(Debug)]
+/// The reason carried on an ARP PPDU. This is distinct from the provider reason carried on a CPR PPDU.
+#[derive(Debug, PartialEq, Eq)]
+pub enum AbortReasonValue {
+    ReasonNotSpecified = 0,
+    UnrecognizedPpdu = 1,
+    UnexpectedPpdu = 2,
+    UnexpectedSessionServicePrimitive = 3,
+    UnrecognizedPpduParameter = 4,
+    UnexpectedPpduParameter = 5,
+    InvalidPpduParameterValue = 6,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum AbortReason {
+    Value(AbortReasonValue),
+    Unknown(Vec<u8>),
+}
+
+impl From<&[u8]> for AbortReason {
+    fn from(value: &[u8]) -> Self {
+        match value {
+            &[0] => AbortReason::Value(AbortReasonValue::ReasonNotSpecified),
+            &[1] => AbortReason::Value(AbortReasonValue::UnrecognizedPpdu),
+            &[2] => AbortReason::Value(AbortReasonValue::UnexpectedPpdu),
+            &[3] => AbortReason::Value(AbortReasonValue::UnexpectedSessionServicePrimitive),
+            &[4] => AbortReason::Value(AbortReasonValue::UnrecognizedPpduParameter),
+            &[5] => AbortReason::Value(AbortReasonValue::UnexpectedPpduParameter),
+            &[6] => AbortReason::Value(AbortReasonValue::InvalidPpduParameterValue),
+            x => AbortReason::Unknown(x.to_vec()),
+        }
+    }
+}
+
+impl From<&AbortReason> for Vec<u8> {
+    fn from(value: &AbortReason) -> Self {
+        match value {
+            AbortReason::Value(AbortReasonValue::ReasonNotSpecified) => vec![0],
+            AbortReason::Value(AbortReasonValue::UnrecognizedPpdu) => vec![1],
+            AbortReason::Value(AbortReasonValue::UnexpectedPpdu) => vec![2],
+            AbortReason::Value(AbortReasonValue::UnexpectedSessionServicePrimitive) => vec![3],
+            AbortReason::Value(AbortReasonValue::UnrecognizedPpduParameter) => vec![4],
+            AbortReason::Value(AbortReasonValue::UnexpectedPpduParameter) => vec![5],
+            AbortReason::Value(AbortReasonValue::InvalidPpduParameterValue) => vec![6],
+            AbortReason::Unknown(data) => data.to_vec(),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
 pub enum EventIdentifierValue {
     CpPpdu = 0,
     CpaPpdu = 1,
@@ -125,7 +174,7 @@ pub enum EventIdentifierValue {
     SessionActivityEndConfirm = 32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, PartialEq, Eq)]
 pub enum EventIdentifier {
     Value(EventIdentifierValue),
     Unknown(Vec<u8>),
@@ -304,15 +353,44 @@ pub struct PresentationContextResult {
     pub provider_reason: Option<PresentationContextResultProviderReason>,
 }
 
+/// The mode selected on the CP PPDU.
+#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+pub enum PresentationMode {
+    Normal,
+    /// X.410-1984 mode for legacy peers. There are no presentation selectors or presentation contexts in this mode.
+    /// User data is exchanged as a single value with an empty presentation context identifier.
+    X410,
+    Unknown,
+}
+
 #[derive(PartialEq, Clone, Debug)]
 pub struct CoppConnectionInformation {
     pub calling_presentation_selector: Option<Vec<u8>>,
     pub called_presentation_selector: Option<Vec<u8>>,
+
+    /// The mode to request as an initiator, or the mode requested by the initiator on a listener.
+    ///
+    /// Defaults to normal mode.
+    pub presentation_mode: PresentationMode,
 }
 
 impl Default for CoppConnectionInformation {
     fn default() -> Self {
-        Self { calling_presentation_selector: None, called_presentation_selector: None }
+        Self { calling_presentation_selector: None, called_presentation_selector: None, presentation_mode: PresentationMode::Normal }
+    }
+}
+
+#[derive(PartialEq, Clone, Debug)]
+pub struct CoppConnectionParameters {
+    /// When an incoming PPDU cannot be parsed, an ARP PPDU is sent to the peer before the error is returned to the user.
+    ///
+    /// Defaults to true. This may be disabled for testing so the connection is left intact.
+    pub automatic_provider_abort: bool,
+}
+
+impl Default for CoppConnectionParameters {
+    fn default() -> Self {
+        Self { automatic_provider_abort: true }
     }
 }
 
@@ -331,8 +409,25 @@ pub enum CoppRecvResult {
     Data(UserData),
     AbortUser(Vec<u8>),
     AbortProvider(Vec<u8>),
-    Finish(Option<Vec<u8>>),
-    Disconnect(Option<Vec<u8>>),
+    /// A release was requested by the remote side with the user data carried on session finish. A disconnect should be sent in response.
+    Finish(Option<UserData>),
+    /// The remote side accepted the release with the user data carried on session disconnect.
+    Disconnect(Option<UserData>),
+    /// The remote side refused the release with the user data carried on session not finished. The connection remains open.
+    NotFinished(Option<UserData>),
+
+    /// A TTD PPDU carried on session typed data.
+    TypedData(UserData),
+    /// A TE PPDU carried on session expedited data.
+    ExpeditedData(UserData),
+    /// A TC PPDU carried on session capability data. A TCC PPDU should be sent in response.
+    CapabilityData(UserData),
+    /// A TCC PPDU carried on the session capability data acknowledgement.
+    CapabilityDataAck(UserData),
+    /// An RS PPDU carried on session resynchronize. An RSA PPDU should be sent in response.
+    Resynchronize(ResynchronizeType, u32, Option<Vec<PresentationContextIdentifier>>, Option<UserData>),
+    /// An RSA PPDU carried on the session resynchronize acknowledgement.
+    ResynchronizeAck(u32, Option<Vec<PresentationContextIdentifier>>, Option<UserData>),
 }
 
 pub trait CoppInitiator: Send {
@@ -346,17 +441,20 @@ pub trait CoppListener: Send {
 
     fn user_abort(self, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
 
-    fn provider_abort(self, provider_reason: Option<ProviderReason>, event_identifier: Option<EventIdentifier>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+    fn provider_abort(self, provider_reason: Option<AbortReason>, event_identifier: Option<EventIdentifier>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
 }
 
 pub trait CoppResponder: Send {
     fn complete_connection(self, accept_data: Option<UserData>) -> impl std::future::Future<Output = Result<impl CoppConnection, CoppError>> + Send;
 
+    /// Completes the connection with a result for each proposed context, in the order they were proposed.
+    fn complete_connection_with_results(self, context_definition_result_list: PresentationContextResultType, accept_data: Option<UserData>) -> impl std::future::Future<Output = Result<impl CoppConnection, CoppError>> + Send;
+
     fn reject(self, context_definition_result_list: PresentationContextResultType, provider_reason: Option<ProviderReason>, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
 
     fn user_abort(self, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
 
-    fn provider_abort(self, provider_reason: Option<ProviderReason>, event_identifier: Option<EventIdentifier>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+    fn provider_abort(self, provider_reason: Option<AbortReason>, event_identifier: Option<EventIdentifier>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
 }
 
 pub trait CoppConnection: Send {
@@ -372,6 +470,35 @@ pub trait CoppWriter: Send {
 
     fn user_abort(self, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
 
-    fn finish(self) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
-    fn disconnect(self) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+    /// Requests the release of the connection. The user data is carried on session finish.
+    /// The writer remains usable as the release may be refused if the negotiated release session functional unit is in use.
+    fn finish(&mut self, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+    /// Accepts the release of the connection. The user data is carried on session disconnect.
+    fn disconnect(self, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+    /// Refuses the release of the connection. The user data is carried on session not finished. The negotiated release session functional unit must have been negotiated.
+    fn not_finished(&mut self, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+
+    /// Sends TTD PPDUs. The typed data session functional unit must have been negotiated.
+    fn send_typed_data(&mut self, user_data: &mut VecDeque<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+
+    /// Sends a TE PPDU. The expedited data session functional unit must have been negotiated and the encoded PPDU may not exceed 14 bytes.
+    fn send_expedited_data(&mut self, user_data: UserData) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+
+    /// Sends a TC PPDU. The capability data session functional unit must have been negotiated.
+    fn send_capability_data(&mut self, user_data: UserData) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+
+    /// Sends a TCC PPDU in response to a TC PPDU.
+    fn send_capability_data_ack(&mut self, user_data: UserData) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+
+    /// Sends an RS PPDU. The resynchronize session functional unit must have been negotiated.
+    fn resynchronize(
+        &mut self,
+        resynchronize_type: ResynchronizeType,
+        serial_number: u32,
+        presentation_contexts: Option<Vec<PresentationContextIdentifier>>,
+        user_data: Option<UserData>,
+    ) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
+
+    /// Sends an RSA PPDU in response to an RS PPDU.
+    fn resynchronize_ack(&mut self, serial_number: u32, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> impl std::future::Future<Output = Result<(), CoppError>> + Send;
 }
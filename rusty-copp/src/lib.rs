@@ -7,7 +7,7 @@ use rusty_cosp::{RustyCospInitiatorIsoStack, RustyCospReaderIsoStack, RustyCospR
 pub use api::*;
 pub use service::*;
 
-pub type RustyCoppReaderIsoStack<R> = RustyCoppReader<RustyCospReaderIsoStack<R>>;
+pub type RustyCoppReaderIsoStack<R, W> = RustyCoppReader<RustyCospReaderIsoStack<R>, RustyCospWriterIsoStack<W>>;
 pub type RustyCoppWriterIsoStack<W> = RustyCoppWriter<RustyCospWriterIsoStack<W>>;
 pub type RustyCoppInitiatorIsoStack<R, W> = RustyCoppInitiator<RustyCospInitiatorIsoStack<R, W>, RustyCospReaderIsoStack<R>, RustyCospWriterIsoStack<W>>;
 pub type RustyCoppListenerIsoStack<R, W> = RustyCoppListener<RustyCospResponderIsoStack<R, W>, RustyCospReaderIsoStack<R>, RustyCospWriterIsoStack<W>>;
@@ -20,7 +20,10 @@ mod tests {
 
     use anyhow::anyhow;
     use der_parser::Oid;
-    use rusty_cosp::{CospConnectionParameters, CospProtocolInformation, RustyCospAcceptor, RustyCospInitiator, RustyCospReader, RustyCospResponder, RustyCospWriter};
+    use rusty_cosp::{
+        CospConnection, CospConnectionParameters, CospFunctionalUnits, CospInitiator, CospProtocolInformation, CospReader, CospRecvResult, CospWriter, RustyCospAcceptor, RustyCospInitiator, RustyCospReader, RustyCospResponder,
+        RustyCospWriter,
+    };
     use rusty_cotp::{CotpProtocolInformation, CotpResponder, RustyCotpConnection, RustyCotpReader, RustyCotpResponder, RustyCotpWriter};
     use rusty_tpkt::{TcpTpktConnection, TcpTpktReader, TcpTpktServer, TcpTpktWriter};
     use tokio::join;
@@ -59,6 +62,7 @@ mod tests {
                 transfer_syntax_name: None,
             }])),
             presentation_contexts,
+            CospConnectionParameters::default(),
         )
         .await?;
 
@@ -85,6 +89,49 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_release_the_connection() -> Result<(), anyhow::Error> {
+        let user_data = |value: u8| {
+            UserData::FullyEncoded(vec![PresentationDataValueList { presentation_context_identifier: vec![0x01], presentation_data_values: PresentationDataValues::SingleAsn1Type(vec![0x02, 0x01, value]), transfer_syntax_name: None }])
+        };
+        let presentation_contexts = vec![PresentationContext {
+            identifier: vec![1],
+            abstract_syntax_name: Oid::from(&[2, 2, 1, 0, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?,
+            transfer_syntax_name_list: vec![Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?],
+        }];
+        let cosp_parameters = CospConnectionParameters { functional_units: CospFunctionalUnits { negotiated_release: true, ..Default::default() }, ..Default::default() };
+        let (client_connection, server_connection) = create_copp_connection_pair_with_options(Some(user_data(1)), CoppConnectionInformation::default(), Some(user_data(2)), presentation_contexts, cosp_parameters).await?;
+
+        let (mut client_reader, mut client_writer) = client_connection.split().await?;
+        let (mut server_reader, mut server_writer) = server_connection.split().await?;
+
+        // The first release is refused and the connection remains open.
+        client_writer.finish(Some(user_data(3))).await?;
+        match server_reader.recv().await? {
+            CoppRecvResult::Finish(data) => assert_eq!(data, Some(user_data(3))),
+            x => assert!(false, "Expected finish: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.not_finished(Some(user_data(5))).await?;
+        match client_reader.recv().await? {
+            CoppRecvResult::NotFinished(data) => assert_eq!(data, Some(user_data(5))),
+            x => assert!(false, "Expected not finished: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        client_writer.finish(Some(user_data(3))).await?;
+        match server_reader.recv().await? {
+            CoppRecvResult::Finish(data) => assert_eq!(data, Some(user_data(3))),
+            x => assert!(false, "Expected finish: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.disconnect(Some(user_data(4))).await?;
+        match client_reader.recv().await? {
+            CoppRecvResult::Disconnect(data) => assert_eq!(data, Some(user_data(4))),
+            x => assert!(false, "Expected disconnect: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[traced_test]
     async fn it_should_reject_the_connection() -> Result<(), anyhow::Error> {
@@ -117,6 +164,7 @@ mod tests {
             let copp_client = RustyCoppInitiator::<RustyCospInitiator<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(
                 cosp_client,
                 options,
+                CoppConnectionParameters::default(),
             );
             let check_value = copp_client
                 .initiate(
@@ -144,8 +192,11 @@ mod tests {
             let cotp_connection = cotp_server.accept(protocol_info.responder()).await?;
             let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_connection, CospConnectionParameters::default()).await?;
             let (copp_listener, _) =
-                RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(cosp_listener)
-                    .await?;
+                RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(
+                    cosp_listener,
+                    CoppConnectionParameters::default(),
+                )
+                .await?;
             copp_listener
                 .reject(
                     PresentationContextResultType::ContextDefinitionList(vec![PresentationContextResult {
@@ -167,11 +218,216 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_exchange_optional_ppdus() -> Result<(), anyhow::Error> {
+        let presentation_contexts = vec![PresentationContext {
+            identifier: vec![3],
+            abstract_syntax_name: Oid::from(&[1, 0, 9506, 2, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?,
+            transfer_syntax_name_list: vec![Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?],
+        }];
+        let functional_units = CospFunctionalUnits { typed_data: true, expedited_data: true, capability_data: true, resynchronize: true, negotiated_release: true };
+        let (client_connection, server_connection) = create_copp_connection_pair_with_options(None, Default::default(), None, presentation_contexts, CospConnectionParameters { functional_units, ..Default::default() }).await?;
+
+        let (mut client_reader, mut client_writer) = client_connection.split().await?;
+        let (mut server_reader, mut server_writer) = server_connection.split().await?;
+
+        let user_data = |value: u8| {
+            UserData::FullyEncoded(vec![PresentationDataValueList { presentation_context_identifier: vec![0x03], presentation_data_values: PresentationDataValues::SingleAsn1Type(vec![0x02, 0x01, value]), transfer_syntax_name: None }])
+        };
+
+        client_writer.send_typed_data(&mut VecDeque::from(vec![user_data(1)])).await?;
+        match server_reader.recv().await? {
+            CoppRecvResult::TypedData(data) => assert_eq!(data, user_data(1)),
+            x => assert!(false, "Expected typed data: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        server_writer.send_expedited_data(user_data(2)).await?;
+        match client_reader.recv().await? {
+            CoppRecvResult::ExpeditedData(data) => assert_eq!(data, user_data(2)),
+            x => assert!(false, "Expected expedited data: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        client_writer.send_capability_data(user_data(3)).await?;
+        match server_reader.recv().await? {
+            CoppRecvResult::CapabilityData(data) => assert_eq!(data, user_data(3)),
+            x => assert!(false, "Expected capability data: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.send_capability_data_ack(user_data(4)).await?;
+        match client_reader.recv().await? {
+            CoppRecvResult::CapabilityDataAck(data) => assert_eq!(data, user_data(4)),
+            x => assert!(false, "Expected capability data ack: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        let context_identifiers = vec![PresentationContextIdentifier { identifier: vec![3], transfer_syntax_name: Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))? }];
+        client_writer.resynchronize(ResynchronizeType::Restart, 10, Some(context_identifiers.clone()), Some(user_data(5))).await?;
+        match server_reader.recv().await? {
+            CoppRecvResult::Resynchronize(resynchronize_type, serial_number, contexts, data) => {
+                assert_eq!(resynchronize_type, ResynchronizeType::Restart);
+                assert_eq!(serial_number, 10);
+                assert_eq!(contexts, Some(context_identifiers.clone()));
+                assert_eq!(data, Some(user_data(5)));
+            }
+            x => assert!(false, "Expected resynchronize: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.resynchronize_ack(10, None, None).await?;
+        match client_reader.recv().await? {
+            CoppRecvResult::ResynchronizeAck(serial_number, contexts, data) => {
+                assert_eq!(serial_number, 10);
+                assert_eq!(contexts, None);
+                assert_eq!(data, None);
+            }
+            x => assert!(false, "Expected resynchronize ack: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_create_x410_connection() -> Result<(), anyhow::Error> {
+        // There are no presentation contexts in X.410-1984 mode. Each user data carries a single value.
+        let user_data = |value: u8| {
+            UserData::FullyEncoded(vec![PresentationDataValueList { presentation_context_identifier: vec![], presentation_data_values: PresentationDataValues::SingleAsn1Type(vec![0x02, 0x01, value]), transfer_syntax_name: None }])
+        };
+        let options = CoppConnectionInformation { presentation_mode: PresentationMode::X410, ..Default::default() };
+        let (client_connection, server_connection) = create_copp_connection_pair_with_options(Some(user_data(1)), options, Some(user_data(2)), vec![], CospConnectionParameters::default()).await?;
+
+        let (mut client_reader, mut client_writer) = client_connection.split().await?;
+        let (mut server_reader, mut server_writer) = server_connection.split().await?;
+
+        client_writer.send(&mut VecDeque::from(vec![user_data(3)])).await?;
+        match server_reader.recv().await? {
+            CoppRecvResult::Data(data) => assert_eq!(data, user_data(3)),
+            x => assert!(false, "Expected data: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.send(&mut VecDeque::from(vec![user_data(4)])).await?;
+        match client_reader.recv().await? {
+            CoppRecvResult::Data(data) => assert_eq!(data, user_data(4)),
+            x => assert!(false, "Expected data: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        client_writer.user_abort(None, Some(user_data(5))).await?;
+        match server_reader.recv().await {
+            Err(CoppError::UserAborted(contexts, data)) => {
+                assert_eq!(contexts, None);
+                assert_eq!(data, Some(user_data(5)));
+            }
+            Err(x) => assert!(false, "Expected a user abort: {x}"),
+            Ok(x) => assert!(false, "Expected a user abort: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_provider_abort_on_malformed_ppdus() -> Result<(), anyhow::Error> {
+        let test_address = format!("127.0.0.1:{}", rand::random_range::<u16, Range<u16>>(20000..30000)).parse()?;
+        let presentation_contexts = vec![PresentationContext {
+            identifier: vec![3],
+            abstract_syntax_name: Oid::from(&[1, 0, 9506, 2, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?,
+            transfer_syntax_name_list: vec![Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?],
+        }];
+
+        // The client talks raw COSP so it can send PPDUs the server cannot parse.
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let tpkt_client = TcpTpktConnection::connect(test_address).await?;
+            let cotp_client = RustyCotpConnection::<TcpTpktReader, TcpTpktWriter>::initiate(tpkt_client, CotpProtocolInformation::initiator(None, None), Default::default()).await?;
+            let cosp_client = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, CospProtocolInformation::new(None, None), Default::default()).await?;
+            let connect_message = messages::connect::ConnectMessage::new(None, PresentationMode::Normal, None, None, PresentationContextType::ContextDefinitionList(presentation_contexts), None);
+            let (cosp_connection, _) = cosp_client.initiate(Some(connect_message.serialise()?)).await?;
+            let (mut cosp_reader, mut cosp_writer) = cosp_connection.split().await?;
+
+            cosp_writer.send(&mut VecDeque::from(vec![vec![0x61, 0x03, 0x02, 0x01]])).await?;
+            match cosp_reader.recv().await {
+                Err(rusty_cosp::CospError::Aborted(Some(data))) => match messages::abortprovider::AbortProviderMessage::parse(data)?.to_error() {
+                    CoppError::ProviderAborted(reason, event) => {
+                        assert_eq!(reason, Some(AbortReason::Value(AbortReasonValue::UnrecognizedPpdu)));
+                        assert_eq!(event, Some(EventIdentifier::Value(EventIdentifierValue::TdPpdu)));
+                    }
+                    x => assert!(false, "Expected a provider abort: {x}"),
+                },
+                Err(x) => assert!(false, "Expected a provider abort: {x}"),
+                Ok(x) => assert!(false, "Expected a provider abort: {}", <CospRecvResult as Into<&'static str>>::into(x)),
+            }
+            Ok(())
+        };
+        let server_path = async {
+            let tpkt_server = TcpTpktServer::listen(test_address).await?;
+            let tpkt_connection = tpkt_server.accept().await?;
+            let (cotp_server, protocol_info) = RustyCotpResponder::<TcpTpktReader, TcpTpktWriter>::new(tpkt_connection, Default::default()).await?;
+            let cotp_connection = cotp_server.accept(protocol_info.responder()).await?;
+            let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_connection, CospConnectionParameters::default()).await?;
+            let (copp_listener, _) =
+                RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(
+                    cosp_listener,
+                    CoppConnectionParameters::default(),
+                )
+                .await?;
+            let (copp_responder, _, _) = copp_listener.accept().await?;
+            let (mut copp_reader, _copp_writer) = copp_responder.complete_connection(None).await?.split().await?;
+
+            match copp_reader.recv().await {
+                Err(CoppError::ProtocolError(_)) => (),
+                Err(x) => assert!(false, "Expected a protocol error: {x}"),
+                Ok(x) => assert!(false, "Expected a protocol error: {}", <CoppRecvResult as Into<&'static str>>::into(x)),
+            }
+            Ok(())
+        };
+
+        let (client, server): (Result<_, anyhow::Error>, Result<_, anyhow::Error>) = join!(client_path, server_path);
+        client?;
+        server?;
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_not_provider_abort_when_disabled() -> Result<(), anyhow::Error> {
+        let test_address = format!("127.0.0.1:{}", rand::random_range::<u16, Range<u16>>(20000..30000)).parse()?;
+
+        let client_path = async {
+            tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
+            let tpkt_client = TcpTpktConnection::connect(test_address).await?;
+            let cotp_client = RustyCotpConnection::<TcpTpktReader, TcpTpktWriter>::initiate(tpkt_client, CotpProtocolInformation::initiator(None, None), Default::default()).await?;
+            let cosp_client = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, CospProtocolInformation::new(None, None), Default::default()).await?;
+            match cosp_client.initiate(Some(vec![0x31, 0x03, 0x02, 0x01])).await {
+                Err(rusty_cosp::CospError::Aborted(Some(_))) => assert!(false, "No provider abort was expected"),
+                _ => (),
+            }
+            Ok(())
+        };
+        let server_path = async {
+            let tpkt_server = TcpTpktServer::listen(test_address).await?;
+            let tpkt_connection = tpkt_server.accept().await?;
+            let (cotp_server, protocol_info) = RustyCotpResponder::<TcpTpktReader, TcpTpktWriter>::new(tpkt_connection, Default::default()).await?;
+            let cotp_connection = cotp_server.accept(protocol_info.responder()).await?;
+            let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_connection, CospConnectionParameters::default()).await?;
+            let copp_listener = RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(
+                cosp_listener,
+                CoppConnectionParameters { automatic_provider_abort: false },
+            )
+            .await;
+            assert!(copp_listener.is_err(), "Expected the malformed CP PPDU to fail");
+            Ok(())
+        };
+
+        let (client, server): (Result<_, anyhow::Error>, Result<_, anyhow::Error>) = join!(client_path, server_path);
+        client?;
+        server?;
+
+        Ok(())
+    }
+
     async fn create_copp_connection_pair_with_options(
         connect_data: Option<UserData>,
         options: CoppConnectionInformation,
         accept_data: Option<UserData>,
         contexts: Vec<PresentationContext>,
+        cosp_parameters: CospConnectionParameters,
     ) -> Result<(impl CoppConnection, impl CoppConnection), anyhow::Error> {
         let test_address = format!("127.0.0.1:{}", rand::random_range::<u16, Range<u16>>(20000..30000)).parse()?;
         // let test_address = "127.0.0.1:10002".parse()?;
@@ -182,10 +438,11 @@ mod tests {
             tokio::time::sleep(Duration::from_millis(1)).await; // Give the server time to start
             let tpkt_client = TcpTpktConnection::connect(test_address).await?;
             let cotp_client = RustyCotpConnection::<TcpTpktReader, TcpTpktWriter>::initiate(tpkt_client, connect_information.clone(), Default::default()).await?;
-            let cosp_client = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, CospProtocolInformation::new(None, None), Default::default()).await?;
+            let cosp_client = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, CospProtocolInformation::new(None, None), cosp_parameters.clone()).await?;
             let copp_client = RustyCoppInitiator::<RustyCospInitiator<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(
                 cosp_client,
                 options,
+                CoppConnectionParameters::default(),
             );
             Ok(copp_client.initiate(PresentationContextType::ContextDefinitionList(contexts), connect_data.clone()).await?)
         };
@@ -194,10 +451,13 @@ mod tests {
             let tpkt_connection = tpkt_server.accept().await?;
             let (cotp_server, protocol_info) = RustyCotpResponder::<TcpTpktReader, TcpTpktWriter>::new(tpkt_connection, Default::default()).await?;
             let cotp_connection = cotp_server.accept(protocol_info.responder()).await?;
-            let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_connection, CospConnectionParameters::default()).await?;
+            let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_connection, cosp_parameters.clone()).await?;
             let (copp_listener, _) =
-                RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(cosp_listener)
-                    .await?;
+                RustyCoppListener::<RustyCospResponder<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>, RustyCospReader<RustyCotpReader<TcpTpktReader>>, RustyCospWriter<RustyCotpWriter<TcpTpktWriter>>>::new(
+                    cosp_listener,
+                    CoppConnectionParameters::default(),
+                )
+                .await?;
             let (copp_responder, _, connect_user_data) = copp_listener.accept().await?;
 
             Ok((copp_responder.complete_connection(accept_data.clone()).await?, connect_user_data))
@@ -1,24 +1,24 @@
 use der_parser::der::{Class, Header, Tag};
 
-use crate::{CoppError, EventIdentifier, ProviderReason, error::protocol_error, messages::parsers::process_integer};
+use crate::{AbortReason, CoppError, EventIdentifier, error::protocol_error, messages::parsers::process_integer};
 
 #[derive(Debug)]
 pub(crate) struct AbortProviderMessage {
-    provider_reason: Option<ProviderReason>,
+    provider_reason: Option<AbortReason>,
     event_identifier: Option<EventIdentifier>,
 }
 
 impl AbortProviderMessage {
-    pub(crate) fn new(provider_reason: Option<ProviderReason>, event_identifier: Option<EventIdentifier>) -> Self {
+    pub(crate) fn new(provider_reason: Option<AbortReason>, event_identifier: Option<EventIdentifier>) -> Self {
         Self { provider_reason, event_identifier }
     }
 
     pub(crate) fn parse(data: Vec<u8>) -> Result<AbortProviderMessage, CoppError> {
-        let mut provider_reason = None; // a.k.a abort reason
+        let mut provider_reason = None;
         let mut event_identifier = None;
 
         // This destructively processes the payload directly into the accept message in a single pass. No retrun is required.
-        der_parser::ber::parse_ber_set_of_v(|data| {
+        der_parser::ber::parse_ber_sequence_of_v(|data| {
             let (abort_message_remainder, object) = der_parser::ber::parse_ber_any(data)?;
 
             match object.header.raw_tag() {
@@ -28,7 +28,7 @@ impl AbortProviderMessage {
             };
             Ok((abort_message_remainder, 0))
         })(&data)
-        .map_err(|e| protocol_error("sd", e))?;
+        .map_err(|e| protocol_error("Failed to parse ARP PPDU", e))?;
 
         Ok(AbortProviderMessage { provider_reason: provider_reason.map(|x| x[..].into()), event_identifier: event_identifier.map(|x| x[..].into()) })
     }
@@ -57,7 +57,7 @@ impl AbortProviderMessage {
         };
 
         Ok(der_parser::ber::BerObject::from_header_and_content(
-            Header::new(Class::ContextSpecific, true, Tag::from(30), der_parser::ber::Length::Definite(0)),
+            Header::new(Class::Universal, true, Tag::Sequence, der_parser::ber::Length::Definite(0)),
             der_parser::ber::BerObjectContent::Sequence(vec![provider_reason_ber, event_identifier_ber].into_iter().filter_map(|f| f).collect()),
         )
         .to_vec()
@@ -4,19 +4,20 @@ use der_parser::{
 };
 
 use crate::{
-    CoppError, PresentationContextIdentifier, UserData,
+    CoppError, PresentationContextIdentifier, PresentationMode, UserData,
     messages::parsers::{process_constructed_data, process_presentation_context_identifier_list},
 };
 
 #[derive(Debug)]
 pub(crate) struct AbortUserMessage {
+    presentation_mode: PresentationMode,
     presentation_contexts: Option<Vec<PresentationContextIdentifier>>,
     user_data: Option<UserData>,
 }
 
 impl AbortUserMessage {
-    pub(crate) fn new(presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Self {
-        Self { presentation_contexts, user_data }
+    pub(crate) fn new(presentation_mode: PresentationMode, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Self {
+        Self { presentation_mode, presentation_contexts, user_data }
     }
 
     pub(crate) fn parse(data: Vec<u8>) -> Result<AbortUserMessage, CoppError> {
@@ -26,8 +27,19 @@ impl AbortUserMessage {
         // This destructively processes the payload directly into the accept message in a single pass. No retrun is required.
         let (_, container) = parse_ber_any(&data).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
         container.header.assert_constructed().map_err(|e| CoppError::ProtocolError(e.to_string()))?;
-        container.header.assert_tag(Tag::Set).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
-        container.header.assert_class(Class::Universal).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+
+        // The X.410-1984 mode RTABapdu is a set whereas the normal mode parameters are tagged [0].
+        if container.header.class() == Class::Universal && container.header.tag() == Tag::Set {
+            for object in process_constructed_data(container.data).map_err(|e| CoppError::ProtocolError(e.to_string()))? {
+                // The abort reason and reflected parameter are specific to reliable transfer, so they are ignored.
+                if let Some(&[162]) = object.header.raw_tag() {
+                    user_data = Some(UserData::from_x410_value(object.data.to_vec()));
+                }
+            }
+            return Ok(AbortUserMessage { presentation_mode: PresentationMode::X410, presentation_contexts: None, user_data });
+        }
+        container.header.assert_tag(Tag::from(0)).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+        container.header.assert_class(Class::ContextSpecific).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
 
         for object in process_constructed_data(container.data).map_err(|e| CoppError::ProtocolError(e.to_string()))? {
             match object.header.raw_tag() {
@@ -38,7 +50,7 @@ impl AbortUserMessage {
                 _ => (),
             };
         }
-        Ok(AbortUserMessage { presentation_contexts: context_definition_list, user_data })
+        Ok(AbortUserMessage { presentation_mode: PresentationMode::Normal, presentation_contexts: context_definition_list, user_data })
     }
 
     pub(crate) fn to_error(self) -> CoppError {
@@ -46,6 +58,11 @@ impl AbortUserMessage {
     }
 
     pub(crate) fn serialise(&self) -> Result<Vec<u8>, CoppError> {
+        match self.presentation_mode {
+            PresentationMode::X410 => return self.serialise_x410(),
+            PresentationMode::Unknown => return Err(CoppError::InternalError(format!("Unsupported mode: {:?}", self.presentation_mode))),
+            PresentationMode::Normal => (),
+        }
         let user_data = match &self.user_data {
             Some(user_data) => Some(user_data.to_ber()),
             None => None,
@@ -82,7 +99,7 @@ impl AbortUserMessage {
                                     .collect(),
                             ),
                         )),
-                        None => return Err(CoppError::ProtocolError("At least one context is required in a context definition list.".into())),
+                        None => None,
                     },
                     user_data,
                 ]
@@ -94,4 +111,27 @@ impl AbortUserMessage {
         .to_vec()
         .map_err(|e| CoppError::InternalError(e.to_string()))?)
     }
+
+    /// X.410-1984 mode aborts with an RTABapdu. Only the user data is carried.
+    fn serialise_x410(&self) -> Result<Vec<u8>, CoppError> {
+        let user_data = match &self.user_data {
+            Some(user_data) => Some(user_data.x410_value()?),
+            None => None,
+        };
+
+        der_parser::ber::BerObject::from_set(
+            user_data
+                .map(|x| {
+                    der_parser::ber::BerObject::from_header_and_content(
+                        Header::new(Class::ContextSpecific, true, Tag::from(2), der_parser::ber::Length::Definite(0)),
+                        // Shoehorn the BER data into the payload but make it still look like BER data.
+                        der_parser::ber::BerObjectContent::OctetString(x),
+                    )
+                })
+                .into_iter()
+                .collect(),
+        )
+        .to_vec()
+        .map_err(|e| CoppError::InternalError(e.to_string()))
+    }
 }
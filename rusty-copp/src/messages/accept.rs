@@ -4,8 +4,10 @@ use der_parser::{
 };
 
 use crate::{
-    CoppError, PresentationContextResultType, UserData,
-    messages::parsers::{PresentationMode, Protocol, process_constructed_data, process_integer, process_octetstring, process_presentation_context_result_list, process_protocol},
+    CoppError, PresentationContextResultType, PresentationMode, UserData,
+    messages::parsers::{
+        Protocol, process_constructed_data, process_integer, process_octetstring, process_presentation_context_result_list, process_protocol, process_x410_connection_data, serialise_mode_selector, serialise_x410_connection_data,
+    },
 };
 
 #[derive(Debug)]
@@ -18,8 +20,13 @@ pub(crate) struct AcceptMessage {
 }
 
 impl AcceptMessage {
-    pub(crate) fn new(protocol: Option<Protocol>, responding_presentation_selector: Option<Vec<u8>>, context_definition_result_list: PresentationContextResultType, user_data: Option<UserData>) -> Self {
-        Self { protocol, presentation_mode: Some(PresentationMode::Normal), responding_presentation_selector, context_definition_result_list, user_data }
+    pub(crate) fn new(protocol: Option<Protocol>, presentation_mode: PresentationMode, responding_presentation_selector: Option<Vec<u8>>, context_definition_result_list: PresentationContextResultType, user_data: Option<UserData>) -> Self {
+        Self { protocol, presentation_mode: Some(presentation_mode), responding_presentation_selector, context_definition_result_list, user_data }
+    }
+
+    /// The mode selector is mandatory, but we are relaxed and assume normal mode if it is missing.
+    pub(crate) fn presentation_mode(&self) -> PresentationMode {
+        self.presentation_mode.unwrap_or(PresentationMode::Normal)
     }
 
     pub(crate) fn user_data(self) -> Option<UserData> {
@@ -45,6 +52,15 @@ impl AcceptMessage {
                         accept_message.presentation_mode = Some(PresentationMode::from(presentation_mode.as_slice()));
                     }
                 }
+                Some(&[161]) => {
+                    // X.410-1984 mode parameters (RTOACapdu). Only the connection data is of interest. The checkpoint and window parameters are ignored.
+                    for x410_object in process_constructed_data(object.data).map_err(|e| CoppError::ProtocolError(format!("Failed to parse X.410-1984 Mode Parameters from COPP Accept Message: {e}")))? {
+                        if let Some(&[162]) = x410_object.header.raw_tag() {
+                            accept_message.user_data =
+                                process_x410_connection_data(x410_object.data).map_err(|e| CoppError::ProtocolError(format!("Failed to parse X.410-1984 Connection Data from COPP Accept Message: {e}")))?.map(UserData::from_x410_value);
+                        }
+                    }
+                }
                 Some(&[162]) => {
                     // This is technically a sequence. But we are going to be relaxed. The standard also says to ignore unknown tags, which can complicate processing. So we treat this as a set.
                     for npm_object in process_constructed_data(object.data).map_err(|e| CoppError::ProtocolError(format!("Failed to parse COPP Accept Mesasge Body: {e}")))? {
@@ -71,8 +87,10 @@ impl AcceptMessage {
     }
 
     pub(crate) fn serialise(&self) -> Result<Vec<u8>, CoppError> {
-        if matches!(self.presentation_mode, Some(PresentationMode::X410)) || matches!(self.presentation_mode, Some(PresentationMode::Unknown)) {
-            return Err(CoppError::InternalError(format!("Unsupported mode: {:?}", self.presentation_mode)));
+        match self.presentation_mode {
+            Some(PresentationMode::X410) => return self.serialise_x410(),
+            Some(PresentationMode::Unknown) => return Err(CoppError::InternalError(format!("Unsupported mode: {:?}", self.presentation_mode))),
+            _ => (),
         }
         let user_data = match &self.user_data {
             Some(user_data) => Some(user_data.to_ber()),
@@ -166,6 +184,25 @@ impl AcceptMessage {
         .to_vec()
         .map_err(|e| CoppError::InternalError(e.to_string()))?)
     }
+
+    /// X.410-1984 mode has no selectors or context results. The user data is carried on the RTOACapdu connection data.
+    fn serialise_x410(&self) -> Result<Vec<u8>, CoppError> {
+        let user_data = match &self.user_data {
+            Some(user_data) => user_data.x410_value()?,
+            None => return Err(CoppError::ProtocolError("User data is required on an X.410-1984 mode CPA PPDU.".into())),
+        };
+
+        der_parser::ber::BerObject::from_set(vec![
+            serialise_mode_selector(&PresentationMode::X410),
+            // X.410-1984 Mode Parameters. The checkpoint size and window size are left as their defaults.
+            der_parser::ber::BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(1), der_parser::ber::Length::Definite(0)),
+                der_parser::ber::BerObjectContent::Set(vec![serialise_x410_connection_data(2, user_data)]),
+            ),
+        ])
+        .to_vec()
+        .map_err(|e| CoppError::InternalError(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -181,6 +218,7 @@ mod tests {
     async fn it_should_parse_accept() -> Result<(), anyhow::Error> {
         let subject = AcceptMessage::new(
             Some(Protocol::Version1),
+            PresentationMode::Normal,
             Some(vec![0x04]),
             PresentationContextResultType::ContextDefinitionList(vec![PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: None, provider_reason: None }]),
             None,
@@ -191,4 +229,18 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_parse_x410_accept() -> Result<(), anyhow::Error> {
+        let user_data = UserData::from_x410_value(vec![0x31, 0x03, 0x80, 0x01, 0x01]);
+        let subject = AcceptMessage::new(None, PresentationMode::X410, None, PresentationContextResultType::ContextDefinitionList(vec![]), Some(user_data.clone()));
+        let data = subject.serialise()?;
+        let result = AcceptMessage::parse(data)?;
+        assert_eq!(result.presentation_mode(), PresentationMode::X410);
+        assert_eq!(result.responding_presentation_selector, None);
+        assert_eq!(result.user_data(), Some(user_data));
+
+        Ok(())
+    }
 }
@@ -4,8 +4,8 @@ use der_parser::{
 };
 
 use crate::{
-    CoppError, PresentationContextType, UserData,
-    messages::parsers::{PresentationMode, Protocol, process_constructed_data, process_octetstring, process_presentation_context_list, process_protocol},
+    CoppError, PresentationContextType, PresentationMode, UserData,
+    messages::parsers::{Protocol, process_constructed_data, process_octetstring, process_presentation_context_list, process_protocol, process_x410_connection_data, serialise_mode_selector, serialise_x410_connection_data},
 };
 
 #[derive(Debug)]
@@ -19,8 +19,20 @@ pub(crate) struct ConnectMessage {
 }
 
 impl ConnectMessage {
-    pub(crate) fn new(protocol: Option<Protocol>, calling_presentation_selector: Option<Vec<u8>>, called_presentation_selector: Option<Vec<u8>>, context_definition_list: PresentationContextType, user_data: Option<UserData>) -> Self {
-        Self { protocol, presentation_mode: Some(PresentationMode::Normal), calling_presentation_selector, called_presentation_selector, context_definition_list, user_data }
+    pub(crate) fn new(
+        protocol: Option<Protocol>,
+        presentation_mode: PresentationMode,
+        calling_presentation_selector: Option<Vec<u8>>,
+        called_presentation_selector: Option<Vec<u8>>,
+        context_definition_list: PresentationContextType,
+        user_data: Option<UserData>,
+    ) -> Self {
+        Self { protocol, presentation_mode: Some(presentation_mode), calling_presentation_selector, called_presentation_selector, context_definition_list, user_data }
+    }
+
+    /// The mode selector is mandatory, but we are relaxed and assume normal mode if it is missing.
+    pub(crate) fn presentation_mode(&self) -> PresentationMode {
+        self.presentation_mode.unwrap_or(PresentationMode::Normal)
     }
 
     pub(crate) fn calling_presentation_selector(&self) -> Option<&Vec<u8>> {
@@ -67,6 +79,14 @@ impl ConnectMessage {
                     .map_err(|e| CoppError::InternalError(e.to_string()))?;
                     connection_message.presentation_mode = Some(presentation_mode);
                 }
+                Some(&[161]) => {
+                    // X.410-1984 mode parameters (RTORQapdu). Only the connection data is of interest. The checkpoint, window and dialogue parameters are ignored.
+                    for x410_object in process_constructed_data(object.data).map_err(|e| CoppError::ProtocolError(e.to_string()))? {
+                        if let Some(&[163]) = x410_object.header.raw_tag() {
+                            connection_message.user_data = process_x410_connection_data(x410_object.data).map_err(|e| CoppError::ProtocolError(e.to_string()))?.map(UserData::from_x410_value);
+                        }
+                    }
+                }
                 Some(&[162]) => {
                     // This is technically a sequence. But we are going to be relaxed. The standard also says to ignore unknown tags, which can complicate processing. So we treat this as a set.
                     for npm_object in process_constructed_data(object.data).map_err(|e| CoppError::InternalError(e.to_string()))? {
@@ -93,8 +113,10 @@ impl ConnectMessage {
     }
 
     pub(crate) fn serialise(&self) -> Result<Vec<u8>, CoppError> {
-        if matches!(self.presentation_mode, Some(PresentationMode::X410)) || matches!(self.presentation_mode, Some(PresentationMode::Unknown)) {
-            return Err(CoppError::InternalError(format!("Unsupported mode: {:?}", self.presentation_mode)));
+        match self.presentation_mode {
+            Some(PresentationMode::X410) => return self.serialise_x410(),
+            Some(PresentationMode::Unknown) => return Err(CoppError::InternalError(format!("Unsupported mode: {:?}", self.presentation_mode))),
+            _ => (),
         }
         Ok(der_parser::ber::BerObject::from_set(vec![
             // Version defaults to 1, omitting.
@@ -176,6 +198,25 @@ impl ConnectMessage {
         .to_vec()
         .map_err(|e| CoppError::InternalError(e.to_string()))?)
     }
+
+    /// X.410-1984 mode has no selectors or context definitions. The user data is carried on the RTORQapdu connection data.
+    fn serialise_x410(&self) -> Result<Vec<u8>, CoppError> {
+        let user_data = match &self.user_data {
+            Some(user_data) => user_data.x410_value()?,
+            None => return Err(CoppError::ProtocolError("User data is required on an X.410-1984 mode CP PPDU.".into())),
+        };
+
+        der_parser::ber::BerObject::from_set(vec![
+            serialise_mode_selector(&PresentationMode::X410),
+            // X.410-1984 Mode Parameters. The checkpoint size, window size and dialogue mode are left as their defaults.
+            der_parser::ber::BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(1), der_parser::ber::Length::Definite(0)),
+                der_parser::ber::BerObjectContent::Set(vec![serialise_x410_connection_data(3, user_data)]),
+            ),
+        ])
+        .to_vec()
+        .map_err(|e| CoppError::InternalError(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -192,6 +233,7 @@ mod tests {
     async fn it_should_parse_connect() -> Result<(), anyhow::Error> {
         let subject = ConnectMessage::new(
             Some(Protocol::Version1),
+            PresentationMode::Normal,
             Some(vec![0x03]),
             Some(vec![0x04]),
             PresentationContextType::ContextDefinitionList(vec![
@@ -215,4 +257,19 @@ mod tests {
         assert_eq!(result.called_presentation_selector(), Some(&vec![4u8]));
         Ok(())
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_parse_x410_connect() -> Result<(), anyhow::Error> {
+        let user_data = UserData::from_x410_value(vec![0x31, 0x03, 0x80, 0x01, 0x01]);
+        let subject = ConnectMessage::new(None, PresentationMode::X410, None, None, PresentationContextType::ContextDefinitionList(vec![]), Some(user_data.clone()));
+        let data = subject.serialise()?;
+        assert_eq!(data, vec![0x31, 0x10, 0xa0, 0x03, 0x80, 0x01, 0x00, 0xa1, 0x09, 0xa3, 0x07, 0xa0, 0x05, 0x31, 0x03, 0x80, 0x01, 0x01]);
+
+        let mut result = ConnectMessage::parse(&data)?;
+        assert_eq!(result.presentation_mode(), PresentationMode::X410);
+        assert_eq!(result.calling_presentation_selector(), None);
+        assert_eq!(result.user_data_mut().take(), Some(user_data));
+        Ok(())
+    }
 }
@@ -4,4 +4,5 @@ pub(crate) mod accept;
 pub(crate) mod connect;
 pub(crate) mod parsers;
 pub(crate) mod reject;
+pub(crate) mod resynchronize;
 pub(crate) mod user_data;
@@ -1,19 +1,12 @@
 use der_parser::{
     Oid,
     asn1_rs::Any,
-    ber::{BerObjectContent, BitStringObject, parse_ber_any},
-    der::{Class, Tag},
+    ber::{BerObject, BerObjectContent, BitStringObject, parse_ber_any},
+    der::{Class, Header, Tag},
     error::BerError,
 };
 
-use crate::{PresentationContext, PresentationContextIdentifier, PresentationContextResult, PresentationContextResultCause, PresentationContextResultProviderReason, PresentationContextResultType, PresentationContextType};
-
-#[derive(Debug)]
-pub(crate) enum PresentationMode {
-    Normal,
-    X410,
-    Unknown,
-}
+use crate::{PresentationContext, PresentationContextIdentifier, PresentationContextResult, PresentationContextResultCause, PresentationContextResultProviderReason, PresentationContextResultType, PresentationContextType, PresentationMode};
 
 impl From<&[u8]> for PresentationMode {
     fn from(value: &[u8]) -> Self {
@@ -25,6 +18,16 @@ impl From<&[u8]> for PresentationMode {
     }
 }
 
+impl From<&PresentationMode> for &[u8] {
+    fn from(value: &PresentationMode) -> Self {
+        match value {
+            PresentationMode::X410 => &[0],
+            // Unknown modes cannot be serialised, so map them to normal mode.
+            PresentationMode::Normal | PresentationMode::Unknown => &[1],
+        }
+    }
+}
+
 #[derive(Debug)]
 pub(crate) enum Protocol {
     Version1,
@@ -131,9 +134,9 @@ pub(crate) fn process_presentation_result_context<'a>(npm_objects: Vec<Any<'a>>)
 
     for npm_object in npm_objects {
         match npm_object.header.raw_tag() {
-            Some(&[0]) => result = process_context_result(npm_object)?,
-            Some(&[1]) => transfer_syntax_name = process_oid(npm_object)?,
-            Some(&[2]) => provider_reason = process_integer(npm_object)?.map(|x| PresentationContextResultProviderReason::from(x.as_slice())),
+            Some(&[128]) => result = process_context_result(npm_object)?,
+            Some(&[129]) => transfer_syntax_name = process_oid(npm_object)?,
+            Some(&[130]) => provider_reason = process_integer(npm_object)?.map(|x| PresentationContextResultProviderReason::from(x.as_slice())),
             _ => (),
         };
     }
@@ -180,6 +183,38 @@ pub(crate) fn process_presentation_context_result_list<'a>(data: &'a [u8]) -> Re
     Ok(PresentationContextResultType::ContextDefinitionList(context_definition_list))
 }
 
+/// X.410-1984 connection data is a choice. Only the open alternative is supported, which carries the user data as is.
+pub(crate) fn process_x410_connection_data(data: &[u8]) -> Result<Option<Vec<u8>>, BerError> {
+    for object in process_constructed_data(data)? {
+        if let Some(&[160]) = object.header.raw_tag() {
+            return Ok(Some(object.data.to_vec()));
+        }
+    }
+    Ok(None)
+}
+
+pub(crate) fn serialise_mode_selector<'a>(presentation_mode: &PresentationMode) -> BerObject<'a> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)),
+        BerObjectContent::Set(vec![BerObject::from_header_and_content(
+            Header::new(Class::ContextSpecific, false, Tag::from(0), der_parser::ber::Length::Definite(0)),
+            BerObjectContent::Integer(presentation_mode.into()),
+        )]),
+    )
+}
+
+/// Serialises X.410-1984 connection data using the open alternative.
+pub(crate) fn serialise_x410_connection_data<'a>(tag: u32, user_data: &'a [u8]) -> BerObject<'a> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), der_parser::ber::Length::Definite(0)),
+        BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
+            Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)),
+            // Shoehorn the BER data into the payload but make it still look like BER data.
+            BerObjectContent::OctetString(user_data),
+        )]),
+    )
+}
+
 pub(crate) fn process_constructed_data<'a>(data: &'a [u8]) -> Result<Vec<Any<'a>>, BerError> {
     let mut remaining = data;
     let mut results = vec![];
@@ -4,9 +4,9 @@ use der_parser::{
 };
 
 use crate::{
-    CoppError, PresentationContextResultType, ProviderReason, UserData,
+    CoppError, PresentationContextResultType, PresentationMode, ProviderReason, UserData,
     error::protocol_error,
-    messages::parsers::{PresentationMode, Protocol, process_constructed_data, process_octetstring, process_presentation_context_result_list, process_protocol},
+    messages::parsers::{Protocol, process_constructed_data, process_octetstring, process_presentation_context_result_list, process_protocol},
 };
 
 #[derive(Debug)]
@@ -22,12 +22,13 @@ pub(crate) struct RejectMessage {
 impl RejectMessage {
     pub(crate) fn new(
         protocol: Option<Protocol>,
+        presentation_mode: PresentationMode,
         responding_presentation_selector: Option<Vec<u8>>,
         context_definition_result_list: PresentationContextResultType,
         provider_reason: Option<ProviderReason>,
         user_data: Option<UserData>,
     ) -> Self {
-        Self { protocol, presentation_mode: Some(PresentationMode::Normal), responding_presentation_selector, context_definition_result_list, provider_reason, user_data }
+        Self { protocol, presentation_mode: Some(presentation_mode), responding_presentation_selector, context_definition_result_list, provider_reason, user_data }
     }
 
     pub(crate) fn to_error(self) -> CoppError {
@@ -47,6 +48,18 @@ impl RejectMessage {
         // This destructively processes the payload directly into the accept message in a single pass. No retrun is required.
         let (_, outer_sequence) = parse_ber_any(&data).map_err(|e| CoppError::ProtocolError(format!("Failed to parse COPP Reject Message: {e}")))?;
 
+        // The X.410-1984 mode RTORJapdu is a set whereas the normal mode parameters are a sequence.
+        if outer_sequence.header.tag() == Tag::Set {
+            reject_message.presentation_mode = Some(PresentationMode::X410);
+            for object in process_constructed_data(outer_sequence.data).map_err(|e| protocol_error("Failed to parse COPP X.410-1984 Reject Message Body", e))? {
+                // The refuse reason is specific to reliable transfer and has no equivalent provider reason, so it is ignored.
+                if let Some(&[161]) = object.header.raw_tag() {
+                    reject_message.user_data = Some(UserData::from_x410_value(object.data.to_vec()));
+                }
+            }
+            return Ok(reject_message);
+        }
+
         outer_sequence.header.assert_tag(Tag::Sequence).map_err(|e| CoppError::ProtocolError(format!("Failed to parse COPP Reject Message: {e}")))?;
         outer_sequence.header.assert_constructed().map_err(|e| CoppError::ProtocolError(format!("Failed to parse COPP Reject Message: {e}")))?;
         outer_sequence.header.assert_class(Class::Universal).map_err(|e| CoppError::ProtocolError(format!("Failed to parse COPP Reject Message: {e}")))?;
@@ -68,8 +81,10 @@ impl RejectMessage {
     }
 
     pub(crate) fn serialise(&self) -> Result<Vec<u8>, CoppError> {
-        if matches!(self.presentation_mode, Some(PresentationMode::X410)) || matches!(self.presentation_mode, Some(PresentationMode::Unknown)) {
-            return Err(CoppError::InternalError(format!("Unsupported mode: {:?}", self.presentation_mode)));
+        match self.presentation_mode {
+            Some(PresentationMode::X410) => return self.serialise_x410(),
+            Some(PresentationMode::Unknown) => return Err(CoppError::InternalError(format!("Unsupported mode: {:?}", self.presentation_mode))),
+            _ => (),
         }
         let provider_reason: Option<Vec<u8>> = match &self.provider_reason {
             Some(value) => Some(value.into()),
@@ -150,6 +165,29 @@ impl RejectMessage {
         .to_vec()
         .map_err(|e| CoppError::InternalError(e.to_string()))?)
     }
+
+    /// X.410-1984 mode rejects with an RTORJapdu. Only the user data is carried.
+    fn serialise_x410(&self) -> Result<Vec<u8>, CoppError> {
+        let user_data = match &self.user_data {
+            Some(user_data) => Some(user_data.x410_value()?),
+            None => None,
+        };
+
+        der_parser::ber::BerObject::from_set(
+            user_data
+                .map(|x| {
+                    der_parser::ber::BerObject::from_header_and_content(
+                        Header::new(Class::ContextSpecific, true, Tag::from(1), der_parser::ber::Length::Definite(0)),
+                        // Shoehorn the BER data into the payload but make it still look like BER data.
+                        der_parser::ber::BerObjectContent::OctetString(x),
+                    )
+                })
+                .into_iter()
+                .collect(),
+        )
+        .to_vec()
+        .map_err(|e| CoppError::InternalError(e.to_string()))
+    }
 }
 
 #[cfg(test)]
@@ -165,6 +203,7 @@ mod tests {
     async fn it_should_parse_reject() -> Result<(), anyhow::Error> {
         let subject = RejectMessage::new(
             Some(Protocol::Version1),
+            PresentationMode::Normal,
             Some(vec![0x04]),
             PresentationContextResultType::ContextDefinitionList(vec![PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: None, provider_reason: None }]),
             None,
@@ -176,4 +215,19 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_parse_x410_reject() -> Result<(), anyhow::Error> {
+        let user_data = UserData::from_x410_value(vec![0x02, 0x01, 0x01]);
+        let subject = RejectMessage::new(None, PresentationMode::X410, None, PresentationContextResultType::ContextDefinitionList(vec![]), None, Some(user_data.clone()));
+        let data = subject.serialise()?;
+        assert_eq!(data, vec![0x31, 0x05, 0xa1, 0x03, 0x02, 0x01, 0x01]);
+
+        let result = RejectMessage::parse(data)?;
+        assert!(matches!(result.presentation_mode, Some(PresentationMode::X410)));
+        assert_eq!(result.user_data, Some(user_data));
+
+        Ok(())
+    }
 }
@@ -0,0 +1,118 @@
+use der_parser::{
+    ber::parse_ber_any,
+    der::{Class, Header, Tag},
+};
+
+use crate::{
+    CoppError, PresentationContextIdentifier, UserData,
+    messages::parsers::{process_constructed_data, process_presentation_context_identifier_list},
+};
+
+/// The RS-PPDU and RSA-PPDU share the same structure. This is used for both.
+#[derive(Debug)]
+pub(crate) struct ResynchronizeMessage {
+    presentation_contexts: Option<Vec<PresentationContextIdentifier>>,
+    user_data: Option<UserData>,
+}
+
+impl ResynchronizeMessage {
+    pub(crate) fn new(presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Self {
+        Self { presentation_contexts, user_data }
+    }
+
+    pub(crate) fn take(self) -> (Option<Vec<PresentationContextIdentifier>>, Option<UserData>) {
+        (self.presentation_contexts, self.user_data)
+    }
+
+    pub(crate) fn parse(data: Vec<u8>) -> Result<ResynchronizeMessage, CoppError> {
+        let mut user_data = None;
+        let mut presentation_contexts = None;
+
+        let (_, container) = parse_ber_any(&data).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+        container.header.assert_constructed().map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+        container.header.assert_tag(Tag::Set).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+        container.header.assert_class(Class::Universal).map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+
+        for object in process_constructed_data(container.data).map_err(|e| CoppError::ProtocolError(e.to_string()))? {
+            match object.header.raw_tag() {
+                Some(&[160]) => {
+                    presentation_contexts = Some(process_presentation_context_identifier_list(object.data).map_err(|e| CoppError::ProtocolError(format!("Failed to parse Context Identifier list on COPP Resynchronize Message: {e}")))?)
+                }
+                Some(&[97]) => user_data = Some(UserData::parse(object)?),
+                _ => (),
+            };
+        }
+        Ok(ResynchronizeMessage { presentation_contexts, user_data })
+    }
+
+    pub(crate) fn serialise(&self) -> Result<Vec<u8>, CoppError> {
+        let presentation_contexts = self.presentation_contexts.as_ref().map(|contexts| {
+            der_parser::ber::BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(0), der_parser::ber::Length::Definite(0)),
+                der_parser::ber::BerObjectContent::Sequence(
+                    contexts
+                        .iter()
+                        .map(|context| {
+                            der_parser::ber::BerObject::from_seq(vec![
+                                der_parser::ber::BerObject::from_obj(der_parser::ber::BerObjectContent::Integer(&context.identifier)),
+                                der_parser::ber::BerObject::from_obj(der_parser::ber::BerObjectContent::OID(context.transfer_syntax_name.clone())),
+                            ])
+                        })
+                        .collect(),
+                ),
+            )
+        });
+        let user_data = self.user_data.as_ref().map(|user_data| user_data.to_ber());
+
+        der_parser::ber::BerObject::from_set(vec![presentation_contexts, user_data].into_iter().flatten().collect()).to_vec().map_err(|e| CoppError::InternalError(e.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use der_parser::Oid;
+    use tracing_test::traced_test;
+
+    use crate::{PresentationDataValueList, PresentationDataValues};
+
+    use super::*;
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_parse_resynchronize() -> Result<(), anyhow::Error> {
+        let presentation_contexts = vec![PresentationContextIdentifier { identifier: vec![3], transfer_syntax_name: Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))? }];
+        let user_data =
+            UserData::FullyEncoded(vec![PresentationDataValueList { transfer_syntax_name: None, presentation_context_identifier: vec![3], presentation_data_values: PresentationDataValues::SingleAsn1Type(vec![0x02, 0x01, 0x05]) }]);
+
+        let data = ResynchronizeMessage::new(Some(presentation_contexts.clone()), Some(user_data.clone())).serialise()?;
+        let (result_contexts, result_user_data) = ResynchronizeMessage::parse(data)?.take();
+        assert_eq!(result_contexts, Some(presentation_contexts));
+        assert_eq!(result_user_data, Some(user_data));
+
+        let data = ResynchronizeMessage::new(None, None).serialise()?;
+        let (result_contexts, result_user_data) = ResynchronizeMessage::parse(data)?.take();
+        assert_eq!(result_contexts, None);
+        assert_eq!(result_user_data, None);
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_encode_resynchronize_as_a_set() -> Result<(), anyhow::Error> {
+        let presentation_contexts = vec![PresentationContextIdentifier { identifier: vec![3], transfer_syntax_name: Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))? }];
+        let user_data =
+            UserData::FullyEncoded(vec![PresentationDataValueList { transfer_syntax_name: None, presentation_context_identifier: vec![3], presentation_data_values: PresentationDataValues::SingleAsn1Type(vec![0x02, 0x01, 0x05]) }]);
+        let expected = vec![
+            0x31, 0x17, 0xa0, 0x09, 0x30, 0x07, 0x02, 0x01, 0x03, 0x06, 0x02, 0x51, 0x01, 0x61, 0x0a, 0x30, 0x08, 0x02, 0x01, 0x03, 0xa0, 0x03, 0x02, 0x01, 0x05,
+        ];
+
+        assert_eq!(ResynchronizeMessage::new(Some(presentation_contexts.clone()), Some(user_data.clone())).serialise()?, expected);
+        let (result_contexts, result_user_data) = ResynchronizeMessage::parse(expected)?.take();
+        assert_eq!(result_contexts, Some(presentation_contexts));
+        assert_eq!(result_user_data, Some(user_data));
+
+        assert_eq!(ResynchronizeMessage::new(None, None).serialise()?, vec![0x31, 0x00]);
+        assert!(ResynchronizeMessage::parse(vec![0x30, 0x00]).is_err(), "Expected a SEQUENCE to be rejected");
+        Ok(())
+    }
+}
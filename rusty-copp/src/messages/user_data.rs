@@ -80,6 +80,20 @@ impl UserData {
         let (_, packet) = parse_ber_any(data).map_err(|e| CoppError::ProtocolError(format!("Failed to parse user data: {e}")))?;
         Ok(UserData::parse(packet)?)
     }
+
+    /// X.410-1984 mode carries a single value with no presentation context.
+    pub(crate) fn from_x410_value(data: Vec<u8>) -> UserData {
+        UserData::FullyEncoded(vec![PresentationDataValueList { transfer_syntax_name: None, presentation_context_identifier: vec![], presentation_data_values: PresentationDataValues::SingleAsn1Type(data) }])
+    }
+
+    pub(crate) fn x410_value(&self) -> Result<&[u8], CoppError> {
+        match self {
+            UserData::FullyEncoded(presentation_data_value_lists) => match presentation_data_value_lists.as_slice() {
+                [PresentationDataValueList { presentation_data_values: PresentationDataValues::SingleAsn1Type(data), .. }] => Ok(data),
+                _ => Err(CoppError::ProtocolError("X.410-1984 mode user data must contain exactly one presentation data value.".into())),
+            },
+        }
+    }
 }
 
 impl PresentationDataValueList {
@@ -1,12 +1,14 @@
-use std::{collections::VecDeque, marker::PhantomData};
+use std::{collections::VecDeque, marker::PhantomData, sync::Arc};
 
 use der_parser::Oid;
 use rusty_cosp::{CospAcceptor, CospConnection, CospError, CospInitiator, CospReader, CospRecvResult, CospResponder, CospWriter, ReasonCode};
+use tokio::sync::Mutex;
 
 use crate::{
-    CoppConnection, CoppConnectionInformation, CoppError, CoppInitResult, CoppInitiator, CoppListener, CoppReader, CoppRecvResult, CoppResponder, CoppWriter, EventIdentifier, PresentationContextIdentifier, PresentationContextResult,
-    PresentationContextResultCause, PresentationContextResultType, PresentationContextType, ProviderReason, UserData,
-    messages::{abortprovider::AbortProviderMessage, abortuser::AbortUserMessage, accept::AcceptMessage, connect::ConnectMessage, reject::RejectMessage},
+    AbortReason, AbortReasonValue, CoppConnection, CoppConnectionInformation, CoppConnectionParameters, CoppError, CoppInitResult, CoppInitiator, CoppListener, CoppReader, CoppRecvResult, CoppResponder, CoppWriter, EventIdentifier,
+    EventIdentifierValue, PresentationContextIdentifier, PresentationContextResult, PresentationContextResultCause, PresentationContextResultProviderReason, PresentationContextResultProviderReasonValue, PresentationContextResultType,
+    PresentationContextType, PresentationMode, ProviderReason, ResynchronizeType, UserData,
+    messages::{abortprovider::AbortProviderMessage, abortuser::AbortUserMessage, accept::AcceptMessage, connect::ConnectMessage, reject::RejectMessage, resynchronize::ResynchronizeMessage},
 };
 
 pub struct RustyCoppInitiator<T: CospInitiator, R: CospReader, W: CospWriter> {
@@ -14,11 +16,12 @@ pub struct RustyCoppInitiator<T: CospInitiator, R: CospReader, W: CospWriter> {
     cosp_reader: PhantomData<R>,
     cosp_writer: PhantomData<W>,
     options: CoppConnectionInformation,
+    parameters: CoppConnectionParameters,
 }
 
 impl<T: CospInitiator, R: CospReader, W: CospWriter> RustyCoppInitiator<T, R, W> {
-    pub fn new(cosp_initiator: impl CospInitiator, options: CoppConnectionInformation) -> RustyCoppInitiator<impl CospInitiator, impl CospReader, impl CospWriter> {
-        RustyCoppInitiator { cosp_initiator, cosp_reader: PhantomData::<R>, cosp_writer: PhantomData::<W>, options }
+    pub fn new(cosp_initiator: impl CospInitiator, options: CoppConnectionInformation, parameters: CoppConnectionParameters) -> RustyCoppInitiator<impl CospInitiator, impl CospReader, impl CospWriter> {
+        RustyCoppInitiator { cosp_initiator, cosp_reader: PhantomData::<R>, cosp_writer: PhantomData::<W>, options, parameters }
     }
 }
 
@@ -26,27 +29,41 @@ impl<T: CospInitiator, R: CospReader, W: CospWriter> CoppInitiator for RustyCopp
     async fn initiate(self, presentation_contexts: PresentationContextType, user_data: Option<UserData>) -> Result<CoppInitResult<impl CoppConnection>, CoppError> {
         let cosp_initiator = self.cosp_initiator;
 
-        let connect_message = ConnectMessage::new(None, self.options.calling_presentation_selector, self.options.called_presentation_selector, presentation_contexts, user_data);
+        let presentation_mode = self.options.presentation_mode;
+        let connect_message = ConnectMessage::new(None, presentation_mode, self.options.calling_presentation_selector, self.options.called_presentation_selector, presentation_contexts, user_data);
         let data = connect_message.serialise()?;
 
         let (cosp_connection, accept_data) = match cosp_initiator.initiate(Some(data)).await {
             Ok(x) => x,
             Err(CospError::Refused(Some(ReasonCode::RejectionByCalledSsUserWithData(user_data)))) => return Err(RejectMessage::parse(user_data)?.to_error()),
             Err(CospError::Aborted(Some(user_data))) => match user_data.get(0) {
-                Some(160) => return Err(AbortUserMessage::parse(user_data)?.to_error()),
-                Some(30) => return Err(AbortProviderMessage::parse(user_data)?.to_error()),
+                // The ARU PPDU is tagged [0] in normal mode and is a SET in X.410-1984 mode.
+                Some(160) | Some(49) => return Err(AbortUserMessage::parse(user_data)?.to_error()),
+                // The ARP PPDU is a universal SEQUENCE.
+                Some(48) => return Err(AbortProviderMessage::parse(user_data)?.to_error()),
                 Some(x) => return Err(CoppError::ProtocolError(format!("COPP abort expected does not match a supported header: {x}"))),
                 None => return Err(CoppError::ProtocolError("COPP abort expected but no data was received.".into())),
             },
             Err(e) => Err(e)?,
         };
+        let (cosp_reader, cosp_writer) = cosp_connection.split().await?;
         let accept_message = match accept_data {
-            Some(data) => AcceptMessage::parse(data)?,
-            None => return Err(CoppError::ProtocolError("No accept message data was received fromt he remote host.".to_string())),
+            Some(data) => match AcceptMessage::parse(data) {
+                Ok(x) => x,
+                Err(e) => return Err(provider_abort_on_error(cosp_writer, &self.parameters, AbortReasonValue::UnrecognizedPpdu, EventIdentifierValue::CpaPpdu, e).await),
+            },
+            None => {
+                let error = CoppError::ProtocolError("No accept message data was received fromt he remote host.".to_string());
+                return Err(provider_abort_on_error(cosp_writer, &self.parameters, AbortReasonValue::UnexpectedSessionServicePrimitive, EventIdentifierValue::CpaPpdu, error).await);
+            }
         };
 
-        let (cosp_reader, cosp_writer) = cosp_connection.split().await?;
-        Ok(CoppInitResult::Success(RustyCoppConnection::new(cosp_reader, cosp_writer), accept_message.user_data()))
+        if accept_message.presentation_mode() != presentation_mode {
+            let error = CoppError::ProtocolError(format!("The CPA PPDU mode {:?} does not match the requested mode {:?}", accept_message.presentation_mode(), presentation_mode));
+            return Err(provider_abort_on_error(cosp_writer, &self.parameters, AbortReasonValue::InvalidPpduParameterValue, EventIdentifierValue::CpaPpdu, error).await);
+        }
+
+        Ok(CoppInitResult::Success(RustyCoppConnection::new(cosp_reader, cosp_writer, self.parameters, presentation_mode), accept_message.user_data()))
     }
 }
 
@@ -57,20 +74,45 @@ pub struct RustyCoppListener<T: CospResponder, R: CospReader, W: CospWriter> {
     cosp_writer: PhantomData<W>,
     presentation_context: PresentationContextType,
     connection_information: CoppConnectionInformation,
+    parameters: CoppConnectionParameters,
 }
 
 impl<T: CospResponder, R: CospReader, W: CospWriter> RustyCoppListener<T, R, W> {
-    pub async fn new(cosp_listener: impl CospAcceptor) -> Result<(RustyCoppListener<impl CospResponder, impl CospReader, impl CospWriter>, CoppConnectionInformation), CoppError> {
+    pub async fn new(cosp_listener: impl CospAcceptor, parameters: CoppConnectionParameters) -> Result<(RustyCoppListener<impl CospResponder, impl CospReader, impl CospWriter>, CoppConnectionInformation), CoppError> {
         let (cosp_responder, user_data) = cosp_listener.accept().await?;
 
-        let mut connect_message = match user_data {
-            Some(user_data) => ConnectMessage::parse(&user_data)?,
-            None => return Err(CoppError::ProtocolError("No presentation connection data received.".to_string())),
+        let connect_message = match user_data {
+            Some(user_data) => ConnectMessage::parse(&user_data).map_err(|e| (AbortReasonValue::UnrecognizedPpdu, e)),
+            None => Err((AbortReasonValue::UnexpectedSessionServicePrimitive, CoppError::ProtocolError("No presentation connection data received.".to_string()))),
+        }
+        .and_then(|connect_message| match connect_message.presentation_mode() {
+            PresentationMode::Unknown => Err((AbortReasonValue::InvalidPpduParameterValue, CoppError::ProtocolError("Unsupported mode requested on the CP PPDU.".into()))),
+            _ => Ok(connect_message),
+        });
+        let mut connect_message = match connect_message {
+            Ok(x) => x,
+            Err((abort_reason, error)) => {
+                if parameters.automatic_provider_abort {
+                    match provider_abort_data(abort_reason, EventIdentifierValue::CpPpdu) {
+                        Ok(abort_data) => {
+                            if let Err(e) = cosp_responder.abort(Some(abort_data)).await {
+                                tracing::warn!("Failed to send provider abort: {e}");
+                            }
+                        }
+                        Err(e) => tracing::warn!("Failed to serialise provider abort: {e}"),
+                    }
+                }
+                return Err(error);
+            }
         };
 
         let presentation_user_data = connect_message.user_data_mut().take();
         let presentation_context = connect_message.context_definition_list();
-        let copp_information = CoppConnectionInformation { calling_presentation_selector: connect_message.calling_presentation_selector().cloned(), called_presentation_selector: connect_message.called_presentation_selector().cloned() };
+        let copp_information = CoppConnectionInformation {
+            calling_presentation_selector: connect_message.calling_presentation_selector().cloned(),
+            called_presentation_selector: connect_message.called_presentation_selector().cloned(),
+            presentation_mode: connect_message.presentation_mode(),
+        };
 
         Ok((
             RustyCoppListener {
@@ -80,6 +122,7 @@ impl<T: CospResponder, R: CospReader, W: CospWriter> RustyCoppListener<T, R, W>
                 user_data: presentation_user_data,
                 connection_information: copp_information.clone(),
                 presentation_context: presentation_context.clone(),
+                parameters,
                 // resultant_contexts: None,
             },
             copp_information,
@@ -89,21 +132,25 @@ impl<T: CospResponder, R: CospReader, W: CospWriter> RustyCoppListener<T, R, W>
 
 impl<T: CospResponder, R: CospReader, W: CospWriter> CoppListener for RustyCoppListener<T, R, W> {
     async fn accept(self) -> Result<(impl CoppResponder, PresentationContextType, Option<UserData>), CoppError> {
-        Ok((RustyCoppResponder::<T, R, W>::new(self.cosp_responder, self.connection_information), self.presentation_context, self.user_data))
+        let mut responder = RustyCoppResponder::<T, R, W>::new(self.cosp_responder, self.connection_information, self.parameters);
+        responder.presentation_context = Some(self.presentation_context.clone());
+        Ok((responder, self.presentation_context, self.user_data))
     }
 
     async fn reject(self, context_definition_result_list: PresentationContextResultType, provider_reason: Option<ProviderReason>, user_data: Option<UserData>) -> Result<(), CoppError> {
         let responder = self.connection_information.called_presentation_selector;
-        self.cosp_responder.refuse(Some(ReasonCode::RejectionByCalledSsUserWithData(RejectMessage::new(None, responder, context_definition_result_list, provider_reason, user_data).serialise()?))).await?;
+        self.cosp_responder
+            .refuse(Some(ReasonCode::RejectionByCalledSsUserWithData(RejectMessage::new(None, self.connection_information.presentation_mode, responder, context_definition_result_list, provider_reason, user_data).serialise()?)))
+            .await?;
         Ok(())
     }
 
     async fn user_abort(self, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Result<(), CoppError> {
-        self.cosp_responder.abort(Some(AbortUserMessage::new(presentation_contexts, user_data).serialise()?)).await?;
+        self.cosp_responder.abort(Some(AbortUserMessage::new(self.connection_information.presentation_mode, presentation_contexts, user_data).serialise()?)).await?;
         Ok(())
     }
 
-    async fn provider_abort(self, provider_reason: Option<ProviderReason>, event_identifier: Option<EventIdentifier>) -> Result<(), CoppError> {
+    async fn provider_abort(self, provider_reason: Option<AbortReason>, event_identifier: Option<EventIdentifier>) -> Result<(), CoppError> {
         self.cosp_responder.abort(Some(AbortProviderMessage::new(provider_reason, event_identifier).serialise()?)).await?;
         Ok(())
     }
@@ -114,73 +161,154 @@ pub struct RustyCoppResponder<T: CospResponder, R: CospReader, W: CospWriter> {
     cosp_reader: PhantomData<R>,
     cosp_writer: PhantomData<W>,
     connection_information: CoppConnectionInformation,
+    parameters: CoppConnectionParameters,
+    // The contexts proposed on the CP PPDU. Those offering BER are accepted when the connection is completed without explicit results.
+    presentation_context: Option<PresentationContextType>,
 }
 
 impl<T: CospResponder, R: CospReader, W: CospWriter> RustyCoppResponder<T, R, W> {
-    pub fn new(cosp_responder: T, connection_information: CoppConnectionInformation) -> RustyCoppResponder<impl CospResponder, impl CospReader, impl CospWriter> {
-        RustyCoppResponder { cosp_responder, cosp_reader: PhantomData::<R>, cosp_writer: PhantomData::<W>, connection_information }
+    pub fn new(cosp_responder: T, connection_information: CoppConnectionInformation, parameters: CoppConnectionParameters) -> RustyCoppResponder<impl CospResponder, impl CospReader, impl CospWriter> {
+        RustyCoppResponder { cosp_responder, cosp_reader: PhantomData::<R>, cosp_writer: PhantomData::<W>, connection_information, parameters, presentation_context: None }
     }
 }
 
 impl<T: CospResponder, R: CospReader, W: CospWriter> CoppResponder for RustyCoppResponder<T, R, W> {
     async fn complete_connection(self, accept_data: Option<UserData>) -> Result<impl CoppConnection, CoppError> {
-        let contexts = PresentationContextResultType::ContextDefinitionList(vec![
-            PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: Some(Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?), provider_reason: None },
-            PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: Some(Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?), provider_reason: None },
-        ]);
+        // A responder that was not created from a CP PPDU assumes the usual ACSE and application contexts.
+        let contexts = match &self.presentation_context {
+            Some(presentation_context) => default_context_results(presentation_context)?,
+            None => {
+                let transfer_syntax_name = Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?;
+                PresentationContextResultType::ContextDefinitionList(
+                    (0..2).map(|_| PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: Some(transfer_syntax_name.clone()), provider_reason: None }).collect(),
+                )
+            }
+        };
+        self.complete_connection_with_results(contexts, accept_data).await
+    }
+
+    async fn complete_connection_with_results(self, context_definition_result_list: PresentationContextResultType, accept_data: Option<UserData>) -> Result<impl CoppConnection, CoppError> {
+        // The result list must line up with the proposed list.
+        if let (Some(PresentationContextType::ContextDefinitionList(proposed)), PresentationContextResultType::ContextDefinitionList(results)) = (&self.presentation_context, &context_definition_result_list)
+            && proposed.len() != results.len()
+        {
+            return Err(CoppError::InternalError(format!("Expected {} presentation context results but found {}", proposed.len(), results.len())));
+        }
 
         let responder = self.cosp_responder;
-        let accept_message = AcceptMessage::new(None, self.connection_information.called_presentation_selector, contexts, accept_data);
+        let presentation_mode = self.connection_information.presentation_mode;
+        let accept_message = AcceptMessage::new(None, presentation_mode, self.connection_information.called_presentation_selector, context_definition_result_list, accept_data);
         let accept_message_data = Some(accept_message.serialise()?);
         let (cosp_reader, cosp_writer) = responder.complete_connection(accept_message_data).await?.split().await?;
-        Ok(RustyCoppConnection::new(cosp_reader, cosp_writer))
+        Ok(RustyCoppConnection::new(cosp_reader, cosp_writer, self.parameters, presentation_mode))
     }
 
     async fn reject(self, context_definition_result_list: PresentationContextResultType, provider_reason: Option<ProviderReason>, user_data: Option<UserData>) -> Result<(), CoppError> {
         let responder = self.connection_information.called_presentation_selector;
-        self.cosp_responder.refuse(Some(ReasonCode::RejectionByCalledSsUserWithData(RejectMessage::new(None, responder, context_definition_result_list, provider_reason, user_data).serialise()?))).await?;
+        self.cosp_responder
+            .refuse(Some(ReasonCode::RejectionByCalledSsUserWithData(RejectMessage::new(None, self.connection_information.presentation_mode, responder, context_definition_result_list, provider_reason, user_data).serialise()?)))
+            .await?;
         Ok(())
     }
 
     async fn user_abort(self, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Result<(), CoppError> {
-        self.cosp_responder.abort(Some(AbortUserMessage::new(presentation_contexts, user_data).serialise()?)).await?;
+        self.cosp_responder.abort(Some(AbortUserMessage::new(self.connection_information.presentation_mode, presentation_contexts, user_data).serialise()?)).await?;
         Ok(())
     }
 
-    async fn provider_abort(self, provider_reason: Option<ProviderReason>, event_identifier: Option<EventIdentifier>) -> Result<(), CoppError> {
+    async fn provider_abort(self, provider_reason: Option<AbortReason>, event_identifier: Option<EventIdentifier>) -> Result<(), CoppError> {
         self.cosp_responder.abort(Some(AbortProviderMessage::new(provider_reason, event_identifier).serialise()?)).await?;
         Ok(())
     }
 }
 
+/// Builds the result list for the proposed contexts. A context is accepted with BER if BER was proposed for it and is otherwise rejected by the provider as none of its transfer syntaxes are supported.
+pub fn default_context_results(presentation_context: &PresentationContextType) -> Result<PresentationContextResultType, CoppError> {
+    let transfer_syntax_name = Oid::from(&[2, 1, 1]).map_err(|e| CoppError::InternalError(e.to_string()))?;
+    let PresentationContextType::ContextDefinitionList(contexts) = presentation_context;
+    Ok(PresentationContextResultType::ContextDefinitionList(
+        contexts
+            .iter()
+            .map(|context| match context.transfer_syntax_name_list.contains(&transfer_syntax_name) {
+                true => PresentationContextResult { result: PresentationContextResultCause::Acceptance, transfer_syntax_name: Some(transfer_syntax_name.clone()), provider_reason: None },
+                false => PresentationContextResult {
+                    result: PresentationContextResultCause::ProviderRejection,
+                    transfer_syntax_name: None,
+                    provider_reason: Some(PresentationContextResultProviderReason::Value(PresentationContextResultProviderReasonValue::ProposedAbstrctSyntaxNotSupported)),
+                },
+            })
+            .collect(),
+    ))
+}
+
+/// Serialises an ARP PPDU used to signal a local protocol failure to the peer.
+fn provider_abort_data(abort_reason: AbortReasonValue, event_identifier: EventIdentifierValue) -> Result<Vec<u8>, CoppError> {
+    AbortProviderMessage::new(Some(AbortReason::Value(abort_reason)), Some(EventIdentifier::Value(event_identifier))).serialise()
+}
+
+/// Sends an ARP PPDU on the writer if automatic provider aborts are enabled and returns the original error.
+/// Failures to send the abort are logged as the original error is the more useful one to the user.
+async fn provider_abort_on_error(cosp_writer: impl CospWriter, parameters: &CoppConnectionParameters, abort_reason: AbortReasonValue, event_identifier: EventIdentifierValue, error: CoppError) -> CoppError {
+    if !parameters.automatic_provider_abort {
+        return error;
+    }
+    match provider_abort_data(abort_reason, event_identifier) {
+        Ok(abort_data) => {
+            if let Err(e) = cosp_writer.abort(Some(abort_data)).await {
+                tracing::warn!("Failed to send provider abort: {e}");
+            }
+        }
+        Err(e) => tracing::warn!("Failed to serialise provider abort: {e}"),
+    }
+    error
+}
+
 pub struct RustyCoppConnection<R: CospReader, W: CospWriter> {
     cosp_reader: R,
     cosp_writer: W,
+    parameters: CoppConnectionParameters,
+    presentation_mode: PresentationMode,
 }
 
 impl<R: CospReader, W: CospWriter> RustyCoppConnection<R, W> {
-    fn new(cosp_reader: R, cosp_writer: W) -> RustyCoppConnection<impl CospReader, impl CospWriter> {
-        RustyCoppConnection { cosp_reader, cosp_writer }
+    fn new(cosp_reader: R, cosp_writer: W, parameters: CoppConnectionParameters, presentation_mode: PresentationMode) -> RustyCoppConnection<impl CospReader, impl CospWriter> {
+        RustyCoppConnection { cosp_reader, cosp_writer, parameters, presentation_mode }
     }
 }
 
 impl<R: CospReader, W: CospWriter> CoppConnection for RustyCoppConnection<R, W> {
     async fn split(self) -> Result<(impl CoppReader, impl CoppWriter), CoppError> {
-        Ok((RustyCoppReader::new(self.cosp_reader), RustyCoppWriter::new(self.cosp_writer)))
+        // The reader shares the writer so it can abort the connection when it receives a PPDU it cannot process.
+        let cosp_writer = Arc::new(Mutex::new(Some(self.cosp_writer)));
+        Ok((RustyCoppReader::new(self.cosp_reader, cosp_writer.clone(), self.parameters, self.presentation_mode), RustyCoppWriter::new(cosp_writer, self.presentation_mode)))
     }
 }
 
-pub struct RustyCoppReader<R: CospReader> {
+pub struct RustyCoppReader<R: CospReader, W: CospWriter> {
     cosp_reader: R,
+    cosp_writer: Arc<Mutex<Option<W>>>,
+    parameters: CoppConnectionParameters,
+    presentation_mode: PresentationMode,
 }
 
-impl<R: CospReader> RustyCoppReader<R> {
-    fn new(cosp_reader: R) -> RustyCoppReader<impl CospReader> {
-        RustyCoppReader { cosp_reader }
+impl<R: CospReader, W: CospWriter> RustyCoppReader<R, W> {
+    fn new(cosp_reader: R, cosp_writer: Arc<Mutex<Option<W>>>, parameters: CoppConnectionParameters, presentation_mode: PresentationMode) -> RustyCoppReader<impl CospReader, impl CospWriter> {
+        RustyCoppReader { cosp_reader, cosp_writer, parameters, presentation_mode }
+    }
+
+    async fn provider_abort_on_error(&mut self, abort_reason: AbortReasonValue, event_identifier: EventIdentifierValue, error: CoppError) -> CoppError {
+        if !self.parameters.automatic_provider_abort {
+            return error;
+        }
+        match self.cosp_writer.lock().await.take() {
+            Some(cosp_writer) => provider_abort_on_error(cosp_writer, &self.parameters, abort_reason, event_identifier, error).await,
+            // The writer has already been consumed by a release or abort.
+            None => error,
+        }
     }
 }
 
-impl<R: CospReader> CoppReader for RustyCoppReader<R> {
+impl<R: CospReader, W: CospWriter> CoppReader for RustyCoppReader<R, W> {
     async fn recv(&mut self) -> Result<CoppRecvResult, CoppError> {
         let message = match self.cosp_reader.recv().await {
             Ok(x) => x,
@@ -188,62 +316,190 @@ impl<R: CospReader> CoppReader for RustyCoppReader<R> {
             Err(CospError::Refused(Some(ReasonCode::RejectionByCalledSsUserWithData(user_data)))) => return Err(RejectMessage::parse(user_data)?.to_error()),
             Err(CospError::Aborted(None)) => return Err(CoppError::ProviderAborted(None, None)),
             Err(CospError::Aborted(Some(user_data))) => match user_data.get(0) {
-                Some(160) => return Err(AbortUserMessage::parse(user_data)?.to_error()),
-                Some(30) => return Err(AbortProviderMessage::parse(user_data)?.to_error()),
+                // The ARU PPDU is tagged [0] in normal mode and is a SET in X.410-1984 mode.
+                Some(160) | Some(49) => return Err(AbortUserMessage::parse(user_data)?.to_error()),
+                // The ARP PPDU is a universal SEQUENCE.
+                Some(48) => return Err(AbortProviderMessage::parse(user_data)?.to_error()),
                 Some(x) => return Err(CoppError::ProtocolError(format!("COPP abort expected does not match a supported header: {x}"))),
                 None => return Err(CoppError::ProtocolError("COPP abort expected but no data was received.".into())),
             },
             Result::Err(e) => Err(e)?,
         };
 
-        match message {
-            // There is no special encoding of user data on finish or disconnect.
+        // PPDUs that cannot be processed are reported to the peer with the event that carried them.
+        let (event_identifier, result) = match message {
             CospRecvResult::Closed => return Ok(CoppRecvResult::Closed),
-            CospRecvResult::Finish(x) => Ok(CoppRecvResult::Finish(x)),
-            CospRecvResult::Disconnect(x) => Ok(CoppRecvResult::Disconnect(x)),
-            CospRecvResult::Data(items) => Ok(CoppRecvResult::Data(UserData::parse_raw(&items).map_err(|e| CoppError::ProtocolError(e.to_string()))?)),
+            // The user data on finish, disconnect and not finished is encoded as user data without a PPDU.
+            CospRecvResult::Finish(None) => return Ok(CoppRecvResult::Finish(None)),
+            CospRecvResult::Finish(Some(items)) if self.presentation_mode == PresentationMode::X410 => return Ok(CoppRecvResult::Finish(Some(UserData::from_x410_value(items)))),
+            CospRecvResult::Finish(Some(items)) => (EventIdentifierValue::SessionReleaseIndication, UserData::parse_raw(&items).map(|x| CoppRecvResult::Finish(Some(x)))),
+            CospRecvResult::Disconnect(None) => return Ok(CoppRecvResult::Disconnect(None)),
+            CospRecvResult::Disconnect(Some(items)) if self.presentation_mode == PresentationMode::X410 => return Ok(CoppRecvResult::Disconnect(Some(UserData::from_x410_value(items)))),
+            CospRecvResult::Disconnect(Some(items)) => (EventIdentifierValue::SessionReleaseConfirm, UserData::parse_raw(&items).map(|x| CoppRecvResult::Disconnect(Some(x)))),
+            CospRecvResult::NotFinished(None) => return Ok(CoppRecvResult::NotFinished(None)),
+            CospRecvResult::NotFinished(Some(items)) if self.presentation_mode == PresentationMode::X410 => return Ok(CoppRecvResult::NotFinished(Some(UserData::from_x410_value(items)))),
+            CospRecvResult::NotFinished(Some(items)) => (EventIdentifierValue::SessionReleaseConfirm, UserData::parse_raw(&items).map(|x| CoppRecvResult::NotFinished(Some(x)))),
+            // There is no TD PPDU in X.410-1984 mode. The user data is carried as is.
+            CospRecvResult::Data(items) if self.presentation_mode == PresentationMode::X410 => return Ok(CoppRecvResult::Data(UserData::from_x410_value(items))),
+            CospRecvResult::Data(items) => (EventIdentifierValue::TdPpdu, UserData::parse_raw(&items).map(CoppRecvResult::Data)),
+            // The TTD, TE, TC and TCC PPDUs are all encoded as user data.
+            CospRecvResult::TypedData(items) => (EventIdentifierValue::TtdPpdu, UserData::parse_raw(&items).map(CoppRecvResult::TypedData)),
+            CospRecvResult::ExpeditedData(items) => (EventIdentifierValue::TePpdu, UserData::parse_raw(&items).map(CoppRecvResult::ExpeditedData)),
+            CospRecvResult::CapabilityData(Some(items)) => (EventIdentifierValue::TcPpdu, UserData::parse_raw(&items).map(CoppRecvResult::CapabilityData)),
+            CospRecvResult::CapabilityData(None) => (EventIdentifierValue::TcPpdu, Err(CoppError::ProtocolError("No TC PPDU was found on the capability data.".into()))),
+            CospRecvResult::CapabilityDataAck(Some(items)) => (EventIdentifierValue::TccPpdu, UserData::parse_raw(&items).map(CoppRecvResult::CapabilityDataAck)),
+            CospRecvResult::CapabilityDataAck(None) => (EventIdentifierValue::TccPpdu, Err(CoppError::ProtocolError("No TCC PPDU was found on the capability data acknowledgement.".into()))),
+            CospRecvResult::Resynchronize(resynchronize_type, serial_number, Some(items)) => (
+                EventIdentifierValue::RsPpdu,
+                ResynchronizeMessage::parse(items).map(|message| {
+                    let (presentation_contexts, user_data) = message.take();
+                    CoppRecvResult::Resynchronize(resynchronize_type, serial_number, presentation_contexts, user_data)
+                }),
+            ),
+            CospRecvResult::Resynchronize(_, _, None) => (EventIdentifierValue::RsPpdu, Err(CoppError::ProtocolError("No RS PPDU was found on the resynchronize indication.".into()))),
+            CospRecvResult::ResynchronizeAck(serial_number, Some(items)) => (
+                EventIdentifierValue::RsaPpdu,
+                ResynchronizeMessage::parse(items).map(|message| {
+                    let (presentation_contexts, user_data) = message.take();
+                    CoppRecvResult::ResynchronizeAck(serial_number, presentation_contexts, user_data)
+                }),
+            ),
+            CospRecvResult::ResynchronizeAck(_, None) => (EventIdentifierValue::RsaPpdu, Err(CoppError::ProtocolError("No RSA PPDU was found on the resynchronize acknowledgement.".into()))),
+        };
+
+        match result {
+            Ok(x) => Ok(x),
+            Err(e) => Err(self.provider_abort_on_error(AbortReasonValue::UnrecognizedPpdu, event_identifier, e).await),
         }
     }
 }
 
 pub struct RustyCoppWriter<W: CospWriter> {
-    cosp_writer: W,
+    cosp_writer: Arc<Mutex<Option<W>>>,
     buffer: VecDeque<Vec<u8>>,
+    presentation_mode: PresentationMode,
 }
 
 impl<W: CospWriter> RustyCoppWriter<W> {
-    fn new(cosp_writer: W) -> RustyCoppWriter<impl CospWriter> {
-        RustyCoppWriter { cosp_writer, buffer: VecDeque::new() }
+    fn new(cosp_writer: Arc<Mutex<Option<W>>>, presentation_mode: PresentationMode) -> RustyCoppWriter<impl CospWriter> {
+        RustyCoppWriter { cosp_writer, buffer: VecDeque::new(), presentation_mode }
+    }
+
+    /// Only the kernel functional unit is available in X.410-1984 mode.
+    fn verify_normal_mode(&self) -> Result<(), CoppError> {
+        match self.presentation_mode {
+            PresentationMode::X410 => Err(CoppError::ProtocolError("This service is not available in X.410-1984 mode.".into())),
+            _ => Ok(()),
+        }
     }
+
+    /// There is no PPDU for a release. The user data is encoded on its own.
+    fn release_data(&self, user_data: Option<UserData>) -> Result<Option<Vec<u8>>, CoppError> {
+        match (user_data, self.presentation_mode) {
+            (None, _) => Ok(None),
+            (Some(user_data), PresentationMode::X410) => Ok(Some(user_data.x410_value()?.to_vec())),
+            (Some(user_data), _) => Ok(Some(user_data.to_ber().to_vec().map_err(|e| CoppError::ProtocolError(e.to_string()))?)),
+        }
+    }
+
+    async fn take_writer(&self) -> Result<W, CoppError> {
+        self.cosp_writer.lock().await.take().ok_or_else(connection_aborted)
+    }
+}
+
+/// The error raised when the writer was consumed by an automatic provider abort.
+fn connection_aborted() -> CoppError {
+    CoppError::ProtocolError("The connection has already been aborted.".into())
 }
 
 impl<W: CospWriter> CoppWriter for RustyCoppWriter<W> {
     async fn send(&mut self, user_data: &mut VecDeque<UserData>) -> Result<(), CoppError> {
         while let Some(user_data_item) = user_data.pop_front() {
-            self.buffer.push_back(user_data_item.to_ber().to_vec().map_err(|e| CoppError::ProtocolError(e.to_string()))?);
+            let data = match self.presentation_mode {
+                // There is no TD PPDU in X.410-1984 mode. The user data is sent as is.
+                PresentationMode::X410 => user_data_item.x410_value()?.to_vec(),
+                _ => user_data_item.to_ber().to_vec().map_err(|e| CoppError::ProtocolError(e.to_string()))?,
+            };
+            self.buffer.push_back(data);
         }
 
+        let mut cosp_writer = self.cosp_writer.lock().await;
+        let cosp_writer = cosp_writer.as_mut().ok_or_else(connection_aborted)?;
         while !self.buffer.is_empty() {
-            self.cosp_writer.send(&mut self.buffer).await?;
+            cosp_writer.send(&mut self.buffer).await?;
         }
 
         // Perform one more to ensure lower levels are also flushed even if this layer is complete.
-        self.cosp_writer.send(&mut self.buffer).await?;
+        cosp_writer.send(&mut self.buffer).await?;
         Ok(())
     }
 
     async fn user_abort(self, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Result<(), CoppError> {
-        self.cosp_writer.abort(Some(AbortUserMessage::new(presentation_contexts, user_data).serialise()?)).await?;
+        let abort_data = AbortUserMessage::new(self.presentation_mode, presentation_contexts, user_data).serialise()?;
+        self.take_writer().await?.abort(Some(abort_data)).await?;
+        Ok(())
+    }
+
+    async fn finish(&mut self, user_data: Option<UserData>) -> Result<(), CoppError> {
+        let data = self.release_data(user_data)?;
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.finish(data).await?;
+        Ok(())
+    }
+
+    async fn disconnect(self, user_data: Option<UserData>) -> Result<(), CoppError> {
+        let data = self.release_data(user_data)?;
+        self.take_writer().await?.disconnect(data).await?;
+        Ok(())
+    }
+
+    async fn not_finished(&mut self, user_data: Option<UserData>) -> Result<(), CoppError> {
+        let data = self.release_data(user_data)?;
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.not_finished(data).await?;
+        Ok(())
+    }
+
+    async fn send_typed_data(&mut self, user_data: &mut VecDeque<UserData>) -> Result<(), CoppError> {
+        self.verify_normal_mode()?;
+        let mut buffer = VecDeque::new();
+        while let Some(user_data_item) = user_data.pop_front() {
+            buffer.push_back(user_data_item.to_ber().to_vec().map_err(|e| CoppError::ProtocolError(e.to_string()))?);
+        }
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.send_typed_data(&mut buffer).await?;
+        Ok(())
+    }
+
+    async fn send_expedited_data(&mut self, user_data: UserData) -> Result<(), CoppError> {
+        self.verify_normal_mode()?;
+        let data = user_data.to_ber().to_vec().map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.send_expedited_data(data).await?;
+        Ok(())
+    }
+
+    async fn send_capability_data(&mut self, user_data: UserData) -> Result<(), CoppError> {
+        self.verify_normal_mode()?;
+        let data = user_data.to_ber().to_vec().map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.send_capability_data(Some(data)).await?;
+        Ok(())
+    }
+
+    async fn send_capability_data_ack(&mut self, user_data: UserData) -> Result<(), CoppError> {
+        self.verify_normal_mode()?;
+        let data = user_data.to_ber().to_vec().map_err(|e| CoppError::ProtocolError(e.to_string()))?;
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.send_capability_data_ack(Some(data)).await?;
         Ok(())
     }
 
-    async fn finish(self) -> Result<(), CoppError> {
-        self.cosp_writer.finish(None).await?;
+    async fn resynchronize(&mut self, resynchronize_type: ResynchronizeType, serial_number: u32, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Result<(), CoppError> {
+        self.verify_normal_mode()?;
+        let message = ResynchronizeMessage::new(presentation_contexts, user_data);
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.resynchronize(resynchronize_type, serial_number, Some(message.serialise()?)).await?;
         Ok(())
     }
 
-    async fn disconnect(self) -> Result<(), CoppError> {
-        self.cosp_writer.disconnect(None).await?;
+    async fn resynchronize_ack(&mut self, serial_number: u32, presentation_contexts: Option<Vec<PresentationContextIdentifier>>, user_data: Option<UserData>) -> Result<(), CoppError> {
+        self.verify_normal_mode()?;
+        let message = ResynchronizeMessage::new(presentation_contexts, user_data);
+        self.cosp_writer.lock().await.as_mut().ok_or_else(connection_aborted)?.resynchronize_ack(serial_number, Some(message.serialise()?)).await?;
         Ok(())
     }
 }
@@ -56,14 +56,68 @@ pub struct CospConnectionParameters {
     ///
     /// Defaults to 1MB for payload plus a 1024 byte overhead to account
     pub maximum_reassembled_payload_size: usize,
+
+    /// The optional functional units to propose as an initiator, or to allow as a responder.
+    ///
+    /// Defaults to none. Only the kernel and full duplex functional units will be used.
+    pub functional_units: CospFunctionalUnits,
 }
 
 impl Default for CospConnectionParameters {
     fn default() -> Self {
-        Self { maximum_reassembled_payload_size: 1024 * 1024 + 1024 }
+        Self { maximum_reassembled_payload_size: 1024 * 1024 + 1024, functional_units: CospFunctionalUnits::default() }
     }
 }
 
+/// Optional functional units that may be negotiated on top of the kernel and full duplex functional units.
+///
+/// The negotiated set is the intersection of what the initiator proposes and what the responder allows.
+#[derive(PartialEq, Eq, Clone, Copy, Debug, Default)]
+pub struct CospFunctionalUnits {
+    /// Allows typed data to be exchanged.
+    pub typed_data: bool,
+
+    /// Allows expedited data to be exchanged. Expedited data is limited to 14 bytes of user data.
+    pub expedited_data: bool,
+
+    /// Allows capability data to be exchanged. This also proposes activity management as it is a prerequisite.
+    /// Activities are not supported, so capability data may be sent at any time.
+    pub capability_data: bool,
+
+    /// Allows the resynchronize service to be used.
+    pub resynchronize: bool,
+
+    /// Allows a finish to be refused with a not finished. Tokens are not supported, so either side may request the release.
+    pub negotiated_release: bool,
+}
+
+impl CospFunctionalUnits {
+    /// Returns the functional units supported by both sets.
+    pub fn intersection(&self, other: &CospFunctionalUnits) -> CospFunctionalUnits {
+        CospFunctionalUnits {
+            typed_data: self.typed_data && other.typed_data,
+            expedited_data: self.expedited_data && other.expedited_data,
+            capability_data: self.capability_data && other.capability_data,
+            resynchronize: self.resynchronize && other.resynchronize,
+            negotiated_release: self.negotiated_release && other.negotiated_release,
+        }
+    }
+
+    /// Returns true if all the functional units in this set are also in the other set.
+    pub fn is_subset_of(&self, other: &CospFunctionalUnits) -> bool {
+        self.intersection(other) == *self
+    }
+}
+
+/// The type of resynchronization requested.
+#[derive(Clone, Copy, Debug, IntoStaticStr, PartialEq, Eq)]
+pub enum ResynchronizeType {
+    Restart,
+    Abandon,
+    Set,
+    Unknown(u8),
+}
+
 /// Protocol information such as the calling party and the called party.
 #[derive(PartialEq, Clone, Debug)]
 pub struct CospProtocolInformation {
@@ -104,6 +158,28 @@ pub enum CospRecvResult {
 
     /// Indicates the remote side accepts the finish indication. The reader and writer may now be dropped.
     Disconnect(Option<Vec<u8>>),
+
+    /// Indicates the remote side refused the finish indication. The connection remains open. Requires the negotiated release functional unit.
+    NotFinished(Option<Vec<u8>>),
+
+    /// Indicates typed data was received. Requires the typed data functional unit.
+    TypedData(Vec<u8>),
+
+    /// Indicates expedited data was received. Requires the expedited data functional unit.
+    ExpeditedData(Vec<u8>),
+
+    /// Indicates capability data was received. An acknowledgement should be sent in response. Requires the capability data functional unit.
+    CapabilityData(Option<Vec<u8>>),
+
+    /// Indicates the remote side acknowledged capability data. Requires the capability data functional unit.
+    CapabilityDataAck(Option<Vec<u8>>),
+
+    /// Indicates the remote side requested a resynchronization with the given type and serial number.
+    /// A resynchronize acknowledgement should be sent in response. Requires the resynchronize functional unit.
+    Resynchronize(ResynchronizeType, u32, Option<Vec<u8>>),
+
+    /// Indicates the remote side acknowledged a resynchronization at the given serial number. Requires the resynchronize functional unit.
+    ResynchronizeAck(u32, Option<Vec<u8>>),
 }
 
 /// Initiates a COSP connection.
@@ -158,13 +234,34 @@ pub trait CospWriter: Send {
     fn send(&mut self, input: &mut VecDeque<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
 
     /// Signals the intent to close a connection. A disconnect should be received before dropping the reader and writer.
-    fn finish(self, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+    /// The writer remains usable as the finish may be refused with a not finished if the negotiated release functional unit is in use.
+    fn finish(&mut self, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
 
     /// Confirms that a finish was received and this side is okay to close the connection.
-    /// As this is a kernel implementation, this is the only normal flow options.
     /// Abnormal flows would be to send back an abort, or simply close the connection by dropping the reader and writer.
     fn disconnect(self, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
 
+    /// Refuses a finish that was received. The connection remains open. Requires the negotiated release functional unit.
+    fn not_finished(&mut self, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+
     /// Abort the connection. The reader and writer should be dropped after this is sent.
     fn abort(self, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+
+    /// Send typed data to the remote host. Requires the typed data functional unit.
+    fn send_typed_data(&mut self, input: &mut VecDeque<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+
+    /// Send expedited data to the remote host. The user data is limited to 14 bytes. Requires the expedited data functional unit.
+    fn send_expedited_data(&mut self, user_data: Vec<u8>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+
+    /// Send capability data to the remote host. An acknowledgement is expected in response. Requires the capability data functional unit.
+    fn send_capability_data(&mut self, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+
+    /// Acknowledge capability data received from the remote host. Requires the capability data functional unit.
+    fn send_capability_data_ack(&mut self, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+
+    /// Request a resynchronization. An acknowledgement is expected in response. Requires the resynchronize functional unit.
+    fn resynchronize(&mut self, resynchronize_type: ResynchronizeType, serial_number: u32, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
+
+    /// Acknowledge a resynchronization received from the remote host. Requires the resynchronize functional unit.
+    fn resynchronize_ack(&mut self, serial_number: u32, user_data: Option<Vec<u8>>) -> impl std::future::Future<Output = Result<(), CospError>> + Send;
 }
@@ -344,10 +344,10 @@ mod tests {
 
         let cotp_client = cotp_initiator?;
         let cotp_server = cotp_acceptor?;
-        let cosp_client_connector = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, options.clone(), connection_options).await?;
+        let cosp_client_connector = RustyCospInitiator::<RustyCotpReader<TcpTpktReader>, RustyCotpWriter<TcpTpktWriter>>::new(cotp_client, options.clone(), connection_options.clone()).await?;
 
         let (cosp_client, cosp_server) = join!(async { cosp_client_connector.initiate(connect_data.map(|o| o.to_vec())).await }, async {
-            let (cosp_server_connector, connection_information) = RustyCospAcceptorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cotp_server, CospConnectionParameters::default()).await?;
+            let (cosp_server_connector, connection_information) = RustyCospAcceptorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cotp_server, connection_options).await?;
             let (acceptor, user_data) = cosp_server_connector.accept().await?;
             assert_eq!(connect_data.map(|x| x.to_vec()), user_data);
             assert_eq!(connection_information.called_session_selector(), options.called_session_selector());
@@ -429,6 +429,48 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_refuse_a_finish_with_negotiated_release() -> Result<(), anyhow::Error> {
+        let functional_units = CospFunctionalUnits { negotiated_release: true, ..Default::default() };
+        let (client_connection, server_connection) =
+            create_cosp_connection_pair_with_options(Some(&[5, 6, 7]), CospProtocolInformation::new(None, None), CospConnectionParameters { functional_units, ..Default::default() }, Some(vec![5, 4, 3])).await?;
+
+        let (mut client_reader, mut client_writer) = client_connection.split().await?;
+        let (mut server_reader, mut server_writer) = server_connection.split().await?;
+
+        client_writer.finish(Some(b"Finish Data".to_vec())).await?;
+        match server_reader.recv().await? {
+            CospRecvResult::Finish(data) => assert_eq!(data, Some(b"Finish Data".to_vec())),
+            x => assert!(false, "Expected a finish: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.not_finished(Some(b"Not Finished Data".to_vec())).await?;
+        match client_reader.recv().await? {
+            CospRecvResult::NotFinished(data) => assert_eq!(data, Some(b"Not Finished Data".to_vec())),
+            x => assert!(false, "Expected a not finished: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        // The connection remains open after the refusal.
+        client_writer.send(&mut VecDeque::from(vec![[0x61, 0x02, 0x05, 0x00].to_vec()])).await?;
+        match server_reader.recv().await? {
+            CospRecvResult::Data(data) => assert_eq!(hex::encode(data), "61020500"),
+            x => assert!(false, "Expected the connection to be open: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        client_writer.finish(None).await?;
+        match server_reader.recv().await? {
+            CospRecvResult::Finish(data) => assert_eq!(data, None),
+            x => assert!(false, "Expected a finish: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.disconnect(None).await?;
+        match client_reader.recv().await? {
+            CospRecvResult::Disconnect(data) => assert_eq!(data, None),
+            x => assert!(false, "Expected a disconnect: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        Ok(())
+    }
+
     #[tokio::test]
     #[traced_test]
     async fn it_should_support_abort() -> Result<(), anyhow::Error> {
@@ -456,4 +498,82 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_exchange_optional_data() -> Result<(), anyhow::Error> {
+        let functional_units = CospFunctionalUnits { typed_data: true, expedited_data: true, capability_data: true, resynchronize: true, negotiated_release: true };
+        let (client_connection, server_connection) =
+            create_cosp_connection_pair_with_options(Some(&[5, 6, 7]), CospProtocolInformation::new(None, None), CospConnectionParameters { functional_units, ..Default::default() }, Some(vec![5, 4, 3])).await?;
+
+        let (mut client_reader, mut client_writer) = client_connection.split().await?;
+        let (mut server_reader, mut server_writer) = server_connection.split().await?;
+
+        let mut typed_data = vec![0u8; 100000];
+        rand::fill(typed_data.as_mut_slice());
+        client_writer.send_typed_data(&mut VecDeque::from(vec![typed_data.clone()])).await?;
+        match server_reader.recv().await? {
+            CospRecvResult::TypedData(data) => assert_eq!(data, typed_data),
+            x => assert!(false, "Expected typed data: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        server_writer.send_expedited_data(b"Expedited".to_vec()).await?;
+        match client_reader.recv().await? {
+            CospRecvResult::ExpeditedData(data) => assert_eq!(data, b"Expedited".to_vec()),
+            x => assert!(false, "Expected expedited data: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        client_writer.send_capability_data(Some(b"Capability".to_vec())).await?;
+        match server_reader.recv().await? {
+            CospRecvResult::CapabilityData(data) => assert_eq!(data, Some(b"Capability".to_vec())),
+            x => assert!(false, "Expected capability data: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+        server_writer.send_capability_data_ack(None).await?;
+        match client_reader.recv().await? {
+            CospRecvResult::CapabilityDataAck(data) => assert_eq!(data, None),
+            x => assert!(false, "Expected capability data ack: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        server_writer.resynchronize(ResynchronizeType::Set, 1234, Some(b"Resync".to_vec())).await?;
+        match client_reader.recv().await? {
+            CospRecvResult::Resynchronize(resynchronize_type, serial_number, data) => {
+                assert_eq!(resynchronize_type, ResynchronizeType::Set);
+                assert_eq!(serial_number, 1234);
+                assert_eq!(data, Some(b"Resync".to_vec()));
+            }
+            x => assert!(false, "Expected resynchronize: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+        client_writer.resynchronize_ack(1234, None).await?;
+        match server_reader.recv().await? {
+            CospRecvResult::ResynchronizeAck(serial_number, data) => {
+                assert_eq!(serial_number, 1234);
+                assert_eq!(data, None);
+            }
+            x => assert!(false, "Expected resynchronize ack: {:?}", <CospRecvResult as Into<&'static str>>::into(x)),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn it_should_not_send_optional_data_that_was_not_negotiated() -> Result<(), anyhow::Error> {
+        let (client_connection, _server_connection) = create_cosp_connection_pair_with_options(Some(&[5, 6, 7]), CospProtocolInformation::new(None, None), Default::default(), Some(vec![5, 4, 3])).await?;
+        let (_client_reader, mut client_writer) = client_connection.split().await?;
+
+        match client_writer.send_typed_data(&mut VecDeque::from(vec![vec![1, 2, 3]])).await {
+            Err(CospError::ProtocolError(_)) => (),
+            _ => assert!(false, "Expected typed data to be rejected."),
+        }
+        match client_writer.send_expedited_data(vec![1, 2, 3]).await {
+            Err(CospError::ProtocolError(_)) => (),
+            _ => assert!(false, "Expected expedited data to be rejected."),
+        }
+        match client_writer.not_finished(None).await {
+            Err(CospError::ProtocolError(_)) => (),
+            _ => assert!(false, "Expected not finished to be rejected."),
+        }
+
+        Ok(())
+    }
 }
@@ -1,4 +1,5 @@
 use crate::{
+    CospFunctionalUnits,
     api::CospError,
     message::parameters::TsduMaximumSize,
     packet::parameters::{EnclosureField, SessionPduParameter, SessionUserRequirementsField},
@@ -8,11 +9,16 @@ pub(crate) struct AcceptMessage {
     has_more_data: bool,
     user_data: Option<Vec<u8>>,
     maximum_size_to_responder: TsduMaximumSize,
+    functional_units: CospFunctionalUnits,
 }
 
 impl AcceptMessage {
-    pub(crate) fn new(has_more_data: bool, maximum_size_to_responder: TsduMaximumSize, user_data: Option<Vec<u8>>) -> Self {
-        Self { has_more_data, user_data, maximum_size_to_responder }
+    pub(crate) fn new(has_more_data: bool, maximum_size_to_responder: TsduMaximumSize, functional_units: CospFunctionalUnits, user_data: Option<Vec<u8>>) -> Self {
+        Self { has_more_data, user_data, maximum_size_to_responder, functional_units }
+    }
+
+    pub(crate) fn functional_units(&self) -> &CospFunctionalUnits {
+        &self.functional_units
     }
 
     pub(crate) fn user_data(&self) -> Option<&Vec<u8>> {
@@ -58,10 +64,11 @@ impl AcceptMessage {
             Some(_) => return Err(CospError::ProtocolError("Only version 2 is supported but not was requested by the server on accept.".into())),
             None => return Err(CospError::ProtocolError("Only version 2 is supported but version 1 was implied by the server on accept.".into())),
         }
-        if session_user_requirements.0 != 2 {
-            return Err(CospError::ProtocolError(format!("Only full duplex mode functional unit is supported in accept but got: {:?}", session_user_requirements)));
+        if !session_user_requirements.is_supported() {
+            return Err(CospError::ProtocolError(format!("Only full duplex mode and the optional data functional units are supported in accept but got: {:?}", session_user_requirements)));
         }
 
-        Ok(AcceptMessage { user_data, maximum_size_to_responder, has_more_data: !enclosure.unwrap_or_else(|| EnclosureField(2)).end() })
+        let functional_units = CospFunctionalUnits::from(&session_user_requirements);
+        Ok(AcceptMessage { user_data, maximum_size_to_responder, functional_units, has_more_data: !enclosure.unwrap_or_else(|| EnclosureField(2)).end() })
     }
 }
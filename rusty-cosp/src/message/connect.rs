@@ -1,4 +1,5 @@
 use crate::{
+    CospFunctionalUnits,
     api::CospError,
     message::parameters::TsduMaximumSize,
     packet::parameters::{DataOverflowField, SessionPduParameter, SessionUserRequirementsField},
@@ -10,6 +11,7 @@ pub(crate) struct ConnectMessage {
     calling_session_selector: Option<Vec<u8>>,
     data_overflow: Option<DataOverflowField>,
     maximum_size_to_initiator: TsduMaximumSize,
+    functional_units: CospFunctionalUnits,
 }
 
 impl ConnectMessage {
@@ -25,6 +27,10 @@ impl ConnectMessage {
         &self.maximum_size_to_initiator
     }
 
+    pub(crate) fn functional_units(&self) -> &CospFunctionalUnits {
+        &self.functional_units
+    }
+
     pub(crate) fn called_session_selector(&self) -> Option<&Vec<u8>> {
         self.called_session_selector.as_ref()
     }
@@ -86,6 +92,7 @@ impl ConnectMessage {
             (Some(_), Some(_)) => return Err(CospError::ProtocolError(format!("User Data and Overflow data was detected. Cannot continue to connect."))),
         };
 
-        Ok(ConnectMessage { user_data, data_overflow, called_session_selector, calling_session_selector, maximum_size_to_initiator })
+        let functional_units = CospFunctionalUnits::from(&session_user_requirements);
+        Ok(ConnectMessage { user_data, data_overflow, called_session_selector, calling_session_selector, maximum_size_to_initiator, functional_units })
     }
 }
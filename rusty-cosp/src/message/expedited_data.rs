@@ -0,0 +1,25 @@
+use crate::{api::CospError, packet::parameters::SessionPduParameter};
+
+pub(crate) struct ExpeditedDataMessage {
+    user_data: Vec<u8>,
+}
+
+impl ExpeditedDataMessage {
+    pub(crate) fn take_user_data(self) -> Vec<u8> {
+        self.user_data
+    }
+
+    pub(crate) fn from_parameters(parameters: &[SessionPduParameter]) -> Result<Self, CospError> {
+        let mut user_data = None;
+
+        // Not minding about order or duplicates. Ignore everything else.
+        for parameter in parameters {
+            if let SessionPduParameter::UserDataParameter(data) = parameter {
+                user_data = Some(data.clone());
+            }
+        }
+
+        // User data is mandatory on expedited data.
+        Ok(ExpeditedDataMessage { user_data: user_data.ok_or_else(|| CospError::ProtocolError("No user data was found on an Expedited Data SPDU.".into()))? })
+    }
+}
@@ -4,22 +4,30 @@ use tracing::warn;
 use crate::{
     api::CospError,
     message::{
-        abort::AbortMessage, accept::AcceptMessage, connect::ConnectMessage, connect_data_overflow::ConnectDataOverflowMessage, data_transfer::DataTransferMessage, disconnect::DisconnectMessage, finish::FinishMessage,
-        overflow_accept::OverflowAcceptMessage, refuse::RefuseMessage,
+        abort::AbortMessage, accept::AcceptMessage, capability_data::CapabilityDataMessage, capability_data_ack::CapabilityDataAckMessage, connect::ConnectMessage, connect_data_overflow::ConnectDataOverflowMessage,
+        data_transfer::DataTransferMessage, disconnect::DisconnectMessage, expedited_data::ExpeditedDataMessage, finish::FinishMessage, not_finished::NotFinishedMessage, overflow_accept::OverflowAcceptMessage, refuse::RefuseMessage, resynchronize::ResynchronizeMessage,
+        resynchronize_ack::ResynchronizeAckMessage, typed_data::TypedDataMessage,
     },
     packet::{parameters::SessionPduParameter, pdu::SessionPduList},
 };
 
 pub(crate) mod abort;
 pub(crate) mod accept;
+pub(crate) mod capability_data;
+pub(crate) mod capability_data_ack;
 pub(crate) mod connect;
 pub(crate) mod connect_data_overflow;
 pub(crate) mod data_transfer;
 pub(crate) mod disconnect;
+pub(crate) mod expedited_data;
 pub(crate) mod finish;
+pub(crate) mod not_finished;
 pub(crate) mod overflow_accept;
 pub(crate) mod parameters;
 pub(crate) mod refuse;
+pub(crate) mod resynchronize;
+pub(crate) mod resynchronize_ack;
+pub(crate) mod typed_data;
 
 #[derive(IntoStaticStr)]
 pub(crate) enum CospMessage {
@@ -27,11 +35,18 @@ pub(crate) enum CospMessage {
     AC(AcceptMessage),
     RF(RefuseMessage),
     FN(FinishMessage),
+    NF(NotFinishedMessage),
     DN(DisconnectMessage),
     AB(AbortMessage),
     CDO(ConnectDataOverflowMessage),
     OA(OverflowAcceptMessage),
     DT(DataTransferMessage),
+    TD(TypedDataMessage),
+    EX(ExpeditedDataMessage),
+    CD(CapabilityDataMessage),
+    CapabilityDataAck(CapabilityDataAckMessage),
+    RS(ResynchronizeMessage),
+    RA(ResynchronizeAckMessage),
 }
 
 impl CospMessage {
@@ -39,7 +54,7 @@ impl CospMessage {
         if spdu_list.session_pdus().len() == 0 {
             return Err(CospError::ProtocolError("Cannot process empty PDU.".into()));
         } else if spdu_list.session_pdus().len() == 1 {
-            CospMessage::process_basic(&spdu_list.session_pdus()[0])
+            CospMessage::process_basic(&spdu_list.session_pdus()[0], spdu_list.user_information())
         } else if spdu_list.session_pdus().len() == 2 {
             CospMessage::process_basic_concatenated(&spdu_list.session_pdus()[0], &spdu_list.session_pdus()[1], spdu_list.user_information())
         } else {
@@ -48,16 +63,19 @@ impl CospMessage {
         }
     }
 
-    fn process_basic(message_parameter: &SessionPduParameter) -> Result<Self, CospError> {
+    fn process_basic(message_parameter: &SessionPduParameter, user_information: &[u8]) -> Result<Self, CospError> {
         Ok(match message_parameter {
             SessionPduParameter::Connect(parameters) => CospMessage::CN(ConnectMessage::from_parameters(parameters.as_slice())?),
             SessionPduParameter::Accept(parameters) => CospMessage::AC(AcceptMessage::from_parameters(parameters.as_slice())?),
             SessionPduParameter::Refuse(parameters) => CospMessage::RF(RefuseMessage::from_parameters(parameters.as_slice())?),
             SessionPduParameter::Finish(parameters) => CospMessage::FN(FinishMessage::from_parameters(parameters.as_slice())?),
+            SessionPduParameter::NotFinished(parameters) => CospMessage::NF(NotFinishedMessage::from_parameters(parameters.as_slice())?),
             SessionPduParameter::Disconnect(parameters) => CospMessage::DN(DisconnectMessage::from_parameters(parameters.as_slice())?),
             SessionPduParameter::Abort(parameters) => CospMessage::AB(AbortMessage::from_parameters(parameters.as_slice())?),
             SessionPduParameter::ConnectDataOverflow(parameters) => CospMessage::CDO(ConnectDataOverflowMessage::from_parameters(parameters.as_slice())?),
             SessionPduParameter::OverflowAccept(parameters) => CospMessage::OA(OverflowAcceptMessage::from_parameters(parameters.as_slice())?),
+            SessionPduParameter::TypedData(parameters) => CospMessage::TD(TypedDataMessage::from_parameters(parameters.as_slice(), user_information.to_vec())?),
+            SessionPduParameter::ExpeditedData(parameters) => CospMessage::EX(ExpeditedDataMessage::from_parameters(parameters.as_slice())?),
             _ => return Err(CospError::ProtocolError(format!("Unsupported SPDU: {}", <&SessionPduParameter as Into<&'static str>>::into(message_parameter)))),
         })
     }
@@ -71,6 +89,10 @@ impl CospMessage {
         };
         Ok(match message_parameter {
             SessionPduParameter::DataTransfer(parameters) => CospMessage::DT(DataTransferMessage::from_parameters(parameters.as_slice(), user_information.to_vec())?),
+            SessionPduParameter::CapabilityData(parameters) => CospMessage::CD(CapabilityDataMessage::from_parameters(parameters.as_slice())?),
+            SessionPduParameter::CapabilityDataAck(parameters) => CospMessage::CapabilityDataAck(CapabilityDataAckMessage::from_parameters(parameters.as_slice())?),
+            SessionPduParameter::Resynchronize(parameters) => CospMessage::RS(ResynchronizeMessage::from_parameters(parameters.as_slice())?),
+            SessionPduParameter::ResynchronizeAck(parameters) => CospMessage::RA(ResynchronizeAckMessage::from_parameters(parameters.as_slice())?),
             _ => {
                 return Err(CospError::ProtocolError(format!("Unsupported SPDU as concatenated body: {}", <&SessionPduParameter as Into<&'static str>>::into(message_parameter))));
             }
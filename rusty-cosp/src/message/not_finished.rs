@@ -0,0 +1,24 @@
+use crate::{api::CospError, packet::parameters::SessionPduParameter};
+
+pub(crate) struct NotFinishedMessage {
+    user_data: Option<Vec<u8>>,
+}
+
+impl NotFinishedMessage {
+    pub(crate) fn take_user_data(self) -> Option<Vec<u8>> {
+        self.user_data
+    }
+
+    pub(crate) fn from_parameters(parameters: &[SessionPduParameter]) -> Result<Self, CospError> {
+        let mut user_data = None;
+
+        // Not minding about order or duplicates. Ignore everything else.
+        for parameter in parameters {
+            if let SessionPduParameter::UserDataParameter(data) = parameter {
+                user_data = Some(data.clone());
+            }
+        }
+
+        Ok(NotFinishedMessage { user_data })
+    }
+}
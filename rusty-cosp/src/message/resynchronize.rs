@@ -0,0 +1,43 @@
+use crate::{ResynchronizeType, api::CospError, packet::parameters::SessionPduParameter};
+
+pub(crate) struct ResynchronizeMessage {
+    resynchronize_type: ResynchronizeType,
+    serial_number: u32,
+    user_data: Option<Vec<u8>>,
+}
+
+impl ResynchronizeMessage {
+    pub(crate) fn resynchronize_type(&self) -> ResynchronizeType {
+        self.resynchronize_type
+    }
+
+    pub(crate) fn serial_number(&self) -> u32 {
+        self.serial_number
+    }
+
+    pub(crate) fn take_user_data(self) -> Option<Vec<u8>> {
+        self.user_data
+    }
+
+    pub(crate) fn from_parameters(parameters: &[SessionPduParameter]) -> Result<Self, CospError> {
+        let mut user_data = None;
+        let mut serial_number = None;
+        let mut resynchronize_type = None;
+
+        // Not minding about order or duplicates.
+        for parameter in parameters {
+            match parameter {
+                SessionPduParameter::ResyncTypeParameter(field) => resynchronize_type = Some(*field),
+                SessionPduParameter::SerialNumberParameter(value) => serial_number = Some(*value),
+                SessionPduParameter::UserDataParameter(data) => user_data = Some(data.clone()),
+                _ => (), // Ignore everything else.
+            };
+        }
+
+        Ok(ResynchronizeMessage {
+            resynchronize_type: resynchronize_type.ok_or_else(|| CospError::ProtocolError("No resync type was found on a Resynchronize SPDU.".into()))?,
+            serial_number: serial_number.ok_or_else(|| CospError::ProtocolError("No serial number was found on a Resynchronize SPDU.".into()))?,
+            user_data,
+        })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{api::CospError, packet::parameters::SessionPduParameter};
+
+pub(crate) struct ResynchronizeAckMessage {
+    serial_number: u32,
+    user_data: Option<Vec<u8>>,
+}
+
+impl ResynchronizeAckMessage {
+    pub(crate) fn serial_number(&self) -> u32 {
+        self.serial_number
+    }
+
+    pub(crate) fn take_user_data(self) -> Option<Vec<u8>> {
+        self.user_data
+    }
+
+    pub(crate) fn from_parameters(parameters: &[SessionPduParameter]) -> Result<Self, CospError> {
+        let mut user_data = None;
+        let mut serial_number = None;
+
+        // Not minding about order or duplicates.
+        for parameter in parameters {
+            match parameter {
+                SessionPduParameter::SerialNumberParameter(value) => serial_number = Some(*value),
+                SessionPduParameter::UserDataParameter(data) => user_data = Some(data.clone()),
+                _ => (), // Ignore everything else.
+            };
+        }
+
+        Ok(ResynchronizeAckMessage { serial_number: serial_number.ok_or_else(|| CospError::ProtocolError("No serial number was found on a Resynchronize Ack SPDU.".into()))?, user_data })
+    }
+}
@@ -0,0 +1,32 @@
+use crate::{
+    api::CospError,
+    packet::parameters::{EnclosureField, SessionPduParameter},
+};
+
+pub(crate) struct TypedDataMessage {
+    enclosure: Option<EnclosureField>,
+    user_information: Vec<u8>,
+}
+
+impl TypedDataMessage {
+    pub(crate) fn enclosure(&self) -> Option<EnclosureField> {
+        self.enclosure
+    }
+
+    pub(crate) fn take_user_information(self) -> Vec<u8> {
+        self.user_information
+    }
+
+    pub(crate) fn from_parameters(parameters: &[SessionPduParameter], user_information: Vec<u8>) -> Result<Self, CospError> {
+        let mut enclosure = None;
+
+        // Not minding about order or duplicates. Ignore everything else.
+        for parameter in parameters {
+            if let SessionPduParameter::EnclosureParameter(field) = parameter {
+                enclosure = Some(*field);
+            }
+        }
+
+        Ok(TypedDataMessage { enclosure, user_information })
+    }
+}
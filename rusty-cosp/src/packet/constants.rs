@@ -7,9 +7,17 @@ pub(crate) const GIVE_TOKENS_SI_CODE: u8 = 1;
 
 pub(crate) const REFUSE_SI_CODE: u8 = 12;
 pub(crate) const FINISH_SI_CODE: u8 = 9;
+pub(crate) const NOT_FINISHED_SI_CODE: u8 = 8;
 pub(crate) const DISCONNECT_SI_CODE: u8 = 10;
 pub(crate) const ABORT_SI_CODE: u8 = 25;
 
+pub(crate) const TYPED_DATA_SI_CODE: u8 = 33;
+pub(crate) const EXPEDITED_DATA_SI_CODE: u8 = 5;
+pub(crate) const CAPABILITY_DATA_SI_CODE: u8 = 61;
+pub(crate) const CAPABILITY_DATA_ACK_SI_CODE: u8 = 62;
+pub(crate) const RESYNCHRONIZE_SI_CODE: u8 = 53;
+pub(crate) const RESYNCHRONIZE_ACK_SI_CODE: u8 = 34;
+
 pub(crate) const CONNECT_ACCEPT_ITEM_PARAMETER_CODE: u8 = 5;
 pub(crate) const USER_DATA_PARAMETER_CODE: u8 = 193;
 pub(crate) const EXTENDED_USER_DATA_PARAMETER_CODE: u8 = 194;
@@ -20,6 +28,8 @@ pub(crate) const CALLED_SESSION_SELECTOR: u8 = 52;
 pub(crate) const DATA_OVERFLOW_PARAMETER_CODE: u8 = 60;
 pub(crate) const ENCLOSURE_PARAMETER_CODE: u8 = 25;
 pub(crate) const REASON_CODE_PARAMETER_CODE: u8 = 50;
+pub(crate) const RESYNC_TYPE_PARAMETER_CODE: u8 = 27;
+pub(crate) const SERIAL_NUMBER_PARAMETER_CODE: u8 = 42;
 
 pub(crate) const PROTOCOL_OPTIONS_PARAMETER_CODE: u8 = 19;
 pub(crate) const TSDU_MAXIMUM_SIZE_PARAMETER_CODE: u8 = 21;
@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use bitfield::bitfield;
 use strum::IntoStaticStr;
 
-use crate::{ReasonCode, api::CospError, packet::constants::REASON_CODE_PARAMETER_CODE};
+use crate::{CospFunctionalUnits, ReasonCode, ResynchronizeType, api::CospError, packet::constants::REASON_CODE_PARAMETER_CODE};
 
 #[derive(Debug, IntoStaticStr)]
 pub(crate) enum SessionPduParameter {
@@ -15,10 +15,17 @@ pub(crate) enum SessionPduParameter {
     Accept(Vec<SessionPduParameter>),
     Refuse(Vec<SessionPduParameter>),
     Finish(Vec<SessionPduParameter>),
+    NotFinished(Vec<SessionPduParameter>),
     Disconnect(Vec<SessionPduParameter>),
     Abort(Vec<SessionPduParameter>),
     GiveTokens(),
     DataTransfer(Vec<SessionPduParameter>),
+    TypedData(Vec<SessionPduParameter>),
+    ExpeditedData(Vec<SessionPduParameter>),
+    CapabilityData(Vec<SessionPduParameter>),
+    CapabilityDataAck(Vec<SessionPduParameter>),
+    Resynchronize(Vec<SessionPduParameter>),
+    ResynchronizeAck(Vec<SessionPduParameter>),
 
     ConnectAcceptItemParameter(Vec<SessionPduParameter>),
 
@@ -33,6 +40,8 @@ pub(crate) enum SessionPduParameter {
     ExtendedUserDataParameter(Vec<u8>),
     DataOverflowParameter(DataOverflowField),
     EnclosureParameter(EnclosureField),
+    ResyncTypeParameter(ResynchronizeType),
+    SerialNumberParameter(u32),
 
     Unknown,
 }
@@ -161,6 +170,51 @@ impl TryFrom<&ReasonCode> for Vec<u8> {
     }
 }
 
+// ---
+// Resync Type
+
+impl From<u8> for ResynchronizeType {
+    fn from(value: u8) -> Self {
+        match value {
+            0 => ResynchronizeType::Restart,
+            1 => ResynchronizeType::Abandon,
+            2 => ResynchronizeType::Set,
+            x => ResynchronizeType::Unknown(x),
+        }
+    }
+}
+
+impl From<&ResynchronizeType> for u8 {
+    fn from(value: &ResynchronizeType) -> Self {
+        match value {
+            ResynchronizeType::Restart => 0,
+            ResynchronizeType::Abandon => 1,
+            ResynchronizeType::Set => 2,
+            ResynchronizeType::Unknown(x) => *x,
+        }
+    }
+}
+
+// ---
+// Serial Number
+
+/// Serial numbers are encoded as 1 to 6 IA5 decimal digits.
+pub(crate) const MAX_SERIAL_NUMBER: u32 = 999999;
+
+pub(crate) fn encode_serial_number(serial_number: u32) -> Result<Vec<u8>, CospError> {
+    if serial_number > MAX_SERIAL_NUMBER {
+        return Err(CospError::ProtocolError(format!("Serial number {} exceeds the maximum of {}", serial_number, MAX_SERIAL_NUMBER)));
+    }
+    Ok(serial_number.to_string().into_bytes())
+}
+
+pub(crate) fn decode_serial_number(data: &[u8]) -> Result<u32, CospError> {
+    if data.is_empty() || data.len() > 6 || !data.iter().all(|x| x.is_ascii_digit()) {
+        return Err(CospError::ProtocolError(format!("Invalid serial number: {:?}", data)));
+    }
+    String::from_utf8_lossy(data).parse::<u32>().map_err(|e| CospError::ProtocolError(format!("Invalid serial number: {}", e)))
+}
+
 // ---
 // Session User Requirements
 
@@ -198,6 +252,57 @@ impl Default for SessionUserRequirementsField {
     }
 }
 
+// Kernel and full duplex are always used. Activity management is only used as a prerequisite of capability data.
+const FULL_DUPLEX_REQUIREMENT: u16 = 0x0002;
+const EXPEDITED_REQUIREMENT: u16 = 0x0004;
+const RESYNCHRONIZE_REQUIREMENT: u16 = 0x0020;
+const ACTIVITY_MANAGEMENT_REQUIREMENT: u16 = 0x0040;
+const NEGOTIATED_RELEASE_REQUIREMENT: u16 = 0x0080;
+const CAPABILITY_DATA_REQUIREMENT: u16 = 0x0100;
+const TYPED_DATA_REQUIREMENT: u16 = 0x0400;
+
+impl SessionUserRequirementsField {
+    /// Returns true if there are no requirements outside of those this implementation can support.
+    pub(crate) fn is_supported(&self) -> bool {
+        let supported = FULL_DUPLEX_REQUIREMENT | EXPEDITED_REQUIREMENT | RESYNCHRONIZE_REQUIREMENT | ACTIVITY_MANAGEMENT_REQUIREMENT | NEGOTIATED_RELEASE_REQUIREMENT | CAPABILITY_DATA_REQUIREMENT | TYPED_DATA_REQUIREMENT;
+        self.full_duplex() && self.0 & !supported == 0
+    }
+}
+
+impl From<&CospFunctionalUnits> for SessionUserRequirementsField {
+    fn from(value: &CospFunctionalUnits) -> Self {
+        let mut requirements = FULL_DUPLEX_REQUIREMENT;
+        if value.typed_data {
+            requirements |= TYPED_DATA_REQUIREMENT;
+        }
+        if value.expedited_data {
+            requirements |= EXPEDITED_REQUIREMENT;
+        }
+        if value.capability_data {
+            requirements |= CAPABILITY_DATA_REQUIREMENT | ACTIVITY_MANAGEMENT_REQUIREMENT;
+        }
+        if value.resynchronize {
+            requirements |= RESYNCHRONIZE_REQUIREMENT;
+        }
+        if value.negotiated_release {
+            requirements |= NEGOTIATED_RELEASE_REQUIREMENT;
+        }
+        Self(requirements)
+    }
+}
+
+impl From<&SessionUserRequirementsField> for CospFunctionalUnits {
+    fn from(value: &SessionUserRequirementsField) -> Self {
+        CospFunctionalUnits {
+            typed_data: value.typed_data(),
+            expedited_data: value.expedited(),
+            capability_data: value.capability_data() && value.activity_management(),
+            resynchronize: value.resynchronize(),
+            negotiated_release: value.negotiated_release(),
+        }
+    }
+}
+
 // ---
 // Data Overflow Field
 
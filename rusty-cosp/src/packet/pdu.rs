@@ -4,15 +4,16 @@ use bitfield::bitfield;
 use tracing::warn;
 
 use crate::{
-    ReasonCode,
+    ReasonCode, ResynchronizeType,
     api::CospError,
     packet::{
         constants::{
             ABORT_SI_CODE, ACCEPT_SI_CODE, CALLED_SESSION_SELECTOR, CALLING_SESSION_SELECTOR, CONNECT_ACCEPT_ITEM_PARAMETER_CODE, CONNECT_DATA_OVERFLOW_SI_CODE, CONNECT_SI_CODE, DATA_OVERFLOW_PARAMETER_CODE, DATA_TRANSFER_SI_CODE,
-            DISCONNECT_SI_CODE, ENCLOSURE_PARAMETER_CODE, EXTENDED_USER_DATA_PARAMETER_CODE, FINISH_SI_CODE, GIVE_TOKENS_SI_CODE, OVERFLOW_ACCEPT_SI_CODE, PROTOCOL_OPTIONS_PARAMETER_CODE, REASON_CODE_PARAMETER_CODE, REFUSE_SI_CODE,
-            SESSION_USER_REQUIREMENTS_PARAMETER_CODE, TSDU_MAXIMUM_SIZE_PARAMETER_CODE, USER_DATA_PARAMETER_CODE, VERSION_NUMBER_PARAMETER_CODE,
+            DISCONNECT_SI_CODE, ENCLOSURE_PARAMETER_CODE, EXTENDED_USER_DATA_PARAMETER_CODE, FINISH_SI_CODE, GIVE_TOKENS_SI_CODE, NOT_FINISHED_SI_CODE, OVERFLOW_ACCEPT_SI_CODE, PROTOCOL_OPTIONS_PARAMETER_CODE, REASON_CODE_PARAMETER_CODE, REFUSE_SI_CODE,
+            SESSION_USER_REQUIREMENTS_PARAMETER_CODE, TSDU_MAXIMUM_SIZE_PARAMETER_CODE, USER_DATA_PARAMETER_CODE, VERSION_NUMBER_PARAMETER_CODE, CAPABILITY_DATA_ACK_SI_CODE, CAPABILITY_DATA_SI_CODE, EXPEDITED_DATA_SI_CODE,
+            RESYNCHRONIZE_ACK_SI_CODE, RESYNCHRONIZE_SI_CODE, RESYNC_TYPE_PARAMETER_CODE, SERIAL_NUMBER_PARAMETER_CODE, TYPED_DATA_SI_CODE,
         },
-        parameters::{DataOverflowField, EnclosureField, ProtocolOptionsField, SessionPduParameter, SessionUserRequirementsField, TsduMaximumSizeField, VersionNumberField, encode_length},
+        parameters::{DataOverflowField, EnclosureField, ProtocolOptionsField, SessionPduParameter, SessionUserRequirementsField, TsduMaximumSizeField, VersionNumberField, decode_serial_number, encode_length, encode_serial_number},
     },
     serialise_parameter_value,
 };
@@ -61,9 +62,16 @@ fn serialise_parameters(parameters: &[SessionPduParameter]) -> Result<Vec<u8>, C
             SessionPduParameter::Accept(sub_parameters) => serialise_composite_parameter(ACCEPT_SI_CODE, &sub_parameters)?,
             SessionPduParameter::Refuse(sub_parameters) => serialise_composite_parameter(REFUSE_SI_CODE, &sub_parameters)?,
             SessionPduParameter::Finish(sub_parameters) => serialise_composite_parameter(FINISH_SI_CODE, &sub_parameters)?,
+            SessionPduParameter::NotFinished(sub_parameters) => serialise_composite_parameter(NOT_FINISHED_SI_CODE, &sub_parameters)?,
             SessionPduParameter::Disconnect(sub_parameters) => serialise_composite_parameter(DISCONNECT_SI_CODE, &sub_parameters)?,
             SessionPduParameter::Abort(sub_parameters) => serialise_composite_parameter(ABORT_SI_CODE, &sub_parameters)?,
             SessionPduParameter::DataTransfer(sub_parameters) => serialise_composite_parameter(DATA_TRANSFER_SI_CODE, &sub_parameters)?,
+            SessionPduParameter::TypedData(sub_parameters) => serialise_composite_parameter(TYPED_DATA_SI_CODE, &sub_parameters)?,
+            SessionPduParameter::ExpeditedData(sub_parameters) => serialise_composite_parameter(EXPEDITED_DATA_SI_CODE, &sub_parameters)?,
+            SessionPduParameter::CapabilityData(sub_parameters) => serialise_composite_parameter(CAPABILITY_DATA_SI_CODE, &sub_parameters)?,
+            SessionPduParameter::CapabilityDataAck(sub_parameters) => serialise_composite_parameter(CAPABILITY_DATA_ACK_SI_CODE, &sub_parameters)?,
+            SessionPduParameter::Resynchronize(sub_parameters) => serialise_composite_parameter(RESYNCHRONIZE_SI_CODE, &sub_parameters)?,
+            SessionPduParameter::ResynchronizeAck(sub_parameters) => serialise_composite_parameter(RESYNCHRONIZE_ACK_SI_CODE, &sub_parameters)?,
 
             SessionPduParameter::GiveTokens() => vec![GIVE_TOKENS_SI_CODE, 00],
 
@@ -79,6 +87,8 @@ fn serialise_parameters(parameters: &[SessionPduParameter]) -> Result<Vec<u8>, C
             SessionPduParameter::ExtendedUserDataParameter(data) => serialise_data_parameter(EXTENDED_USER_DATA_PARAMETER_CODE, data)?,
             SessionPduParameter::DataOverflowParameter(field) => serialise_parameter_value!(DATA_OVERFLOW_PARAMETER_CODE, field.0)?,
             SessionPduParameter::EnclosureParameter(field) => serialise_parameter_value!(ENCLOSURE_PARAMETER_CODE, field.0)?,
+            SessionPduParameter::ResyncTypeParameter(field) => serialise_parameter_value!(RESYNC_TYPE_PARAMETER_CODE, u8::from(field))?,
+            SessionPduParameter::SerialNumberParameter(value) => serialise_data_parameter(SERIAL_NUMBER_PARAMETER_CODE, &encode_serial_number(*value)?)?,
 
             SessionPduParameter::ReasonCodeParameter(field) => field.try_into()?,
 
@@ -122,8 +132,12 @@ fn deserialise_parameters(outer: bool, data: &[u8]) -> Result<(Vec<SessionPduPar
             ACCEPT_SI_CODE => SessionPduParameter::Accept(deserialise_parameters(false, payload)?.0),
             REFUSE_SI_CODE => SessionPduParameter::Refuse(deserialise_parameters(false, payload)?.0),
             FINISH_SI_CODE => SessionPduParameter::Finish(deserialise_parameters(false, payload)?.0),
+            NOT_FINISHED_SI_CODE if outer => SessionPduParameter::NotFinished(deserialise_parameters(false, payload)?.0),
             DISCONNECT_SI_CODE => SessionPduParameter::Disconnect(deserialise_parameters(false, payload)?.0),
             ABORT_SI_CODE if outer => SessionPduParameter::Abort(deserialise_parameters(false, payload)?.0),
+            // Shares a code with the Connect Accept Item parameter.
+            EXPEDITED_DATA_SI_CODE if outer => SessionPduParameter::ExpeditedData(deserialise_parameters(false, payload)?.0),
+            TYPED_DATA_SI_CODE if outer => SessionPduParameter::TypedData(deserialise_parameters(false, payload)?.0),
             OVERFLOW_ACCEPT_SI_CODE => SessionPduParameter::OverflowAccept(deserialise_parameters(false, payload)?.0),
             CONNECT_DATA_OVERFLOW_SI_CODE => SessionPduParameter::ConnectDataOverflow(deserialise_parameters(false, payload)?.0),
 
@@ -131,6 +145,10 @@ fn deserialise_parameters(outer: bool, data: &[u8]) -> Result<(Vec<SessionPduPar
             GIVE_TOKENS_SI_CODE if parameters.len() == 0 => SessionPduParameter::GiveTokens(),
             // Category 2 message. Must come after Give Tokens. Their SI codes are the same.
             DATA_TRANSFER_SI_CODE => SessionPduParameter::DataTransfer(deserialise_parameters(false, payload)?.0),
+            CAPABILITY_DATA_SI_CODE if outer => SessionPduParameter::CapabilityData(deserialise_parameters(false, payload)?.0),
+            CAPABILITY_DATA_ACK_SI_CODE if outer => SessionPduParameter::CapabilityDataAck(deserialise_parameters(false, payload)?.0),
+            RESYNCHRONIZE_SI_CODE if outer => SessionPduParameter::Resynchronize(deserialise_parameters(false, payload)?.0),
+            RESYNCHRONIZE_ACK_SI_CODE if outer => SessionPduParameter::ResynchronizeAck(deserialise_parameters(false, payload)?.0),
 
             CONNECT_ACCEPT_ITEM_PARAMETER_CODE => SessionPduParameter::ConnectAcceptItemParameter(deserialise_parameters(false, payload)?.0),
 
@@ -147,6 +165,8 @@ fn deserialise_parameters(outer: bool, data: &[u8]) -> Result<(Vec<SessionPduPar
             ENCLOSURE_PARAMETER_CODE => SessionPduParameter::EnclosureParameter(parse_enclosure_item(payload)?),
 
             REASON_CODE_PARAMETER_CODE => parse_reason_code(payload)?,
+            RESYNC_TYPE_PARAMETER_CODE => SessionPduParameter::ResyncTypeParameter(parse_resync_type(payload)?),
+            SERIAL_NUMBER_PARAMETER_CODE => SessionPduParameter::SerialNumberParameter(decode_serial_number(payload)?),
 
             // TRANSPORT_DISCONNECT_PARAMETER_CODE => SessionPduParameter::TransportDisconnectItem(parse_transport_disconnect(data)?),
             // REFLECT_PARAMETER_VALUES_PARAMETER_CODE => SessionPduParameter::ReflectParameterValues(data.to_vec()),
@@ -156,7 +176,8 @@ fn deserialise_parameters(outer: bool, data: &[u8]) -> Result<(Vec<SessionPduPar
                 SessionPduParameter::Unknown
             }
         };
-        if let SessionPduParameter::DataTransfer(_) = parameter {
+        // The remaining data is user information for these SPDUs.
+        if let SessionPduParameter::DataTransfer(_) | SessionPduParameter::TypedData(_) = parameter {
             parameters.push_back(parameter);
             break;
         }
@@ -200,6 +221,11 @@ fn parse_reason_code(data: &[u8]) -> Result<SessionPduParameter, CospError> {
     Ok(SessionPduParameter::ReasonCodeParameter(ReasonCode::new(data[0], &data[1..])))
 }
 
+fn parse_resync_type(data: &[u8]) -> Result<ResynchronizeType, CospError> {
+    verify_length("Resync Type", 1, data)?;
+    Ok(ResynchronizeType::from(data[0]))
+}
+
 fn verify_length(label: &str, expected_length: usize, data: &[u8]) -> Result<(), CospError> {
     if expected_length != data.len() {
         return Err(CospError::ProtocolError(format!("Invalid Length: {} - Expected {}, Got {}", label, expected_length, data.len())));
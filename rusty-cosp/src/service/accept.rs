@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use rusty_cotp::{CotpReader, CotpWriter};
 
 use crate::{
-    CospConnectionParameters, CospError,
+    CospConnectionParameters, CospError, CospFunctionalUnits,
     message::{CospMessage, accept::AcceptMessage, parameters::TsduMaximumSize},
     packet::{
         parameters::{EnclosureField, SessionPduParameter, SessionUserRequirementsField, TsduMaximumSizeField, VersionNumberField},
@@ -12,9 +12,9 @@ use crate::{
     service::message::{MAX_PAYLOAD_SIZE, receive_message},
 };
 
-pub(crate) async fn send_accept(writer: &mut impl CotpWriter, initiator_size: &TsduMaximumSize, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
+pub(crate) async fn send_accept(writer: &mut impl CotpWriter, initiator_size: &TsduMaximumSize, functional_units: &CospFunctionalUnits, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
     // As we may need to send multiple accept payloads, we will precalculate the size of the header without enclosure.
-    let optimistic_accept = serialise_accept(initiator_size, None, None, Some(&[]))?;
+    let optimistic_accept = serialise_accept(initiator_size, functional_units, None, None, Some(&[]))?;
     // Add an extra 8 bytes for enclosure and headers.
     let optimistic_size = optimistic_accept.len() + user_data.as_ref().map(|data| data.len()).unwrap_or(0) + 8;
 
@@ -24,7 +24,7 @@ pub(crate) async fn send_accept(writer: &mut impl CotpWriter, initiator_size: &T
     };
 
     if optimistic_size <= calculated_max_payload_size {
-        let payload_data = serialise_accept(initiator_size, None, None, user_data.as_ref().map(|x| x.as_slice()))?;
+        let payload_data = serialise_accept(initiator_size, functional_units, None, None, user_data.as_ref().map(|x| x.as_slice()))?;
         return Ok(writer.send(&mut VecDeque::from(vec![payload_data])).await?);
     }
 
@@ -44,7 +44,7 @@ pub(crate) async fn send_accept(writer: &mut impl CotpWriter, initiator_size: &T
             cursor = user_data.len()
         }
 
-        let payload_data = serialise_accept(initiator_size, Some(beginning), Some(cursor >= user_data.len()), Some(&user_data[start_index..cursor]))?;
+        let payload_data = serialise_accept(initiator_size, functional_units, Some(beginning), Some(cursor >= user_data.len()), Some(&user_data[start_index..cursor]))?;
         writer.send(&mut VecDeque::from(vec![payload_data])).await?;
         if cursor >= user_data.len() {
             return Ok(());
@@ -53,7 +53,7 @@ pub(crate) async fn send_accept(writer: &mut impl CotpWriter, initiator_size: &T
     }
 }
 
-pub(crate) fn serialise_accept(initiator_size: &TsduMaximumSize, is_first: Option<bool>, is_last: Option<bool>, user_data: Option<&[u8]>) -> Result<Vec<u8>, CospError> {
+pub(crate) fn serialise_accept(initiator_size: &TsduMaximumSize, functional_units: &CospFunctionalUnits, is_first: Option<bool>, is_last: Option<bool>, user_data: Option<&[u8]>) -> Result<Vec<u8>, CospError> {
     let mut connect_accept_sub_parameters = Vec::new();
     if let TsduMaximumSize::Size(initiator_size) = initiator_size {
         // This will set the responder size to 0x0000 to indicate that we accept unlimited size. But we also echo back the initiator size if it is not unlimited.
@@ -63,7 +63,7 @@ pub(crate) fn serialise_accept(initiator_size: &TsduMaximumSize, is_first: Optio
 
     let mut session_parameters = vec![
         SessionPduParameter::ConnectAcceptItemParameter(connect_accept_sub_parameters),
-        SessionPduParameter::SessionUserRequirementsParameter(SessionUserRequirementsField(2)), // Accept Full Duplex only
+        SessionPduParameter::SessionUserRequirementsParameter(SessionUserRequirementsField::from(functional_units)), // Full Duplex and the negotiated optional functional units
     ];
     let enclosure_value = match is_first {
         Some(value) if value => 1,
@@ -113,5 +113,5 @@ pub(crate) async fn receive_accept_with_all_user_data(reader: &mut impl CotpRead
         true => Some(buffer.drain(..).collect()),
         false => None,
     };
-    Ok(AcceptMessage::new(false, *accept_message.maximum_size_to_responder(), user_data))
+    Ok(AcceptMessage::new(false, *accept_message.maximum_size_to_responder(), *accept_message.functional_units(), user_data))
 }
@@ -0,0 +1,15 @@
+use crate::{
+    CospError,
+    packet::{parameters::SessionPduParameter, pdu::SessionPduList},
+};
+
+/// Capability data is a category 2 SPDU so it must be concatenated after a token SPDU.
+pub(crate) fn serialise_capability_data(user_data: Option<Vec<u8>>) -> Result<Vec<u8>, CospError> {
+    let parameters = user_data.into_iter().map(SessionPduParameter::UserDataParameter).collect();
+    SessionPduList::new(vec![SessionPduParameter::GiveTokens(), SessionPduParameter::CapabilityData(parameters)], vec![]).serialise()
+}
+
+pub(crate) fn serialise_capability_data_ack(user_data: Option<Vec<u8>>) -> Result<Vec<u8>, CospError> {
+    let parameters = user_data.into_iter().map(SessionPduParameter::UserDataParameter).collect();
+    SessionPduList::new(vec![SessionPduParameter::GiveTokens(), SessionPduParameter::CapabilityDataAck(parameters)], vec![]).serialise()
+}
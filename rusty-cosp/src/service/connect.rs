@@ -3,7 +3,7 @@ use std::collections::VecDeque;
 use rusty_cotp::CotpWriter;
 
 use crate::{
-    api::{CospError, CospProtocolInformation},
+    api::{CospError, CospFunctionalUnits, CospProtocolInformation},
     packet::{
         parameters::{DataOverflowField, ProtocolOptionsField, SessionPduParameter, SessionUserRequirementsField, TsduMaximumSizeField, VersionNumberField},
         pdu::SessionPduList,
@@ -15,7 +15,7 @@ pub(crate) enum SendConnectionRequestResult {
     Overflow(usize),
 }
 
-pub(crate) async fn send_connect_reqeust(writer: &mut impl CotpWriter, options: CospProtocolInformation, user_data: Option<&[u8]>) -> Result<SendConnectionRequestResult, CospError> {
+pub(crate) async fn send_connect_reqeust(writer: &mut impl CotpWriter, options: CospProtocolInformation, functional_units: &CospFunctionalUnits, user_data: Option<&[u8]>) -> Result<SendConnectionRequestResult, CospError> {
     const MAX_USER_DATA_PAYLOAD_SIZE: usize = 512;
     const MAX_EXTENDED_USER_DATA_PAYLOAD_SIZE: usize = 10240;
 
@@ -29,7 +29,7 @@ pub(crate) async fn send_connect_reqeust(writer: &mut impl CotpWriter, options:
 
     let mut parameters = vec![
         SessionPduParameter::ConnectAcceptItemParameter(connect_accept_parameters),
-        SessionPduParameter::SessionUserRequirementsParameter(SessionUserRequirementsField(2)), // Full Duplex only
+        SessionPduParameter::SessionUserRequirementsParameter(SessionUserRequirementsField::from(functional_units)), // Full Duplex and any requested optional functional units
     ];
     match options.calling_session_selector() {
         Some(calling_session) => parameters.push(SessionPduParameter::CallingSessionSelectorParameter(calling_session.clone())),
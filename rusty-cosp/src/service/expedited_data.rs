@@ -0,0 +1,14 @@
+use crate::{
+    CospError,
+    packet::{parameters::SessionPduParameter, pdu::SessionPduList},
+};
+
+/// The maximum amount of user data that may be carried on an expedited data SPDU.
+pub(crate) const MAX_EXPEDITED_USER_DATA_SIZE: usize = 14;
+
+pub(crate) fn serialise_expedited_data(user_data: Vec<u8>) -> Result<Vec<u8>, CospError> {
+    if user_data.len() > MAX_EXPEDITED_USER_DATA_SIZE {
+        return Err(CospError::ProtocolError(format!("Expedited data is limited to {} bytes but {} bytes was provided.", MAX_EXPEDITED_USER_DATA_SIZE, user_data.len())));
+    }
+    SessionPduList::new(vec![SessionPduParameter::ExpeditedData(vec![SessionPduParameter::UserDataParameter(user_data)])], vec![]).serialise()
+}
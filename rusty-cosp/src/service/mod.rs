@@ -4,7 +4,7 @@ use rusty_cotp::{CotpConnection, CotpReader, CotpWriter};
 use rusty_tpkt::ProtocolInformation;
 
 use crate::{
-    CospAcceptor, CospConnection, CospConnectionParameters, CospError, CospInitiator, CospProtocolInformation, CospReader, CospRecvResult, CospResponder, CospWriter, ReasonCode,
+    CospAcceptor, CospConnection, CospConnectionParameters, CospError, CospFunctionalUnits, CospInitiator, CospProtocolInformation, CospReader, CospRecvResult, CospResponder, CospWriter, ReasonCode, ResynchronizeType,
     abort::{receive_abort_with_all_user_data, send_abort},
     disconnect::{receive_disconnect_with_all_user_data, send_disconnect},
     finish::{receive_finish_with_all_user_data, send_finish},
@@ -16,20 +16,30 @@ use crate::{
     refuse::{receive_refuse_with_all_user_data, send_refuse},
     service::{
         accept::{receive_accept_with_all_user_data, send_accept},
+        capability_data::{serialise_capability_data, serialise_capability_data_ack},
         connect::{SendConnectionRequestResult, send_connect_reqeust},
+        expedited_data::serialise_expedited_data,
         message::{MAX_PAYLOAD_SIZE, MIN_PAYLOAD_SIZE, receive_message},
+        not_finished::serialise_not_finished,
         overflow::{receive_connect_data_overflow, send_connect_data_overflow, send_overflow_accept},
+        resynchronize::{serialise_resynchronize, serialise_resynchronize_ack},
+        typed_data::serialise_typed_data,
     },
 };
 
 pub(crate) mod abort;
 pub(crate) mod accept;
+pub(crate) mod capability_data;
 pub(crate) mod connect;
 pub(crate) mod disconnect;
+pub(crate) mod expedited_data;
 pub(crate) mod finish;
 pub(crate) mod message;
+pub(crate) mod not_finished;
 pub(crate) mod overflow;
 pub(crate) mod refuse;
+pub(crate) mod resynchronize;
+pub(crate) mod typed_data;
 
 /// An initiator that uses a COTP connection to signal a new COSP connection.
 pub struct RustyCospInitiator<R: CotpReader, W: CotpWriter> {
@@ -54,7 +64,8 @@ impl<R: CotpReader, W: CotpWriter> CospInitiator for RustyCospInitiator<R, W> {
     async fn initiate(self, user_data: Option<Vec<u8>>) -> Result<(impl CospConnection, Option<Vec<u8>>), CospError> {
         let (mut cotp_reader, mut cotp_writer) = (self.cotp_reader, self.cotp_writer);
 
-        let send_connect_result = send_connect_reqeust(&mut cotp_writer, self.options, user_data.as_deref()).await?;
+        let proposed_functional_units = self.connection_options.functional_units;
+        let send_connect_result = send_connect_reqeust(&mut cotp_writer, self.options, &proposed_functional_units, user_data.as_deref()).await?;
 
         let accept_message = match (send_connect_result, user_data) {
             (SendConnectionRequestResult::Complete, _) => receive_accept_or_refuse_or_abort_with_all_user_data(&mut cotp_reader, &self.connection_options).await?,
@@ -66,7 +77,12 @@ impl<R: CotpReader, W: CotpWriter> CospInitiator for RustyCospInitiator<R, W> {
             (SendConnectionRequestResult::Overflow(_), None) => return Err(CospError::InternalError("User data was sent even though user data was not provided.".into())),
         };
 
-        Ok((RustyCospConnection::new(cotp_reader, cotp_writer, *accept_message.maximum_size_to_responder(), self.connection_options, self.protocol_information_list), accept_message.user_data().map(|data| data.clone())))
+        let functional_units = *accept_message.functional_units();
+        if !functional_units.is_subset_of(&proposed_functional_units) {
+            return Err(CospError::ProtocolError(format!("The accepted functional units {:?} were not proposed.", functional_units)));
+        }
+
+        Ok((RustyCospConnection::new(cotp_reader, cotp_writer, *accept_message.maximum_size_to_responder(), functional_units, self.connection_options, self.protocol_information_list), accept_message.user_data().map(|data| data.clone())))
     }
 }
 
@@ -96,6 +112,7 @@ pub struct RustyCospAcceptor<R: CotpReader, W: CotpWriter> {
     cotp_writer: W,
     user_data: Option<Vec<u8>>,
     tsdu_maximum_size: TsduMaximumSize,
+    functional_units: CospFunctionalUnits,
     cosp_connection_parameters: CospConnectionParameters,
     protocol_information_list: Vec<Box<dyn ProtocolInformation>>,
 }
@@ -112,6 +129,7 @@ impl<R: CotpReader, W: CotpWriter> RustyCospAcceptor<R, W> {
         };
 
         let maximum_size_to_initiator = connect_request.maximum_size_to_initiator();
+        let functional_units = connect_request.functional_units().intersection(&connection_parameters.functional_units);
         let has_more_data = match &connect_request.data_overflow() {
             Some(overflow) => overflow.more_data(),
             None => false,
@@ -135,7 +153,7 @@ impl<R: CotpReader, W: CotpWriter> RustyCospAcceptor<R, W> {
         let cosp_protocol_information = CospProtocolInformation::new(connect_request.calling_session_selector().map(|x| x.clone()), connect_request.called_session_selector().map(|x| x.clone()));
         protocol_information_list.push(Box::new(cosp_protocol_information.clone()));
         Ok((
-            RustyCospAcceptor { cotp_reader, cotp_writer, user_data, tsdu_maximum_size: *maximum_size_to_initiator, protocol_information_list: protocol_information_list, cosp_connection_parameters: connection_parameters },
+            RustyCospAcceptor { cotp_reader, cotp_writer, user_data, tsdu_maximum_size: *maximum_size_to_initiator, functional_units, protocol_information_list: protocol_information_list, cosp_connection_parameters: connection_parameters },
             cosp_protocol_information,
         ))
     }
@@ -146,7 +164,7 @@ impl<R: CotpReader, W: CotpWriter> CospAcceptor for RustyCospAcceptor<R, W> {
         let cotp_reader = self.cotp_reader;
         let cotp_writer = self.cotp_writer;
 
-        Ok((RustyCospResponder::<R, W>::new(cotp_reader, cotp_writer, self.tsdu_maximum_size, self.cosp_connection_parameters, self.protocol_information_list), self.user_data))
+        Ok((RustyCospResponder::<R, W>::new(cotp_reader, cotp_writer, self.tsdu_maximum_size, self.functional_units, self.cosp_connection_parameters, self.protocol_information_list), self.user_data))
     }
 
     async fn refuse(self, reason_code: Option<ReasonCode>) -> Result<(), CospError> {
@@ -165,6 +183,7 @@ pub struct RustyCospResponder<R: CotpReader, W: CotpWriter> {
     cotp_reader: R,
     cotp_writer: W,
     maximum_size_to_initiator: TsduMaximumSize,
+    functional_units: CospFunctionalUnits,
     connection_options: CospConnectionParameters,
     protocol_information_list: Vec<Box<dyn ProtocolInformation>>,
 }
@@ -174,10 +193,11 @@ impl<R: CotpReader, W: CotpWriter> RustyCospResponder<R, W> {
         cotp_reader: impl CotpReader,
         cotp_writer: impl CotpWriter,
         maximum_size_to_initiator: TsduMaximumSize,
+        functional_units: CospFunctionalUnits,
         connection_options: CospConnectionParameters,
         protocol_information_list: Vec<Box<dyn ProtocolInformation>>,
     ) -> RustyCospResponder<impl CotpReader, impl CotpWriter> {
-        RustyCospResponder { cotp_reader, cotp_writer, maximum_size_to_initiator, connection_options, protocol_information_list }
+        RustyCospResponder { cotp_reader, cotp_writer, maximum_size_to_initiator, functional_units, connection_options, protocol_information_list }
     }
 }
 
@@ -186,8 +206,8 @@ impl<R: CotpReader, W: CotpWriter> CospResponder for RustyCospResponder<R, W> {
         let cotp_reader = self.cotp_reader;
         let mut cotp_writer = self.cotp_writer;
 
-        send_accept(&mut cotp_writer, &self.maximum_size_to_initiator, accept_data).await?;
-        Ok(RustyCospConnection::new(cotp_reader, cotp_writer, self.maximum_size_to_initiator, self.connection_options, self.protocol_information_list))
+        send_accept(&mut cotp_writer, &self.maximum_size_to_initiator, &self.functional_units, accept_data).await?;
+        Ok(RustyCospConnection::new(cotp_reader, cotp_writer, self.maximum_size_to_initiator, self.functional_units, self.connection_options, self.protocol_information_list))
     }
 
     async fn refuse(self, reason_code: Option<ReasonCode>) -> Result<(), CospError> {
@@ -206,6 +226,7 @@ pub struct RustyCospConnection<R: CotpReader, W: CotpWriter> {
     cotp_reader: R,
     cotp_writer: W,
     remote_max_size: TsduMaximumSize,
+    functional_units: CospFunctionalUnits,
     connection_options: CospConnectionParameters,
     protocol_information_list: Vec<Box<dyn ProtocolInformation>>,
 }
@@ -215,10 +236,11 @@ impl<R: CotpReader, W: CotpWriter> RustyCospConnection<R, W> {
         cotp_reader: R,
         cotp_writer: W,
         remote_max_size: TsduMaximumSize,
+        functional_units: CospFunctionalUnits,
         connection_options: CospConnectionParameters,
         protocol_information_list: Vec<Box<dyn ProtocolInformation>>,
     ) -> RustyCospConnection<impl CotpReader, impl CotpWriter> {
-        RustyCospConnection { cotp_reader, cotp_writer, remote_max_size, connection_options, protocol_information_list }
+        RustyCospConnection { cotp_reader, cotp_writer, remote_max_size, functional_units, connection_options, protocol_information_list }
     }
 }
 
@@ -229,8 +251,8 @@ impl<R: CotpReader, W: CotpWriter> CospConnection for RustyCospConnection<R, W>
 
     async fn split(self) -> Result<(impl CospReader, impl CospWriter), CospError> {
         Ok((
-            RustyCospReader { cotp_reader: self.cotp_reader, buffer: VecDeque::new(), connection_options: self.connection_options },
-            RustyCospWriter { buffer: VecDeque::new(), cotp_writer: self.cotp_writer, remote_max_size: self.remote_max_size },
+            RustyCospReader { cotp_reader: self.cotp_reader, buffer: VecDeque::new(), typed_data_buffer: VecDeque::new(), functional_units: self.functional_units, connection_options: self.connection_options },
+            RustyCospWriter { buffer: VecDeque::new(), cotp_writer: self.cotp_writer, remote_max_size: self.remote_max_size, functional_units: self.functional_units },
         ))
     }
 }
@@ -239,6 +261,8 @@ impl<R: CotpReader, W: CotpWriter> CospConnection for RustyCospConnection<R, W>
 pub struct RustyCospReader<R: CotpReader> {
     cotp_reader: R,
     buffer: VecDeque<u8>,
+    typed_data_buffer: VecDeque<u8>,
+    functional_units: CospFunctionalUnits,
     connection_options: CospConnectionParameters,
 }
 
@@ -262,11 +286,36 @@ impl<R: CotpReader> CospReader for RustyCospReader<R> {
                     let disconnect_message = receive_disconnect_with_all_user_data(&mut self.cotp_reader, message).await?;
                     return Ok(CospRecvResult::Disconnect(disconnect_message.user_data().cloned()));
                 }
+                CospMessage::NF(message) if self.functional_units.negotiated_release => return Ok(CospRecvResult::NotFinished(message.take_user_data())),
                 CospMessage::AB(message) => {
                     let abort_message = receive_abort_with_all_user_data(&mut self.cotp_reader, message, &self.connection_options).await?;
                     return Err(CospError::Aborted(abort_message.user_data().cloned()));
                 }
-                message => return Err(CospError::ProtocolError(format!("Expected payload of type Data Transfer, Finish, Disconnect or Abort but found {}", <CospMessage as Into<&'static str>>::into(message)))),
+                CospMessage::TD(message) if self.functional_units.typed_data => {
+                    let enclosure = message.enclosure();
+                    self.typed_data_buffer.extend(message.take_user_information());
+                    if self.typed_data_buffer.len() > self.connection_options.maximum_reassembled_payload_size {
+                        return Err(CospError::ProtocolError("Message length is exceeds maximum payload size.".into()));
+                    }
+                    match enclosure {
+                        Some(x) if !x.end() => continue,
+                        _ => return Ok(CospRecvResult::TypedData(self.typed_data_buffer.drain(..).collect())),
+                    }
+                }
+                CospMessage::EX(message) if self.functional_units.expedited_data => return Ok(CospRecvResult::ExpeditedData(message.take_user_data())),
+                CospMessage::CD(message) if self.functional_units.capability_data => return Ok(CospRecvResult::CapabilityData(message.take_user_data())),
+                CospMessage::CapabilityDataAck(message) if self.functional_units.capability_data => return Ok(CospRecvResult::CapabilityDataAck(message.take_user_data())),
+                CospMessage::RS(message) if self.functional_units.resynchronize => {
+                    let (resynchronize_type, serial_number) = (message.resynchronize_type(), message.serial_number());
+                    return Ok(CospRecvResult::Resynchronize(resynchronize_type, serial_number, message.take_user_data()));
+                }
+                CospMessage::RA(message) if self.functional_units.resynchronize => {
+                    let serial_number = message.serial_number();
+                    return Ok(CospRecvResult::ResynchronizeAck(serial_number, message.take_user_data()));
+                }
+                message => {
+                    return Err(CospError::ProtocolError(format!("Expected payload of type Data Transfer, Finish, Disconnect, Abort or a negotiated optional data SPDU but found {}", <CospMessage as Into<&'static str>>::into(message))));
+                }
             };
 
             let enclosure = data_transfer_message.enclosure();
@@ -285,6 +334,26 @@ pub struct RustyCospWriter<W: CotpWriter> {
     cotp_writer: W,
     buffer: VecDeque<Vec<u8>>,
     remote_max_size: TsduMaximumSize,
+    functional_units: CospFunctionalUnits,
+}
+
+impl<W: CotpWriter> RustyCospWriter<W> {
+    fn verify_functional_unit(negotiated: bool, name: &str) -> Result<(), CospError> {
+        match negotiated {
+            true => Ok(()),
+            false => Err(CospError::ProtocolError(format!("The {} functional unit was not negotiated on this connection.", name))),
+        }
+    }
+
+    async fn flush(&mut self) -> Result<(), CospError> {
+        while !self.buffer.is_empty() {
+            self.cotp_writer.send(&mut self.buffer).await?;
+        }
+
+        // Perform one more to ensure lower levels are also flushed even if this layer is complete.
+        self.cotp_writer.send(&mut self.buffer).await?;
+        Ok(())
+    }
 }
 
 impl<W: CotpWriter> CospWriter for RustyCospWriter<W> {
@@ -320,16 +389,10 @@ impl<W: CotpWriter> CospWriter for RustyCospWriter<W> {
             }
         }
 
-        while !self.buffer.is_empty() {
-            self.cotp_writer.send(&mut self.buffer).await?;
-        }
-
-        // Perform one more to ensure lower levels are also flushed even if this layer is complete.
-        self.cotp_writer.send(&mut self.buffer).await?;
-        Ok(())
+        self.flush().await
     }
 
-    async fn finish(mut self, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
+    async fn finish(&mut self, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
         send_finish(&mut self.cotp_writer, self.remote_max_size, user_data).await?;
         Ok(())
     }
@@ -339,8 +402,52 @@ impl<W: CotpWriter> CospWriter for RustyCospWriter<W> {
         Ok(())
     }
 
+    async fn not_finished(&mut self, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
+        Self::verify_functional_unit(self.functional_units.negotiated_release, "negotiated release")?;
+        self.buffer.push_back(serialise_not_finished(user_data)?);
+        self.flush().await
+    }
+
     async fn abort(mut self, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
         send_abort(&mut self.cotp_writer, self.remote_max_size, user_data).await?;
         Ok(())
     }
+
+    async fn send_typed_data(&mut self, input: &mut VecDeque<Vec<u8>>) -> Result<(), CospError> {
+        Self::verify_functional_unit(self.functional_units.typed_data, "typed data")?;
+        while let Some(data_item) = input.pop_front() {
+            self.buffer.extend(serialise_typed_data(self.remote_max_size, data_item)?);
+        }
+        self.flush().await
+    }
+
+    async fn send_expedited_data(&mut self, user_data: Vec<u8>) -> Result<(), CospError> {
+        Self::verify_functional_unit(self.functional_units.expedited_data, "expedited data")?;
+        self.buffer.push_back(serialise_expedited_data(user_data)?);
+        self.flush().await
+    }
+
+    async fn send_capability_data(&mut self, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
+        Self::verify_functional_unit(self.functional_units.capability_data, "capability data")?;
+        self.buffer.push_back(serialise_capability_data(user_data)?);
+        self.flush().await
+    }
+
+    async fn send_capability_data_ack(&mut self, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
+        Self::verify_functional_unit(self.functional_units.capability_data, "capability data")?;
+        self.buffer.push_back(serialise_capability_data_ack(user_data)?);
+        self.flush().await
+    }
+
+    async fn resynchronize(&mut self, resynchronize_type: ResynchronizeType, serial_number: u32, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
+        Self::verify_functional_unit(self.functional_units.resynchronize, "resynchronize")?;
+        self.buffer.push_back(serialise_resynchronize(resynchronize_type, serial_number, user_data)?);
+        self.flush().await
+    }
+
+    async fn resynchronize_ack(&mut self, serial_number: u32, user_data: Option<Vec<u8>>) -> Result<(), CospError> {
+        Self::verify_functional_unit(self.functional_units.resynchronize, "resynchronize")?;
+        self.buffer.push_back(serialise_resynchronize_ack(serial_number, user_data)?);
+        self.flush().await
+    }
 }
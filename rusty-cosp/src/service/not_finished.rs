@@ -0,0 +1,9 @@
+use crate::{
+    CospError,
+    packet::{parameters::SessionPduParameter, pdu::SessionPduList},
+};
+
+pub(crate) fn serialise_not_finished(user_data: Option<Vec<u8>>) -> Result<Vec<u8>, CospError> {
+    let parameters = user_data.into_iter().map(SessionPduParameter::UserDataParameter).collect();
+    SessionPduList::new(vec![SessionPduParameter::NotFinished(parameters)], vec![]).serialise()
+}
@@ -0,0 +1,22 @@
+use crate::{
+    CospError, ResynchronizeType,
+    packet::{parameters::SessionPduParameter, pdu::SessionPduList},
+};
+
+/// Resynchronize is a category 2 SPDU so it must be concatenated after a token SPDU.
+/// No tokens are available in full duplex mode so the token setting item is not sent.
+pub(crate) fn serialise_resynchronize(resynchronize_type: ResynchronizeType, serial_number: u32, user_data: Option<Vec<u8>>) -> Result<Vec<u8>, CospError> {
+    let mut parameters = vec![SessionPduParameter::ResyncTypeParameter(resynchronize_type), SessionPduParameter::SerialNumberParameter(serial_number)];
+    if let Some(user_data) = user_data {
+        parameters.push(SessionPduParameter::UserDataParameter(user_data));
+    }
+    SessionPduList::new(vec![SessionPduParameter::GiveTokens(), SessionPduParameter::Resynchronize(parameters)], vec![]).serialise()
+}
+
+pub(crate) fn serialise_resynchronize_ack(serial_number: u32, user_data: Option<Vec<u8>>) -> Result<Vec<u8>, CospError> {
+    let mut parameters = vec![SessionPduParameter::SerialNumberParameter(serial_number)];
+    if let Some(user_data) = user_data {
+        parameters.push(SessionPduParameter::UserDataParameter(user_data));
+    }
+    SessionPduList::new(vec![SessionPduParameter::GiveTokens(), SessionPduParameter::ResynchronizeAck(parameters)], vec![]).serialise()
+}
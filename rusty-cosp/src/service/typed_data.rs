@@ -0,0 +1,32 @@
+use crate::{
+    CospError,
+    message::parameters::TsduMaximumSize,
+    packet::{
+        parameters::{EnclosureField, SessionPduParameter},
+        pdu::SessionPduList,
+    },
+    service::message::{MAX_PAYLOAD_SIZE, MIN_PAYLOAD_SIZE},
+};
+
+/// Typed data is a category 1 SPDU so it is not concatenated with a token SPDU. It is segmented the same way as data transfer.
+pub(crate) fn serialise_typed_data(remote_max_size: TsduMaximumSize, data_item: Vec<u8>) -> Result<Vec<Vec<u8>>, CospError> {
+    const HEADER_LENGTH_WITHOUT_ENCLOSURE: usize = 2; // TD
+
+    match remote_max_size {
+        TsduMaximumSize::Size(x) if data_item.len() < MAX_PAYLOAD_SIZE && data_item.len() + HEADER_LENGTH_WITHOUT_ENCLOSURE < x as usize => Ok(vec![SessionPduList::new(vec![SessionPduParameter::TypedData(vec![])], data_item).serialise()?]),
+        TsduMaximumSize::Unlimited => Ok(vec![SessionPduList::new(vec![SessionPduParameter::TypedData(vec![])], data_item).serialise()?]),
+        TsduMaximumSize::Size(x) => {
+            let mut payloads = vec![];
+            let mut cursor: usize = 0;
+            let payload_length = usize::max(MIN_PAYLOAD_SIZE, usize::min(MAX_PAYLOAD_SIZE, x as usize));
+
+            while cursor < data_item.len() {
+                let start = cursor;
+                cursor = usize::min(cursor + payload_length, data_item.len());
+                let enclosure = EnclosureField(if start == 0 { 1 } else { 0 } + if cursor == data_item.len() { 2 } else { 0 });
+                payloads.push(SessionPduList::new(vec![SessionPduParameter::TypedData(vec![SessionPduParameter::EnclosureParameter(enclosure)])], data_item[start..cursor].to_vec()).serialise()?);
+            }
+            Ok(payloads)
+        }
+    }
+}
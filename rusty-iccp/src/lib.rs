@@ -31,6 +31,9 @@ pub trait IccpClient: Send + Sync {
     async fn clone(&self) -> Box<dyn IccpClient>;
 
     // --- Completed ---
+    /// Concludes the association. The client cannot be used afterwards.
+    async fn conclude(&mut self) -> Result<(), IccpError>;
+
     async fn get_data_values(&mut self, names: Vec<String>) -> Result<Vec<IccpAccessResult>, IccpError>;
     async fn get_data_set_names(&mut self, scope: IccpScope) -> Result<Vec<String>, IccpError>;
     async fn get_data_value_names(&mut self, scope: IccpScope) -> Result<Vec<String>, IccpError>;
@@ -435,8 +438,10 @@ impl RustyIccpClient {
      * Reads the ICCP Version, Bilateral Table Name and Supported Features.
      */
     pub async fn get_iccp_connection_parameters(&mut self, domain: String) -> Result<(), IccpError> {
-        let mms_read_result =
-            self.mms_client.read(MmsVariableAccessSpecification::ListOfVariables(vec![ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific(domain, "BilateralTable".into())) }])).await?;
+        let mms_read_result = self
+            .mms_client
+            .read(MmsVariableAccessSpecification::ListOfVariables(vec![ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific(domain, "BilateralTable".into())), alternate_access: None }]))
+            .await?;
         if mms_read_result.len() != 3 {}
         Ok(())
     }
@@ -448,8 +453,12 @@ impl IccpClient for RustyIccpClient {
         Box::new(RustyIccpClient { mms_client: self.mms_client.clone() })
     }
 
+    async fn conclude(&mut self) -> Result<(), IccpError> {
+        Ok(self.mms_client.conclude().await?)
+    }
+
     async fn get_data_values(&mut self, names: Vec<String>) -> Result<Vec<IccpAccessResult>, IccpError> {
-        let spec = names.into_iter().map(|x| ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific(x)) }).collect();
+        let spec = names.into_iter().map(|x| ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific(x)), alternate_access: None }).collect();
         let results = self.mms_client.read(rusty_mms::MmsVariableAccessSpecification::ListOfVariables(spec)).await?;
 
         Ok(results
@@ -503,7 +512,10 @@ impl IccpClient for RustyIccpClient {
 
     async fn create_data_set(&mut self, domain: String, name: String, identifiers: Vec<String>) -> Result<(), IccpError> {
         self.mms_client
-            .define_named_variable_list(MmsObjectName::DomainSpecific(domain, name), identifiers.into_iter().map(|x| ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific(x)) }).collect())
+            .define_named_variable_list(
+                MmsObjectName::DomainSpecific(domain, name),
+                identifiers.into_iter().map(|x| ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific(x)), alternate_access: None }).collect(),
+            )
             .await?;
         Ok(())
     }
@@ -521,7 +533,10 @@ impl IccpClient for RustyIccpClient {
     async fn start_transfer_set(&mut self, domain: String, name: String) -> Result<(), IccpError> {
         let transfer_set_name_structure = self
             .mms_client
-            .read(MmsVariableAccessSpecification::ListOfVariables(vec![ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific(domain.clone(), "Next_DSTransfer_Set".into())) }]))
+            .read(MmsVariableAccessSpecification::ListOfVariables(vec![ListOfVariablesItem {
+                variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific(domain.clone(), "Next_DSTransfer_Set".into())),
+                alternate_access: None,
+            }]))
             .await?;
 
         let transfer_set_name = match transfer_set_name_structure.as_slice() {
@@ -534,7 +549,10 @@ impl IccpClient for RustyIccpClient {
 
         self.mms_client
             .write(
-                MmsVariableAccessSpecification::ListOfVariables(vec![ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific(domain.clone(), transfer_set_name.clone())) }]),
+                MmsVariableAccessSpecification::ListOfVariables(vec![ListOfVariablesItem {
+                    variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific(domain.clone(), transfer_set_name.clone())),
+                    alternate_access: None,
+                }]),
                 vec![MmsServiceData::Structure(vec![
                     MmsServiceData::Structure(vec![MmsServiceData::Integer(BigInt::from(1)), MmsServiceData::VisibleString(domain.clone()), MmsServiceData::VisibleString(name)]),
                     MmsServiceData::Integer(BigInt::from(0)),                        // Start Time
@@ -753,7 +771,7 @@ mod tests {
     use std::time::Duration;
 
     use rand::random_range;
-    use rusty_mms_service::{MmsServiceConnectionParameters, create_mms_service_client, create_mms_service_server};
+    use rusty_mms_service::{MmsServiceConnectionParameters, create_mms_service_client, create_mms_service_server, message::MmsServiceMessage};
     use tokio::{self, join};
 
     use crate::{IccpClient, IccpServer, RustyIccpClient, RustyIccpServer, error::IccpError};
@@ -788,4 +806,36 @@ mod tests {
 
         Ok(())
     }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_conclude_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(|e| IccpError::InternalError(format!("Test Failed: {e}")))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let server = server_results?;
+
+        let mut iccp_client = RustyIccpClient::new(client.clone());
+        let mut iccp_server = RustyIccpServer::new(server.clone());
+
+        let client_future = tokio::task::spawn(async move { iccp_client.conclude().await });
+        match iccp_server.receive_operation().await? {
+            crate::IccpOperation::MmsOperation(MmsServiceMessage::Conclude(message)) => message.respond().await?,
+            x => assert!(false, "Unexpected message: {x:?}"),
+        }
+        // The server answers the release and the association ends.
+        assert!(iccp_server.receive_operation().await.is_err());
+        client_future.await??;
+
+        Ok(())
+    }
 }
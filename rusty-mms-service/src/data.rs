@@ -1,9 +1,13 @@
-use std::ops::Deref;
-
+use chrono::{DateTime, NaiveDateTime, Utc};
 use der_parser::Oid;
 use num_bigint::ToBigInt;
 use num_bigint::{BigInt, BigUint};
-use rusty_mms::{ListOfVariablesItem, MmsAccessError, MmsData, MmsError, MmsObjectName, MmsTypeDescription, MmsTypeDescriptionComponent, MmsVariableAccessSpecification};
+use rusty_mms::{
+    ListOfVariablesItem, MmsAccessError, MmsAlarmAcknowledgmentRule, MmsApplicationReference, MmsBinaryTime, MmsData, MmsDomainState, MmsEntryContent, MmsEntryForm, MmsError, MmsEventConditionClass, MmsEventConditionState,
+    MmsEventNotification, MmsEventTime, MmsEventTransition, MmsFileAttributes, MmsJournalEntry, MmsJournalEntryToStartAfter, MmsJournalEvent, MmsJournalRangeStart, MmsJournalRangeStop, MmsJournalVariable, MmsLogicalStatus,
+    MmsMonitoredVariable, MmsObjectName, MmsPhysicalStatus, MmsProgramInvocationState, MmsScope, MmsSemaphoreClass, MmsStatus, MmsTypeDescription, MmsTypeDescriptionComponent, MmsTypeSpecification, MmsUtcTime,
+    MmsVariableAccessSpecification, VariableSpecification,
+};
 
 use crate::error::{MmsServiceError, to_mms_error};
 
@@ -72,11 +76,12 @@ pub enum MmsServiceData {
     OctetString(Vec<u8>),
     VisibleString(String),
     GeneralizedTime(String),
-    BinaryTime(Vec<u8>),
+    BinaryTime(MmsBinaryTime),
     Bcd(Vec<u8>),
     BooleanArray(Vec<bool>),
     ObjectId(Oid<'static>),
     MmsString(String),
+    UtcTime(MmsUtcTime),
 }
 
 #[derive(Debug, PartialEq)]
@@ -115,6 +120,7 @@ pub enum MmsServiceTypeDescription {
     Bcd(u8),
     ObjId,
     MmsString(i32),
+    UtcTime,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -135,6 +141,482 @@ pub struct VariableAccessAttributes {
     pub type_description: MmsServiceTypeDescription,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct NamedTypeAttributes {
+    pub deletable: bool,
+    pub type_specification: MmsServiceTypeSpecification,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DomainAttributes {
+    pub list_of_capabilities: Vec<String>,
+    pub state: MmsDomainState,
+    pub deletable: bool,
+    pub sharable: bool,
+    pub list_of_program_invocations: Vec<String>,
+    pub upload_in_progress: i8,
+}
+
+/// A segment of a domain's load data. Only non-coded load data is supported.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DomainSegment {
+    pub load_data: Vec<u8>,
+    pub more_follows: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct UploadSequence {
+    pub ulsm_id: i32,
+    pub list_of_capabilities: Vec<String>,
+}
+
+/// A permanent monitor outlives the association that created the program invocation. A current monitor does not.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ProgramInvocationMonitor {
+    Permanent,
+    Current,
+}
+
+pub(crate) fn convert_low_level_monitor_type_to_high_level_monitor(monitor_type: Option<bool>) -> Option<ProgramInvocationMonitor> {
+    monitor_type.map(|permanent| if permanent { ProgramInvocationMonitor::Permanent } else { ProgramInvocationMonitor::Current })
+}
+
+pub(crate) fn convert_high_level_monitor_to_low_level_monitor_type(monitor: Option<ProgramInvocationMonitor>) -> Option<bool> {
+    monitor.map(|monitor| monitor == ProgramInvocationMonitor::Permanent)
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProgramInvocationAttributes {
+    pub state: MmsProgramInvocationState,
+    pub list_of_domain_names: Vec<String>,
+    pub deletable: bool,
+    pub reusable: bool,
+    pub monitor: bool,
+    pub execution_argument: String,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FileAttributes {
+    pub size_of_file: u32,
+    pub last_modified: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DirectoryEntry {
+    pub file_name: Vec<String>,
+    pub file_attributes: FileAttributes,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FileDirectory {
+    pub list_of_directory_entry: Vec<DirectoryEntry>,
+    pub more_follows: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct FileSegment {
+    pub file_data: Vec<u8>,
+    pub more_follows: bool,
+}
+
+// GeneralizedTime is sent in UTC. A time without a zone designator is also taken to be UTC.
+pub(crate) fn convert_low_level_file_attributes_to_high_level_file_attributes(file_attributes: MmsFileAttributes) -> Result<FileAttributes, MmsServiceError> {
+    let last_modified = match file_attributes.last_modified {
+        Some(last_modified) => Some(NaiveDateTime::parse_from_str(last_modified.trim_end_matches('Z'), "%Y%m%d%H%M%S%.f").map_err(to_mms_error(&format!("Failed to parse Last Modified: {}", last_modified)))?.and_utc()),
+        None => None,
+    };
+    Ok(FileAttributes { size_of_file: BigInt::from_signed_bytes_be(&file_attributes.size_of_file).try_into().map_err(to_mms_error("Failed to parse Size of File"))?, last_modified })
+}
+
+pub(crate) fn convert_high_level_file_attributes_to_low_level_file_attributes(file_attributes: FileAttributes) -> MmsFileAttributes {
+    MmsFileAttributes { size_of_file: BigInt::from(file_attributes.size_of_file).to_signed_bytes_be(), last_modified: file_attributes.last_modified.map(|x| x.format("%Y%m%d%H%M%S%.3fZ").to_string()) }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum JournalRangeStop {
+    EndingTime(MmsBinaryTime),
+    NumberOfEntries(i32),
+}
+
+/// Selects the entries returned when reading a journal. The default selects every entry.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JournalReadSpecification {
+    pub range_start_specification: Option<MmsJournalRangeStart>,
+    pub range_stop_specification: Option<JournalRangeStop>,
+    pub list_of_variables: Option<Vec<String>>, // All variables are returned if not present
+    pub entry_to_start_after: Option<MmsJournalEntryToStartAfter>,
+}
+
+pub(crate) fn convert_low_level_range_stop_to_high_level_range_stop(range_stop_specification: MmsJournalRangeStop) -> Result<JournalRangeStop, MmsServiceError> {
+    Ok(match range_stop_specification {
+        MmsJournalRangeStop::EndingTime(ending_time) => JournalRangeStop::EndingTime(ending_time),
+        MmsJournalRangeStop::NumberOfEntries(number_of_entries) => JournalRangeStop::NumberOfEntries(BigInt::from_signed_bytes_be(&number_of_entries).try_into().map_err(to_mms_error("Failed to parse Number of Entries"))?),
+    })
+}
+
+pub(crate) fn convert_high_level_range_stop_to_low_level_range_stop(range_stop_specification: JournalRangeStop) -> MmsJournalRangeStop {
+    match range_stop_specification {
+        JournalRangeStop::EndingTime(ending_time) => MmsJournalRangeStop::EndingTime(ending_time),
+        JournalRangeStop::NumberOfEntries(number_of_entries) => MmsJournalRangeStop::NumberOfEntries(BigInt::from(number_of_entries).to_signed_bytes_be()),
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JournalVariable {
+    pub variable_tag: String,
+    pub value_specification: MmsServiceData,
+}
+
+/// An entry is either data, optionally tied to an event condition transition, or an annotation.
+#[derive(Clone, Debug, PartialEq)]
+pub enum JournalEntryForm {
+    Data { event: Option<MmsJournalEvent>, list_of_variables: Vec<JournalVariable> },
+    Annotation(String),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JournalEntryContent {
+    pub occurrence_time: MmsBinaryTime,
+    pub entry_form: JournalEntryForm,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct JournalEntry {
+    pub entry_identifier: Vec<u8>,
+    pub originating_application: MmsApplicationReference,
+    pub entry_content: JournalEntryContent,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct JournalEntries {
+    pub list_of_journal_entry: Vec<JournalEntry>,
+    pub more_follows: bool,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct JournalStatus {
+    pub current_entries: u32,
+    pub deletable: bool,
+}
+
+pub(crate) fn convert_low_level_entry_content_to_high_level_entry_content(entry_content: &MmsEntryContent) -> Result<JournalEntryContent, MmsError> {
+    let entry_form = match &entry_content.entry_form {
+        MmsEntryForm::Data { event, list_of_variables } => JournalEntryForm::Data {
+            event: event.clone(),
+            list_of_variables: list_of_variables
+                .iter()
+                .flatten()
+                .map(|variable| Ok(JournalVariable { variable_tag: variable.variable_tag.clone(), value_specification: convert_low_level_data_to_high_level_data(&variable.value_specification)? }))
+                .collect::<Result<Vec<_>, MmsError>>()?,
+        },
+        MmsEntryForm::Annotation(annotation) => JournalEntryForm::Annotation(annotation.clone()),
+    };
+    Ok(JournalEntryContent { occurrence_time: entry_content.occurrence_time.clone(), entry_form })
+}
+
+pub(crate) fn convert_high_level_entry_content_to_low_level_entry_content(entry_content: &JournalEntryContent) -> Result<MmsEntryContent, MmsError> {
+    let entry_form = match &entry_content.entry_form {
+        JournalEntryForm::Data { event, list_of_variables } => MmsEntryForm::Data {
+            event: event.clone(),
+            list_of_variables: match list_of_variables.is_empty() {
+                true => None,
+                false => Some(
+                    list_of_variables
+                        .iter()
+                        .map(|variable| Ok(MmsJournalVariable { variable_tag: variable.variable_tag.clone(), value_specification: convert_high_level_data_to_low_level_data(&variable.value_specification)? }))
+                        .collect::<Result<Vec<_>, MmsError>>()?,
+                ),
+            },
+        },
+        JournalEntryForm::Annotation(annotation) => MmsEntryForm::Annotation(annotation.clone()),
+    };
+    Ok(MmsEntryContent { occurrence_time: entry_content.occurrence_time.clone(), entry_form })
+}
+
+pub(crate) fn convert_low_level_journal_entry_to_high_level_journal_entry(entry: &MmsJournalEntry) -> Result<JournalEntry, MmsError> {
+    Ok(JournalEntry { entry_identifier: entry.entry_identifier.clone(), originating_application: entry.originating_application.clone(), entry_content: convert_low_level_entry_content_to_high_level_entry_content(&entry.entry_content)? })
+}
+
+pub(crate) fn convert_high_level_journal_entry_to_low_level_journal_entry(entry: &JournalEntry) -> Result<MmsJournalEntry, MmsError> {
+    Ok(MmsJournalEntry { entry_identifier: entry.entry_identifier.clone(), originating_application: entry.originating_application.clone(), entry_content: convert_high_level_entry_content_to_low_level_entry_content(&entry.entry_content)? })
+}
+
+/// Priorities and severities range from 0 (highest) to 127 (lowest).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventConditionDefinition {
+    pub class: MmsEventConditionClass,
+    pub priority: Option<u8>,
+    pub severity: Option<u8>,
+    pub alarm_summary_reports: Option<bool>,
+    /// The boolean variable a monitored event condition watches.
+    pub monitored_variable: Option<VariableSpecification>,
+    pub evaluation_interval: Option<u32>, // Milliseconds
+}
+
+/// Only the attributes that are present are altered.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventConditionMonitoring {
+    pub enabled: Option<bool>,
+    pub priority: Option<u8>,
+    pub alarm_summary_reports: Option<bool>,
+    pub evaluation_interval: Option<u32>, // Milliseconds
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventConditionAttributes {
+    pub deletable: bool,
+    pub class: MmsEventConditionClass,
+    pub priority: u8,
+    pub severity: u8,
+    pub alarm_summary_reports: bool,
+    pub monitored_variable: Option<MmsMonitoredVariable>,
+    pub evaluation_interval: Option<u32>, // Milliseconds
+}
+
+// Both priority and severity default to normal when they are not sent.
+const NORMAL_PRIORITY: u8 = 64;
+const NORMAL_SEVERITY: u8 = 64;
+
+fn convert_low_level_u8(value: &[u8], error_message: &str) -> Result<u8, MmsServiceError> {
+    BigInt::from_signed_bytes_be(value).try_into().map_err(to_mms_error(error_message))
+}
+
+fn convert_low_level_u16(value: &[u8], error_message: &str) -> Result<u16, MmsServiceError> {
+    BigInt::from_signed_bytes_be(value).try_into().map_err(to_mms_error(error_message))
+}
+
+fn convert_low_level_u32(value: &[u8], error_message: &str) -> Result<u32, MmsServiceError> {
+    BigInt::from_signed_bytes_be(value).try_into().map_err(to_mms_error(error_message))
+}
+
+pub(crate) fn convert_low_level_event_condition_definition_to_high_level_event_condition_definition(
+    class: MmsEventConditionClass,
+    priority: Option<Vec<u8>>,
+    severity: Option<Vec<u8>>,
+    alarm_summary_reports: Option<bool>,
+    monitored_variable: Option<Box<VariableSpecification>>,
+    evaluation_interval: Option<Vec<u8>>,
+) -> Result<EventConditionDefinition, MmsServiceError> {
+    Ok(EventConditionDefinition {
+        class,
+        priority: priority.map(|x| convert_low_level_u8(&x, "Failed to parse Priority")).transpose()?,
+        severity: severity.map(|x| convert_low_level_u8(&x, "Failed to parse Severity")).transpose()?,
+        alarm_summary_reports,
+        monitored_variable: monitored_variable.map(|x| *x),
+        evaluation_interval: evaluation_interval.map(|x| convert_low_level_u32(&x, "Failed to parse Evaluation Interval")).transpose()?,
+    })
+}
+
+pub(crate) fn convert_low_level_event_condition_monitoring_to_high_level_event_condition_monitoring(
+    enabled: Option<bool>,
+    priority: Option<Vec<u8>>,
+    alarm_summary_reports: Option<bool>,
+    evaluation_interval: Option<Vec<u8>>,
+) -> Result<EventConditionMonitoring, MmsServiceError> {
+    Ok(EventConditionMonitoring {
+        enabled,
+        priority: priority.map(|x| convert_low_level_u8(&x, "Failed to parse Priority")).transpose()?,
+        alarm_summary_reports,
+        evaluation_interval: evaluation_interval.map(|x| convert_low_level_u32(&x, "Failed to parse Evaluation Interval")).transpose()?,
+    })
+}
+
+pub(crate) fn convert_low_level_event_condition_attributes_to_high_level_event_condition_attributes(
+    mms_deletable: Option<bool>,
+    class: MmsEventConditionClass,
+    priority: Option<Vec<u8>>,
+    severity: Option<Vec<u8>>,
+    alarm_summary_reports: Option<bool>,
+    monitored_variable: Option<MmsMonitoredVariable>,
+    evaluation_interval: Option<Vec<u8>>,
+) -> Result<EventConditionAttributes, MmsServiceError> {
+    Ok(EventConditionAttributes {
+        deletable: mms_deletable.unwrap_or(false),
+        class,
+        priority: priority.map(|x| convert_low_level_u8(&x, "Failed to parse Priority")).transpose()?.unwrap_or(NORMAL_PRIORITY),
+        severity: severity.map(|x| convert_low_level_u8(&x, "Failed to parse Severity")).transpose()?.unwrap_or(NORMAL_SEVERITY),
+        alarm_summary_reports: alarm_summary_reports.unwrap_or(false),
+        monitored_variable,
+        evaluation_interval: evaluation_interval.map(|x| convert_low_level_u32(&x, "Failed to parse Evaluation Interval")).transpose()?,
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventEnrollmentDefinition {
+    pub event_condition_name: MmsObjectName,
+    pub event_condition_transitions: Vec<MmsEventTransition>,
+    pub alarm_acknowledgment_rule: MmsAlarmAcknowledgmentRule,
+    pub event_action_name: Option<MmsObjectName>,
+    pub client_application: Option<MmsApplicationReference>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum EventTime {
+    TimeOfDay(MmsBinaryTime),
+    TimeSequenceIdentifier(u32),
+    Undefined,
+}
+
+pub(crate) fn convert_low_level_event_time_to_high_level_event_time(event_time: MmsEventTime) -> Result<EventTime, MmsServiceError> {
+    Ok(match event_time {
+        MmsEventTime::TimeOfDay(time_of_day) => EventTime::TimeOfDay(time_of_day),
+        MmsEventTime::TimeSequenceIdentifier(identifier) => EventTime::TimeSequenceIdentifier(convert_low_level_u32(&identifier, "Failed to parse Time Sequence Identifier")?),
+        MmsEventTime::Undefined => EventTime::Undefined,
+    })
+}
+
+pub(crate) fn convert_high_level_event_time_to_low_level_event_time(event_time: EventTime) -> MmsEventTime {
+    match event_time {
+        EventTime::TimeOfDay(time_of_day) => MmsEventTime::TimeOfDay(time_of_day),
+        EventTime::TimeSequenceIdentifier(identifier) => MmsEventTime::TimeSequenceIdentifier(BigInt::from(identifier).to_signed_bytes_be()),
+        EventTime::Undefined => MmsEventTime::Undefined,
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventNotification {
+    pub event_enrollment_name: MmsObjectName,
+    pub event_condition_name: MmsObjectName,
+    pub severity: u8,
+    pub current_state: Option<MmsEventConditionState>,
+    pub transition_time: EventTime,
+    pub notification_lost: bool,
+    pub alarm_acknowledgment_rule: Option<MmsAlarmAcknowledgmentRule>,
+}
+
+pub(crate) fn convert_low_level_event_notification_to_high_level_event_notification(event_notification: MmsEventNotification) -> Result<EventNotification, MmsServiceError> {
+    Ok(EventNotification {
+        event_enrollment_name: event_notification.event_enrollment_name,
+        event_condition_name: event_notification.event_condition_name,
+        severity: convert_low_level_u8(&event_notification.severity, "Failed to parse Severity")?,
+        current_state: event_notification.current_state,
+        transition_time: convert_low_level_event_time_to_high_level_event_time(event_notification.transition_time)?,
+        notification_lost: event_notification.notification_lost.unwrap_or(false),
+        alarm_acknowledgment_rule: event_notification.alarm_acknowledgment_rule,
+    })
+}
+
+pub(crate) fn convert_high_level_event_notification_to_low_level_event_notification(event_notification: EventNotification) -> MmsEventNotification {
+    MmsEventNotification {
+        event_enrollment_name: event_notification.event_enrollment_name,
+        event_condition_name: event_notification.event_condition_name,
+        severity: BigInt::from(event_notification.severity).to_signed_bytes_be(),
+        current_state: event_notification.current_state,
+        transition_time: convert_high_level_event_time_to_low_level_event_time(event_notification.transition_time),
+        notification_lost: if event_notification.notification_lost { Some(true) } else { None },
+        alarm_acknowledgment_rule: event_notification.alarm_acknowledgment_rule,
+    }
+}
+
+/// Only the options that are present are sent. The server applies its defaults to the rest.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TakeControlOptions {
+    /// The token to take from a pool semaphore. Any free token is granted if this is not present.
+    pub named_token: Option<String>,
+    pub priority: Option<u8>,
+    pub acceptable_delay: Option<u32>, // Milliseconds
+    pub control_timeout: Option<u32>,  // Milliseconds
+    pub abort_on_timeout: Option<bool>,
+    pub relinquish_if_connection_lost: Option<bool>,
+}
+
+pub(crate) fn convert_low_level_take_control_options_to_high_level_take_control_options(
+    named_token: Option<String>,
+    priority: Option<Vec<u8>>,
+    acceptable_delay: Option<Vec<u8>>,
+    control_timeout: Option<Vec<u8>>,
+    abort_on_timeout: Option<bool>,
+    relinquish_if_connection_lost: Option<bool>,
+) -> Result<TakeControlOptions, MmsServiceError> {
+    Ok(TakeControlOptions {
+        named_token,
+        priority: priority.map(|x| convert_low_level_u8(&x, "Failed to parse Priority")).transpose()?,
+        acceptable_delay: acceptable_delay.map(|x| convert_low_level_u32(&x, "Failed to parse Acceptable Delay")).transpose()?,
+        control_timeout: control_timeout.map(|x| convert_low_level_u32(&x, "Failed to parse Control Timeout")).transpose()?,
+        abort_on_timeout,
+        relinquish_if_connection_lost,
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SemaphoreStatus {
+    pub deletable: bool,
+    pub class: MmsSemaphoreClass,
+    pub number_of_tokens: u16,
+    pub number_of_owned_tokens: u16,
+    pub number_of_hung_tokens: u16,
+}
+
+pub(crate) fn convert_low_level_semaphore_status_to_high_level_semaphore_status(
+    mms_deletable: bool,
+    class: MmsSemaphoreClass,
+    number_of_tokens: Vec<u8>,
+    number_of_owned_tokens: Vec<u8>,
+    number_of_hung_tokens: Vec<u8>,
+) -> Result<SemaphoreStatus, MmsServiceError> {
+    Ok(SemaphoreStatus {
+        deletable: mms_deletable,
+        class,
+        number_of_tokens: convert_low_level_u16(&number_of_tokens, "Failed to parse Number of Tokens")?,
+        number_of_owned_tokens: convert_low_level_u16(&number_of_owned_tokens, "Failed to parse Number of Owned Tokens")?,
+        number_of_hung_tokens: convert_low_level_u16(&number_of_hung_tokens, "Failed to parse Number of Hung Tokens")?,
+    })
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct VmdStatus {
+    pub logical_status: MmsLogicalStatus,
+    pub physical_status: MmsPhysicalStatus,
+    /// The meaning of these bits is defined by the VMD. At most 128 bits are allowed.
+    pub local_detail: Option<Vec<bool>>,
+}
+
+impl Default for VmdStatus {
+    fn default() -> Self {
+        Self { logical_status: MmsLogicalStatus::StateChangesAllowed, physical_status: MmsPhysicalStatus::Operational, local_detail: None }
+    }
+}
+
+pub(crate) fn convert_low_level_status_to_high_level_status(status: MmsStatus) -> Result<VmdStatus, MmsServiceError> {
+    let local_detail = match status.local_detail {
+        Some((padding, values)) => {
+            let padded_length = values.len() * 8;
+            if padding > 7 || padded_length > 128 || (padded_length == 0 && padding != 0) {
+                return Err(MmsServiceError::ProtocolError(format!("Received Invalid Local Detail. Padded Length: {}, Padding: {}", padded_length, padding)));
+            }
+            Some((0..(padded_length - padding as usize)).map(|i| values[i / 8] & (0x80 >> (i % 8)) != 0).collect())
+        }
+        None => None,
+    };
+    Ok(VmdStatus { logical_status: status.logical_status, physical_status: status.physical_status, local_detail })
+}
+
+pub(crate) fn convert_high_level_status_to_low_level_status(status: VmdStatus) -> Result<MmsStatus, MmsServiceError> {
+    let local_detail = match status.local_detail {
+        Some(items) => {
+            if items.len() > 128 {
+                return Err(MmsServiceError::ProtocolError(format!("Local Detail has {} bits but at most 128 are allowed", items.len())));
+            }
+            let buffer_length = items.len().div_ceil(8);
+            let mut bit_string_data = vec![0; buffer_length];
+            for (i, item) in items.iter().enumerate() {
+                if *item {
+                    bit_string_data[i / 8] |= 0x80 >> (i % 8);
+                }
+            }
+            Some(((buffer_length * 8 - items.len()) as u8, bit_string_data))
+        }
+        None => None,
+    };
+    Ok(MmsStatus { logical_status: status.logical_status, physical_status: status.physical_status, local_detail })
+}
+
+#[derive(Debug, PartialEq)]
+pub struct CapabilityList {
+    pub list_of_capabilities: Vec<String>,
+    pub more_follows: bool,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum MmsServiceDeleteObjectScope {
     Specific(Vec<MmsObjectName>),
@@ -143,6 +625,36 @@ pub enum MmsServiceDeleteObjectScope {
     Vmd,
 }
 
+pub(crate) fn convert_delete_object_scope_to_request(scope_of_delete: MmsServiceDeleteObjectScope) -> (Option<MmsScope>, Option<Vec<MmsObjectName>>, Option<String>) {
+    match scope_of_delete {
+        MmsServiceDeleteObjectScope::Specific(mms_object_names) => (Some(MmsScope::Specific), Some(mms_object_names), None),
+        MmsServiceDeleteObjectScope::AaSpecific => (Some(MmsScope::AaSpecific), None, None),
+        MmsServiceDeleteObjectScope::Domain(domain_name) => (Some(MmsScope::Domain), None, Some(domain_name)),
+        MmsServiceDeleteObjectScope::Vmd => (Some(MmsScope::Vmd), None, None),
+    }
+}
+
+pub(crate) fn convert_request_to_delete_object_scope(scope_of_delete: Option<MmsScope>, list_of_names: Option<Vec<MmsObjectName>>, domain_name: Option<String>) -> Result<MmsServiceDeleteObjectScope, MmsServiceError> {
+    Ok(match (scope_of_delete, list_of_names, domain_name) {
+        (Some(MmsScope::Vmd), None, None) => MmsServiceDeleteObjectScope::Vmd,
+        (Some(MmsScope::AaSpecific), None, None) => MmsServiceDeleteObjectScope::AaSpecific,
+        (Some(MmsScope::Domain), None, Some(domain_name)) => MmsServiceDeleteObjectScope::Domain(domain_name),
+        (Some(MmsScope::Specific), Some(items), None) => MmsServiceDeleteObjectScope::Specific(items),
+        (None, Some(items), None) => MmsServiceDeleteObjectScope::Specific(items),
+        // Sad cases
+        (Some(MmsScope::Vmd), Some(_), _) => return Err(MmsServiceError::ProtocolError("VMD scoped deletes cannot specify an object to delete.".into())),
+        (Some(MmsScope::Vmd), _, Some(_)) => return Err(MmsServiceError::ProtocolError("VMD scoped deletes cannot specify a domain to delete.".into())),
+        (Some(MmsScope::AaSpecific), Some(_), _) => return Err(MmsServiceError::ProtocolError("AA scoped deletes cannot specify an object to delete.".into())),
+        (Some(MmsScope::AaSpecific), _, Some(_)) => return Err(MmsServiceError::ProtocolError("AA scoped deletes cannot specify a domain to delete.".into())),
+        (Some(MmsScope::Domain), Some(_), _) => return Err(MmsServiceError::ProtocolError("Domain scoped deletes cannot specify an object to delete.".into())),
+        (Some(MmsScope::Domain), _, None) => return Err(MmsServiceError::ProtocolError("Domain scoped deletes must specify a domain to delete.".into())),
+        (Some(MmsScope::Specific), None, _) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes must specify a list of objects.".into())),
+        (Some(MmsScope::Specific), Some(_), Some(_)) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes cannot specify a domain to delete.".into())),
+        (None, None, _) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes must specify a list of objects.".into())),
+        (None, Some(_), Some(_)) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes cannot specify a domain to delete.".into())),
+    })
+}
+
 #[derive(Debug, PartialEq)]
 pub enum MmsServiceAccessResult {
     Success(MmsServiceData),
@@ -208,34 +720,29 @@ pub(crate) fn convert_high_level_data_to_low_level_data(service_data: &MmsServic
         }
         MmsServiceData::ObjectId(oid) => Ok(MmsData::ObjectId(oid.to_owned())),
         MmsServiceData::MmsString(value) => Ok(MmsData::MmsString(value.into())),
+        MmsServiceData::UtcTime(value) => Ok(MmsData::UtcTime(value.clone())),
     }
 }
 
+pub(crate) fn convert_high_level_type_specification_to_low_level_type_specification(type_specification: &MmsServiceTypeSpecification) -> Result<MmsTypeSpecification, MmsError> {
+    Ok(match type_specification {
+        MmsServiceTypeSpecification::ObjectName(mms_object_name) => MmsTypeSpecification::ObjectName(mms_object_name.clone()),
+        MmsServiceTypeSpecification::TypeDescription(mms_service_type_description) => MmsTypeSpecification::TypeDescription(convert_high_level_data_types_to_low_level_data_types(mms_service_type_description)?),
+    })
+}
+
 pub(crate) fn convert_high_level_data_types_to_low_level_data_types(service_data: &MmsServiceTypeDescription) -> Result<MmsTypeDescription, MmsError> {
     match service_data {
         MmsServiceTypeDescription::Array { packed, number_of_elements, element_type } => Ok(MmsTypeDescription::Array {
             packed: if *packed { Some(true) } else { None },
             number_of_elements: BigInt::from(*number_of_elements).to_signed_bytes_be(),
-            element_type: Box::new(match element_type.deref() {
-                MmsServiceTypeSpecification::ObjectName(mms_object_name) => rusty_mms::MmsTypeSpecification::ObjectName(mms_object_name.clone()),
-                MmsServiceTypeSpecification::TypeDescription(mms_service_type_description) => rusty_mms::MmsTypeSpecification::TypeDescription(convert_high_level_data_types_to_low_level_data_types(&mms_service_type_description)?),
-            }),
+            element_type: Box::new(convert_high_level_type_specification_to_low_level_type_specification(element_type)?),
         }),
         MmsServiceTypeDescription::Structure { packed, components } => Ok(MmsTypeDescription::Structure {
             packed: if *packed { Some(true) } else { None },
             components: components
                 .iter()
-                .map(|component| {
-                    Ok(MmsTypeDescriptionComponent {
-                        component_name: component.component_name.clone(),
-                        component_type: match &component.component_type {
-                            MmsServiceTypeSpecification::ObjectName(mms_object_name) => rusty_mms::MmsTypeSpecification::ObjectName(mms_object_name.clone()),
-                            MmsServiceTypeSpecification::TypeDescription(mms_service_type_description) => {
-                                rusty_mms::MmsTypeSpecification::TypeDescription(convert_high_level_data_types_to_low_level_data_types(&mms_service_type_description)?)
-                            }
-                        },
-                    })
-                })
+                .map(|component| Ok(MmsTypeDescriptionComponent { component_name: component.component_name.clone(), component_type: convert_high_level_type_specification_to_low_level_type_specification(&component.component_type)? }))
                 .collect::<Result<Vec<_>, MmsError>>()?,
         }),
         MmsServiceTypeDescription::Boolean => Ok(MmsTypeDescription::Boolean),
@@ -252,6 +759,7 @@ pub(crate) fn convert_high_level_data_types_to_low_level_data_types(service_data
         MmsServiceTypeDescription::Bcd(number_of_digits) => Ok(MmsTypeDescription::Bcd(BigInt::from(*number_of_digits).to_signed_bytes_be())),
         MmsServiceTypeDescription::ObjId => Ok(MmsTypeDescription::ObjId),
         MmsServiceTypeDescription::MmsString(length) => Ok(MmsTypeDescription::MmsString(BigInt::from(*length).to_signed_bytes_be())),
+        MmsServiceTypeDescription::UtcTime => Ok(MmsTypeDescription::UtcTime),
     }
 }
 
@@ -306,9 +814,17 @@ pub(crate) fn convert_low_level_data_to_high_level_data(service_data: &MmsData)
         }
         MmsData::ObjectId(value) => Ok(MmsServiceData::ObjectId(value.to_owned())),
         MmsData::MmsString(value) => Ok(MmsServiceData::MmsString(value.into())),
+        MmsData::UtcTime(value) => Ok(MmsServiceData::UtcTime(value.clone())),
     }
 }
 
+pub(crate) fn convert_low_level_type_specification_to_high_level_type_specification(type_specification: &MmsTypeSpecification) -> Result<MmsServiceTypeSpecification, MmsServiceError> {
+    Ok(match type_specification {
+        MmsTypeSpecification::ObjectName(mms_object_name) => MmsServiceTypeSpecification::ObjectName(mms_object_name.clone()),
+        MmsTypeSpecification::TypeDescription(mms_type_description) => MmsServiceTypeSpecification::TypeDescription(convert_low_level_data_types_to_high_level_data_types(mms_type_description)?),
+    })
+}
+
 pub(crate) fn convert_low_level_data_types_to_high_level_data_types(service_data: &MmsTypeDescription) -> Result<MmsServiceTypeDescription, MmsServiceError> {
     match service_data {
         MmsTypeDescription::Array { packed, number_of_elements, element_type } => Ok(MmsServiceTypeDescription::Array {
@@ -318,24 +834,13 @@ pub(crate) fn convert_low_level_data_types_to_high_level_data_types(service_data
                 .ok_or(MmsServiceError::ProtocolError("Type Description Element Count is expected to be an unisgned 32 bit integer.".to_string()))?
                 .try_into()
                 .map_err(to_mms_error("Type Description Element Count is expected to be an unisgned 32 bit integer."))?,
-            element_type: Box::new(match element_type.deref() {
-                rusty_mms::MmsTypeSpecification::ObjectName(mms_object_name) => MmsServiceTypeSpecification::ObjectName(mms_object_name.clone()),
-                rusty_mms::MmsTypeSpecification::TypeDescription(mms_type_description) => MmsServiceTypeSpecification::TypeDescription(convert_low_level_data_types_to_high_level_data_types(mms_type_description)?),
-            }),
+            element_type: Box::new(convert_low_level_type_specification_to_high_level_type_specification(element_type)?),
         }),
         MmsTypeDescription::Structure { packed, components } => Ok(MmsServiceTypeDescription::Structure {
             packed: packed.unwrap_or(false),
             components: components
                 .iter()
-                .map(|x| {
-                    Ok(MmsServiceTypeDescriptionComponent {
-                        component_name: x.component_name.clone(),
-                        component_type: match &x.component_type {
-                            rusty_mms::MmsTypeSpecification::ObjectName(mms_object_name) => MmsServiceTypeSpecification::ObjectName(mms_object_name.clone()),
-                            rusty_mms::MmsTypeSpecification::TypeDescription(mms_type_description) => MmsServiceTypeSpecification::TypeDescription(convert_low_level_data_types_to_high_level_data_types(mms_type_description)?),
-                        },
-                    })
-                })
+                .map(|x| Ok(MmsServiceTypeDescriptionComponent { component_name: x.component_name.clone(), component_type: convert_low_level_type_specification_to_high_level_type_specification(&x.component_type)? }))
                 .collect::<Result<Vec<MmsServiceTypeDescriptionComponent>, MmsServiceError>>()?,
         }),
         MmsTypeDescription::Boolean => Ok(MmsServiceTypeDescription::Boolean),
@@ -353,5 +858,6 @@ pub(crate) fn convert_low_level_data_types_to_high_level_data_types(service_data
         MmsTypeDescription::Bcd(length) => Ok(MmsServiceTypeDescription::Bcd(BigInt::from_signed_bytes_be(length).try_into().map_err(to_mms_error("Failed to decode BCD length"))?)),
         MmsTypeDescription::ObjId => Ok(MmsServiceTypeDescription::ObjId),
         MmsTypeDescription::MmsString(length) => Ok(MmsServiceTypeDescription::MmsString(BigInt::from_signed_bytes_be(length).try_into().map_err(to_mms_error("Failed to decode MMS String length"))?)),
+        MmsTypeDescription::UtcTime => Ok(MmsServiceTypeDescription::UtcTime),
     }
 }
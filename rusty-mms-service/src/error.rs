@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use rusty_mms::MmsError;
+use rusty_mms::{MmsError, MmsRejectReason, MmsServiceErrorInfo};
 use rusty_tpkt::TpktError;
 use thiserror::Error;
 
@@ -12,6 +12,18 @@ pub enum MmsServiceError {
     #[error("MMS Protocol Stack Error - {}", .0)]
     ProtocolStackError(#[from] MmsError),
 
+    #[error("MMS Service Error - {:?}", .0)]
+    ServiceError(MmsServiceErrorInfo),
+
+    #[error("MMS Request Rejected - {:?}", .0)]
+    Rejected(MmsRejectReason),
+
+    #[error("MMS Response Rejected - {:?}", .0)]
+    ResponseRejected(MmsRejectReason),
+
+    #[error("MMS Association Released")]
+    Released,
+
     #[error("MMS IO Error: {:?}", .0)]
     IoError(#[from] std::io::Error),
 
@@ -0,0 +1,265 @@
+use std::{
+    collections::HashMap,
+    io::{ErrorKind, SeekFrom},
+    path::{Component, Path, PathBuf},
+};
+
+use chrono::{DateTime, Utc};
+use rusty_mms::{MmsErrorClass, MmsFileError, MmsObjectAccessError, MmsServiceErrorInfo};
+use tokio::{
+    fs::File,
+    io::{AsyncReadExt, AsyncSeekExt, AsyncWrite, AsyncWriteExt},
+};
+
+use crate::{
+    RustyMmsServiceClient,
+    data::{DirectoryEntry, FileAttributes, FileDirectory, FileSegment},
+    error::MmsServiceError,
+    message::MmsServiceMessage,
+};
+
+const DEFAULT_READ_SIZE: usize = 4096;
+const DEFAULT_DIRECTORY_SIZE: usize = 100;
+
+/// Reads a remote file into the writer, returning the number of bytes written. The file is closed on the server even if the transfer fails.
+pub async fn download_file<C: RustyMmsServiceClient + ?Sized, W: AsyncWrite + Unpin + Send>(client: &mut C, file_name: Vec<String>, writer: &mut W) -> Result<u64, MmsServiceError> {
+    let (frsm_id, _) = client.file_open(file_name, 0).await?;
+    let copy_result = copy_remote_file(client, frsm_id, writer).await;
+    let close_result = client.file_close(frsm_id).await;
+    let written = copy_result?;
+    close_result?;
+    Ok(written)
+}
+
+async fn copy_remote_file<C: RustyMmsServiceClient + ?Sized, W: AsyncWrite + Unpin + Send>(client: &mut C, frsm_id: i32, writer: &mut W) -> Result<u64, MmsServiceError> {
+    let mut written = 0;
+    loop {
+        let segment = client.file_read(frsm_id).await?;
+        writer.write_all(&segment.file_data).await?;
+        written += segment.file_data.len() as u64;
+        if !segment.more_follows {
+            break;
+        }
+    }
+    writer.flush().await?;
+    Ok(written)
+}
+
+/// Serves the MMS file services from a local directory. File names are resolved below the root directory and cannot escape it, including through symbolic links.
+///
+/// Each component of an MMS file name is a path relative to the root. Directory entries are named by their path relative to the root, using '/' as the separator.
+pub struct LocalDirectoryFileService {
+    root: PathBuf,
+    read_size: usize,
+    directory_size: usize,
+    next_frsm_id: i32,
+    open_files: HashMap<i32, File>,
+}
+
+impl LocalDirectoryFileService {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into(), read_size: DEFAULT_READ_SIZE, directory_size: DEFAULT_DIRECTORY_SIZE, next_frsm_id: 0, open_files: HashMap::new() }
+    }
+
+    /// The most file data returned by a single file read. This must fit within the negotiated PDU size.
+    pub fn with_read_size(mut self, read_size: usize) -> Self {
+        self.read_size = read_size.max(1);
+        self
+    }
+
+    /// The most entries returned by a single file directory request.
+    pub fn with_directory_size(mut self, directory_size: usize) -> Self {
+        self.directory_size = directory_size.max(1);
+        self
+    }
+
+    /// Answers the message if it is a file service request. Any other message, including obtain file, is handed back to be answered by the application.
+    pub async fn handle(&mut self, message: MmsServiceMessage) -> Result<Option<MmsServiceMessage>, MmsServiceError> {
+        match message {
+            MmsServiceMessage::FileOpen(request) => match self.open(request.file_name(), request.initial_position()).await {
+                Ok((frsm_id, file_attributes)) => request.respond(frsm_id, file_attributes).await?,
+                Err(service_error) => request.respond_with_error(service_error).await?,
+            },
+            MmsServiceMessage::FileRead(request) => match self.read(request.frsm_id()).await {
+                Ok(segment) => request.respond(segment).await?,
+                Err(service_error) => request.respond_with_error(service_error).await?,
+            },
+            MmsServiceMessage::FileClose(request) => match self.open_files.remove(&request.frsm_id()) {
+                Some(_) => request.respond().await?,
+                None => request.respond_with_error(frsm_unknown()).await?,
+            },
+            MmsServiceMessage::FileRename(request) => match self.rename(request.current_file_name(), request.new_file_name()).await {
+                Ok(()) => request.respond().await?,
+                Err(service_error) => request.respond_with_error(service_error).await?,
+            },
+            MmsServiceMessage::FileDelete(request) => match self.delete(request.file_name()).await {
+                Ok(()) => request.respond().await?,
+                Err(service_error) => request.respond_with_error(service_error).await?,
+            },
+            MmsServiceMessage::FileDirectory(request) => match self.directory(request.file_specification(), request.continue_after()).await {
+                Ok(directory) => request.respond(directory).await?,
+                Err(service_error) => request.respond_with_error(service_error).await?,
+            },
+            x => return Ok(Some(x)),
+        }
+        Ok(None)
+    }
+
+    async fn open(&mut self, file_name: &[String], initial_position: u32) -> Result<(i32, FileAttributes), MmsServiceErrorInfo> {
+        let path = self.resolve(file_name).await?;
+        let mut file = File::open(&path).await.map_err(to_file_error)?;
+        let metadata = file.metadata().await.map_err(to_file_error)?;
+        if !metadata.is_file() {
+            return Err(file_error(MmsFileError::FileAccessDenied));
+        }
+        if initial_position as u64 > metadata.len() {
+            return Err(file_error(MmsFileError::PositionInvalid));
+        }
+        file.seek(SeekFrom::Start(initial_position as u64)).await.map_err(to_file_error)?;
+
+        let file_attributes = to_file_attributes(&metadata)?;
+        while self.open_files.contains_key(&self.next_frsm_id) {
+            self.next_frsm_id = self.next_frsm_id.wrapping_add(1);
+        }
+        let frsm_id = self.next_frsm_id;
+        self.next_frsm_id = self.next_frsm_id.wrapping_add(1);
+        self.open_files.insert(frsm_id, file);
+        Ok((frsm_id, file_attributes))
+    }
+
+    async fn read(&mut self, frsm_id: i32) -> Result<FileSegment, MmsServiceErrorInfo> {
+        let file = self.open_files.get_mut(&frsm_id).ok_or_else(frsm_unknown)?;
+
+        let mut file_data = vec![0; self.read_size];
+        let mut filled = 0;
+        while filled < file_data.len() {
+            match file.read(&mut file_data[filled..]).await.map_err(to_file_error)? {
+                0 => break,
+                count => filled += count,
+            }
+        }
+        file_data.truncate(filled);
+
+        let position = file.stream_position().await.map_err(to_file_error)?;
+        let length = file.metadata().await.map_err(to_file_error)?.len();
+        Ok(FileSegment { file_data, more_follows: position < length })
+    }
+
+    async fn rename(&self, current_file_name: &[String], new_file_name: &[String]) -> Result<(), MmsServiceErrorInfo> {
+        let current_path = self.resolve_entry(current_file_name).await?;
+        let new_path = self.resolve_entry(new_file_name).await?;
+        if tokio::fs::try_exists(&new_path).await.map_err(to_file_error)? {
+            return Err(file_error(MmsFileError::DuplicateFilename));
+        }
+        tokio::fs::rename(current_path, new_path).await.map_err(to_file_error)
+    }
+
+    async fn delete(&self, file_name: &[String]) -> Result<(), MmsServiceErrorInfo> {
+        tokio::fs::remove_file(self.resolve_entry(file_name).await?).await.map_err(to_file_error)
+    }
+
+    async fn directory(&self, file_specification: &Option<Vec<String>>, continue_after: &Option<Vec<String>>) -> Result<FileDirectory, MmsServiceErrorInfo> {
+        let root = self.canonical_root().await?;
+        let path = match file_specification {
+            Some(file_specification) => self.resolve(file_specification).await?,
+            None => root.clone(),
+        };
+
+        let mut entries = vec![];
+        let metadata = tokio::fs::metadata(&path).await.map_err(to_file_error)?;
+        if metadata.is_dir() {
+            let mut directory = tokio::fs::read_dir(&path).await.map_err(to_file_error)?;
+            while let Some(entry) = directory.next_entry().await.map_err(to_file_error)? {
+                let metadata = entry.metadata().await.map_err(to_file_error)?;
+                if metadata.is_file() {
+                    entries.push((relative_name(&root, &entry.path())?, to_file_attributes(&metadata)?));
+                }
+            }
+        } else {
+            entries.push((relative_name(&root, &path)?, to_file_attributes(&metadata)?));
+        }
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+
+        if let Some(continue_after) = continue_after {
+            let continue_after = continue_after.join("/");
+            entries.retain(|(name, _)| *name > continue_after);
+        }
+        let more_follows = entries.len() > self.directory_size;
+        entries.truncate(self.directory_size);
+
+        Ok(FileDirectory { list_of_directory_entry: entries.into_iter().map(|(name, file_attributes)| DirectoryEntry { file_name: vec![name], file_attributes }).collect(), more_follows })
+    }
+
+    // Symbolic links are followed, so the resolved path is checked to still be below the root.
+    async fn resolve(&self, file_name: &[String]) -> Result<PathBuf, MmsServiceErrorInfo> {
+        let path = tokio::fs::canonicalize(self.join(file_name)?).await.map_err(to_file_error)?;
+        self.contain(path).await
+    }
+
+    // Only the directory holding the entry is resolved. A rename or delete acts on a link itself, and a new name does not exist yet.
+    async fn resolve_entry(&self, file_name: &[String]) -> Result<PathBuf, MmsServiceErrorInfo> {
+        let path = self.join(file_name)?;
+        let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+            return Err(file_error(MmsFileError::FilenameSyntaxError));
+        };
+        let parent = tokio::fs::canonicalize(parent).await.map_err(to_file_error)?;
+        Ok(self.contain(parent).await?.join(name))
+    }
+
+    async fn contain(&self, path: PathBuf) -> Result<PathBuf, MmsServiceErrorInfo> {
+        if path.starts_with(self.canonical_root().await?) { Ok(path) } else { Err(file_error(MmsFileError::FileAccessDenied)) }
+    }
+
+    async fn canonical_root(&self) -> Result<PathBuf, MmsServiceErrorInfo> {
+        tokio::fs::canonicalize(&self.root).await.map_err(to_file_error)
+    }
+
+    // Only plain path components are accepted so a request cannot name anything outside of the root directory.
+    fn join(&self, file_name: &[String]) -> Result<PathBuf, MmsServiceErrorInfo> {
+        let mut path = self.root.clone();
+        let mut has_component = false;
+        for component in file_name.iter().flat_map(|x| Path::new(x).components()) {
+            match component {
+                Component::Normal(x) => path.push(x),
+                Component::CurDir => continue,
+                _ => return Err(file_error(MmsFileError::FilenameSyntaxError)),
+            }
+            has_component = true;
+        }
+        if !has_component {
+            return Err(file_error(MmsFileError::FilenameSyntaxError));
+        }
+        Ok(path)
+    }
+}
+
+fn relative_name(root: &Path, path: &Path) -> Result<String, MmsServiceErrorInfo> {
+    let relative_path = path.strip_prefix(root).map_err(|_| file_error(MmsFileError::Other))?;
+    let components = relative_path.components().map(|x| x.as_os_str().to_str().ok_or_else(|| file_error(MmsFileError::FilenameSyntaxError))).collect::<Result<Vec<_>, _>>()?;
+    Ok(components.join("/"))
+}
+
+fn to_file_attributes(metadata: &std::fs::Metadata) -> Result<FileAttributes, MmsServiceErrorInfo> {
+    // Files over 4 GiB cannot be described by the file services.
+    let size_of_file = u32::try_from(metadata.len()).map_err(|_| file_error(MmsFileError::Other))?;
+    Ok(FileAttributes { size_of_file, last_modified: metadata.modified().ok().map(DateTime::<Utc>::from) })
+}
+
+fn file_error(error: MmsFileError) -> MmsServiceErrorInfo {
+    MmsServiceErrorInfo::new(MmsErrorClass::File(error))
+}
+
+fn frsm_unknown() -> MmsServiceErrorInfo {
+    MmsServiceErrorInfo::new(MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent))
+}
+
+fn to_file_error(error: std::io::Error) -> MmsServiceErrorInfo {
+    file_error(match error.kind() {
+        ErrorKind::NotFound => MmsFileError::FileNonExistent,
+        ErrorKind::PermissionDenied => MmsFileError::FileAccessDenied,
+        ErrorKind::AlreadyExists => MmsFileError::DuplicateFilename,
+        ErrorKind::StorageFull => MmsFileError::InsufficientSpaceInFilestore,
+        ErrorKind::ResourceBusy => MmsFileError::FileBusy,
+        _ => MmsFileError::Other,
+    })
+}
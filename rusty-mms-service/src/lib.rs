@@ -1,11 +1,13 @@
 use async_trait::async_trait;
 use der_parser::Oid;
+use futures::future::BoxFuture;
 use num_bigint::BigInt;
 use rusty_acse::{
-    AcseRequestInformation, AcseResponseInformation, AeQualifier, ApTitle, AssociateResult, AssociateSourceDiagnostic, AssociateSourceDiagnosticUserCategory, RustyOsiSingleValueAcseInitiatorIsoStack, RustyOsiSingleValueAcseListenerIsoStack,
+    AcseRequestInformation, AcseResponseInformation, AeQualifier, ApTitle, AssociateResult, AssociateSourceDiagnostic, AssociateSourceDiagnosticUserCategory, ReleaseResult, RustyOsiSingleValueAcseInitiatorIsoStack,
+    RustyOsiSingleValueAcseListenerIsoStack,
 };
 use rusty_copp::{CoppConnectionInformation, RustyCoppInitiatorIsoStack, RustyCoppListenerIsoStack};
-use rusty_cosp::{CospConnectionParameters, CospProtocolInformation, RustyCospAcceptorIsoStack, RustyCospInitiatorIsoStack};
+use rusty_cosp::{CospConnectionParameters, CospFunctionalUnits, CospProtocolInformation, RustyCospAcceptorIsoStack, RustyCospInitiatorIsoStack};
 use rusty_cotp::{CotpProtocolInformation, CotpResponder, RustyCotpConnection, RustyCotpResponder};
 use std::{
     collections::{
@@ -16,7 +18,7 @@ use std::{
     ops::Deref,
     sync::{
         Arc,
-        atomic::{AtomicI32, Ordering},
+        atomic::{AtomicBool, AtomicI32, Ordering},
     },
 };
 use tokio::{
@@ -24,31 +26,52 @@ use tokio::{
     sync::{
         Mutex, Notify,
         mpsc::{self, UnboundedReceiver, UnboundedSender},
+        watch,
     },
 };
+use tracing::warn;
 
 use rusty_mms::{
-    ListOfVariablesItem, MmsAccessResult, MmsConfirmedRequest, MmsConfirmedResponse, MmsConnection, MmsData, MmsError, MmsInitiator, MmsListener, MmsMessage, MmsObjectClass, MmsObjectName, MmsObjectScope, MmsReader, MmsRequestInformation,
-    MmsResponder, MmsScope, MmsUnconfirmedService, MmsVariableAccessSpecification, MmsWriteResult, MmsWriter, RustyMmsInitiatorIsoStack, RustyMmsListenerIsoStack,
+    ListOfVariablesItem, MmsAccessResult, MmsCancelError, MmsConcludeRequest, MmsConfirmedRequest, MmsConfirmedResponse, MmsConnection, MmsData, MmsError, MmsErrorClass, MmsEventConditionState, MmsEventNotification, MmsInitiator,
+    MmsJournalLimitSpecification, MmsListener, MmsMessage, MmsObjectClass, MmsObjectName, MmsObjectScope, MmsReader, MmsRequestInformation, MmsResponder, MmsServiceErrorInfo, MmsServicePreemptError, MmsStatus, MmsUnconfirmedService,
+    MmsVariableAccessSpecification, MmsWriteResult, MmsWriter, RustyMmsInitiatorIsoStack, RustyMmsListenerIsoStack,
     parameters::{ParameterSupportOption, ServiceSupportOption},
 };
 use rusty_tpkt::{TcpTpktConnection, TcpTpktReader, TcpTpktServer, TcpTpktWriter};
 
 use crate::{
     data::{
-        Identity, InformationReportMmsServiceMessage, MmsServiceAccessResult, MmsServiceData, MmsServiceDeleteObjectScope, NameList, NamedVariableListAttributes, VariableAccessAttributes, convert_high_level_data_to_low_level_data,
-        convert_low_level_data_to_high_level_data, convert_low_level_data_types_to_high_level_data_types,
+        CapabilityList, DirectoryEntry, DomainAttributes, DomainSegment, EventConditionAttributes, EventConditionDefinition, EventConditionMonitoring, EventEnrollmentDefinition, EventNotification, EventTime, FileAttributes, FileDirectory,
+        FileSegment, Identity, InformationReportMmsServiceMessage, JournalEntries, JournalEntryContent, JournalReadSpecification, JournalStatus, MmsServiceAccessResult, MmsServiceData, MmsServiceDeleteObjectScope,
+        MmsServiceTypeSpecification, NameList, NamedTypeAttributes, NamedVariableListAttributes, ProgramInvocationAttributes, ProgramInvocationMonitor, SemaphoreStatus, TakeControlOptions, UploadSequence, VariableAccessAttributes,
+        VmdStatus, convert_delete_object_scope_to_request, convert_high_level_data_to_low_level_data, convert_high_level_entry_content_to_low_level_entry_content, convert_high_level_event_notification_to_low_level_event_notification,
+        convert_high_level_event_time_to_low_level_event_time, convert_high_level_monitor_to_low_level_monitor_type, convert_high_level_range_stop_to_low_level_range_stop, convert_high_level_status_to_low_level_status,
+        convert_high_level_type_specification_to_low_level_type_specification, convert_low_level_data_to_high_level_data, convert_low_level_data_types_to_high_level_data_types,
+        convert_low_level_event_condition_attributes_to_high_level_event_condition_attributes, convert_low_level_event_condition_definition_to_high_level_event_condition_definition,
+        convert_low_level_event_condition_monitoring_to_high_level_event_condition_monitoring, convert_low_level_event_notification_to_high_level_event_notification, convert_low_level_event_time_to_high_level_event_time,
+        convert_low_level_file_attributes_to_high_level_file_attributes, convert_low_level_journal_entry_to_high_level_journal_entry, convert_low_level_monitor_type_to_high_level_monitor,
+        convert_low_level_range_stop_to_high_level_range_stop, convert_low_level_semaphore_status_to_high_level_semaphore_status, convert_low_level_status_to_high_level_status,
+        convert_low_level_take_control_options_to_high_level_take_control_options, convert_low_level_type_specification_to_high_level_type_specification, convert_request_to_delete_object_scope,
     },
     error::{MmsServiceError, to_mms_error},
     message::{
-        DefineNamedVariableListMmsServiceMessage, DeleteNamedVariableListMmsServiceMessage, GetNameListMmsServiceMessage, GetNamedVariableListAttributesMmsServiceMessage, GetVariableAccessAttributesMmsServiceMessage,
-        IdentifyMmsServiceMessage, MmsServiceMessage, ReadMmsServiceMessage, WriteMmsServiceMessage,
+        AcknowledgeEventNotificationMmsServiceMessage, AlterEventConditionMonitoringMmsServiceMessage, ConcludeMmsServiceMessage, CreateJournalMmsServiceMessage, CreateProgramInvocationMmsServiceMessage,
+        DefineEventConditionMmsServiceMessage, DefineEventEnrollmentMmsServiceMessage, DefineNamedTypeMmsServiceMessage, DefineNamedVariableListMmsServiceMessage, DefineSemaphoreMmsServiceMessage, DeleteDomainMmsServiceMessage,
+        DeleteJournalMmsServiceMessage, DeleteNamedTypeMmsServiceMessage, DeleteNamedVariableListMmsServiceMessage, DeleteProgramInvocationMmsServiceMessage, DeleteSemaphoreMmsServiceMessage, DownloadSegmentMmsServiceMessage,
+        FileCloseMmsServiceMessage, FileDeleteMmsServiceMessage, FileDirectoryMmsServiceMessage, FileOpenMmsServiceMessage, FileReadMmsServiceMessage, FileRenameMmsServiceMessage, GetCapabilityListMmsServiceMessage,
+        GetDomainAttributesMmsServiceMessage, GetEventConditionAttributesMmsServiceMessage, GetNameListMmsServiceMessage, GetNamedTypeAttributesMmsServiceMessage, GetNamedVariableListAttributesMmsServiceMessage,
+        GetProgramInvocationAttributesMmsServiceMessage, GetVariableAccessAttributesMmsServiceMessage, IdentifyMmsServiceMessage, InitializeJournalMmsServiceMessage, InitiateDownloadSequenceMmsServiceMessage,
+        InitiateUploadSequenceMmsServiceMessage, KillMmsServiceMessage, MmsServiceCancellation, MmsServiceMessage, ObtainFileMmsServiceMessage, ReadJournalMmsServiceMessage, ReadMmsServiceMessage, RelinquishControlMmsServiceMessage,
+        RenameMmsServiceMessage, ReportJournalStatusMmsServiceMessage, ReportSemaphoreStatusMmsServiceMessage, ResetMmsServiceMessage, ResumeMmsServiceMessage, StartMmsServiceMessage, StatusMmsServiceMessage, StopMmsServiceMessage,
+        TakeControlMmsServiceMessage, TerminateDownloadSequenceMmsServiceMessage, TerminateUploadSequenceMmsServiceMessage, UploadSegmentMmsServiceMessage, WriteJournalMmsServiceMessage, WriteMmsServiceMessage,
     },
 };
 
 pub mod data;
 pub mod error;
+pub mod file;
 pub mod message;
+pub mod semaphore;
 
 pub struct MmsServiceConnectionIdentityParameters {
     pub tsap_id: Option<Vec<u8>>,
@@ -59,17 +82,19 @@ pub struct MmsServiceConnectionIdentityParameters {
     pub ae_qualifier: Option<Vec<u8>>,
     pub ap_invocation_identifier: Option<Vec<u8>>,
     pub ae_invocation_identifier: Option<Vec<u8>>,
+    pub implementation_information: Option<String>,
 }
 
 impl Default for MmsServiceConnectionIdentityParameters {
     fn default() -> Self {
-        Self { tsap_id: None, session_selector: None, presentation_selector: None, ap_title: None, ae_qualifier: None, ap_invocation_identifier: None, ae_invocation_identifier: None }
+        Self { tsap_id: None, session_selector: None, presentation_selector: None, ap_title: None, ae_qualifier: None, ap_invocation_identifier: None, ae_invocation_identifier: None, implementation_information: None }
     }
 }
 
 pub struct MmsServiceConnectionParameters {
     pub local_detail_calling: Option<i32>,
 
+    /// The identity of the server. A server responds with these values and falls back to echoing what the client called for any that are not set.
     pub called: MmsServiceConnectionIdentityParameters,
     pub calling: MmsServiceConnectionIdentityParameters,
 
@@ -78,6 +103,11 @@ pub struct MmsServiceConnectionParameters {
     pub proposed_data_structure_nesting_level: Option<i8>,
     pub propsed_parameter_cbb: Vec<ParameterSupportOption>,
     pub services_supported_calling: Vec<ServiceSupportOption>,
+
+    /// The status a server responds with by default. Sending an unsolicited status replaces it.
+    pub vmd_status: VmdStatus,
+    /// The capabilities a server responds with by default.
+    pub list_of_capabilities: Vec<String>,
 }
 
 impl Default for MmsServiceConnectionParameters {
@@ -91,19 +121,75 @@ impl Default for MmsServiceConnectionParameters {
             proposed_max_serv_outstanding_calling: 10,
             proposed_max_serv_outstanding_called: 10,
             proposed_data_structure_nesting_level: Some(2),
-            propsed_parameter_cbb: vec![ParameterSupportOption::Str1, ParameterSupportOption::Str2, ParameterSupportOption::Vnam, ParameterSupportOption::Vlis],
+            propsed_parameter_cbb: vec![
+                ParameterSupportOption::Str1,
+                ParameterSupportOption::Str2,
+                ParameterSupportOption::Vnam,
+                ParameterSupportOption::Valt,
+                ParameterSupportOption::Vlis,
+            ],
             services_supported_calling: vec![
+                ServiceSupportOption::Status,
                 ServiceSupportOption::GetNameList,
                 ServiceSupportOption::Identify,
+                ServiceSupportOption::Rename,
                 ServiceSupportOption::Write,
                 ServiceSupportOption::GetVariableAccessAttributes,
                 ServiceSupportOption::DefineNamedVariableList,
                 ServiceSupportOption::GetNamedVariableListAttribute,
                 ServiceSupportOption::DeleteNamedVariableList,
+                ServiceSupportOption::DefineNamedType,
+                ServiceSupportOption::GetNamedTypeAttributes,
+                ServiceSupportOption::DeleteNamedType,
+                ServiceSupportOption::InitiateDownloadSequence,
+                ServiceSupportOption::DownloadSegment,
+                ServiceSupportOption::TerminateDownloadSequence,
+                ServiceSupportOption::InitiateUploadSequence,
+                ServiceSupportOption::UploadSegment,
+                ServiceSupportOption::TerminateUploadSequence,
+                ServiceSupportOption::DeleteDomain,
+                ServiceSupportOption::GetDomainAttributes,
+                ServiceSupportOption::CreateProgramInvocation,
+                ServiceSupportOption::DeleteProgramInvocation,
+                ServiceSupportOption::Start,
+                ServiceSupportOption::Stop,
+                ServiceSupportOption::Resume,
+                ServiceSupportOption::Reset,
+                ServiceSupportOption::Kill,
+                ServiceSupportOption::GetProgramInvocationAttributes,
+                ServiceSupportOption::ReadJournal,
+                ServiceSupportOption::WriteJournal,
+                ServiceSupportOption::InitializeJournal,
+                ServiceSupportOption::ReportJournalStatus,
+                ServiceSupportOption::CreateJournal,
+                ServiceSupportOption::DeleteJournal,
+                ServiceSupportOption::GetCapabilityList,
+                ServiceSupportOption::DefineEventCondition,
+                ServiceSupportOption::AlterEventConditionMonitoring,
+                ServiceSupportOption::GetEventConditionAttributes,
+                ServiceSupportOption::DefineEventEnrollment,
+                ServiceSupportOption::AcknowledgeEventNotification,
+                ServiceSupportOption::TakeControl,
+                ServiceSupportOption::RelinquishControl,
+                ServiceSupportOption::DefineSemaphore,
+                ServiceSupportOption::DeleteSemaphore,
+                ServiceSupportOption::ReportSemaphoreStatus,
+                ServiceSupportOption::ObtainFile,
+                ServiceSupportOption::FileOpen,
+                ServiceSupportOption::FileRead,
+                ServiceSupportOption::FileClose,
+                ServiceSupportOption::FileRename,
+                ServiceSupportOption::FileDelete,
+                ServiceSupportOption::FileDirectory,
+                ServiceSupportOption::UnsolicitedStatus,
                 ServiceSupportOption::Read,
                 ServiceSupportOption::InformationReport,
+                ServiceSupportOption::EventNotification,
                 ServiceSupportOption::Conclude,
             ],
+
+            vmd_status: Default::default(),
+            list_of_capabilities: vec![],
         }
     }
 }
@@ -112,7 +198,18 @@ impl Default for MmsServiceConnectionParameters {
 pub trait RustyMmsServiceClient: Send + Sync {
     fn clone(&self) -> Box<dyn RustyMmsServiceClient>;
 
+    /// The ACSE response the server accepted the association with. This holds the identity the server responded with.
+    fn acse_response_information(&self) -> &AcseResponseInformation;
+
+    /// Concludes the MMS association and then releases the ACSE association. The client cannot be used afterwards.
+    async fn conclude(&mut self) -> Result<(), MmsServiceError>;
+
     async fn identify(&mut self) -> Result<Identity, MmsServiceError>;
+    /// Asks for the status of the VMD. Extended derivation asks the VMD to run its self diagnostics before answering.
+    async fn status(&mut self, extended_derivation: bool) -> Result<VmdStatus, MmsServiceError>;
+    async fn get_capability_list(&mut self, continue_after: Option<String>) -> Result<CapabilityList, MmsServiceError>;
+    /// Renames an object. The new name keeps the scope of the current name.
+    async fn rename(&mut self, object_class: MmsObjectClass, current_name: MmsObjectName, new_identifier: String) -> Result<(), MmsServiceError>;
 
     async fn get_name_list(&mut self, object_class: MmsObjectClass, object_scope: MmsObjectScope, continue_after: Option<String>) -> Result<NameList, MmsServiceError>;
     async fn get_variable_access_attributes(&mut self, object_name: MmsObjectName) -> Result<VariableAccessAttributes, MmsServiceError>;
@@ -121,6 +218,60 @@ pub trait RustyMmsServiceClient: Send + Sync {
     async fn get_named_variable_list_attributes(&mut self, variable_list_name: MmsObjectName) -> Result<NamedVariableListAttributes, MmsServiceError>;
     async fn delete_named_variable_list(&mut self, scope_of_delete: MmsServiceDeleteObjectScope) -> Result<(i32 /* Number Matched */, i32 /* Number Deleted */), MmsServiceError>;
 
+    async fn define_named_type(&mut self, type_name: MmsObjectName, type_specification: MmsServiceTypeSpecification) -> Result<(), MmsServiceError>;
+    async fn get_named_type_attributes(&mut self, type_name: MmsObjectName) -> Result<NamedTypeAttributes, MmsServiceError>;
+    async fn delete_named_type(&mut self, scope_of_delete: MmsServiceDeleteObjectScope) -> Result<(i32 /* Number Matched */, i32 /* Number Deleted */), MmsServiceError>;
+
+    async fn initiate_download_sequence(&mut self, domain_name: String, list_of_capabilities: Vec<String>, sharable: bool) -> Result<(), MmsServiceError>;
+    /// The download segment and terminate download sequence services are requested by the VMD loading the domain.
+    async fn download_segment(&mut self, domain_name: String) -> Result<DomainSegment, MmsServiceError>;
+    async fn terminate_download_sequence(&mut self, domain_name: String, discard: Option<MmsServiceErrorInfo>) -> Result<(), MmsServiceError>;
+    async fn initiate_upload_sequence(&mut self, domain_name: String) -> Result<UploadSequence, MmsServiceError>;
+    async fn upload_segment(&mut self, ulsm_id: i32) -> Result<DomainSegment, MmsServiceError>;
+    async fn terminate_upload_sequence(&mut self, ulsm_id: i32) -> Result<(), MmsServiceError>;
+    async fn delete_domain(&mut self, domain_name: String) -> Result<(), MmsServiceError>;
+    async fn get_domain_attributes(&mut self, domain_name: String) -> Result<DomainAttributes, MmsServiceError>;
+
+    /// Creates a program invocation from the listed domains. A reusable program invocation can be started again after it has stopped running.
+    async fn create_program_invocation(&mut self, program_invocation_name: String, list_of_domain_names: Vec<String>, reusable: bool, monitor: Option<ProgramInvocationMonitor>) -> Result<(), MmsServiceError>;
+    async fn delete_program_invocation(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError>;
+    async fn start(&mut self, program_invocation_name: String, execution_argument: Option<String>) -> Result<(), MmsServiceError>;
+    async fn stop(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError>;
+    async fn resume(&mut self, program_invocation_name: String, execution_argument: Option<String>) -> Result<(), MmsServiceError>;
+    async fn reset(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError>;
+    async fn kill(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError>;
+    async fn get_program_invocation_attributes(&mut self, program_invocation_name: String) -> Result<ProgramInvocationAttributes, MmsServiceError>;
+
+    /// Asks the server to obtain a file. Source file servers are not supported, so the source file is held by this application.
+    async fn obtain_file(&mut self, source_file: Vec<String>, destination_file: Vec<String>) -> Result<(), MmsServiceError>;
+    async fn file_open(&mut self, file_name: Vec<String>, initial_position: u32) -> Result<(i32 /* FRSM ID */, FileAttributes), MmsServiceError>;
+    async fn file_read(&mut self, frsm_id: i32) -> Result<FileSegment, MmsServiceError>;
+    async fn file_close(&mut self, frsm_id: i32) -> Result<(), MmsServiceError>;
+    async fn file_rename(&mut self, current_file_name: Vec<String>, new_file_name: Vec<String>) -> Result<(), MmsServiceError>;
+    async fn file_delete(&mut self, file_name: Vec<String>) -> Result<(), MmsServiceError>;
+    async fn file_directory(&mut self, file_specification: Option<Vec<String>>, continue_after: Option<Vec<String>>) -> Result<FileDirectory, MmsServiceError>;
+
+    async fn read_journal(&mut self, journal_name: MmsObjectName, specification: JournalReadSpecification) -> Result<JournalEntries, MmsServiceError>;
+    async fn write_journal(&mut self, journal_name: MmsObjectName, list_of_journal_entry: Vec<JournalEntryContent>) -> Result<(), MmsServiceError>;
+    /// Deletes the journal entries up to the limit, or every entry if there is no limit.
+    async fn initialize_journal(&mut self, journal_name: MmsObjectName, limit_specification: Option<MmsJournalLimitSpecification>) -> Result<u32 /* Entries Deleted */, MmsServiceError>;
+    async fn report_journal_status(&mut self, journal_name: MmsObjectName) -> Result<JournalStatus, MmsServiceError>;
+    async fn create_journal(&mut self, journal_name: MmsObjectName) -> Result<(), MmsServiceError>;
+    async fn delete_journal(&mut self, journal_name: MmsObjectName) -> Result<(), MmsServiceError>;
+
+    async fn define_event_condition(&mut self, event_condition_name: MmsObjectName, definition: EventConditionDefinition) -> Result<(), MmsServiceError>;
+    async fn alter_event_condition_monitoring(&mut self, event_condition_name: MmsObjectName, monitoring: EventConditionMonitoring) -> Result<(), MmsServiceError>;
+    async fn get_event_condition_attributes(&mut self, event_condition_name: MmsObjectName) -> Result<EventConditionAttributes, MmsServiceError>;
+    async fn define_event_enrollment(&mut self, event_enrollment_name: MmsObjectName, definition: EventEnrollmentDefinition) -> Result<(), MmsServiceError>;
+    async fn acknowledge_event_notification(&mut self, event_enrollment_name: MmsObjectName, acknowledged_state: MmsEventConditionState, time_of_acknowledged_transition: EventTime) -> Result<(), MmsServiceError>;
+
+    /// Returns the token granted by a pool semaphore. Use [semaphore::take_semaphore_control] to relinquish control when the guard is dropped.
+    async fn take_control(&mut self, semaphore_name: MmsObjectName, options: TakeControlOptions) -> Result<Option<String> /* Named Token */, MmsServiceError>;
+    async fn relinquish_control(&mut self, semaphore_name: MmsObjectName, named_token: Option<String>) -> Result<(), MmsServiceError>;
+    async fn define_semaphore(&mut self, semaphore_name: MmsObjectName, number_of_tokens: u16) -> Result<(), MmsServiceError>;
+    async fn delete_semaphore(&mut self, semaphore_name: MmsObjectName) -> Result<(), MmsServiceError>;
+    async fn report_semaphore_status(&mut self, semaphore_name: MmsObjectName) -> Result<SemaphoreStatus, MmsServiceError>;
+
     /// Reads data from an MMS Server.
     ///
     /// This does not expose the specification with result flag. If this is required, cut a ticket and I will add a read_with_specification method.
@@ -129,26 +280,59 @@ pub trait RustyMmsServiceClient: Send + Sync {
 
     async fn send_information_report(&mut self, variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError>;
     async fn receive_information_report(&mut self) -> Result<InformationReportMmsServiceMessage, MmsServiceError>;
+
+    async fn send_event_notification(&mut self, event_notification: EventNotification) -> Result<(), MmsServiceError>;
+    async fn receive_event_notification(&mut self) -> Result<EventNotification, MmsServiceError>;
+
+    async fn send_unsolicited_status(&mut self, status: VmdStatus) -> Result<(), MmsServiceError>;
+    async fn receive_unsolicited_status(&mut self) -> Result<VmdStatus, MmsServiceError>;
 }
 
-// Cleans up after iteself if the job has been cancelled.
-struct ResponseReservation {
+// Confirmed errors and rejects are delivered to the request they answer as errors.
+type MailBox = Arc<Mutex<HashMap<Vec<u8>, Option<Result<MmsConfirmedResponse, MmsServiceError>>>>>;
+
+fn confirmed_outcome(message: MmsMessage) -> Option<(Vec<u8>, Result<MmsConfirmedResponse, MmsServiceError>)> {
+    match message {
+        MmsMessage::ConfirmedResponse { invocation_id, response } => Some((invocation_id, Ok(response))),
+        MmsMessage::ConfirmedError { invocation_id, service_error } => Some((invocation_id, Err(MmsServiceError::ServiceError(service_error)))),
+        MmsMessage::Reject { original_invocation_id: Some(invocation_id), reject_reason } => Some((invocation_id, Err(MmsServiceError::Rejected(reject_reason)))),
+        _ => None,
+    }
+}
+
+// Cleans up after iteself if the job has been cancelled. The server is asked to cancel the request if it is still outstanding.
+struct ResponseReservation<W: MmsWriter + 'static> {
     invocation_id: Vec<u8>,
-    mail_box: Arc<Mutex<HashMap<Vec<u8>, Option<MmsConfirmedResponse>>>>,
+    mail_box: MailBox,
+    writer: Arc<Mutex<W>>,
+    // Cleared when the peer does not support cancel or once the association is being concluded.
+    cancellable: Arc<AtomicBool>,
+    // Only set while the sent request is waiting on its outcome.
+    outstanding: bool,
 }
 
-impl ResponseReservation {
-    fn new(invocation_id: Vec<u8>, mail_box: Arc<Mutex<HashMap<Vec<u8>, Option<MmsConfirmedResponse>>>>) -> Self {
-        Self { invocation_id, mail_box }
+impl<W: MmsWriter + 'static> ResponseReservation<W> {
+    fn new(invocation_id: Vec<u8>, mail_box: MailBox, writer: Arc<Mutex<W>>, cancellable: Arc<AtomicBool>) -> Self {
+        Self { invocation_id, mail_box, writer, cancellable, outstanding: false }
     }
 }
 
-impl Drop for ResponseReservation {
+impl<W: MmsWriter + 'static> Drop for ResponseReservation<W> {
     fn drop(&mut self) {
+        // The reservation may be dropped outside of a runtime, such as after the runtime has shut down. The association is gone by then.
+        let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+            return;
+        };
         let invocation_id = self.invocation_id.clone();
         let mail_box = self.mail_box.clone();
-        tokio::task::spawn(async move {
+        let writer = self.writer.clone();
+        let cancel = self.outstanding && self.cancellable.load(Ordering::Acquire);
+        runtime.spawn(async move {
             mail_box.lock().await.remove(&invocation_id);
+            if cancel {
+                // Ignore send errors here. The association may already be gone, in which case there is nothing to cancel.
+                let _ = writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::CancelRequest { invocation_id }])).await;
+            }
         });
     }
 }
@@ -157,30 +341,69 @@ struct RustyTcpMmsServiceClient<R: MmsReader, W: MmsWriter> {
     reader: Arc<Mutex<R>>,
     writer: Arc<Mutex<W>>,
     invocation_id: Arc<AtomicI32>,
-    mail_box: Arc<Mutex<HashMap<Vec<u8>, Option<MmsConfirmedResponse>>>>,
+    mail_box: MailBox,
+    cancellable: Arc<AtomicBool>,
 
     notify: Arc<Notify>,
     info_report_sender: UnboundedSender<MmsUnconfirmedService>,
     info_report_receiver: Arc<Mutex<UnboundedReceiver<MmsUnconfirmedService>>>,
+    event_notification_sender: UnboundedSender<MmsEventNotification>,
+    event_notification_receiver: Arc<Mutex<UnboundedReceiver<MmsEventNotification>>>,
+    unsolicited_status_sender: UnboundedSender<MmsStatus>,
+    unsolicited_status_receiver: Arc<Mutex<UnboundedReceiver<MmsStatus>>>,
+
+    acse_response_information: Arc<AcseResponseInformation>,
 }
 
-impl<R: MmsReader, W: MmsWriter> RustyTcpMmsServiceClient<R, W> {
-    async fn register_confirmed_message(&mut self, invocation_id: &Vec<u8>) -> Result<ResponseReservation, MmsServiceError> {
+impl<R: MmsReader, W: MmsWriter + 'static> RustyTcpMmsServiceClient<R, W> {
+    async fn register_confirmed_message(&mut self, invocation_id: &Vec<u8>) -> Result<ResponseReservation<W>, MmsServiceError> {
         // Will remove any interest in the result if the call is cancelled.
         match self.mail_box.lock().await.entry(invocation_id.clone()) {
             Vacant(x) => {
                 x.insert_entry(None);
-                Ok(ResponseReservation::new(invocation_id.clone(), self.mail_box.clone()))
+                Ok(ResponseReservation::new(invocation_id.clone(), self.mail_box.clone(), self.writer.clone(), self.cancellable.clone()))
             }
             Occupied(_) => return Err(MmsServiceError::InternalError(format!("Duplicate invocation id detected: {:?}", invocation_id))),
         }
     }
 
-    async fn fetch_confirmed_message(&mut self, invocation_id: Vec<u8>, reservation: ResponseReservation) -> Result<MmsConfirmedResponse, MmsServiceError> {
-        let _reservation = reservation;
+    // Unconfirmed services are queued for whoever is waiting on that type of service.
+    fn deliver_unconfirmed_message(&self, unconfirmed_service: MmsUnconfirmedService) {
+        // Ignore send errors here. Something has gone wrong but should be detected elsewhere.
+        match unconfirmed_service {
+            MmsUnconfirmedService::EventNotification(event_notification) => {
+                let _ = self.event_notification_sender.send(*event_notification);
+            }
+            MmsUnconfirmedService::UnsolicitedStatus(status) => {
+                let _ = self.unsolicited_status_sender.send(status);
+            }
+            unconfirmed_service => {
+                let _ = self.info_report_sender.send(unconfirmed_service);
+            }
+        }
+    }
+
+    // Responses to cancelled requests are no longer of interest.
+    async fn deliver_confirmed_message(&self, invocation_id: Vec<u8>, response: Result<MmsConfirmedResponse, MmsServiceError>) {
+        if let Occupied(mut x) = self.mail_box.lock().await.entry(invocation_id) {
+            x.insert(Some(response));
+            self.notify.notify_one();
+        }
+    }
+
+    async fn fetch_confirmed_message(&mut self, invocation_id: Vec<u8>, reservation: ResponseReservation<W>) -> Result<MmsConfirmedResponse, MmsServiceError> {
+        // The request has been sent, so it stays outstanding unless this is dropped before it has an outcome.
+        let mut reservation = reservation;
+        reservation.outstanding = true;
+        let response = self.receive_confirmed_message(&invocation_id).await;
+        reservation.outstanding = false;
+        response
+    }
+
+    async fn receive_confirmed_message(&mut self, invocation_id: &Vec<u8>) -> Result<MmsConfirmedResponse, MmsServiceError> {
         let mut notify_registration = self.notify.notified();
 
-        Ok(loop {
+        loop {
             select! {
                 _ = notify_registration => {
                     notify_registration = self.notify.notified();
@@ -188,7 +411,7 @@ impl<R: MmsReader, W: MmsWriter> RustyTcpMmsServiceClient<R, W> {
                         Occupied(mut x) => match x.get_mut().take() {
                             Some(response) => {
                                 x.remove();
-                                return Ok(response);
+                                break response;
                             },
                             None => (),
                         },
@@ -203,7 +426,7 @@ impl<R: MmsReader, W: MmsWriter> RustyTcpMmsServiceClient<R, W> {
                         Occupied(mut x) => match x.get_mut().take() {
                             Some(response) => {
                                 x.remove();
-                                return Ok(response);
+                                break response;
                             },
                             None => (),
                         },
@@ -213,24 +436,34 @@ impl<R: MmsReader, W: MmsWriter> RustyTcpMmsServiceClient<R, W> {
                     }
 
                     match reader.recv().await? {
-                        rusty_mms::MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: response_invocation_id, response }) => {
-                            if invocation_id == response_invocation_id {
-                                break response;
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service }) => self.deliver_unconfirmed_message(unconfirmed_service),
+                        // Nobody is waiting on the outcome of a cancel.
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::CancelResponse { .. } | MmsMessage::CancelError { .. }) => (),
+                        // The peer could not work out which request was rejected, so it cannot be given to any caller.
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id: None, reject_reason }) => warn!("Ignoring a reject that does not identify the rejected request: {:?}", reject_reason),
+                        rusty_mms::MmsRecvResult::Message(message) => match confirmed_outcome(message) {
+                            Some((response_invocation_id, response)) => {
+                                if *invocation_id == response_invocation_id {
+                                    break response;
+                                } else {
+                                    self.deliver_confirmed_message(response_invocation_id, response).await;
+                                }
+                            }
+                            None => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())), // TODO Message queue.
+                        },
+                        rusty_mms::MmsRecvResult::ResponseRejected { original_invocation_id, reject_reason } => {
+                            if *invocation_id == original_invocation_id {
+                                break Err(MmsServiceError::ResponseRejected(reject_reason));
                             } else {
-                                self.mail_box.lock().await.insert(response_invocation_id.clone(), Some(response));
-                                self.notify.notify_one();
+                                self.deliver_confirmed_message(original_invocation_id, Err(MmsServiceError::ResponseRejected(reject_reason))).await;
                             }
                         }
-                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service }) => {
-                            let _ = self.info_report_sender.send(unconfirmed_service); // Ignore send errors here. Something has gone wrong but should be detected elsewhere.
-                            ()
-                        }
-                        rusty_mms::MmsRecvResult::Message(_) => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())), // TODO Message queue.
+                        rusty_mms::MmsRecvResult::ReleaseRequest | rusty_mms::MmsRecvResult::ReleaseResponse(_) => return Err(MmsServiceError::ProtocolError("Unexpected release received.".into())),
                         rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
                     }
                 }
             };
-        })
+        }
     }
 }
 
@@ -242,12 +475,59 @@ impl<R: MmsReader + 'static, W: MmsWriter + 'static> RustyMmsServiceClient for R
             writer: self.writer.clone(),
             invocation_id: self.invocation_id.clone(),
             mail_box: self.mail_box.clone(),
+            cancellable: self.cancellable.clone(),
             notify: self.notify.clone(),
             info_report_sender: self.info_report_sender.clone(),
             info_report_receiver: self.info_report_receiver.clone(),
+            event_notification_sender: self.event_notification_sender.clone(),
+            event_notification_receiver: self.event_notification_receiver.clone(),
+            unsolicited_status_sender: self.unsolicited_status_sender.clone(),
+            unsolicited_status_receiver: self.unsolicited_status_receiver.clone(),
+            acse_response_information: self.acse_response_information.clone(),
         })
     }
 
+    fn acse_response_information(&self) -> &AcseResponseInformation {
+        &self.acse_response_information
+    }
+
+    async fn conclude(&mut self) -> Result<(), MmsServiceError> {
+        // Requests dropped from here on are not cancelled. Their outcome is decided by the conclude.
+        self.cancellable.store(false, Ordering::Release);
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConcludeRequest { request: MmsConcludeRequest {} }])).await?;
+
+        let mut reader = self.reader.lock().await;
+        loop {
+            match reader.recv().await? {
+                rusty_mms::MmsRecvResult::Message(MmsMessage::ConcludeResponse { request: _ }) => break,
+                rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service }) => self.deliver_unconfirmed_message(unconfirmed_service),
+                rusty_mms::MmsRecvResult::Message(MmsMessage::CancelResponse { .. } | MmsMessage::CancelError { .. }) => (),
+                rusty_mms::MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id: None, reject_reason }) => warn!("Ignoring a reject that does not identify the rejected request: {:?}", reject_reason),
+                rusty_mms::MmsRecvResult::Message(message) => match confirmed_outcome(message) {
+                    Some((invocation_id, response)) => self.deliver_confirmed_message(invocation_id, response).await,
+                    None => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+                },
+                rusty_mms::MmsRecvResult::ResponseRejected { original_invocation_id, reject_reason } => self.deliver_confirmed_message(original_invocation_id, Err(MmsServiceError::ResponseRejected(reject_reason))).await,
+                rusty_mms::MmsRecvResult::ReleaseRequest | rusty_mms::MmsRecvResult::ReleaseResponse(_) => return Err(MmsServiceError::ProtocolError("Unexpected release received.".into())),
+                rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
+            }
+        }
+
+        // The conclude has been accepted so the association can now be released.
+        self.writer.lock().await.release().await?;
+        loop {
+            match reader.recv().await? {
+                rusty_mms::MmsRecvResult::ReleaseResponse(ReleaseResult::Affirmative) => return Ok(()),
+                // A refused release leaves the association open.
+                rusty_mms::MmsRecvResult::ReleaseResponse(ReleaseResult::Negative) => return Err(MmsServiceError::ProtocolError("The release of the association was refused.".into())),
+                // Any remaining messages are no longer of interest.
+                rusty_mms::MmsRecvResult::Message(_) | rusty_mms::MmsRecvResult::ResponseRejected { .. } => (),
+                rusty_mms::MmsRecvResult::ReleaseRequest => return Err(MmsServiceError::ProtocolError("Unexpected release received.".into())),
+                rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
+            }
+        }
+    }
+
     async fn identify(&mut self) -> Result<Identity, MmsServiceError> {
         let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
         let registration = self.register_confirmed_message(&invocation_id).await?;
@@ -259,6 +539,39 @@ impl<R: MmsReader + 'static, W: MmsWriter + 'static> RustyMmsServiceClient for R
         }
     }
 
+    async fn status(&mut self, extended_derivation: bool) -> Result<VmdStatus, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Status { extended_derivation } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Status(status) => convert_low_level_status_to_high_level_status(status),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn get_capability_list(&mut self, continue_after: Option<String>) -> Result<CapabilityList, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::GetCapabilityList { continue_after } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::GetCapabilityList { list_of_capabilities, more_follows } => Ok(CapabilityList { list_of_capabilities, more_follows: more_follows.unwrap_or(true) }),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn rename(&mut self, object_class: MmsObjectClass, current_name: MmsObjectName, new_identifier: String) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Rename { object_class, current_name, new_identifier } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Rename => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
     async fn get_name_list(&mut self, object_class: MmsObjectClass, object_scope: MmsObjectScope, continue_after: Option<String>) -> Result<NameList, MmsServiceError> {
         let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
         let registration = self.register_confirmed_message(&invocation_id).await?;
@@ -312,12 +625,8 @@ impl<R: MmsReader + 'static, W: MmsWriter + 'static> RustyMmsServiceClient for R
     }
 
     async fn delete_named_variable_list(&mut self, scope_of_delete: MmsServiceDeleteObjectScope) -> Result<(i32, i32), MmsServiceError> {
-        let request_scope = match scope_of_delete {
-            MmsServiceDeleteObjectScope::Specific(mms_object_names) => MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete: Some(MmsScope::Specific), list_of_variable_list_names: Some(mms_object_names), domain_name: None },
-            MmsServiceDeleteObjectScope::AaSpecific => MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete: Some(MmsScope::AaSpecific), list_of_variable_list_names: None, domain_name: None },
-            MmsServiceDeleteObjectScope::Domain(domain_name) => MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete: Some(MmsScope::Domain), list_of_variable_list_names: None, domain_name: Some(domain_name) },
-            MmsServiceDeleteObjectScope::Vmd => MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete: Some(MmsScope::Vmd), list_of_variable_list_names: None, domain_name: None },
-        };
+        let (scope_of_delete, list_of_variable_list_names, domain_name) = convert_delete_object_scope_to_request(scope_of_delete);
+        let request_scope = MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete, list_of_variable_list_names, domain_name };
 
         let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
         let registration = self.register_confirmed_message(&invocation_id).await?;
@@ -331,370 +640,1955 @@ impl<R: MmsReader + 'static, W: MmsWriter + 'static> RustyMmsServiceClient for R
         }
     }
 
-    async fn read(&mut self, specification: MmsVariableAccessSpecification) -> Result<Vec<MmsServiceAccessResult>, MmsServiceError> {
+    async fn define_named_type(&mut self, type_name: MmsObjectName, type_specification: MmsServiceTypeSpecification) -> Result<(), MmsServiceError> {
+        let type_specification = convert_high_level_type_specification_to_low_level_type_specification(&type_specification)?;
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::DefineNamedType { type_name, type_specification } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::DefineNamedType => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn get_named_type_attributes(&mut self, type_name: MmsObjectName) -> Result<NamedTypeAttributes, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::GetNamedTypeAttributes { type_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::GetNamedTypeAttributes { deletable, type_specification } => {
+                Ok(NamedTypeAttributes { deletable, type_specification: convert_low_level_type_specification_to_high_level_type_specification(&type_specification)? })
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn delete_named_type(&mut self, scope_of_delete: MmsServiceDeleteObjectScope) -> Result<(i32, i32), MmsServiceError> {
+        let (scope_of_delete, list_of_type_names, domain_name) = convert_delete_object_scope_to_request(scope_of_delete);
+
         let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
         let registration = self.register_confirmed_message(&invocation_id).await?;
         self.writer
             .lock()
             .await
-            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Read { specification_with_result: Some(false), variable_access_specification: specification } }]))
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::DeleteNamedType { scope_of_delete, list_of_type_names, domain_name } }]))
             .await?;
 
         match self.fetch_confirmed_message(invocation_id, registration).await? {
-            MmsConfirmedResponse::Read { variable_access_specification: _, access_results } => Ok(access_results
-                .into_iter()
-                .map(|x| match x {
-                    MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
-                    MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
-                })
-                .collect::<Result<Vec<MmsServiceAccessResult>, MmsServiceError>>()?),
+            MmsConfirmedResponse::DeleteNamedType { number_matched, number_deleted } => {
+                Ok((BigInt::from_signed_bytes_be(&number_matched).try_into().map_err(to_mms_error(""))?, BigInt::from_signed_bytes_be(&number_deleted).try_into().map_err(to_mms_error(""))?))
+            }
             _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
         }
     }
 
-    async fn write(&mut self, specification: MmsVariableAccessSpecification, values: Vec<MmsServiceData>) -> Result<Vec<MmsWriteResult>, MmsServiceError> {
+    async fn initiate_download_sequence(&mut self, domain_name: String, list_of_capabilities: Vec<String>, sharable: bool) -> Result<(), MmsServiceError> {
         let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
         let registration = self.register_confirmed_message(&invocation_id).await?;
         self.writer
             .lock()
             .await
-            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest {
-                invocation_id: invocation_id.clone(),
-                request: MmsConfirmedRequest::Write { variable_access_specification: specification, list_of_data: values.iter().map(|x| convert_high_level_data_to_low_level_data(x)).collect::<Result<Vec<MmsData>, MmsError>>()? },
-            }]))
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::InitiateDownloadSequence { domain_name, list_of_capabilities, sharable } }]))
             .await?;
 
         match self.fetch_confirmed_message(invocation_id, registration).await? {
-            MmsConfirmedResponse::Write { write_results } => Ok(write_results),
+            MmsConfirmedResponse::InitiateDownloadSequence => Ok(()),
             _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
         }
     }
 
-    async fn send_information_report(&mut self, variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError> {
-        let access_results = access_results
-            .into_iter()
-            .map(|x| match x {
-                MmsServiceAccessResult::Success(mms_data) => Ok(MmsAccessResult::Success(convert_high_level_data_to_low_level_data(&mms_data)?)),
-                MmsServiceAccessResult::Failure(mms_access_error) => Ok(MmsAccessResult::Failure(mms_access_error)),
-            })
-            .collect::<Result<Vec<MmsAccessResult>, MmsServiceError>>()?;
+    async fn download_segment(&mut self, domain_name: String) -> Result<DomainSegment, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::DownloadSegment { domain_name } }])).await?;
 
-        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: rusty_mms::MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } }])).await?;
-        Ok(())
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::DownloadSegment { load_data, more_follows } => Ok(DomainSegment { load_data, more_follows: more_follows.unwrap_or(true) }),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
     }
 
-    async fn receive_information_report(&mut self) -> Result<InformationReportMmsServiceMessage, MmsServiceError> {
-        Ok(loop {
-            let mut receiver = self.info_report_receiver.lock().await;
-            let mut reader = self.reader.lock().await;
+    async fn terminate_download_sequence(&mut self, domain_name: String, discard: Option<MmsServiceErrorInfo>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::TerminateDownloadSequence { domain_name, discard } }])).await?;
 
-            select! {
-                value = reader.recv() => {
-                    match value? {
-                        rusty_mms::MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: response_invocation_id, response }) => {
-                            self.mail_box.lock().await.insert(response_invocation_id, Some(response));
-                        }
-                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } }) => {
-                            break InformationReportMmsServiceMessage {
-                                variable_access_specification,
-                                access_results: access_results
-                                    .into_iter()
-                                    .map(|x| match x {
-                                        MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
-                                        MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
-                                    })
-                                    .collect::<Result<Vec<MmsServiceAccessResult>, MmsServiceError>>()?,
-                            };
-                        }
-                        rusty_mms::MmsRecvResult::Message(_) => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
-                        rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
-                    }
-                },
-                value = receiver.recv() => {
-                    match value {
-                        Some(MmsUnconfirmedService::InformationReport { variable_access_specification, access_results }) =>  {
-                            break InformationReportMmsServiceMessage {
-                                variable_access_specification,
-                                access_results: access_results
-                                    .into_iter()
-                                    .map(|x| match x {
-                                        MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
-                                        MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
-                                    })
-                                    .collect::<Result<Vec<MmsServiceAccessResult>, MmsServiceError>>()?,
-                            };
-                        },
-                        None => todo!(),
-                    }
-                }
-            }
-        })
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::TerminateDownloadSequence => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
     }
-}
-
-pub async fn create_mms_service_client(host: SocketAddr, parameters: MmsServiceConnectionParameters) -> Result<Box<dyn RustyMmsServiceClient>, MmsServiceError> {
-    let tpkt_connection = TcpTpktConnection::connect(host).await.map_err(|e| MmsServiceError::ProtocolError(format!("{e}")))?;
-
-    let cotp_connection_info = CotpProtocolInformation::initiator(parameters.calling.tsap_id, parameters.called.tsap_id);
-    let cotp_connection = RustyCotpConnection::<TcpTpktReader, TcpTpktWriter>::initiate(tpkt_connection, cotp_connection_info, Default::default()).await.map_err(to_mms_error("Failed to create COTP Connection"))?;
 
-    let cosp_connection_info = CospProtocolInformation::new(parameters.calling.session_selector, parameters.called.session_selector);
-    let cosp_initiator = RustyCospInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cotp_connection, cosp_connection_info, Default::default()).await.map_err(to_mms_error("Failed to create COSP Connection"))?;
+    async fn initiate_upload_sequence(&mut self, domain_name: String) -> Result<UploadSequence, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::InitiateUploadSequence { domain_name } }])).await?;
 
-    let copp_connection_info = CoppConnectionInformation { called_presentation_selector: parameters.called.presentation_selector, calling_presentation_selector: parameters.calling.presentation_selector };
-    let copp_initiator = RustyCoppInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_initiator, copp_connection_info);
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::InitiateUploadSequence { ulsm_id, list_of_capabilities } => {
+                Ok(UploadSequence { ulsm_id: BigInt::from_signed_bytes_be(&ulsm_id).try_into().map_err(to_mms_error("Failed to parse ULSM ID"))?, list_of_capabilities })
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
 
-    let acse_connection_info = AcseRequestInformation {
-        application_context_name: Oid::from(&[1, 0, 9506, 2, 3]).map_err(to_mms_error("Failed to create MMS Application Context Name"))?,
-        called_ap_title: parameters.called.ap_title.map(|x| ApTitle::Form2(x)),
-        called_ae_qualifier: parameters.called.ae_qualifier.map(|x| AeQualifier::Form2(x)),
-        called_ap_invocation_identifier: parameters.called.ap_invocation_identifier,
-        called_ae_invocation_identifier: parameters.called.ae_invocation_identifier,
-        calling_ap_title: parameters.calling.ap_title.map(|x| ApTitle::Form2(x)),
-        calling_ae_qualifier: parameters.calling.ae_qualifier.map(|x| AeQualifier::Form2(x)),
-        calling_ap_invocation_identifier: parameters.calling.ap_invocation_identifier,
-        calling_ae_invocation_identifier: parameters.calling.ae_invocation_identifier,
-        ..Default::default()
-    };
-    let acse_initiator = RustyOsiSingleValueAcseInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(copp_initiator, acse_connection_info);
+    async fn upload_segment(&mut self, ulsm_id: i32) -> Result<DomainSegment, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer
+            .lock()
+            .await
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::UploadSegment { ulsm_id: BigInt::from(ulsm_id).to_signed_bytes_be() } }]))
+            .await?;
 
-    let mms_connection_info = MmsRequestInformation {
-        local_detail_calling: parameters.local_detail_calling,
-        proposed_max_serv_outstanding_calling: parameters.proposed_max_serv_outstanding_calling,
-        proposed_max_serv_outstanding_called: parameters.proposed_max_serv_outstanding_called,
-        proposed_data_structure_nesting_level: parameters.proposed_data_structure_nesting_level,
-        proposed_version_number: 1,
-        propsed_parameter_cbb: parameters.propsed_parameter_cbb,
-        services_supported_calling: parameters.services_supported_calling,
-        ..Default::default()
-    };
-    let mms_initiator = RustyMmsInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(acse_initiator, mms_connection_info);
-    let mms_connection = mms_initiator.initiate().await?;
-    let (reader, writer) = mms_connection.split().await?;
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::UploadSegment { load_data, more_follows } => Ok(DomainSegment { load_data, more_follows: more_follows.unwrap_or(true) }),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
 
-    let (sender, receiver) = mpsc::unbounded_channel();
+    async fn terminate_upload_sequence(&mut self, ulsm_id: i32) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer
+            .lock()
+            .await
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::TerminateUploadSequence { ulsm_id: BigInt::from(ulsm_id).to_signed_bytes_be() } }]))
+            .await?;
 
-    Ok(Box::new(RustyTcpMmsServiceClient {
-        reader: Arc::new(Mutex::new(reader)),
-        writer: Arc::new(Mutex::new(writer)),
-        invocation_id: Arc::new(AtomicI32::new(0)),
-        mail_box: Arc::new(Mutex::new(HashMap::new())),
-        notify: Arc::new(Notify::new()),
-        info_report_sender: sender,
-        info_report_receiver: Arc::new(Mutex::new(receiver)),
-    }))
-}
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::TerminateUploadSequence => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
 
-#[async_trait]
-pub trait RustyMmsServiceServer: Send + Sync {
-    fn clone(&self) -> Box<dyn RustyMmsServiceServer>;
+    async fn delete_domain(&mut self, domain_name: String) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::DeleteDomain { domain_name } }])).await?;
 
-    async fn receive_message(&mut self) -> Result<MmsServiceMessage, MmsServiceError>;
-    async fn send_information_report(&mut self, variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError>;
-}
-impl Clone for Box<dyn RustyMmsServiceServer> {
-    fn clone(&self) -> Self {
-        self.deref().clone()
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::DeleteDomain => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
     }
-}
+
+    async fn get_domain_attributes(&mut self, domain_name: String) -> Result<DomainAttributes, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::GetDomainAttributes { domain_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::GetDomainAttributes { list_of_capabilities, state, mms_deletable, sharable, list_of_program_invocations, upload_in_progress } => Ok(DomainAttributes {
+                list_of_capabilities,
+                state,
+                deletable: mms_deletable,
+                sharable,
+                list_of_program_invocations,
+                upload_in_progress: BigInt::from_signed_bytes_be(&upload_in_progress).try_into().map_err(to_mms_error("Failed to parse Upload In Progress"))?,
+            }),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn create_program_invocation(&mut self, program_invocation_name: String, list_of_domain_names: Vec<String>, reusable: bool, monitor: Option<ProgramInvocationMonitor>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer
+            .lock()
+            .await
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest {
+                invocation_id: invocation_id.clone(),
+                request: MmsConfirmedRequest::CreateProgramInvocation {
+                    program_invocation_name,
+                    list_of_domain_names,
+                    reusable: if reusable { None } else { Some(false) },
+                    monitor_type: convert_high_level_monitor_to_low_level_monitor_type(monitor),
+                },
+            }]))
+            .await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::CreateProgramInvocation => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn delete_program_invocation(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::DeleteProgramInvocation { program_invocation_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::DeleteProgramInvocation => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn start(&mut self, program_invocation_name: String, execution_argument: Option<String>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Start { program_invocation_name, execution_argument } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Start => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn stop(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Stop { program_invocation_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Stop => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn resume(&mut self, program_invocation_name: String, execution_argument: Option<String>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Resume { program_invocation_name, execution_argument } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Resume => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn reset(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Reset { program_invocation_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Reset => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn kill(&mut self, program_invocation_name: String) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Kill { program_invocation_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Kill => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn get_program_invocation_attributes(&mut self, program_invocation_name: String) -> Result<ProgramInvocationAttributes, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::GetProgramInvocationAttributes { program_invocation_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::GetProgramInvocationAttributes { state, list_of_domain_names, mms_deletable, reusable, monitor, execution_argument } => {
+                Ok(ProgramInvocationAttributes { state, list_of_domain_names, deletable: mms_deletable, reusable, monitor, execution_argument })
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn obtain_file(&mut self, source_file: Vec<String>, destination_file: Vec<String>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::ObtainFile { source_file, destination_file } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::ObtainFile => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn file_open(&mut self, file_name: Vec<String>, initial_position: u32) -> Result<(i32, FileAttributes), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer
+            .lock()
+            .await
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::FileOpen { file_name, initial_position: BigInt::from(initial_position).to_signed_bytes_be() } }]))
+            .await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::FileOpen { frsm_id, file_attributes } => {
+                Ok((BigInt::from_signed_bytes_be(&frsm_id).try_into().map_err(to_mms_error("Failed to parse FRSM ID"))?, convert_low_level_file_attributes_to_high_level_file_attributes(file_attributes)?))
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn file_read(&mut self, frsm_id: i32) -> Result<FileSegment, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::FileRead { frsm_id: BigInt::from(frsm_id).to_signed_bytes_be() } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::FileRead { file_data, more_follows } => Ok(FileSegment { file_data, more_follows: more_follows.unwrap_or(true) }),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn file_close(&mut self, frsm_id: i32) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer
+            .lock()
+            .await
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::FileClose { frsm_id: BigInt::from(frsm_id).to_signed_bytes_be() } }]))
+            .await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::FileClose => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn file_rename(&mut self, current_file_name: Vec<String>, new_file_name: Vec<String>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::FileRename { current_file_name, new_file_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::FileRename => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn file_delete(&mut self, file_name: Vec<String>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::FileDelete { file_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::FileDelete => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn file_directory(&mut self, file_specification: Option<Vec<String>>, continue_after: Option<Vec<String>>) -> Result<FileDirectory, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::FileDirectory { file_specification, continue_after } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::FileDirectory { list_of_directory_entry, more_follows } => Ok(FileDirectory {
+                list_of_directory_entry: list_of_directory_entry
+                    .into_iter()
+                    .map(|entry| Ok(DirectoryEntry { file_name: entry.file_name, file_attributes: convert_low_level_file_attributes_to_high_level_file_attributes(entry.file_attributes)? }))
+                    .collect::<Result<Vec<_>, MmsServiceError>>()?,
+                more_follows: more_follows.unwrap_or(false),
+            }),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn read_journal(&mut self, journal_name: MmsObjectName, specification: JournalReadSpecification) -> Result<JournalEntries, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let request = MmsConfirmedRequest::ReadJournal {
+            journal_name,
+            range_start_specification: specification.range_start_specification,
+            range_stop_specification: specification.range_stop_specification.map(convert_high_level_range_stop_to_low_level_range_stop),
+            list_of_variables: specification.list_of_variables,
+            entry_to_start_after: specification.entry_to_start_after.map(Box::new),
+        };
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::ReadJournal { list_of_journal_entry, more_follows } => {
+                Ok(JournalEntries { list_of_journal_entry: list_of_journal_entry.iter().map(convert_low_level_journal_entry_to_high_level_journal_entry).collect::<Result<Vec<_>, MmsError>>()?, more_follows: more_follows.unwrap_or(false) })
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn write_journal(&mut self, journal_name: MmsObjectName, list_of_journal_entry: Vec<JournalEntryContent>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let list_of_journal_entry = list_of_journal_entry.iter().map(convert_high_level_entry_content_to_low_level_entry_content).collect::<Result<Vec<_>, MmsError>>()?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::WriteJournal { journal_name, list_of_journal_entry } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::WriteJournal => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn initialize_journal(&mut self, journal_name: MmsObjectName, limit_specification: Option<MmsJournalLimitSpecification>) -> Result<u32, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::InitializeJournal { journal_name, limit_specification } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::InitializeJournal { entries_deleted } => Ok(BigInt::from_signed_bytes_be(&entries_deleted).try_into().map_err(to_mms_error("Failed to parse Entries Deleted"))?),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn report_journal_status(&mut self, journal_name: MmsObjectName) -> Result<JournalStatus, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::ReportJournalStatus { journal_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::ReportJournalStatus { current_entries, mms_deletable } => {
+                Ok(JournalStatus { current_entries: BigInt::from_signed_bytes_be(&current_entries).try_into().map_err(to_mms_error("Failed to parse Current Entries"))?, deletable: mms_deletable })
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn create_journal(&mut self, journal_name: MmsObjectName) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::CreateJournal { journal_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::CreateJournal => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn delete_journal(&mut self, journal_name: MmsObjectName) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::DeleteJournal { journal_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::DeleteJournal => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn define_event_condition(&mut self, event_condition_name: MmsObjectName, definition: EventConditionDefinition) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let request = MmsConfirmedRequest::DefineEventCondition {
+            event_condition_name,
+            class: definition.class,
+            priority: definition.priority.map(|x| BigInt::from(x).to_signed_bytes_be()),
+            severity: definition.severity.map(|x| BigInt::from(x).to_signed_bytes_be()),
+            alarm_summary_reports: definition.alarm_summary_reports,
+            monitored_variable: definition.monitored_variable.map(Box::new),
+            evaluation_interval: definition.evaluation_interval.map(|x| BigInt::from(x).to_signed_bytes_be()),
+        };
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::DefineEventCondition => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn alter_event_condition_monitoring(&mut self, event_condition_name: MmsObjectName, monitoring: EventConditionMonitoring) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let request = MmsConfirmedRequest::AlterEventConditionMonitoring {
+            event_condition_name,
+            enabled: monitoring.enabled,
+            priority: monitoring.priority.map(|x| BigInt::from(x).to_signed_bytes_be()),
+            alarm_summary_reports: monitoring.alarm_summary_reports,
+            evaluation_interval: monitoring.evaluation_interval.map(|x| BigInt::from(x).to_signed_bytes_be()),
+        };
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::AlterEventConditionMonitoring => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn get_event_condition_attributes(&mut self, event_condition_name: MmsObjectName) -> Result<EventConditionAttributes, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::GetEventConditionAttributes { event_condition_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::GetEventConditionAttributes { mms_deletable, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval } => {
+                Ok(convert_low_level_event_condition_attributes_to_high_level_event_condition_attributes(mms_deletable, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval)?)
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn define_event_enrollment(&mut self, event_enrollment_name: MmsObjectName, definition: EventEnrollmentDefinition) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let request = MmsConfirmedRequest::DefineEventEnrollment {
+            event_enrollment_name,
+            event_condition_name: definition.event_condition_name,
+            event_condition_transitions: definition.event_condition_transitions,
+            alarm_acknowledgment_rule: definition.alarm_acknowledgment_rule,
+            event_action_name: definition.event_action_name.map(Box::new),
+            client_application: definition.client_application.map(Box::new),
+        };
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::DefineEventEnrollment => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn acknowledge_event_notification(&mut self, event_enrollment_name: MmsObjectName, acknowledged_state: MmsEventConditionState, time_of_acknowledged_transition: EventTime) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let request = MmsConfirmedRequest::AcknowledgeEventNotification { event_enrollment_name, acknowledged_state, time_of_acknowledged_transition: convert_high_level_event_time_to_low_level_event_time(time_of_acknowledged_transition) };
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::AcknowledgeEventNotification => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn take_control(&mut self, semaphore_name: MmsObjectName, options: TakeControlOptions) -> Result<Option<String>, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let request = MmsConfirmedRequest::TakeControl {
+            semaphore_name,
+            named_token: options.named_token,
+            priority: options.priority.map(|x| BigInt::from(x).to_signed_bytes_be()),
+            acceptable_delay: options.acceptable_delay.map(|x| BigInt::from(x).to_signed_bytes_be()),
+            control_timeout: options.control_timeout.map(|x| BigInt::from(x).to_signed_bytes_be()),
+            abort_on_timeout: options.abort_on_timeout,
+            relinquish_if_connection_lost: options.relinquish_if_connection_lost,
+        };
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::TakeControl { named_token } => Ok(named_token),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn relinquish_control(&mut self, semaphore_name: MmsObjectName, named_token: Option<String>) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::RelinquishControl { semaphore_name, named_token } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::RelinquishControl => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn define_semaphore(&mut self, semaphore_name: MmsObjectName, number_of_tokens: u16) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        let request = MmsConfirmedRequest::DefineSemaphore { semaphore_name, number_of_tokens: BigInt::from(number_of_tokens).to_signed_bytes_be() };
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::DefineSemaphore => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn delete_semaphore(&mut self, semaphore_name: MmsObjectName) -> Result<(), MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::DeleteSemaphore { semaphore_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::DeleteSemaphore => Ok(()),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn report_semaphore_status(&mut self, semaphore_name: MmsObjectName) -> Result<SemaphoreStatus, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::ReportSemaphoreStatus { semaphore_name } }])).await?;
+
+        match self.fetch_confirmed_message(invocation_id.clone(), registration).await? {
+            MmsConfirmedResponse::ReportSemaphoreStatus { mms_deletable, class, number_of_tokens, number_of_owned_tokens, number_of_hung_tokens } => {
+                Ok(convert_low_level_semaphore_status_to_high_level_semaphore_status(mms_deletable, class, number_of_tokens, number_of_owned_tokens, number_of_hung_tokens)?)
+            }
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn read(&mut self, specification: MmsVariableAccessSpecification) -> Result<Vec<MmsServiceAccessResult>, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer
+            .lock()
+            .await
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: invocation_id.clone(), request: MmsConfirmedRequest::Read { specification_with_result: Some(false), variable_access_specification: specification } }]))
+            .await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Read { variable_access_specification: _, access_results } => Ok(access_results
+                .into_iter()
+                .map(|x| match x {
+                    MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
+                    MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
+                })
+                .collect::<Result<Vec<MmsServiceAccessResult>, MmsServiceError>>()?),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn write(&mut self, specification: MmsVariableAccessSpecification, values: Vec<MmsServiceData>) -> Result<Vec<MmsWriteResult>, MmsServiceError> {
+        let invocation_id = BigInt::from(self.invocation_id.fetch_add(1, Ordering::Acquire)).to_signed_bytes_be();
+        let registration = self.register_confirmed_message(&invocation_id).await?;
+        self.writer
+            .lock()
+            .await
+            .send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest {
+                invocation_id: invocation_id.clone(),
+                request: MmsConfirmedRequest::Write { variable_access_specification: specification, list_of_data: values.iter().map(|x| convert_high_level_data_to_low_level_data(x)).collect::<Result<Vec<MmsData>, MmsError>>()? },
+            }]))
+            .await?;
+
+        match self.fetch_confirmed_message(invocation_id, registration).await? {
+            MmsConfirmedResponse::Write { write_results } => Ok(write_results),
+            _ => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+        }
+    }
+
+    async fn send_information_report(&mut self, variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError> {
+        let access_results = access_results
+            .into_iter()
+            .map(|x| match x {
+                MmsServiceAccessResult::Success(mms_data) => Ok(MmsAccessResult::Success(convert_high_level_data_to_low_level_data(&mms_data)?)),
+                MmsServiceAccessResult::Failure(mms_access_error) => Ok(MmsAccessResult::Failure(mms_access_error)),
+            })
+            .collect::<Result<Vec<MmsAccessResult>, MmsServiceError>>()?;
+
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: rusty_mms::MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } }])).await?;
+        Ok(())
+    }
+
+    async fn receive_information_report(&mut self) -> Result<InformationReportMmsServiceMessage, MmsServiceError> {
+        Ok(loop {
+            let mut receiver = self.info_report_receiver.lock().await;
+            let mut reader = self.reader.lock().await;
+
+            select! {
+                value = reader.recv() => {
+                    match value? {
+                        rusty_mms::MmsRecvResult::Message(message @ (MmsMessage::ConfirmedResponse { .. } | MmsMessage::ConfirmedError { .. } | MmsMessage::Reject { original_invocation_id: Some(_), .. })) => {
+                            if let Some((response_invocation_id, response)) = confirmed_outcome(message) {
+                                self.deliver_confirmed_message(response_invocation_id, response).await;
+                            }
+                        }
+                        rusty_mms::MmsRecvResult::ResponseRejected { original_invocation_id, reject_reason } => self.deliver_confirmed_message(original_invocation_id, Err(MmsServiceError::ResponseRejected(reject_reason))).await,
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::CancelResponse { .. } | MmsMessage::CancelError { .. }) => (),
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id: None, reject_reason }) => warn!("Ignoring a reject that does not identify the rejected request: {:?}", reject_reason),
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } }) => {
+                            break InformationReportMmsServiceMessage {
+                                variable_access_specification,
+                                access_results: access_results
+                                    .into_iter()
+                                    .map(|x| match x {
+                                        MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
+                                        MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
+                                    })
+                                    .collect::<Result<Vec<MmsServiceAccessResult>, MmsServiceError>>()?,
+                            };
+                        }
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service }) => self.deliver_unconfirmed_message(unconfirmed_service),
+                        rusty_mms::MmsRecvResult::Message(_) => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+                        rusty_mms::MmsRecvResult::ReleaseRequest | rusty_mms::MmsRecvResult::ReleaseResponse(_) => return Err(MmsServiceError::ProtocolError("Unexpected release received.".into())),
+                        rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
+                    }
+                },
+                value = receiver.recv() => {
+                    match value {
+                        Some(MmsUnconfirmedService::InformationReport { variable_access_specification, access_results }) =>  {
+                            break InformationReportMmsServiceMessage {
+                                variable_access_specification,
+                                access_results: access_results
+                                    .into_iter()
+                                    .map(|x| match x {
+                                        MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
+                                        MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
+                                    })
+                                    .collect::<Result<Vec<MmsServiceAccessResult>, MmsServiceError>>()?,
+                            };
+                        },
+                        Some(unconfirmed_service) => self.deliver_unconfirmed_message(unconfirmed_service),
+                        None => todo!(),
+                    }
+                }
+            }
+        })
+    }
+
+    async fn send_event_notification(&mut self, event_notification: EventNotification) -> Result<(), MmsServiceError> {
+        let event_notification = convert_high_level_event_notification_to_low_level_event_notification(event_notification);
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::EventNotification(Box::new(event_notification)) }])).await?;
+        Ok(())
+    }
+
+    async fn receive_event_notification(&mut self) -> Result<EventNotification, MmsServiceError> {
+        Ok(loop {
+            let mut receiver = self.event_notification_receiver.lock().await;
+            let mut reader = self.reader.lock().await;
+
+            select! {
+                value = reader.recv() => {
+                    match value? {
+                        rusty_mms::MmsRecvResult::Message(message @ (MmsMessage::ConfirmedResponse { .. } | MmsMessage::ConfirmedError { .. } | MmsMessage::Reject { original_invocation_id: Some(_), .. })) => {
+                            if let Some((response_invocation_id, response)) = confirmed_outcome(message) {
+                                self.deliver_confirmed_message(response_invocation_id, response).await;
+                            }
+                        }
+                        rusty_mms::MmsRecvResult::ResponseRejected { original_invocation_id, reject_reason } => self.deliver_confirmed_message(original_invocation_id, Err(MmsServiceError::ResponseRejected(reject_reason))).await,
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::CancelResponse { .. } | MmsMessage::CancelError { .. }) => (),
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id: None, reject_reason }) => warn!("Ignoring a reject that does not identify the rejected request: {:?}", reject_reason),
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::EventNotification(event_notification) }) => {
+                            break convert_low_level_event_notification_to_high_level_event_notification(*event_notification)?;
+                        }
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service }) => self.deliver_unconfirmed_message(unconfirmed_service),
+                        rusty_mms::MmsRecvResult::Message(_) => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+                        rusty_mms::MmsRecvResult::ReleaseRequest | rusty_mms::MmsRecvResult::ReleaseResponse(_) => return Err(MmsServiceError::ProtocolError("Unexpected release received.".into())),
+                        rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
+                    }
+                },
+                value = receiver.recv() => {
+                    match value {
+                        Some(event_notification) => break convert_low_level_event_notification_to_high_level_event_notification(event_notification)?,
+                        None => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
+                    }
+                }
+            }
+        })
+    }
+
+    async fn send_unsolicited_status(&mut self, status: VmdStatus) -> Result<(), MmsServiceError> {
+        let status = convert_high_level_status_to_low_level_status(status)?;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::UnsolicitedStatus(status) }])).await?;
+        Ok(())
+    }
+
+    async fn receive_unsolicited_status(&mut self) -> Result<VmdStatus, MmsServiceError> {
+        Ok(loop {
+            let mut receiver = self.unsolicited_status_receiver.lock().await;
+            let mut reader = self.reader.lock().await;
+
+            select! {
+                value = reader.recv() => {
+                    match value? {
+                        rusty_mms::MmsRecvResult::Message(message @ (MmsMessage::ConfirmedResponse { .. } | MmsMessage::ConfirmedError { .. } | MmsMessage::Reject { original_invocation_id: Some(_), .. })) => {
+                            if let Some((response_invocation_id, response)) = confirmed_outcome(message) {
+                                self.deliver_confirmed_message(response_invocation_id, response).await;
+                            }
+                        }
+                        rusty_mms::MmsRecvResult::ResponseRejected { original_invocation_id, reject_reason } => self.deliver_confirmed_message(original_invocation_id, Err(MmsServiceError::ResponseRejected(reject_reason))).await,
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::CancelResponse { .. } | MmsMessage::CancelError { .. }) => (),
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id: None, reject_reason }) => warn!("Ignoring a reject that does not identify the rejected request: {:?}", reject_reason),
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::UnsolicitedStatus(status) }) => {
+                            break convert_low_level_status_to_high_level_status(status)?;
+                        }
+                        rusty_mms::MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service }) => self.deliver_unconfirmed_message(unconfirmed_service),
+                        rusty_mms::MmsRecvResult::Message(_) => return Err(MmsServiceError::ProtocolError("Unexpected payload received.".into())),
+                        rusty_mms::MmsRecvResult::ReleaseRequest | rusty_mms::MmsRecvResult::ReleaseResponse(_) => return Err(MmsServiceError::ProtocolError("Unexpected release received.".into())),
+                        rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
+                    }
+                },
+                value = receiver.recv() => {
+                    match value {
+                        Some(status) => break convert_low_level_status_to_high_level_status(status)?,
+                        None => return Err(MmsServiceError::ProtocolError("Connection Closed".into())),
+                    }
+                }
+            }
+        })
+    }
+}
+
+/// Negotiates the release session functional unit so the release that follows a conclude can be refused.
+fn cosp_parameters() -> CospConnectionParameters {
+    CospConnectionParameters { functional_units: CospFunctionalUnits { negotiated_release: true, ..Default::default() }, ..Default::default() }
+}
+
+pub async fn create_mms_service_client(host: SocketAddr, parameters: MmsServiceConnectionParameters) -> Result<Box<dyn RustyMmsServiceClient>, MmsServiceError> {
+    let tpkt_connection = TcpTpktConnection::connect(host).await.map_err(|e| MmsServiceError::ProtocolError(format!("{e}")))?;
+
+    let cotp_connection_info = CotpProtocolInformation::initiator(parameters.calling.tsap_id, parameters.called.tsap_id);
+    let cotp_connection = RustyCotpConnection::<TcpTpktReader, TcpTpktWriter>::initiate(tpkt_connection, cotp_connection_info, Default::default()).await.map_err(to_mms_error("Failed to create COTP Connection"))?;
+
+    let cosp_connection_info = CospProtocolInformation::new(parameters.calling.session_selector, parameters.called.session_selector);
+    let cosp_initiator = RustyCospInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cotp_connection, cosp_connection_info, cosp_parameters()).await.map_err(to_mms_error("Failed to create COSP Connection"))?;
+
+    let copp_connection_info = CoppConnectionInformation { called_presentation_selector: parameters.called.presentation_selector, calling_presentation_selector: parameters.calling.presentation_selector, ..Default::default() };
+    let copp_initiator = RustyCoppInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_initiator, copp_connection_info, Default::default());
+
+    let acse_connection_info = AcseRequestInformation {
+        application_context_name: Oid::from(&[1, 0, 9506, 2, 3]).map_err(to_mms_error("Failed to create MMS Application Context Name"))?,
+        called_ap_title: parameters.called.ap_title.map(|x| ApTitle::Form2(x)),
+        called_ae_qualifier: parameters.called.ae_qualifier.map(|x| AeQualifier::Form2(x)),
+        called_ap_invocation_identifier: parameters.called.ap_invocation_identifier,
+        called_ae_invocation_identifier: parameters.called.ae_invocation_identifier,
+        calling_ap_title: parameters.calling.ap_title.map(|x| ApTitle::Form2(x)),
+        calling_ae_qualifier: parameters.calling.ae_qualifier.map(|x| AeQualifier::Form2(x)),
+        calling_ap_invocation_identifier: parameters.calling.ap_invocation_identifier,
+        calling_ae_invocation_identifier: parameters.calling.ae_invocation_identifier,
+        implementation_information: parameters.calling.implementation_information,
+        ..Default::default()
+    };
+    let acse_initiator = RustyOsiSingleValueAcseInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(copp_initiator, acse_connection_info);
+
+    let mms_connection_info = MmsRequestInformation {
+        local_detail_calling: parameters.local_detail_calling,
+        proposed_max_serv_outstanding_calling: parameters.proposed_max_serv_outstanding_calling,
+        proposed_max_serv_outstanding_called: parameters.proposed_max_serv_outstanding_called,
+        proposed_data_structure_nesting_level: parameters.proposed_data_structure_nesting_level,
+        proposed_version_number: 1,
+        propsed_parameter_cbb: parameters.propsed_parameter_cbb,
+        services_supported_calling: parameters.services_supported_calling,
+        ..Default::default()
+    };
+    let mms_initiator = RustyMmsInitiatorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(acse_initiator, mms_connection_info);
+    let (mms_connection, acse_response_information) = mms_initiator.initiate().await?;
+    let cancellable = mms_connection.services_supported().iter().any(|x| matches!(x, ServiceSupportOption::Cancel));
+    let (reader, writer) = mms_connection.split().await?;
+
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let (event_notification_sender, event_notification_receiver) = mpsc::unbounded_channel();
+    let (unsolicited_status_sender, unsolicited_status_receiver) = mpsc::unbounded_channel();
+
+    Ok(Box::new(RustyTcpMmsServiceClient {
+        reader: Arc::new(Mutex::new(reader)),
+        writer: Arc::new(Mutex::new(writer)),
+        invocation_id: Arc::new(AtomicI32::new(0)),
+        mail_box: Arc::new(Mutex::new(HashMap::new())),
+        cancellable: Arc::new(AtomicBool::new(cancellable)),
+        notify: Arc::new(Notify::new()),
+        info_report_sender: sender,
+        info_report_receiver: Arc::new(Mutex::new(receiver)),
+        event_notification_sender,
+        event_notification_receiver: Arc::new(Mutex::new(event_notification_receiver)),
+        unsolicited_status_sender,
+        unsolicited_status_receiver: Arc::new(Mutex::new(unsolicited_status_receiver)),
+        acse_response_information: Arc::new(acse_response_information),
+    }))
+}
+
+#[async_trait]
+pub trait RustyMmsServiceServer: Send + Sync {
+    fn clone(&self) -> Box<dyn RustyMmsServiceServer>;
+
+    /// The ACSE request the client associated with. This holds the calling identity of the client.
+    fn acse_request_information(&self) -> &AcseRequestInformation;
+    /// The ACSE response the association was accepted with.
+    fn acse_response_information(&self) -> &AcseResponseInformation;
+
+    /// Fails with the released error once the client has concluded and released the association.
+    async fn receive_message(&mut self) -> Result<MmsServiceMessage, MmsServiceError>;
+    async fn send_information_report(&mut self, variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError>;
+    async fn send_event_notification(&mut self, event_notification: EventNotification) -> Result<(), MmsServiceError>;
+    /// Announces a change of status, such as going into maintenance. Later status requests are answered with it by default.
+    async fn send_unsolicited_status(&mut self, status: VmdStatus) -> Result<(), MmsServiceError>;
+}
+impl Clone for Box<dyn RustyMmsServiceServer> {
+    fn clone(&self) -> Self {
+        self.deref().clone()
+    }
+}
 
 struct RustyTcpMmsServiceServer<R: MmsReader, W: MmsWriter> {
     reader: Arc<Mutex<R>>,
     writer: Arc<Mutex<W>>,
+    outstanding_requests: Arc<Mutex<HashMap<u32, watch::Sender<bool>>>>,
+    vmd_status: Arc<Mutex<VmdStatus>>,
+    list_of_capabilities: Arc<Vec<String>>,
+
+    acse_request_information: Arc<AcseRequestInformation>,
+    acse_response_information: Arc<AcseResponseInformation>,
+}
+
+impl<R: MmsReader, W: MmsWriter + 'static> RustyTcpMmsServiceServer<R, W> {
+    async fn register_confirmed_request(&self, invocation_id: u32) -> (Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, MmsServiceCancellation) {
+        let (sender, receiver) = watch::channel(false);
+        self.outstanding_requests.lock().await.insert(invocation_id, sender);
+
+        let writer = self.writer.clone();
+        let outstanding_requests = self.outstanding_requests.clone();
+        let response = Box::new(move |msg: MmsMessage| {
+            let callback_writer = writer.clone();
+            let callback_outstanding_requests = outstanding_requests.clone();
+            Box::pin(async move {
+                // The client has already been answered if the request was cancelled.
+                if callback_outstanding_requests.lock().await.remove(&invocation_id).is_none() {
+                    return Ok(());
+                }
+                callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await
+            }) as BoxFuture<'static, Result<(), MmsError>>
+        });
+        (response, MmsServiceCancellation::new(receiver))
+    }
+
+    async fn cancel(&self, invocation_id: Vec<u8>) -> Result<(), MmsServiceError> {
+        let outstanding_request = match BigInt::from_signed_bytes_be(&invocation_id).try_into() {
+            Ok(id) => self.outstanding_requests.lock().await.remove(&id),
+            Err(_) => None,
+        };
+        let messages = match outstanding_request {
+            Some(cancellation) => {
+                cancellation.send_replace(true);
+                vec![
+                    MmsMessage::ConfirmedError { invocation_id: invocation_id.clone(), service_error: MmsServiceErrorInfo::new(MmsErrorClass::ServicePreempt(MmsServicePreemptError::Cancel)) },
+                    MmsMessage::CancelResponse { invocation_id },
+                ]
+            }
+            // The request has already been answered or was never received.
+            None => vec![MmsMessage::CancelError { invocation_id, service_error: MmsServiceErrorInfo::new(MmsErrorClass::Cancel(MmsCancelError::InvokeIdUnknown)) }],
+        };
+        self.writer.lock().await.send(&mut VecDeque::from(messages)).await?;
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl<R: MmsReader + 'static, W: MmsWriter + 'static> RustyMmsServiceServer for RustyTcpMmsServiceServer<R, W> {
+    fn clone(&self) -> Box<dyn RustyMmsServiceServer> {
+        Box::new(RustyTcpMmsServiceServer {
+            reader: self.reader.clone(),
+            writer: self.writer.clone(),
+            outstanding_requests: self.outstanding_requests.clone(),
+            vmd_status: self.vmd_status.clone(),
+            list_of_capabilities: self.list_of_capabilities.clone(),
+            acse_request_information: self.acse_request_information.clone(),
+            acse_response_information: self.acse_response_information.clone(),
+        })
+    }
+
+    fn acse_request_information(&self) -> &AcseRequestInformation {
+        &self.acse_request_information
+    }
+
+    fn acse_response_information(&self) -> &AcseResponseInformation {
+        &self.acse_response_information
+    }
+
+    async fn receive_message(&mut self) -> Result<MmsServiceMessage, MmsServiceError> {
+        let mms_message: MmsMessage = loop {
+            let result = self.reader.lock().await.recv().await?;
+            match result {
+                rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection closed".into())),
+                // Cancels are answered here. The handler of the request observes it through its cancellation.
+                rusty_mms::MmsRecvResult::Message(MmsMessage::CancelRequest { invocation_id }) => self.cancel(invocation_id).await?,
+                // The server makes no confirmed requests, so a reject can only be about a response or report it has already sent.
+                rusty_mms::MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id, reject_reason }) => warn!("The client rejected a PDU (invocation id {:?}): {:?}", original_invocation_id, reject_reason),
+                rusty_mms::MmsRecvResult::Message(mms_message) => break mms_message,
+                // The client releases the association after a conclude. It is always accepted.
+                rusty_mms::MmsRecvResult::ReleaseRequest => {
+                    self.writer.lock().await.release_response(ReleaseResult::Affirmative).await?;
+                    return Err(MmsServiceError::Released);
+                }
+                rusty_mms::MmsRecvResult::ReleaseResponse(_) => return Err(MmsServiceError::ProtocolError("Unexpected release response received.".into())),
+                // The server makes no confirmed requests so nothing is waiting on the rejected response.
+                rusty_mms::MmsRecvResult::ResponseRejected { .. } => (),
+            }
+        };
+        let (invocation_id, request) = match mms_message {
+            MmsMessage::ConfirmedRequest { invocation_id, request } => (invocation_id, request),
+            MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } } => {
+                let access_results = access_results
+                    .into_iter()
+                    .map(|access_result| match access_result {
+                        MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
+                        MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
+                    })
+                    .collect::<Result<Vec<MmsServiceAccessResult>, MmsError>>()?;
+                return Ok(MmsServiceMessage::InformationReport(InformationReportMmsServiceMessage { variable_access_specification, access_results }));
+            }
+            MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::EventNotification(event_notification) } => {
+                return Ok(MmsServiceMessage::EventNotification(convert_low_level_event_notification_to_high_level_event_notification(*event_notification)?));
+            }
+            MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::UnsolicitedStatus(status) } => {
+                return Ok(MmsServiceMessage::UnsolicitedStatus(convert_low_level_status_to_high_level_status(status)?));
+            }
+            MmsMessage::ConcludeRequest { request: _ } => {
+                let writer = self.writer.clone();
+                return Ok(MmsServiceMessage::Conclude(ConcludeMmsServiceMessage::new(Box::new(move |msg: MmsMessage| {
+                    let callback_writer = writer.clone();
+                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
+                }))));
+            }
+            x => return Err(MmsServiceError::ProtocolError(format!("Unexpected message received: {:?}", x))),
+        };
+
+        let invocation_id: u32 = BigInt::from_signed_bytes_be(invocation_id.as_slice()).try_into().map_err(|_| MmsServiceError::ProtocolError(format!("Invalid Invication Id: {:?}", invocation_id)))?;
+        let (response, cancellation) = self.register_confirmed_request(invocation_id).await;
+
+        Ok(match request {
+            MmsConfirmedRequest::GetNameList { object_class, object_scope, continue_after } => {
+                MmsServiceMessage::GetNameList(GetNameListMmsServiceMessage::new(invocation_id, object_class, object_scope, continue_after, response, cancellation))
+            }
+            MmsConfirmedRequest::Identify => MmsServiceMessage::Identify(IdentifyMmsServiceMessage::new(invocation_id, response, cancellation)),
+            MmsConfirmedRequest::Status { extended_derivation } => MmsServiceMessage::Status(StatusMmsServiceMessage::new(invocation_id, extended_derivation, self.vmd_status.lock().await.clone(), response, cancellation)),
+            MmsConfirmedRequest::GetCapabilityList { continue_after } => {
+                MmsServiceMessage::GetCapabilityList(GetCapabilityListMmsServiceMessage::new(invocation_id, continue_after, self.list_of_capabilities.clone(), response, cancellation))
+            }
+            MmsConfirmedRequest::Rename { object_class, current_name, new_identifier } => MmsServiceMessage::Rename(RenameMmsServiceMessage::new(invocation_id, object_class, current_name, new_identifier, response, cancellation)),
+            MmsConfirmedRequest::Read { specification_with_result, variable_access_specification } => {
+                MmsServiceMessage::Read(ReadMmsServiceMessage::new(invocation_id, variable_access_specification, specification_with_result, response, cancellation))
+            }
+            MmsConfirmedRequest::Write { variable_access_specification, list_of_data } => MmsServiceMessage::Write(WriteMmsServiceMessage::new(invocation_id, variable_access_specification, list_of_data, response, cancellation)?),
+            MmsConfirmedRequest::GetVariableAccessAttributes { object_name } => MmsServiceMessage::GetVariableAccessAttributes(GetVariableAccessAttributesMmsServiceMessage::new(invocation_id, object_name, response, cancellation)),
+            MmsConfirmedRequest::DefineNamedVariableList { variable_list_name, list_of_variables } => {
+                MmsServiceMessage::DefineNamedVariableList(DefineNamedVariableListMmsServiceMessage::new(invocation_id, variable_list_name, list_of_variables, response, cancellation))
+            }
+            MmsConfirmedRequest::GetNamedVariableListAttributes { object_name } => MmsServiceMessage::GetNamedVariableListAttributes(GetNamedVariableListAttributesMmsServiceMessage::new(invocation_id, object_name, response, cancellation)),
+            MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete, list_of_variable_list_names, domain_name } => MmsServiceMessage::DeleteNamedVariableList(DeleteNamedVariableListMmsServiceMessage::new(
+                invocation_id,
+                convert_request_to_delete_object_scope(scope_of_delete, list_of_variable_list_names, domain_name)?,
+                response,
+                cancellation,
+            )),
+            MmsConfirmedRequest::DefineNamedType { type_name, type_specification } => MmsServiceMessage::DefineNamedType(DefineNamedTypeMmsServiceMessage::new(invocation_id, type_name, type_specification, response, cancellation)?),
+            MmsConfirmedRequest::GetNamedTypeAttributes { type_name } => MmsServiceMessage::GetNamedTypeAttributes(GetNamedTypeAttributesMmsServiceMessage::new(invocation_id, type_name, response, cancellation)),
+            MmsConfirmedRequest::DeleteNamedType { scope_of_delete, list_of_type_names, domain_name } => {
+                MmsServiceMessage::DeleteNamedType(DeleteNamedTypeMmsServiceMessage::new(invocation_id, convert_request_to_delete_object_scope(scope_of_delete, list_of_type_names, domain_name)?, response, cancellation))
+            }
+            MmsConfirmedRequest::InitiateDownloadSequence { domain_name, list_of_capabilities, sharable } => {
+                MmsServiceMessage::InitiateDownloadSequence(InitiateDownloadSequenceMmsServiceMessage::new(invocation_id, domain_name, list_of_capabilities, sharable, response, cancellation))
+            }
+            MmsConfirmedRequest::DownloadSegment { domain_name } => MmsServiceMessage::DownloadSegment(DownloadSegmentMmsServiceMessage::new(invocation_id, domain_name, response, cancellation)),
+            MmsConfirmedRequest::TerminateDownloadSequence { domain_name, discard } => {
+                MmsServiceMessage::TerminateDownloadSequence(TerminateDownloadSequenceMmsServiceMessage::new(invocation_id, domain_name, discard, response, cancellation))
+            }
+            MmsConfirmedRequest::InitiateUploadSequence { domain_name } => MmsServiceMessage::InitiateUploadSequence(InitiateUploadSequenceMmsServiceMessage::new(invocation_id, domain_name, response, cancellation)),
+            MmsConfirmedRequest::UploadSegment { ulsm_id } => MmsServiceMessage::UploadSegment(UploadSegmentMmsServiceMessage::new(invocation_id, ulsm_id, response, cancellation)?),
+            MmsConfirmedRequest::TerminateUploadSequence { ulsm_id } => MmsServiceMessage::TerminateUploadSequence(TerminateUploadSequenceMmsServiceMessage::new(invocation_id, ulsm_id, response, cancellation)?),
+            MmsConfirmedRequest::DeleteDomain { domain_name } => MmsServiceMessage::DeleteDomain(DeleteDomainMmsServiceMessage::new(invocation_id, domain_name, response, cancellation)),
+            MmsConfirmedRequest::GetDomainAttributes { domain_name } => MmsServiceMessage::GetDomainAttributes(GetDomainAttributesMmsServiceMessage::new(invocation_id, domain_name, response, cancellation)),
+            MmsConfirmedRequest::CreateProgramInvocation { program_invocation_name, list_of_domain_names, reusable, monitor_type } => MmsServiceMessage::CreateProgramInvocation(CreateProgramInvocationMmsServiceMessage::new(
+                invocation_id,
+                program_invocation_name,
+                list_of_domain_names,
+                reusable.unwrap_or(true),
+                convert_low_level_monitor_type_to_high_level_monitor(monitor_type),
+                response,
+                cancellation,
+            )),
+            MmsConfirmedRequest::DeleteProgramInvocation { program_invocation_name } => {
+                MmsServiceMessage::DeleteProgramInvocation(DeleteProgramInvocationMmsServiceMessage::new(invocation_id, program_invocation_name, response, cancellation))
+            }
+            MmsConfirmedRequest::Start { program_invocation_name, execution_argument } => MmsServiceMessage::Start(StartMmsServiceMessage::new(invocation_id, program_invocation_name, execution_argument, response, cancellation)),
+            MmsConfirmedRequest::Stop { program_invocation_name } => MmsServiceMessage::Stop(StopMmsServiceMessage::new(invocation_id, program_invocation_name, response, cancellation)),
+            MmsConfirmedRequest::Resume { program_invocation_name, execution_argument } => MmsServiceMessage::Resume(ResumeMmsServiceMessage::new(invocation_id, program_invocation_name, execution_argument, response, cancellation)),
+            MmsConfirmedRequest::Reset { program_invocation_name } => MmsServiceMessage::Reset(ResetMmsServiceMessage::new(invocation_id, program_invocation_name, response, cancellation)),
+            MmsConfirmedRequest::Kill { program_invocation_name } => MmsServiceMessage::Kill(KillMmsServiceMessage::new(invocation_id, program_invocation_name, response, cancellation)),
+            MmsConfirmedRequest::GetProgramInvocationAttributes { program_invocation_name } => {
+                MmsServiceMessage::GetProgramInvocationAttributes(GetProgramInvocationAttributesMmsServiceMessage::new(invocation_id, program_invocation_name, response, cancellation))
+            }
+            MmsConfirmedRequest::ObtainFile { source_file, destination_file } => MmsServiceMessage::ObtainFile(ObtainFileMmsServiceMessage::new(invocation_id, source_file, destination_file, response, cancellation)),
+            MmsConfirmedRequest::FileOpen { file_name, initial_position } => MmsServiceMessage::FileOpen(FileOpenMmsServiceMessage::new(invocation_id, file_name, initial_position, response, cancellation)?),
+            MmsConfirmedRequest::FileRead { frsm_id } => MmsServiceMessage::FileRead(FileReadMmsServiceMessage::new(invocation_id, frsm_id, response, cancellation)?),
+            MmsConfirmedRequest::FileClose { frsm_id } => MmsServiceMessage::FileClose(FileCloseMmsServiceMessage::new(invocation_id, frsm_id, response, cancellation)?),
+            MmsConfirmedRequest::FileRename { current_file_name, new_file_name } => MmsServiceMessage::FileRename(FileRenameMmsServiceMessage::new(invocation_id, current_file_name, new_file_name, response, cancellation)),
+            MmsConfirmedRequest::FileDelete { file_name } => MmsServiceMessage::FileDelete(FileDeleteMmsServiceMessage::new(invocation_id, file_name, response, cancellation)),
+            MmsConfirmedRequest::FileDirectory { file_specification, continue_after } => MmsServiceMessage::FileDirectory(FileDirectoryMmsServiceMessage::new(invocation_id, file_specification, continue_after, response, cancellation)),
+            MmsConfirmedRequest::ReadJournal { journal_name, range_start_specification, range_stop_specification, list_of_variables, entry_to_start_after } => {
+                let specification = JournalReadSpecification {
+                    range_start_specification,
+                    range_stop_specification: range_stop_specification.map(convert_low_level_range_stop_to_high_level_range_stop).transpose()?,
+                    list_of_variables,
+                    entry_to_start_after: entry_to_start_after.map(|x| *x),
+                };
+                MmsServiceMessage::ReadJournal(ReadJournalMmsServiceMessage::new(invocation_id, journal_name, specification, response, cancellation))
+            }
+            MmsConfirmedRequest::WriteJournal { journal_name, list_of_journal_entry } => MmsServiceMessage::WriteJournal(WriteJournalMmsServiceMessage::new(invocation_id, journal_name, list_of_journal_entry, response, cancellation)?),
+            MmsConfirmedRequest::InitializeJournal { journal_name, limit_specification } => {
+                MmsServiceMessage::InitializeJournal(InitializeJournalMmsServiceMessage::new(invocation_id, journal_name, limit_specification, response, cancellation))
+            }
+            MmsConfirmedRequest::ReportJournalStatus { journal_name } => MmsServiceMessage::ReportJournalStatus(ReportJournalStatusMmsServiceMessage::new(invocation_id, journal_name, response, cancellation)),
+            MmsConfirmedRequest::CreateJournal { journal_name } => MmsServiceMessage::CreateJournal(CreateJournalMmsServiceMessage::new(invocation_id, journal_name, response, cancellation)),
+            MmsConfirmedRequest::DeleteJournal { journal_name } => MmsServiceMessage::DeleteJournal(DeleteJournalMmsServiceMessage::new(invocation_id, journal_name, response, cancellation)),
+            MmsConfirmedRequest::DefineEventCondition { event_condition_name, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval } => {
+                let definition = convert_low_level_event_condition_definition_to_high_level_event_condition_definition(class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval)?;
+                MmsServiceMessage::DefineEventCondition(DefineEventConditionMmsServiceMessage::new(invocation_id, event_condition_name, definition, response, cancellation))
+            }
+            MmsConfirmedRequest::AlterEventConditionMonitoring { event_condition_name, enabled, priority, alarm_summary_reports, evaluation_interval } => {
+                let monitoring = convert_low_level_event_condition_monitoring_to_high_level_event_condition_monitoring(enabled, priority, alarm_summary_reports, evaluation_interval)?;
+                MmsServiceMessage::AlterEventConditionMonitoring(AlterEventConditionMonitoringMmsServiceMessage::new(invocation_id, event_condition_name, monitoring, response, cancellation))
+            }
+            MmsConfirmedRequest::GetEventConditionAttributes { event_condition_name } => {
+                MmsServiceMessage::GetEventConditionAttributes(GetEventConditionAttributesMmsServiceMessage::new(invocation_id, event_condition_name, response, cancellation))
+            }
+            MmsConfirmedRequest::DefineEventEnrollment { event_enrollment_name, event_condition_name, event_condition_transitions, alarm_acknowledgment_rule, event_action_name, client_application } => {
+                let definition =
+                    EventEnrollmentDefinition { event_condition_name, event_condition_transitions, alarm_acknowledgment_rule, event_action_name: event_action_name.map(|x| *x), client_application: client_application.map(|x| *x) };
+                MmsServiceMessage::DefineEventEnrollment(DefineEventEnrollmentMmsServiceMessage::new(invocation_id, event_enrollment_name, definition, response, cancellation))
+            }
+            MmsConfirmedRequest::AcknowledgeEventNotification { event_enrollment_name, acknowledged_state, time_of_acknowledged_transition } => {
+                let time_of_acknowledged_transition = convert_low_level_event_time_to_high_level_event_time(time_of_acknowledged_transition)?;
+                MmsServiceMessage::AcknowledgeEventNotification(AcknowledgeEventNotificationMmsServiceMessage::new(invocation_id, event_enrollment_name, acknowledged_state, time_of_acknowledged_transition, response, cancellation))
+            }
+            MmsConfirmedRequest::TakeControl { semaphore_name, named_token, priority, acceptable_delay, control_timeout, abort_on_timeout, relinquish_if_connection_lost } => {
+                let options = convert_low_level_take_control_options_to_high_level_take_control_options(named_token, priority, acceptable_delay, control_timeout, abort_on_timeout, relinquish_if_connection_lost)?;
+                MmsServiceMessage::TakeControl(TakeControlMmsServiceMessage::new(invocation_id, semaphore_name, options, response, cancellation))
+            }
+            MmsConfirmedRequest::RelinquishControl { semaphore_name, named_token } => MmsServiceMessage::RelinquishControl(RelinquishControlMmsServiceMessage::new(invocation_id, semaphore_name, named_token, response, cancellation)),
+            MmsConfirmedRequest::DefineSemaphore { semaphore_name, number_of_tokens } => MmsServiceMessage::DefineSemaphore(DefineSemaphoreMmsServiceMessage::new(invocation_id, semaphore_name, number_of_tokens, response, cancellation)?),
+            MmsConfirmedRequest::DeleteSemaphore { semaphore_name } => MmsServiceMessage::DeleteSemaphore(DeleteSemaphoreMmsServiceMessage::new(invocation_id, semaphore_name, response, cancellation)),
+            MmsConfirmedRequest::ReportSemaphoreStatus { semaphore_name } => MmsServiceMessage::ReportSemaphoreStatus(ReportSemaphoreStatusMmsServiceMessage::new(invocation_id, semaphore_name, response, cancellation)),
+        })
+    }
+
+    async fn send_information_report(&mut self, variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError> {
+        let access_results = access_results
+            .into_iter()
+            .map(|x| match x {
+                MmsServiceAccessResult::Success(mms_data) => Ok(MmsAccessResult::Success(convert_high_level_data_to_low_level_data(&mms_data)?)),
+                MmsServiceAccessResult::Failure(mms_access_error) => Ok(MmsAccessResult::Failure(mms_access_error)),
+            })
+            .collect::<Result<Vec<MmsAccessResult>, MmsServiceError>>()?;
+
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: rusty_mms::MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } }])).await?;
+        Ok(())
+    }
+
+    async fn send_event_notification(&mut self, event_notification: EventNotification) -> Result<(), MmsServiceError> {
+        let event_notification = convert_high_level_event_notification_to_low_level_event_notification(event_notification);
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::EventNotification(Box::new(event_notification)) }])).await?;
+        Ok(())
+    }
+
+    async fn send_unsolicited_status(&mut self, status: VmdStatus) -> Result<(), MmsServiceError> {
+        let low_level_status = convert_high_level_status_to_low_level_status(status.clone())?;
+        // Updated first so that a status request from a client reacting to the announcement sees the new status.
+        *self.vmd_status.lock().await = status;
+        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::UnsolicitedStatus(low_level_status) }])).await?;
+        Ok(())
+    }
+}
+
+pub async fn create_mms_service_server(address: SocketAddr, parameters: MmsServiceConnectionParameters) -> Result<Box<dyn RustyMmsServiceServer>, MmsServiceError> {
+    let tpkt_connection = TcpTpktServer::listen(address).await?.accept().await?;
+
+    let (cotp_listener, cotp_connection_info) = RustyCotpResponder::<TcpTpktReader, TcpTpktWriter>::new(tpkt_connection, Default::default()).await.map_err(to_mms_error("Failed to create COTP Server"))?;
+    let cotp_connection = cotp_listener.accept(cotp_connection_info).await.map_err(to_mms_error(""))?;
+
+    let (cosp_listener, _) = RustyCospAcceptorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cotp_connection, cosp_parameters()).await.map_err(to_mms_error("Failed to create COSP Connection"))?;
+
+    // TODO: Need to expose this.
+    let _copp_connection_info = CoppConnectionInformation { called_presentation_selector: parameters.called.presentation_selector, calling_presentation_selector: parameters.calling.presentation_selector, ..Default::default() };
+    let (copp_responder, _) = RustyCoppListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_listener, Default::default()).await.map_err(to_mms_error(""))?;
+
+    let (mut acse_listener, acse_request_info) = RustyOsiSingleValueAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(copp_responder).await.map_err(to_mms_error(""))?;
+    let acse_response_info = AcseResponseInformation {
+        application_context_name: Oid::from(&[1, 0, 9506, 2, 3]).map_err(to_mms_error(""))?,
+        associate_result: AssociateResult::Accepted,
+        associate_source_diagnostic: AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::Null),
+        responding_ap_title: parameters.called.ap_title.map(ApTitle::Form2).or_else(|| acse_request_info.called_ap_title.clone()),
+        responding_ae_qualifier: parameters.called.ae_qualifier.map(AeQualifier::Form2).or_else(|| acse_request_info.called_ae_qualifier.clone()),
+        responding_ap_invocation_identifier: parameters.called.ap_invocation_identifier.or_else(|| acse_request_info.called_ap_invocation_identifier.clone()),
+        responding_ae_invocation_identifier: parameters.called.ae_invocation_identifier.or_else(|| acse_request_info.called_ae_invocation_identifier.clone()),
+        responder_acse_requirements: None,
+        mechanism_name: None,
+        responding_authentication_value: None,
+        implementation_information: parameters.called.implementation_information,
+    };
+    acse_listener.set_response(Some(acse_response_info.clone()));
+
+    let mms_listener = RustyMmsListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(acse_listener).await.map_err(to_mms_error(""))?;
+    let mms_responder = mms_listener.responder().await.map_err(to_mms_error(""))?;
+    let mms_connection = mms_responder.accept().await.map_err(to_mms_error(""))?;
+
+    let (mms_reader, mms_writer) = mms_connection.split().await.map_err(to_mms_error(""))?;
+
+    Ok(Box::new(RustyTcpMmsServiceServer {
+        reader: Arc::new(Mutex::new(mms_reader)),
+        writer: Arc::new(Mutex::new(mms_writer)),
+        outstanding_requests: Arc::new(Mutex::new(HashMap::new())),
+        vmd_status: Arc::new(Mutex::new(parameters.vmd_status)),
+        list_of_capabilities: Arc::new(parameters.list_of_capabilities),
+        acse_request_information: Arc::new(acse_request_info),
+        acse_response_information: Arc::new(acse_response_info),
+    }))
 }
 
-impl<R: MmsReader, W: MmsWriter> RustyTcpMmsServiceServer<R, W> {}
+#[cfg(test)]
+mod tests {
+    use crate::data::{
+        CapabilityList, DomainAttributes, DomainSegment, EventConditionAttributes, EventConditionDefinition, EventConditionMonitoring, EventEnrollmentDefinition, EventNotification, EventTime, FileSegment, JournalEntries, JournalEntry,
+        JournalEntryContent, JournalEntryForm, JournalRangeStop, JournalReadSpecification, JournalStatus, JournalVariable, MmsServiceAccessResult, MmsServiceData, MmsServiceDataFloat, MmsServiceDeleteObjectScope, MmsServiceTypeDescription,
+        MmsServiceTypeDescriptionComponent, MmsServiceTypeSpecification, NameList, NamedTypeAttributes, NamedVariableListAttributes, ProgramInvocationAttributes, ProgramInvocationMonitor, SemaphoreStatus, TakeControlOptions,
+        UploadSequence, VariableAccessAttributes, VmdStatus,
+    };
+    use crate::error::to_mms_error;
+    use crate::file::{LocalDirectoryFileService, download_file};
+    use crate::semaphore::take_semaphore_control;
+    use crate::{create_mms_service_client, create_mms_service_server};
+    use std::time::Duration;
+
+    use anyhow::anyhow;
+    use der_parser::Oid;
+    use num_bigint::{BigInt, BigUint};
+    use rand::random_range;
+    use rusty_mms::{
+        ListOfVariablesItem, MmsAccessError, MmsAlarmAcknowledgmentRule, MmsApplicationReference, MmsBasicObjectClass, MmsBinaryTime, MmsConfirmedResponseRejectCode, MmsDomainState, MmsErrorClass, MmsEventConditionClass,
+        MmsEventConditionState, MmsEventTransition, MmsFileError, MmsJournalEvent, MmsLogicalStatus, MmsMonitoredVariable, MmsObjectAccessError, MmsObjectClass, MmsObjectName, MmsObjectScope, MmsPhysicalStatus, MmsProgramInvocationState,
+        MmsRejectReason, MmsResourceError, MmsSemaphoreClass, MmsServiceErrorInfo, MmsServiceProblem, MmsTimeQuality, MmsUtcTime, MmsVariableAccessSpecification, MmsWriteResult, VariableSpecification,
+    };
+    use tokio::{join, sync::mpsc};
+    use tracing_test::traced_test;
+
+    use crate::{Identity, MmsServiceConnectionIdentityParameters, MmsServiceConnectionParameters, error::MmsServiceError, message::MmsServiceMessage};
+    use rusty_acse::{AeQualifier, ApTitle};
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_a_large_number_of_operations() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{port}").parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let server = server_results?;
+
+        let mut client1 = client.clone();
+        let client_task1 = tokio::task::spawn(async move {
+            for _ in 1..10000 {
+                client1.identify().await?;
+            }
+            Ok::<(), MmsServiceError>(())
+        });
+        let mut client2 = client.clone();
+        let client_task2 = tokio::task::spawn(async move {
+            for _ in 1..10000 {
+                client2.identify().await?;
+            }
+            Ok::<(), MmsServiceError>(())
+        });
+        let mut client3 = client.clone();
+        let client_task3 = tokio::task::spawn(async move {
+            for _ in 1..10000 {
+                client3.identify().await?;
+            }
+            Ok::<(), MmsServiceError>(())
+        });
+        let mut client4 = client.clone();
+        let client_task4 = tokio::task::spawn(async move {
+            for _ in 1..10000 {
+                client4.identify().await?;
+            }
+            Ok::<(), MmsServiceError>(())
+        });
+        let mut client5 = client.clone();
+        let client_task5 = tokio::task::spawn(async move {
+            for _ in 1..10000 {
+                client5.identify().await?;
+            }
+            Ok::<(), MmsServiceError>(())
+        });
+
+        let mut server1 = server.clone();
+        let server_task1 = tokio::task::spawn(async move {
+            for x in 1..10000 {
+                let value = match server1.receive_message().await {
+                    Ok(x) => x,
+                    Err(_) => {
+                        break;
+                    }
+                };
+                if let MmsServiceMessage::Identify(message) = value {
+                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool1".into(), abstract_syntaxes: None }).await.expect("")
+                }
+            }
+        });
+        let mut server2 = server.clone();
+        let server_task2 = tokio::task::spawn(async move {
+            for x in 1..10000 {
+                let value = match server2.receive_message().await {
+                    Ok(x) => x,
+                    Err(_) => {
+                        break;
+                    }
+                };
+                if let MmsServiceMessage::Identify(message) = value {
+                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool2".into(), abstract_syntaxes: None }).await.expect("")
+                }
+            }
+        });
+        let mut server3 = server.clone();
+        let server_task3 = tokio::task::spawn(async move {
+            for x in 1..10000 {
+                let value = match server3.receive_message().await {
+                    Ok(x) => x,
+                    Err(_) => {
+                        break;
+                    }
+                };
+                if let MmsServiceMessage::Identify(message) = value {
+                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool3".into(), abstract_syntaxes: None }).await.expect("")
+                }
+            }
+        });
+        let mut server4 = server.clone();
+        let server_task4 = tokio::task::spawn(async move {
+            for x in 1..10000 {
+                let value = match server4.receive_message().await {
+                    Ok(x) => x,
+                    Err(_) => {
+                        break;
+                    }
+                };
+                if let MmsServiceMessage::Identify(message) = value {
+                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool4".into(), abstract_syntaxes: None }).await.expect("")
+                }
+            }
+        });
+        let mut server5 = server.clone();
+        let server_task5 = tokio::task::spawn(async move {
+            for x in 1..10000 {
+                let value = match server5.receive_message().await {
+                    Ok(x) => x,
+                    Err(_) => {
+                        break;
+                    }
+                };
+                if let MmsServiceMessage::Identify(message) = value {
+                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool5".into(), abstract_syntaxes: None }).await.expect("")
+                }
+            }
+        });
+        drop(server);
+
+        let (client_task_result1, client_task_result2, client_task_result3, client_task_result4, client_task_result5, server_task_result1, server_task_result2, server_task_result3, server_task_result4, server_task_result5) =
+            join!(client_task1, client_task2, client_task3, client_task4, client_task5, server_task1, server_task2, server_task3, server_task4, server_task5);
+        client_task_result1??;
+        client_task_result2??;
+        client_task_result3??;
+        client_task_result4??;
+        client_task_result5??;
+        server_task_result1?;
+        server_task_result2?;
+        server_task_result3?;
+        server_task_result4?;
+        server_task_result5?;
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_association_identity() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                let parameters = MmsServiceConnectionParameters {
+                    calling: MmsServiceConnectionIdentityParameters { ap_title: Some(Oid::from(&[1, 3, 9999, 1])?), ae_qualifier: Some(vec![11]), implementation_information: Some("Client".into()), ..Default::default() },
+                    called: MmsServiceConnectionIdentityParameters { ap_title: Some(Oid::from(&[1, 3, 9999, 2])?), ae_qualifier: Some(vec![12]), ..Default::default() },
+                    ..Default::default()
+                };
+                Ok::<_, anyhow::Error>(create_mms_service_client(address, parameters).await?)
+            },
+            async {
+                let parameters = MmsServiceConnectionParameters {
+                    called: MmsServiceConnectionIdentityParameters { ap_title: Some(Oid::from(&[1, 3, 9999, 3])?), ap_invocation_identifier: Some(vec![13]), implementation_information: Some("Server".into()), ..Default::default() },
+                    ..Default::default()
+                };
+                Ok::<_, anyhow::Error>(create_mms_service_server(address, parameters).await?)
+            }
+        );
+
+        let client = client_results?;
+        let server = server_results?;
+
+        assert_eq!(server.acse_request_information().calling_ap_title, Some(ApTitle::Form2(Oid::from(&[1, 3, 9999, 1])?)));
+        assert_eq!(server.acse_request_information().calling_ae_qualifier, Some(AeQualifier::Form2(vec![11])));
+        assert_eq!(server.acse_request_information().implementation_information, Some("Client".into()));
+
+        // The configured identity is used and the unset qualifier echoes what the client called.
+        let response = client.acse_response_information();
+        assert_eq!(response.responding_ap_title, Some(ApTitle::Form2(Oid::from(&[1, 3, 9999, 3])?)));
+        assert_eq!(response.responding_ae_qualifier, Some(AeQualifier::Form2(vec![12])));
+        assert_eq!(response.responding_ap_invocation_identifier, Some(vec![13]));
+        assert_eq!(response.implementation_information, Some("Server".into()));
+        assert_eq!(response, server.acse_response_information());
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_identify_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let mut server = server_results?;
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.identify().await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::Identify(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        request.respond(Identity { vendor_name: "Yo".into(), model_name: "There".into(), revision: "Fool".into(), abstract_syntaxes: None }).await.expect("Test Failed");
+        assert_eq!(client_task.await??, Identity { vendor_name: "Yo".into(), model_name: "There".into(), revision: "Fool".into(), abstract_syntaxes: None });
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.identify().await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::Identify(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        request.respond(Identity { vendor_name: "This".into(), model_name: "Is".into(), revision: "Another".into(), abstract_syntaxes: Some(vec![Oid::from(&[1, 2, 3, 4])?, Oid::from(&[4, 3, 2, 1])?]) }).await.expect("Test Failed");
+        assert_eq!(client_task.await??, Identity { vendor_name: "This".into(), model_name: "Is".into(), revision: "Another".into(), abstract_syntaxes: Some(vec![Oid::from(&[1, 2, 3, 4])?, Oid::from(&[4, 3, 2, 1])?]) });
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_conclude_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let mut server = server_results?;
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.conclude().await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::Conclude(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        request.respond().await.expect("Test Failed");
+
+        // The release that follows the conclude is answered by the server.
+        match server.receive_message().await {
+            Err(MmsServiceError::Released) => (),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        client_task.await??;
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_get_name_list_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..20001);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let mut client = client_results?;
+        let mut server = server_results?;
+
+        let client_task = tokio::task::spawn(async move { client.get_name_list(MmsObjectClass::Basic(MmsBasicObjectClass::Domain), MmsObjectScope::Vmd, Some("Some Thing".into())).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetNameList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.object_class(), &MmsObjectClass::Basic(MmsBasicObjectClass::Domain));
+        assert_eq!(request.object_scope(), &MmsObjectScope::Vmd);
+        assert_eq!(request.continue_after(), &Some("Some Thing".into()));
+        request.respond(vec!["Domain1".into(), "Domain2".into()], true).await?;
+
+        assert_eq!(client_task.await??, NameList { identifiers: vec!["Domain1".into(), "Domain2".into()], more_follows: true });
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_get_name_list_error_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let mut client = client_results?;
+        let mut server = server_results?;
+
+        let client_task = tokio::task::spawn(async move { client.get_name_list(MmsObjectClass::Basic(MmsBasicObjectClass::Domain), MmsObjectScope::Vmd, None).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetNameList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        request.respond_with_error(MmsServiceErrorInfo { error_class: MmsErrorClass::Access(MmsObjectAccessError::ObjectAccessDenied), additional_code: Some(vec![7]), additional_description: Some("Restricted".into()) }).await?;
+
+        // The refusal completes the pending request instead of leaving the client waiting.
+        match client_task.await? {
+            Err(MmsServiceError::ServiceError(x)) => {
+                assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectAccessDenied));
+                assert_eq!(x.additional_code, Some(vec![7]));
+                assert_eq!(x.additional_description, Some("Restricted".into()));
+            }
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_cancel_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let mut server = server_results?;
+
+        // Dropping the request when it times out cancels it on the server.
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { tokio::time::timeout(Duration::from_millis(100), task_client.get_name_list(MmsObjectClass::Basic(MmsBasicObjectClass::Domain), MmsObjectScope::Vmd, None)).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetNameList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert!(!request.cancellation().is_cancelled());
+        assert!(client_task.await?.is_err());
+
+        let mut task_server = server.clone();
+        let server_task = tokio::task::spawn(async move { task_server.receive_message().await });
+        tokio::time::timeout(Duration::from_millis(1000), request.cancellation().cancelled()).await?;
+        assert!(request.cancellation().is_cancelled());
+        // The client has already been answered so this is not sent.
+        request.respond(vec!["Domain1".into()], false).await?;
+
+        // The association is still usable after the cancel.
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.identify().await });
+        let request = match server_task.await? {
+            Ok(MmsServiceMessage::Identify(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        request.respond(Identity { vendor_name: "Yo".into(), model_name: "There".into(), revision: "Fool".into(), abstract_syntaxes: None }).await?;
+        assert_eq!(client_task.await??, Identity { vendor_name: "Yo".into(), model_name: "There".into(), revision: "Fool".into(), abstract_syntaxes: None });
+
+        // A request dropped once the conclude has started is not cancelled.
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { tokio::time::timeout(Duration::from_millis(100), task_client.get_name_list(MmsObjectClass::Basic(MmsBasicObjectClass::Domain), MmsObjectScope::Vmd, None)).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetNameList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        let mut task_client = client.clone();
+        let conclude_task = tokio::task::spawn(async move { task_client.conclude().await });
+        let conclude = match server.receive_message().await {
+            Ok(MmsServiceMessage::Conclude(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert!(client_task.await?.is_err());
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        conclude.respond().await?;
+        match server.receive_message().await {
+            Err(MmsServiceError::Released) => (),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        conclude_task.await??;
+        assert!(!request.cancellation().is_cancelled());
+
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_get_attribute_list_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let mut server = server_results?;
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.get_variable_access_attributes(MmsObjectName::AaSpecific("Mine".into())).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetVariableAccessAttributes(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.object_name(), &MmsObjectName::AaSpecific("Mine".into()));
+        request.respond(true, MmsServiceTypeDescription::Boolean).await?;
+        assert_eq!(client_task.await??, VariableAccessAttributes { deletable: true, type_description: MmsServiceTypeDescription::Boolean });
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.get_variable_access_attributes(MmsObjectName::AaSpecific("Mine".into())).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetVariableAccessAttributes(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.object_name(), &MmsObjectName::AaSpecific("Mine".into()));
+        request.respond(false, MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 }).await?;
+        assert_eq!(client_task.await??, VariableAccessAttributes { deletable: false, type_description: MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 } });
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.get_variable_access_attributes(MmsObjectName::VmdSpecific("SomeVar".into())).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetVariableAccessAttributes(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.object_name(), &MmsObjectName::VmdSpecific("SomeVar".into()));
+        request
+            .respond(
+                true,
+                MmsServiceTypeDescription::Structure {
+                    packed: false,
+                    components: vec![
+                        MmsServiceTypeDescriptionComponent { component_name: Some("This One".into()), component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Boolean) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Integer(10)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BitString(127)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Unsigned(128)) },
+                        MmsServiceTypeDescriptionComponent {
+                            component_name: None,
+                            component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 }),
+                        },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::OctetString(255)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::VisibleString(256)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::GeneralizedTime) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(true)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(false)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Bcd(255)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::ObjId) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::MmsString(20)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::UtcTime) },
+                        MmsServiceTypeDescriptionComponent {
+                            component_name: Some("Some Array".into()),
+                            component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Array {
+                                packed: true,
+                                number_of_elements: 10000,
+                                element_type: Box::new(crate::data::MmsServiceTypeSpecification::ObjectName(MmsObjectName::VmdSpecific("An Array Type".into()))),
+                            }),
+                        },
+                    ],
+                },
+            )
+            .await?;
+        assert_eq!(
+            client_task.await??,
+            VariableAccessAttributes {
+                deletable: true,
+                type_description: MmsServiceTypeDescription::Structure {
+                    packed: false,
+                    components: vec![
+                        MmsServiceTypeDescriptionComponent { component_name: Some("This One".into()), component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Boolean) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Integer(10)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BitString(127)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Unsigned(128)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 }) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::OctetString(255)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::VisibleString(256)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::GeneralizedTime) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(true)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(false)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Bcd(255)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::ObjId) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::MmsString(20)) },
+                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::UtcTime) },
+                        MmsServiceTypeDescriptionComponent {
+                            component_name: Some("Some Array".into()),
+                            component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Array {
+                                packed: true,
+                                number_of_elements: 10000,
+                                element_type: Box::new(MmsServiceTypeSpecification::ObjectName(MmsObjectName::VmdSpecific("An Array Type".into())))
+                            })
+                        }
+                    ]
+                }
+            }
+        );
 
-#[async_trait]
-impl<R: MmsReader + 'static, W: MmsWriter + 'static> RustyMmsServiceServer for RustyTcpMmsServiceServer<R, W> {
-    fn clone(&self) -> Box<dyn RustyMmsServiceServer> {
-        Box::new(RustyTcpMmsServiceServer { reader: self.reader.clone(), writer: self.writer.clone() })
+        Ok(())
     }
 
-    async fn receive_message(&mut self) -> Result<MmsServiceMessage, MmsServiceError> {
-        let mms_message: MmsMessage = match self.reader.lock().await.recv().await? {
-            rusty_mms::MmsRecvResult::Closed => return Err(MmsServiceError::ProtocolError("Connection closed".into())),
-            rusty_mms::MmsRecvResult::Message(mms_message) => mms_message,
-        };
-        let (invocation_id, request) = match mms_message {
-            MmsMessage::ConfirmedRequest { invocation_id, request } => (invocation_id, request),
-            MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } } => {
-                let access_results = access_results
-                    .into_iter()
-                    .map(|access_result| match access_result {
-                        MmsAccessResult::Success(mms_data) => Ok(MmsServiceAccessResult::Success(convert_low_level_data_to_high_level_data(&mms_data)?)),
-                        MmsAccessResult::Failure(mms_access_error) => Ok(MmsServiceAccessResult::Failure(mms_access_error)),
-                    })
-                    .collect::<Result<Vec<MmsServiceAccessResult>, MmsError>>()?;
-                return Ok(MmsServiceMessage::InformationReport(InformationReportMmsServiceMessage { variable_access_specification, access_results }));
-            }
-            _ => todo!(),
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_define_named_variable_list_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let mut server = server_results?;
+
+        let client_task = tokio::task::spawn(async move {
+            client
+                .clone()
+                .define_named_variable_list(
+                    MmsObjectName::AaSpecific("Hello".into()),
+                    vec![
+                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())), alternate_access: None },
+                        // Not really suitble for this request, but putting it in anyway.
+                        ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated, alternate_access: None },
+                    ],
+                )
+                .await
+        });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DefineNamedVariableList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
         };
+        assert_eq!(request.variable_list_name(), &MmsObjectName::AaSpecific("Hello".into()));
+        assert_eq!(
+            request.list_of_variables(),
+            &vec![
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())), alternate_access: None },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated, alternate_access: None }
+            ]
+        );
+        request.respond().await?;
 
-        let writer = self.writer.clone();
-        let invocation_id: u32 = BigInt::from_signed_bytes_be(invocation_id.as_slice()).try_into().map_err(|_| MmsServiceError::ProtocolError(format!("Invalid Invication Id: {:?}", invocation_id)))?;
+        assert_eq!(client_task.await??, ());
 
-        Ok(match request {
-            MmsConfirmedRequest::GetNameList { object_class, object_scope, continue_after } => MmsServiceMessage::GetNameList(GetNameListMmsServiceMessage::new(
-                invocation_id,
-                object_class,
-                object_scope,
-                continue_after,
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )),
-            MmsConfirmedRequest::Identify => MmsServiceMessage::Identify(IdentifyMmsServiceMessage::new(
-                invocation_id,
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )),
-            MmsConfirmedRequest::Read { specification_with_result, variable_access_specification } => MmsServiceMessage::Read(ReadMmsServiceMessage::new(
-                invocation_id,
-                variable_access_specification,
-                specification_with_result,
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )),
-            MmsConfirmedRequest::Write { variable_access_specification, list_of_data } => MmsServiceMessage::Write(WriteMmsServiceMessage::new(
-                invocation_id,
-                variable_access_specification,
-                list_of_data,
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )?),
-            MmsConfirmedRequest::GetVariableAccessAttributes { object_name } => MmsServiceMessage::GetVariableAccessAttributes(GetVariableAccessAttributesMmsServiceMessage::new(
-                invocation_id,
-                object_name,
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )),
-            MmsConfirmedRequest::DefineNamedVariableList { variable_list_name, list_of_variables } => MmsServiceMessage::DefineNamedVariableList(DefineNamedVariableListMmsServiceMessage::new(
-                invocation_id,
-                variable_list_name,
-                list_of_variables,
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )),
-            MmsConfirmedRequest::GetNamedVariableListAttributes { object_name } => MmsServiceMessage::GetNamedVariableListAttributes(GetNamedVariableListAttributesMmsServiceMessage::new(
-                invocation_id,
-                object_name,
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )),
-            MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete, list_of_variable_list_names, domain_name } => MmsServiceMessage::DeleteNamedVariableList(DeleteNamedVariableListMmsServiceMessage::new(
-                invocation_id,
-                match (scope_of_delete, list_of_variable_list_names, domain_name) {
-                    (Some(MmsScope::Vmd), None, None) => MmsServiceDeleteObjectScope::Vmd,
-                    (Some(MmsScope::AaSpecific), None, None) => MmsServiceDeleteObjectScope::AaSpecific,
-                    (Some(MmsScope::Domain), None, Some(domain_name)) => MmsServiceDeleteObjectScope::Domain(domain_name),
-                    (Some(MmsScope::Specific), Some(items), None) => MmsServiceDeleteObjectScope::Specific(items),
-                    (None, Some(items), None) => MmsServiceDeleteObjectScope::Specific(items),
-                    // Sad cases
-                    (Some(MmsScope::Vmd), Some(_), _) => return Err(MmsServiceError::ProtocolError("VMD scoped deletes cannot specify a variable to delete.".into())),
-                    (Some(MmsScope::Vmd), _, Some(_)) => return Err(MmsServiceError::ProtocolError("VMD scoped deletes cannot specify a domain to delete.".into())),
-                    (Some(MmsScope::AaSpecific), Some(_), _) => return Err(MmsServiceError::ProtocolError("AA scoped deletes cannot specify a variable to delete.".into())),
-                    (Some(MmsScope::AaSpecific), _, Some(_)) => return Err(MmsServiceError::ProtocolError("AA scoped deletes cannot specify a domain to delete.".into())),
-                    (Some(MmsScope::Domain), Some(_), _) => return Err(MmsServiceError::ProtocolError("Domain scoped deletes cannot specify a variable to delete.".into())),
-                    (Some(MmsScope::Domain), _, None) => return Err(MmsServiceError::ProtocolError("Domain scoped deletes must specify a domain to delete.".into())),
-                    (Some(MmsScope::Specific), None, _) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes must specify a list of variables.".into())),
-                    (Some(MmsScope::Specific), Some(_), Some(_)) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes cannot specify a domain to delete.".into())),
-                    (None, None, _) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes must specify a list of variables.".into())),
-                    (None, Some(_), Some(_)) => return Err(MmsServiceError::ProtocolError("Specific scoped deletes cannot specify a domain to delete.".into())),
-                },
-                Box::new(move |msg: MmsMessage| {
-                    let callback_writer = writer.clone();
-                    Box::pin(async move { callback_writer.lock().await.send(&mut VecDeque::from(vec![msg])).await })
-                }),
-            )),
-        })
+        Ok(())
     }
 
-    async fn send_information_report(&mut self, variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError> {
-        let access_results = access_results
-            .into_iter()
-            .map(|x| match x {
-                MmsServiceAccessResult::Success(mms_data) => Ok(MmsAccessResult::Success(convert_high_level_data_to_low_level_data(&mms_data)?)),
-                MmsServiceAccessResult::Failure(mms_access_error) => Ok(MmsAccessResult::Failure(mms_access_error)),
-            })
-            .collect::<Result<Vec<MmsAccessResult>, MmsServiceError>>()?;
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_get_named_variable_list_attributes_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let mut server = server_results?;
+
+        let client_task = tokio::task::spawn(async move { client.clone().get_named_variable_list_attributes(MmsObjectName::VmdSpecific("AList".into())).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetNamedVariableListAttributes(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.variable_list_name(), &MmsObjectName::VmdSpecific("AList".into()));
+        request
+            .respond(
+                true,
+                vec![
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated, alternate_access: None },
+                ],
+            )
+            .await?;
+
+        assert_eq!(
+            client_task.await??,
+            NamedVariableListAttributes {
+                deletable: true,
+                list_of_variables: vec![
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated, alternate_access: None },
+                ]
+            }
+        );
 
-        self.writer.lock().await.send(&mut VecDeque::from(vec![MmsMessage::Unconfirmed { unconfirmed_service: rusty_mms::MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } }])).await?;
         Ok(())
     }
-}
 
-pub async fn create_mms_service_server(address: SocketAddr, parameters: MmsServiceConnectionParameters) -> Result<Box<dyn RustyMmsServiceServer>, MmsServiceError> {
-    let tpkt_connection = TcpTpktServer::listen(address).await?.accept().await?;
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_delete_named_variable_list_operation() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
 
-    let (cotp_listener, cotp_connection_info) = RustyCotpResponder::<TcpTpktReader, TcpTpktWriter>::new(tpkt_connection, Default::default()).await.map_err(to_mms_error("Failed to create COTP Server"))?;
-    let cotp_connection = cotp_listener.accept(cotp_connection_info).await.map_err(to_mms_error(""))?;
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
 
-    let (cosp_listener, _) = RustyCospAcceptorIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cotp_connection, CospConnectionParameters::default()).await.map_err(to_mms_error("Failed to create COSP Connection"))?;
+        let client = client_results?;
+        let mut server = server_results?;
 
-    // TODO: Need to expose this.
-    let _copp_connection_info = CoppConnectionInformation { called_presentation_selector: parameters.called.presentation_selector, calling_presentation_selector: parameters.calling.presentation_selector };
-    let (copp_responder, _) = RustyCoppListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(cosp_listener).await.map_err(to_mms_error(""))?;
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::Vmd).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::Vmd);
+        request.respond(2, 1).await?;
+        assert_eq!(client_task.await??, (2, 1));
 
-    let (mut acse_listener, acse_request_info) = RustyOsiSingleValueAcseListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(copp_responder).await.map_err(to_mms_error(""))?;
-    acse_listener.set_response(Some(AcseResponseInformation {
-        application_context_name: Oid::from(&[1, 0, 9506, 2, 3]).map_err(to_mms_error(""))?,
-        associate_result: AssociateResult::Accepted,
-        associate_source_diagnostic: AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::Null),
-        responding_ap_title: acse_request_info.called_ap_title,
-        responding_ae_qualifier: acse_request_info.called_ae_qualifier,
-        responding_ap_invocation_identifier: acse_request_info.called_ap_invocation_identifier,
-        responding_ae_invocation_identifier: acse_request_info.called_ae_invocation_identifier,
-        implementation_information: None,
-    }));
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::Domain("TheEntireDomain".into())).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::Domain("TheEntireDomain".into()));
+        request.respond(10, 3).await?;
+        assert_eq!(client_task.await??, (10, 3));
 
-    let mms_listener = RustyMmsListenerIsoStack::<TcpTpktReader, TcpTpktWriter>::new(acse_listener).await.map_err(to_mms_error(""))?;
-    let mms_responder = mms_listener.responder().await.map_err(to_mms_error(""))?;
-    let mms_connection = mms_responder.accept().await.map_err(to_mms_error(""))?;
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::AaSpecific).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::AaSpecific);
+        request.respond(10, 3).await?;
+        assert_eq!(client_task.await??, (10, 3));
 
-    let (mms_reader, mms_writer) = mms_connection.split().await.map_err(to_mms_error(""))?;
+        let mut task_client = client.clone();
+        let client_task =
+            tokio::task::spawn(
+                async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::Specific(vec![MmsObjectName::AaSpecific("AaOne".into()), MmsObjectName::VmdSpecific("ThisDomain".into())])).await },
+            );
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::Specific(vec![MmsObjectName::AaSpecific("AaOne".into()), MmsObjectName::VmdSpecific("ThisDomain".into())]));
+        request.respond(10, 3).await?;
+        assert_eq!(client_task.await??, (10, 3));
 
-    Ok(Box::new(RustyTcpMmsServiceServer { reader: Arc::new(Mutex::new(mms_reader)), writer: Arc::new(Mutex::new(mms_writer)) }))
-}
+        Ok(())
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    #[traced_test]
+    async fn test_named_type_operations() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+
+        let (client_results, server_results) = join!(
+            async {
+                // Allow the server to start listening first.
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
+            },
+            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+        );
+
+        let client = client_results?;
+        let mut server = server_results?;
+
+        let data_real_q = || {
+            MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Structure {
+                packed: false,
+                components: vec![
+                    MmsServiceTypeDescriptionComponent { component_name: Some("Value".into()), component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 }) },
+                    MmsServiceTypeDescriptionComponent { component_name: Some("Flags".into()), component_type: MmsServiceTypeSpecification::ObjectName(MmsObjectName::VmdSpecific("Data_Flags".into())) },
+                ],
+            })
+        };
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.define_named_type(MmsObjectName::VmdSpecific("Data_RealQ".into()), data_real_q()).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DefineNamedType(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.type_name(), &MmsObjectName::VmdSpecific("Data_RealQ".into()));
+        assert_eq!(request.type_specification(), &data_real_q());
+        request.respond().await?;
+        client_task.await??;
 
-#[cfg(test)]
-mod tests {
-    use crate::data::{
-        MmsServiceAccessResult, MmsServiceData, MmsServiceDataFloat, MmsServiceDeleteObjectScope, MmsServiceTypeDescription, MmsServiceTypeDescriptionComponent, MmsServiceTypeSpecification, NameList, NamedVariableListAttributes,
-        VariableAccessAttributes,
-    };
-    use crate::error::to_mms_error;
-    use crate::{create_mms_service_client, create_mms_service_server};
-    use std::time::Duration;
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.get_named_type_attributes(MmsObjectName::VmdSpecific("Data_RealQ".into())).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetNamedTypeAttributes(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.type_name(), &MmsObjectName::VmdSpecific("Data_RealQ".into()));
+        request.respond(true, data_real_q()).await?;
+        assert_eq!(client_task.await??, NamedTypeAttributes { deletable: true, type_specification: data_real_q() });
 
-    use anyhow::anyhow;
-    use der_parser::Oid;
-    use num_bigint::{BigInt, BigUint};
-    use rand::random_range;
-    use rusty_mms::{ListOfVariablesItem, MmsAccessError, MmsBasicObjectClass, MmsObjectClass, MmsObjectName, MmsObjectScope, MmsVariableAccessSpecification, MmsWriteResult, VariableSpecification};
-    use tokio::join;
-    use tracing_test::traced_test;
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.delete_named_type(MmsServiceDeleteObjectScope::Specific(vec![MmsObjectName::VmdSpecific("Data_RealQ".into())])).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DeleteNamedType(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::Specific(vec![MmsObjectName::VmdSpecific("Data_RealQ".into())]));
+        request.respond(1, 1).await?;
+        assert_eq!(client_task.await??, (1, 1));
 
-    use crate::{Identity, MmsServiceConnectionParameters, error::MmsServiceError, message::MmsServiceMessage};
+        Ok(())
+    }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     #[traced_test]
-    async fn test_a_large_number_of_operations() -> Result<(), anyhow::Error> {
+    async fn test_domain_operations() -> Result<(), anyhow::Error> {
         let port: u16 = random_range(20000..30000);
-        let address = format!("127.0.0.1:{port}").parse().map_err(to_mms_error("Test Failed"))?;
+        let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
 
         let (client_results, server_results) = join!(
             async {
@@ -706,138 +2600,115 @@ mod tests {
         );
 
         let client = client_results?;
-        let server = server_results?;
+        let mut server = server_results?;
 
-        let mut client1 = client.clone();
-        let client_task1 = tokio::task::spawn(async move {
-            for _ in 1..10000 {
-                client1.identify().await?;
-            }
-            Ok::<(), MmsServiceError>(())
-        });
-        let mut client2 = client.clone();
-        let client_task2 = tokio::task::spawn(async move {
-            for _ in 1..10000 {
-                client2.identify().await?;
-            }
-            Ok::<(), MmsServiceError>(())
-        });
-        let mut client3 = client.clone();
-        let client_task3 = tokio::task::spawn(async move {
-            for _ in 1..10000 {
-                client3.identify().await?;
-            }
-            Ok::<(), MmsServiceError>(())
-        });
-        let mut client4 = client.clone();
-        let client_task4 = tokio::task::spawn(async move {
-            for _ in 1..10000 {
-                client4.identify().await?;
-            }
-            Ok::<(), MmsServiceError>(())
-        });
-        let mut client5 = client.clone();
-        let client_task5 = tokio::task::spawn(async move {
-            for _ in 1..10000 {
-                client5.identify().await?;
-            }
-            Ok::<(), MmsServiceError>(())
-        });
+        let domain_attributes =
+            || DomainAttributes { list_of_capabilities: vec!["Capability".into()], state: MmsDomainState::Ready, deletable: false, sharable: true, list_of_program_invocations: vec!["Program".into()], upload_in_progress: 0 };
 
-        let mut server1 = server.clone();
-        let server_task1 = tokio::task::spawn(async move {
-            for x in 1..10000 {
-                let value = match server1.receive_message().await {
-                    Ok(x) => x,
-                    Err(_) => {
-                        break;
-                    }
-                };
-                if let MmsServiceMessage::Identify(message) = value {
-                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool1".into(), abstract_syntaxes: None }).await.expect("")
-                }
-            }
-        });
-        let mut server2 = server.clone();
-        let server_task2 = tokio::task::spawn(async move {
-            for x in 1..10000 {
-                let value = match server2.receive_message().await {
-                    Ok(x) => x,
-                    Err(_) => {
-                        break;
-                    }
-                };
-                if let MmsServiceMessage::Identify(message) = value {
-                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool2".into(), abstract_syntaxes: None }).await.expect("")
-                }
-            }
-        });
-        let mut server3 = server.clone();
-        let server_task3 = tokio::task::spawn(async move {
-            for x in 1..10000 {
-                let value = match server3.receive_message().await {
-                    Ok(x) => x,
-                    Err(_) => {
-                        break;
-                    }
-                };
-                if let MmsServiceMessage::Identify(message) = value {
-                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool3".into(), abstract_syntaxes: None }).await.expect("")
-                }
-            }
-        });
-        let mut server4 = server.clone();
-        let server_task4 = tokio::task::spawn(async move {
-            for x in 1..10000 {
-                let value = match server4.receive_message().await {
-                    Ok(x) => x,
-                    Err(_) => {
-                        break;
-                    }
-                };
-                if let MmsServiceMessage::Identify(message) = value {
-                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool4".into(), abstract_syntaxes: None }).await.expect("")
-                }
-            }
-        });
-        let mut server5 = server.clone();
-        let server_task5 = tokio::task::spawn(async move {
-            for x in 1..10000 {
-                let value = match server5.receive_message().await {
-                    Ok(x) => x,
-                    Err(_) => {
-                        break;
-                    }
-                };
-                if let MmsServiceMessage::Identify(message) = value {
-                    message.respond(Identity { vendor_name: "Yo".into(), model_name: format!("{x}"), revision: "Fool5".into(), abstract_syntaxes: None }).await.expect("")
-                }
-            }
-        });
-        drop(server);
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.get_domain_attributes("ICC1".into()).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::GetDomainAttributes(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.domain_name(), "ICC1");
+        request.respond(domain_attributes()).await?;
+        assert_eq!(client_task.await??, domain_attributes());
 
-        let (client_task_result1, client_task_result2, client_task_result3, client_task_result4, client_task_result5, server_task_result1, server_task_result2, server_task_result3, server_task_result4, server_task_result5) =
-            join!(client_task1, client_task2, client_task3, client_task4, client_task5, server_task1, server_task2, server_task3, server_task4, server_task5);
-        client_task_result1??;
-        client_task_result2??;
-        client_task_result3??;
-        client_task_result4??;
-        client_task_result5??;
-        server_task_result1?;
-        server_task_result2?;
-        server_task_result3?;
-        server_task_result4?;
-        server_task_result5?;
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.initiate_download_sequence("ICC2".into(), vec!["Capability".into()], false).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::InitiateDownloadSequence(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.domain_name(), "ICC2");
+        assert_eq!(request.list_of_capabilities(), &vec!["Capability".to_string()]);
+        assert!(!request.sharable());
+        request.respond().await?;
+        client_task.await??;
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.download_segment("ICC2".into()).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DownloadSegment(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.domain_name(), "ICC2");
+        request.respond(DomainSegment { load_data: vec![1, 2, 3], more_follows: false }).await?;
+        assert_eq!(client_task.await??, DomainSegment { load_data: vec![1, 2, 3], more_follows: false });
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.terminate_download_sequence("ICC2".into(), Some(MmsServiceErrorInfo::new(MmsErrorClass::Resource(MmsResourceError::MemoryUnavailable)))).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::TerminateDownloadSequence(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.domain_name(), "ICC2");
+        assert_eq!(request.discard(), &Some(MmsServiceErrorInfo::new(MmsErrorClass::Resource(MmsResourceError::MemoryUnavailable))));
+        request.respond().await?;
+        client_task.await??;
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.initiate_upload_sequence("ICC1".into()).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::InitiateUploadSequence(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.domain_name(), "ICC1");
+        request.respond(UploadSequence { ulsm_id: 300, list_of_capabilities: vec![] }).await?;
+        assert_eq!(client_task.await??, UploadSequence { ulsm_id: 300, list_of_capabilities: vec![] });
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.upload_segment(300).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::UploadSegment(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.ulsm_id(), 300);
+        request.respond(DomainSegment { load_data: vec![4, 5, 6], more_follows: true }).await?;
+        assert_eq!(client_task.await??, DomainSegment { load_data: vec![4, 5, 6], more_follows: true });
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.terminate_upload_sequence(300).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::TerminateUploadSequence(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.ulsm_id(), 300);
+        request.respond().await?;
+        client_task.await??;
+
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.delete_domain("ICC2".into()).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::DeleteDomain(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        assert_eq!(request.domain_name(), "ICC2");
+        request.respond().await?;
+        client_task.await??;
 
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     #[traced_test]
-    async fn test_identify_operation() -> Result<(), anyhow::Error> {
+    async fn test_file_operations() -> Result<(), anyhow::Error> {
         let port: u16 = random_range(20000..30000);
         let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
 
+        let root = std::env::temp_dir().join(format!("rusty-mms-file-test-{}", port));
+        tokio::fs::create_dir_all(root.join("COMTRADE")).await?;
+        let record: Vec<u8> = (0..100).collect();
+        tokio::fs::write(root.join("COMTRADE").join("record.dat"), &record).await?;
+        tokio::fs::write(root.join("COMTRADE").join("record.cfg"), b"config").await?;
+        tokio::fs::write(root.join("operator.log"), b"log").await?;
+        let outside = std::env::temp_dir().join(format!("rusty-mms-file-test-{}-outside", port));
+        tokio::fs::create_dir_all(&outside).await?;
+        tokio::fs::write(outside.join("secret.txt"), b"secret").await?;
+        tokio::fs::symlink(&outside, root.join("escape")).await?;
+        tokio::fs::symlink(outside.join("secret.txt"), root.join("secret.txt")).await?;
+
         let (client_results, server_results) = join!(
             async {
                 // Allow the server to start listening first.
@@ -847,34 +2718,80 @@ mod tests {
             async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
         );
 
-        let client = client_results?;
+        let mut client = client_results?;
         let mut server = server_results?;
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.identify().await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::Identify(x)) => x,
+        let mut file_service = LocalDirectoryFileService::new(root.clone()).with_read_size(30).with_directory_size(1);
+        let server_task = tokio::task::spawn(async move {
+            loop {
+                if let Some(message) = file_service.handle(server.receive_message().await?).await? {
+                    return Err::<(), anyhow::Error>(anyhow!("Unexpected message: {:?}", message));
+                }
+            }
+        });
+
+        let mut downloaded = vec![];
+        assert_eq!(download_file(client.as_mut(), vec!["COMTRADE/record.dat".into()], &mut downloaded).await?, 100);
+        assert_eq!(downloaded, record);
+
+        let (frsm_id, file_attributes) = client.file_open(vec!["COMTRADE".into(), "record.cfg".into()], 2).await?;
+        assert_eq!(file_attributes.size_of_file, 6);
+        assert!(file_attributes.last_modified.is_some());
+        assert_eq!(client.file_read(frsm_id).await?, FileSegment { file_data: b"nfig".to_vec(), more_follows: false });
+        client.file_close(frsm_id).await?;
+        match client.file_read(frsm_id).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent)),
             x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        request.respond(Identity { vendor_name: "Yo".into(), model_name: "There".into(), revision: "Fool".into(), abstract_syntaxes: None }).await.expect("Test Failed");
-        assert_eq!(client_task.await??, Identity { vendor_name: "Yo".into(), model_name: "There".into(), revision: "Fool".into(), abstract_syntaxes: None });
+        }
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.identify().await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::Identify(x)) => x,
+        let directory = client.file_directory(Some(vec!["COMTRADE".into()]), None).await?;
+        assert_eq!(directory.list_of_directory_entry.iter().map(|x| x.file_name.clone()).collect::<Vec<_>>(), vec![vec!["COMTRADE/record.cfg".to_string()]]);
+        assert!(directory.more_follows);
+        let directory = client.file_directory(Some(vec!["COMTRADE".into()]), Some(directory.list_of_directory_entry[0].file_name.clone())).await?;
+        assert_eq!(directory.list_of_directory_entry.iter().map(|x| (x.file_name.clone(), x.file_attributes.size_of_file)).collect::<Vec<_>>(), vec![(vec!["COMTRADE/record.dat".to_string()], 100)]);
+        assert!(!directory.more_follows);
+
+        client.file_rename(vec!["operator.log".into()], vec!["operator.old".into()]).await?;
+        match client.file_rename(vec!["COMTRADE/record.cfg".into()], vec!["COMTRADE/record.dat".into()]).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::File(MmsFileError::DuplicateFilename)),
             x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        request.respond(Identity { vendor_name: "This".into(), model_name: "Is".into(), revision: "Another".into(), abstract_syntaxes: Some(vec![Oid::from(&[1, 2, 3, 4])?, Oid::from(&[4, 3, 2, 1])?]) }).await.expect("Test Failed");
-        assert_eq!(client_task.await??, Identity { vendor_name: "This".into(), model_name: "Is".into(), revision: "Another".into(), abstract_syntaxes: Some(vec![Oid::from(&[1, 2, 3, 4])?, Oid::from(&[4, 3, 2, 1])?]) });
+        }
+        client.file_delete(vec!["operator.old".into()]).await?;
+        match client.file_open(vec!["operator.old".into()], 0).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::File(MmsFileError::FileNonExistent)),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+        match client.file_delete(vec!["..".into(), "outside.log".into()]).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::File(MmsFileError::FilenameSyntaxError)),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+
+        // Symbolic links cannot be used to reach outside of the root.
+        for result in [
+            client.file_open(vec!["secret.txt".into()], 0).await.map(|_| ()),
+            client.file_open(vec!["escape/secret.txt".into()], 0).await.map(|_| ()),
+            client.file_delete(vec!["escape/secret.txt".into()]).await,
+            client.file_rename(vec!["COMTRADE/record.cfg".into()], vec!["escape/record.cfg".into()]).await,
+        ] {
+            match result {
+                Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::File(MmsFileError::FileAccessDenied)),
+                x => return Err(anyhow!("Test Failed: {:?}", x)),
+            }
+        }
+        assert!(tokio::fs::try_exists(outside.join("secret.txt")).await?);
+        assert!(!tokio::fs::try_exists(outside.join("record.cfg")).await?);
+
+        server_task.abort();
+        tokio::fs::remove_dir_all(root).await?;
+        tokio::fs::remove_dir_all(outside).await?;
 
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     #[traced_test]
-    async fn test_get_name_list_operation() -> Result<(), anyhow::Error> {
-        let port: u16 = random_range(20000..20001);
+    async fn test_journal_operations() -> Result<(), anyhow::Error> {
+        let port: u16 = random_range(20000..30000);
         let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
 
         let (client_results, server_results) = join!(
@@ -889,24 +2806,100 @@ mod tests {
         let mut client = client_results?;
         let mut server = server_results?;
 
-        let client_task = tokio::task::spawn(async move { client.get_name_list(MmsObjectClass::Basic(MmsBasicObjectClass::Domain), MmsObjectScope::Vmd, Some("Some Thing".into())).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::GetNameList(x)) => x,
+        let journal_name = MmsObjectName::DomainSpecific("ICCP".into(), "Block4Log".into());
+        let server_journal_name = journal_name.clone();
+        let server_task = tokio::task::spawn(async move {
+            let non_existent = || MmsServiceErrorInfo::new(MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent));
+            let mut journal: Option<Vec<JournalEntry>> = None;
+            loop {
+                match server.receive_message().await? {
+                    MmsServiceMessage::CreateJournal(request) if request.journal_name() == &server_journal_name => {
+                        journal = Some(vec![]);
+                        request.respond().await?;
+                    }
+                    MmsServiceMessage::WriteJournal(request) => match journal.as_mut() {
+                        Some(entries) if request.journal_name() == &server_journal_name => {
+                            for entry_content in request.list_of_journal_entry() {
+                                let entry_identifier = (entries.len() as u32).to_be_bytes().to_vec();
+                                entries.push(JournalEntry { entry_identifier, originating_application: MmsApplicationReference::default(), entry_content: entry_content.clone() });
+                            }
+                            request.respond().await?;
+                        }
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::ReadJournal(request) => match journal.as_ref() {
+                        Some(entries) if request.journal_name() == &server_journal_name => {
+                            let limit = match request.specification().range_stop_specification {
+                                Some(JournalRangeStop::NumberOfEntries(x)) => x as usize,
+                                _ => usize::MAX,
+                            };
+                            request.respond(JournalEntries { list_of_journal_entry: entries.iter().take(limit).cloned().collect(), more_follows: entries.len() > limit }).await?;
+                        }
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::ReportJournalStatus(request) => match journal.as_ref() {
+                        Some(entries) => request.respond(JournalStatus { current_entries: entries.len() as u32, deletable: true }).await?,
+                        None => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::InitializeJournal(request) => match journal.as_mut() {
+                        Some(entries) => {
+                            let entries_deleted = entries.len() as u32;
+                            entries.clear();
+                            request.respond(entries_deleted).await?;
+                        }
+                        None => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::DeleteJournal(request) => {
+                        journal = None;
+                        request.respond().await?;
+                    }
+                    x => return Err::<(), anyhow::Error>(anyhow!("Unexpected message: {:?}", x)),
+                }
+            }
+        });
+
+        let entry_contents = vec![
+            JournalEntryContent {
+                occurrence_time: MmsBinaryTime::with_date(43_200_000, 14_610),
+                entry_form: JournalEntryForm::Data {
+                    event: Some(MmsJournalEvent { event_condition_name: MmsObjectName::VmdSpecific("Breaker".into()), current_state: MmsEventConditionState::Active }),
+                    list_of_variables: vec![JournalVariable { variable_tag: "State".into(), value_specification: MmsServiceData::Integer(BigInt::from(2)) }],
+                },
+            },
+            JournalEntryContent { occurrence_time: MmsBinaryTime::with_date(43_201_000, 14_610), entry_form: JournalEntryForm::Annotation("Breaker tripped".into()) },
+        ];
+
+        client.create_journal(journal_name.clone()).await?;
+        client.write_journal(journal_name.clone(), entry_contents.clone()).await?;
+        assert_eq!(client.report_journal_status(journal_name.clone()).await?, JournalStatus { current_entries: 2, deletable: true });
+
+        let entries = client.read_journal(journal_name.clone(), JournalReadSpecification::default()).await?;
+        assert_eq!(entries.list_of_journal_entry.into_iter().map(|x| (x.entry_identifier, x.entry_content)).collect::<Vec<_>>(), vec![(vec![0, 0, 0, 0], entry_contents[0].clone()), (vec![0, 0, 0, 1], entry_contents[1].clone())]);
+        assert!(!entries.more_follows);
+        let entries = client.read_journal(journal_name.clone(), JournalReadSpecification { range_stop_specification: Some(JournalRangeStop::NumberOfEntries(1)), ..Default::default() }).await?;
+        assert_eq!(entries.list_of_journal_entry.len(), 1);
+        assert!(entries.more_follows);
+        match client.read_journal(MmsObjectName::VmdSpecific("Missing".into()), JournalReadSpecification::default()).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent)),
             x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.object_class(), &MmsObjectClass::Basic(MmsBasicObjectClass::Domain));
-        assert_eq!(request.object_scope(), &MmsObjectScope::Vmd);
-        assert_eq!(request.continue_after(), &Some("Some Thing".into()));
-        request.respond(vec!["Domain1".into(), "Domain2".into()], true).await?;
+        }
 
-        assert_eq!(client_task.await??, NameList { identifiers: vec!["Domain1".into(), "Domain2".into()], more_follows: true });
+        assert_eq!(client.initialize_journal(journal_name.clone(), None).await?, 2);
+        assert_eq!(client.report_journal_status(journal_name.clone()).await?.current_entries, 0);
+        client.delete_journal(journal_name.clone()).await?;
+        match client.report_journal_status(journal_name).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent)),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+
+        server_task.abort();
 
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     #[traced_test]
-    async fn test_get_attribute_list_operation() -> Result<(), anyhow::Error> {
+    async fn test_event_operations() -> Result<(), anyhow::Error> {
         let port: u16 = random_range(20000..30000);
         let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
 
@@ -919,109 +2912,127 @@ mod tests {
             async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
         );
 
-        let client = client_results?;
+        let mut client = client_results?;
         let mut server = server_results?;
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.get_variable_access_attributes(MmsObjectName::AaSpecific("Mine".into())).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::GetVariableAccessAttributes(x)) => x,
-            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        let event_condition_name = MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_Trip".into());
+        let event_enrollment_name = MmsObjectName::AaSpecific("Breaker_Trip_Enrollment".into());
+        let event_notification = EventNotification {
+            event_enrollment_name: event_enrollment_name.clone(),
+            event_condition_name: event_condition_name.clone(),
+            severity: 10,
+            current_state: Some(MmsEventConditionState::Active),
+            transition_time: EventTime::TimeOfDay(MmsBinaryTime::with_date(43_200_000, 14_610)),
+            notification_lost: false,
+            alarm_acknowledgment_rule: Some(MmsAlarmAcknowledgmentRule::Simple),
         };
-        assert_eq!(request.object_name(), &MmsObjectName::AaSpecific("Mine".into()));
-        request.respond(true, MmsServiceTypeDescription::Boolean).await?;
-        assert_eq!(client_task.await??, VariableAccessAttributes { deletable: true, type_description: MmsServiceTypeDescription::Boolean });
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.get_variable_access_attributes(MmsObjectName::AaSpecific("Mine".into())).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::GetVariableAccessAttributes(x)) => x,
-            x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.object_name(), &MmsObjectName::AaSpecific("Mine".into()));
-        request.respond(false, MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 }).await?;
-        assert_eq!(client_task.await??, VariableAccessAttributes { deletable: false, type_description: MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 } });
+        let server_event_notification = event_notification.clone();
+        let server_task = tokio::task::spawn(async move {
+            let non_existent = || MmsServiceErrorInfo::new(MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent));
+            let mut event_condition: Option<(MmsObjectName, EventConditionAttributes)> = None;
+            loop {
+                match server.receive_message().await? {
+                    MmsServiceMessage::DefineEventCondition(request) => {
+                        let definition = request.definition().clone();
+                        let attributes = EventConditionAttributes {
+                            deletable: true,
+                            class: definition.class,
+                            priority: definition.priority.unwrap_or(64),
+                            severity: definition.severity.unwrap_or(64),
+                            alarm_summary_reports: definition.alarm_summary_reports.unwrap_or(false),
+                            monitored_variable: definition.monitored_variable.map(MmsMonitoredVariable::VariableReference),
+                            evaluation_interval: definition.evaluation_interval,
+                        };
+                        event_condition = Some((request.event_condition_name().clone(), attributes));
+                        request.respond().await?;
+                    }
+                    MmsServiceMessage::AlterEventConditionMonitoring(request) => match event_condition.as_mut() {
+                        Some((name, attributes)) if name == request.event_condition_name() => {
+                            let monitoring = request.monitoring().clone();
+                            attributes.priority = monitoring.priority.unwrap_or(attributes.priority);
+                            attributes.alarm_summary_reports = monitoring.alarm_summary_reports.unwrap_or(attributes.alarm_summary_reports);
+                            attributes.evaluation_interval = monitoring.evaluation_interval.or(attributes.evaluation_interval);
+                            request.respond().await?;
+                        }
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::GetEventConditionAttributes(request) => match event_condition.as_ref() {
+                        Some((name, attributes)) if name == request.event_condition_name() => request.respond(attributes.clone()).await?,
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::DefineEventEnrollment(request) => {
+                        if !event_condition.as_ref().is_some_and(|(name, _)| name == &request.definition().event_condition_name) {
+                            request.respond_with_error(non_existent()).await?;
+                            continue;
+                        }
+                        // The notification is sent before the response to check the client queues it while waiting.
+                        server.send_event_notification(server_event_notification.clone()).await?;
+                        request.respond().await?;
+                    }
+                    MmsServiceMessage::AcknowledgeEventNotification(request) => {
+                        assert_eq!(request.acknowledged_state(), &MmsEventConditionState::Active);
+                        assert_eq!(request.time_of_acknowledged_transition(), &server_event_notification.transition_time);
+                        request.respond().await?;
+                    }
+                    x => return Err::<(), anyhow::Error>(anyhow!("Unexpected message: {:?}", x)),
+                }
+            }
+        });
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.get_variable_access_attributes(MmsObjectName::VmdSpecific("SomeVar".into())).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::GetVariableAccessAttributes(x)) => x,
-            x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.object_name(), &MmsObjectName::VmdSpecific("SomeVar".into()));
-        request
-            .respond(
-                true,
-                MmsServiceTypeDescription::Structure {
-                    packed: false,
-                    components: vec![
-                        MmsServiceTypeDescriptionComponent { component_name: Some("This One".into()), component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Boolean) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Integer(10)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BitString(127)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Unsigned(128)) },
-                        MmsServiceTypeDescriptionComponent {
-                            component_name: None,
-                            component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 }),
-                        },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::OctetString(255)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::VisibleString(256)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::GeneralizedTime) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(true)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(false)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Bcd(255)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::ObjId) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::MmsString(20)) },
-                        MmsServiceTypeDescriptionComponent {
-                            component_name: Some("Some Array".into()),
-                            component_type: crate::data::MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Array {
-                                packed: true,
-                                number_of_elements: 10000,
-                                element_type: Box::new(crate::data::MmsServiceTypeSpecification::ObjectName(MmsObjectName::VmdSpecific("An Array Type".into()))),
-                            }),
-                        },
-                    ],
-                },
+        let monitored_variable = VariableSpecification::Name(MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_State".into()));
+        client
+            .define_event_condition(
+                event_condition_name.clone(),
+                EventConditionDefinition { class: MmsEventConditionClass::Monitored, priority: None, severity: Some(10), alarm_summary_reports: None, monitored_variable: Some(monitored_variable.clone()), evaluation_interval: Some(1000) },
             )
             .await?;
         assert_eq!(
-            client_task.await??,
-            VariableAccessAttributes {
+            client.get_event_condition_attributes(event_condition_name.clone()).await?,
+            EventConditionAttributes {
                 deletable: true,
-                type_description: MmsServiceTypeDescription::Structure {
-                    packed: false,
-                    components: vec![
-                        MmsServiceTypeDescriptionComponent { component_name: Some("This One".into()), component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Boolean) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Integer(10)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BitString(127)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Unsigned(128)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::FloatingPoint { format_width: 32, exponent_width: 8 }) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::OctetString(255)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::VisibleString(256)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::GeneralizedTime) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(true)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::BinaryTime(false)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Bcd(255)) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::ObjId) },
-                        MmsServiceTypeDescriptionComponent { component_name: None, component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::MmsString(20)) },
-                        MmsServiceTypeDescriptionComponent {
-                            component_name: Some("Some Array".into()),
-                            component_type: MmsServiceTypeSpecification::TypeDescription(MmsServiceTypeDescription::Array {
-                                packed: true,
-                                number_of_elements: 10000,
-                                element_type: Box::new(MmsServiceTypeSpecification::ObjectName(MmsObjectName::VmdSpecific("An Array Type".into())))
-                            })
-                        }
-                    ]
-                }
+                class: MmsEventConditionClass::Monitored,
+                priority: 64,
+                severity: 10,
+                alarm_summary_reports: false,
+                monitored_variable: Some(MmsMonitoredVariable::VariableReference(monitored_variable)),
+                evaluation_interval: Some(1000),
             }
         );
 
+        client.alter_event_condition_monitoring(event_condition_name.clone(), EventConditionMonitoring { priority: Some(0), alarm_summary_reports: Some(true), ..Default::default() }).await?;
+        let attributes = client.get_event_condition_attributes(event_condition_name.clone()).await?;
+        assert_eq!((attributes.priority, attributes.alarm_summary_reports), (0, true));
+        match client.get_event_condition_attributes(MmsObjectName::VmdSpecific("Missing".into())).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent)),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+
+        client
+            .define_event_enrollment(
+                event_enrollment_name.clone(),
+                EventEnrollmentDefinition {
+                    event_condition_name: event_condition_name.clone(),
+                    event_condition_transitions: vec![MmsEventTransition::IdleToActive],
+                    alarm_acknowledgment_rule: MmsAlarmAcknowledgmentRule::Simple,
+                    event_action_name: None,
+                    client_application: None,
+                },
+            )
+            .await?;
+        let received = client.receive_event_notification().await?;
+        assert_eq!(received, event_notification);
+
+        client.acknowledge_event_notification(event_enrollment_name, MmsEventConditionState::Active, received.transition_time).await?;
+
+        server_task.abort();
+
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     #[traced_test]
-    async fn test_define_named_variable_list_operation() -> Result<(), anyhow::Error> {
+    async fn test_semaphore_operations() -> Result<(), anyhow::Error> {
         let port: u16 = random_range(20000..30000);
         let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
 
@@ -1034,46 +3045,101 @@ mod tests {
             async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
         );
 
-        let client = client_results?;
+        let mut client = client_results?;
         let mut server = server_results?;
 
-        let client_task = tokio::task::spawn(async move {
-            client
-                .clone()
-                .define_named_variable_list(
-                    MmsObjectName::AaSpecific("Hello".into()),
-                    vec![
-                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())) },
-                        // Not really suitble for this request, but putting it in anyway.
-                        ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated },
-                    ],
-                )
-                .await
+        let semaphore_name = MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_Control".into());
+        let (relinquished_sender, mut relinquished_receiver) = mpsc::unbounded_channel();
+        let server_task = tokio::task::spawn(async move {
+            let non_existent = || MmsServiceErrorInfo::new(MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent));
+            let mut semaphore: Option<(MmsObjectName, SemaphoreStatus)> = None;
+            loop {
+                match server.receive_message().await? {
+                    MmsServiceMessage::DefineSemaphore(request) => {
+                        let status = SemaphoreStatus { deletable: true, class: MmsSemaphoreClass::Token, number_of_tokens: request.number_of_tokens(), number_of_owned_tokens: 0, number_of_hung_tokens: 0 };
+                        semaphore = Some((request.semaphore_name().clone(), status));
+                        request.respond().await?;
+                    }
+                    MmsServiceMessage::TakeControl(request) => match semaphore.as_mut() {
+                        Some((name, status)) if name == request.semaphore_name() => {
+                            if status.number_of_owned_tokens == status.number_of_tokens {
+                                request.respond_with_error(MmsServiceErrorInfo::new(MmsErrorClass::Resource(MmsResourceError::CapabilityUnavailable))).await?;
+                                continue;
+                            }
+                            assert_eq!(request.options(), &TakeControlOptions { priority: Some(10), control_timeout: Some(5000), ..Default::default() });
+                            status.number_of_owned_tokens += 1;
+                            request.respond(None).await?;
+                        }
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::RelinquishControl(request) => match semaphore.as_mut() {
+                        Some((name, status)) if name == request.semaphore_name() && status.number_of_owned_tokens > 0 => {
+                            status.number_of_owned_tokens -= 1;
+                            request.respond().await?;
+                            relinquished_sender.send(())?;
+                        }
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::ReportSemaphoreStatus(request) => match semaphore.as_ref() {
+                        Some((name, status)) if name == request.semaphore_name() => request.respond(status.clone()).await?,
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::DeleteSemaphore(request) => {
+                        semaphore = None;
+                        request.respond().await?;
+                    }
+                    x => return Err::<(), anyhow::Error>(anyhow!("Unexpected message: {:?}", x)),
+                }
+            }
         });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::DefineNamedVariableList(x)) => x,
+
+        let options = || TakeControlOptions { priority: Some(10), control_timeout: Some(5000), ..Default::default() };
+        client.define_semaphore(semaphore_name.clone(), 1).await?;
+
+        let control = take_semaphore_control(client.as_mut(), semaphore_name.clone(), options()).await?;
+        assert_eq!(control.named_token(), &None);
+        assert_eq!(client.report_semaphore_status(semaphore_name.clone()).await?.number_of_owned_tokens, 1);
+        match client.take_control(semaphore_name.clone(), options()).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Resource(MmsResourceError::CapabilityUnavailable)),
             x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.variable_list_name(), &MmsObjectName::AaSpecific("Hello".into()));
-        assert_eq!(
-            request.list_of_variables(),
-            &vec![
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated }
-            ]
-        );
-        request.respond().await?;
+        }
 
-        assert_eq!(client_task.await??, ());
+        // Dropping the guard relinquishes control in the background.
+        drop(control);
+        relinquished_receiver.recv().await.ok_or_else(|| anyhow!("Test Failed"))?;
+        assert_eq!(client.report_semaphore_status(semaphore_name.clone()).await?, SemaphoreStatus { deletable: true, class: MmsSemaphoreClass::Token, number_of_tokens: 1, number_of_owned_tokens: 0, number_of_hung_tokens: 0 });
+
+        take_semaphore_control(client.as_mut(), semaphore_name.clone(), options()).await?.relinquish().await?;
+        relinquished_receiver.recv().await.ok_or_else(|| anyhow!("Test Failed"))?;
+
+        // Without a runtime the guard cannot relinquish control, but dropping it must not panic.
+        let control = take_semaphore_control(client.as_mut(), semaphore_name.clone(), options()).await?;
+        std::thread::spawn(move || drop(control)).join().map_err(|_| anyhow!("Test Failed"))?;
+        assert_eq!(client.report_semaphore_status(semaphore_name.clone()).await?.number_of_owned_tokens, 1);
+        client.relinquish_control(semaphore_name.clone(), None).await?;
+        relinquished_receiver.recv().await.ok_or_else(|| anyhow!("Test Failed"))?;
+        match client.relinquish_control(semaphore_name.clone(), None).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent)),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+
+        client.delete_semaphore(semaphore_name.clone()).await?;
+        match client.report_semaphore_status(semaphore_name).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent)),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+
+        server_task.abort();
 
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     #[traced_test]
-    async fn test_get_named_variable_list_attributes_operation() -> Result<(), anyhow::Error> {
+    async fn test_vmd_status_operations() -> Result<(), anyhow::Error> {
         let port: u16 = random_range(20000..30000);
         let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
+        let server_parameters = MmsServiceConnectionParameters { list_of_capabilities: vec!["Block1".into(), "Block2".into(), "Block5".into()], ..Default::default() };
 
         let (client_results, server_results) = join!(
             async {
@@ -1081,45 +3147,54 @@ mod tests {
                 tokio::time::sleep(Duration::from_millis(1)).await;
                 create_mms_service_client(address, MmsServiceConnectionParameters::default()).await
             },
-            async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
+            async { create_mms_service_server(address, server_parameters).await }
         );
 
-        let client = client_results?;
+        let mut client = client_results?;
         let mut server = server_results?;
+        let mut notifier = server.clone();
 
-        let client_task = tokio::task::spawn(async move { client.clone().get_named_variable_list_attributes(MmsObjectName::VmdSpecific("AList".into())).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::GetNamedVariableListAttributes(x)) => x,
-            x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.variable_list_name(), &MmsObjectName::VmdSpecific("AList".into()));
-        request
-            .respond(
-                true,
-                vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated },
-                ],
-            )
-            .await?;
-
-        assert_eq!(
-            client_task.await??,
-            NamedVariableListAttributes {
-                deletable: true,
-                list_of_variables: vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("World".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Invalidated },
-                ]
+        let (unsolicited_status_sender, mut unsolicited_status_receiver) = mpsc::unbounded_channel();
+        let server_task = tokio::task::spawn(async move {
+            loop {
+                match server.receive_message().await? {
+                    MmsServiceMessage::Status(request) => request.respond_with_default().await?,
+                    MmsServiceMessage::GetCapabilityList(request) => request.respond_with_default().await?,
+                    MmsServiceMessage::Rename(request) => {
+                        assert_eq!(request.object_class(), &MmsObjectClass::Basic(MmsBasicObjectClass::NamedVariable));
+                        assert_eq!(request.current_name(), &MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_1".into()));
+                        assert_eq!(request.new_identifier(), "Breaker_2");
+                        request.respond().await?;
+                    }
+                    MmsServiceMessage::UnsolicitedStatus(status) => unsolicited_status_sender.send(status)?,
+                    x => return Err::<(), anyhow::Error>(anyhow!("Unexpected message: {:?}", x)),
+                }
             }
-        );
+        });
+
+        assert_eq!(client.status(false).await?, VmdStatus::default());
+        assert_eq!(client.get_capability_list(None).await?, CapabilityList { list_of_capabilities: vec!["Block1".into(), "Block2".into(), "Block5".into()], more_follows: false });
+        assert_eq!(client.get_capability_list(Some("Block1".into())).await?, CapabilityList { list_of_capabilities: vec!["Block2".into(), "Block5".into()], more_follows: false });
+
+        // The server announces that it is going into maintenance and then reports it when polled.
+        let maintenance = VmdStatus { logical_status: MmsLogicalStatus::NoStateChangesAllowed, physical_status: MmsPhysicalStatus::NeedsCommissioning, local_detail: Some(vec![true, false, true, false, false, true, false, true, true]) };
+        notifier.send_unsolicited_status(maintenance.clone()).await?;
+        assert_eq!(client.receive_unsolicited_status().await?, maintenance);
+        assert_eq!(client.status(true).await?, maintenance);
+
+        client.send_unsolicited_status(VmdStatus { physical_status: MmsPhysicalStatus::PartiallyOperational, ..Default::default() }).await?;
+        assert_eq!(unsolicited_status_receiver.recv().await.ok_or_else(|| anyhow!("Test Failed"))?.physical_status, MmsPhysicalStatus::PartiallyOperational);
+
+        client.rename(MmsObjectClass::Basic(MmsBasicObjectClass::NamedVariable), MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_1".into()), "Breaker_2".into()).await?;
+
+        server_task.abort();
 
         Ok(())
     }
 
     #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
     #[traced_test]
-    async fn test_delete_named_variable_list_operation() -> Result<(), anyhow::Error> {
+    async fn test_program_invocation_operations() -> Result<(), anyhow::Error> {
         let port: u16 = random_range(20000..30000);
         let address = format!("127.0.0.1:{}", port).parse().map_err(to_mms_error("Test Failed"))?;
 
@@ -1132,51 +3207,108 @@ mod tests {
             async { create_mms_service_server(address, MmsServiceConnectionParameters::default()).await }
         );
 
-        let client = client_results?;
+        let mut client = client_results?;
         let mut server = server_results?;
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::Vmd).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
-            x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::Vmd);
-        request.respond(2, 1).await?;
-        assert_eq!(client_task.await??, (2, 1));
+        let server_task = tokio::task::spawn(async move {
+            let non_existent = || MmsServiceErrorInfo::new(MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent));
+            let state_conflict = || MmsServiceErrorInfo::new(MmsErrorClass::Service(MmsServiceProblem::ObjectStateConflict));
+            let mut program_invocation: Option<(String, ProgramInvocationAttributes)> = None;
+            loop {
+                match server.receive_message().await? {
+                    MmsServiceMessage::CreateProgramInvocation(request) => {
+                        assert_eq!(request.monitor(), &Some(ProgramInvocationMonitor::Current));
+                        let attributes = ProgramInvocationAttributes {
+                            state: MmsProgramInvocationState::Idle,
+                            list_of_domain_names: request.list_of_domain_names().to_vec(),
+                            deletable: true,
+                            reusable: request.reusable(),
+                            monitor: request.monitor().is_some(),
+                            execution_argument: String::new(),
+                        };
+                        program_invocation = Some((request.program_invocation_name().to_string(), attributes));
+                        request.respond().await?;
+                    }
+                    MmsServiceMessage::Start(request) => match program_invocation.as_mut() {
+                        Some((name, attributes)) if name == request.program_invocation_name() => {
+                            if attributes.state != MmsProgramInvocationState::Idle {
+                                request.respond_with_error(state_conflict()).await?;
+                                continue;
+                            }
+                            attributes.state = MmsProgramInvocationState::Running;
+                            attributes.execution_argument = request.execution_argument().clone().unwrap_or_default();
+                            request.respond().await?;
+                        }
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::Stop(request) => match program_invocation.as_mut() {
+                        Some((name, attributes)) if name == request.program_invocation_name() && attributes.state == MmsProgramInvocationState::Running => {
+                            attributes.state = MmsProgramInvocationState::Stopped;
+                            request.respond().await?;
+                        }
+                        _ => request.respond_with_error(state_conflict()).await?,
+                    },
+                    MmsServiceMessage::Resume(request) => match program_invocation.as_mut() {
+                        Some((name, attributes)) if name == request.program_invocation_name() && attributes.state == MmsProgramInvocationState::Stopped => {
+                            attributes.state = MmsProgramInvocationState::Running;
+                            request.respond().await?;
+                        }
+                        _ => request.respond_with_error(state_conflict()).await?,
+                    },
+                    MmsServiceMessage::Reset(request) => match program_invocation.as_mut() {
+                        Some((name, attributes)) if name == request.program_invocation_name() && attributes.state == MmsProgramInvocationState::Stopped => {
+                            attributes.state = if attributes.reusable { MmsProgramInvocationState::Idle } else { MmsProgramInvocationState::Unrunnable };
+                            request.respond().await?;
+                        }
+                        _ => request.respond_with_error(state_conflict()).await?,
+                    },
+                    MmsServiceMessage::Kill(request) => match program_invocation.as_mut() {
+                        Some((name, attributes)) if name == request.program_invocation_name() => {
+                            attributes.state = MmsProgramInvocationState::Unrunnable;
+                            request.respond().await?;
+                        }
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::GetProgramInvocationAttributes(request) => match program_invocation.as_ref() {
+                        Some((name, attributes)) if name == request.program_invocation_name() => request.respond(attributes.clone()).await?,
+                        _ => request.respond_with_error(non_existent()).await?,
+                    },
+                    MmsServiceMessage::DeleteProgramInvocation(request) => {
+                        program_invocation = None;
+                        request.respond().await?;
+                    }
+                    x => return Err::<(), anyhow::Error>(anyhow!("Unexpected message: {:?}", x)),
+                }
+            }
+        });
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::Domain("TheEntireDomain".into())).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
+        client.create_program_invocation("Transfer_Control".into(), vec!["ICCP".into(), "Breakers".into()], true, Some(ProgramInvocationMonitor::Current)).await?;
+        client.start("Transfer_Control".into(), Some("Area 2".into())).await?;
+        assert_eq!(
+            client.get_program_invocation_attributes("Transfer_Control".into()).await?,
+            ProgramInvocationAttributes { state: MmsProgramInvocationState::Running, list_of_domain_names: vec!["ICCP".into(), "Breakers".into()], deletable: true, reusable: true, monitor: true, execution_argument: "Area 2".into() }
+        );
+        match client.start("Transfer_Control".into(), None).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Service(MmsServiceProblem::ObjectStateConflict)),
             x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::Domain("TheEntireDomain".into()));
-        request.respond(10, 3).await?;
-        assert_eq!(client_task.await??, (10, 3));
+        }
 
-        let mut task_client = client.clone();
-        let client_task = tokio::task::spawn(async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::AaSpecific).await });
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
-            x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::AaSpecific);
-        request.respond(10, 3).await?;
-        assert_eq!(client_task.await??, (10, 3));
+        client.stop("Transfer_Control".into()).await?;
+        client.resume("Transfer_Control".into(), None).await?;
+        client.stop("Transfer_Control".into()).await?;
+        client.reset("Transfer_Control".into()).await?;
+        assert_eq!(client.get_program_invocation_attributes("Transfer_Control".into()).await?.state, MmsProgramInvocationState::Idle);
 
-        let mut task_client = client.clone();
-        let client_task =
-            tokio::task::spawn(
-                async move { task_client.delete_named_variable_list(crate::data::MmsServiceDeleteObjectScope::Specific(vec![MmsObjectName::AaSpecific("AaOne".into()), MmsObjectName::VmdSpecific("ThisDomain".into())])).await },
-            );
-        let request = match server.receive_message().await {
-            Ok(MmsServiceMessage::DeleteNamedVariableList(x)) => x,
+        client.kill("Transfer_Control".into()).await?;
+        assert_eq!(client.get_program_invocation_attributes("Transfer_Control".into()).await?.state, MmsProgramInvocationState::Unrunnable);
+
+        client.delete_program_invocation("Transfer_Control".into()).await?;
+        match client.get_program_invocation_attributes("Transfer_Control".into()).await {
+            Err(MmsServiceError::ServiceError(x)) => assert_eq!(x.error_class, MmsErrorClass::Access(MmsObjectAccessError::ObjectNonExistent)),
             x => return Err(anyhow!("Test Failed: {:?}", x)),
-        };
-        assert_eq!(request.scope_of_delete(), &MmsServiceDeleteObjectScope::Specific(vec![MmsObjectName::AaSpecific("AaOne".into()), MmsObjectName::VmdSpecific("ThisDomain".into())]));
-        request.respond(10, 3).await?;
-        assert_eq!(client_task.await??, (10, 3));
+        }
+
+        server_task.abort();
 
         Ok(())
     }
@@ -1218,6 +3350,9 @@ mod tests {
                 MmsServiceAccessResult::Success(MmsServiceData::OctetString(vec![1, 2, 3, 4, 5])),
                 MmsServiceAccessResult::Success(MmsServiceData::MmsString("AnMmsString".into())),
                 MmsServiceAccessResult::Success(MmsServiceData::VisibleString("Hello".into())),
+                MmsServiceAccessResult::Success(MmsServiceData::UtcTime(MmsUtcTime::new(1700000000, 0x400000, MmsTimeQuality { clock_not_synchronized: true, time_accuracy: 10, ..Default::default() }))),
+                MmsServiceAccessResult::Success(MmsServiceData::BinaryTime(MmsBinaryTime::with_date(43_200_000, 14_600))),
+                MmsServiceAccessResult::Success(MmsServiceData::BinaryTime(MmsBinaryTime::time_of_day(1_000))),
             ])
             .await?;
         assert_eq!(
@@ -1233,6 +3368,9 @@ mod tests {
                 MmsServiceAccessResult::Success(MmsServiceData::OctetString(vec![1, 2, 3, 4, 5])),
                 MmsServiceAccessResult::Success(MmsServiceData::MmsString("AnMmsString".into())),
                 MmsServiceAccessResult::Success(MmsServiceData::VisibleString("Hello".into())),
+                MmsServiceAccessResult::Success(MmsServiceData::UtcTime(MmsUtcTime::new(1700000000, 0x400000, MmsTimeQuality { clock_not_synchronized: true, time_accuracy: 10, ..Default::default() }))),
+                MmsServiceAccessResult::Success(MmsServiceData::BinaryTime(MmsBinaryTime::with_date(43_200_000, 14_600))),
+                MmsServiceAccessResult::Success(MmsServiceData::BinaryTime(MmsBinaryTime::time_of_day(1_000))),
             ]
         );
 
@@ -1240,9 +3378,9 @@ mod tests {
         let client_task = tokio::task::spawn(async move {
             task_client
                 .read(MmsVariableAccessSpecification::ListOfVariables(vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())), alternate_access: None },
                 ]))
                 .await
         });
@@ -1253,9 +3391,9 @@ mod tests {
         assert_eq!(
             request.specification(),
             &MmsVariableAccessSpecification::ListOfVariables(vec![
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())) },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())), alternate_access: None },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())), alternate_access: None },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())), alternate_access: None },
             ])
         );
         request.respond(vec![MmsServiceAccessResult::Success(MmsServiceData::Boolean(true))]).await?;
@@ -1297,9 +3435,9 @@ mod tests {
             task_client
                 .write(
                     MmsVariableAccessSpecification::ListOfVariables(vec![
-                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())) },
-                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())) },
-                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())) },
+                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())), alternate_access: None },
+                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())), alternate_access: None },
+                        ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())), alternate_access: None },
                     ]),
                     vec![MmsServiceData::Boolean(true), MmsServiceData::Boolean(false), MmsServiceData::Boolean(true)],
                 )
@@ -1312,9 +3450,9 @@ mod tests {
         assert_eq!(
             request.specification(),
             &MmsVariableAccessSpecification::ListOfVariables(vec![
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())) },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One1".into())), alternate_access: None },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One2".into())), alternate_access: None },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("One3".into())), alternate_access: None },
             ])
         );
         request
@@ -1333,6 +3471,30 @@ mod tests {
             ]
         );
 
+        // A response the client cannot parse fails the request instead of leaving it waiting.
+        let mut task_client = client.clone();
+        let client_task = tokio::task::spawn(async move { task_client.write(MmsVariableAccessSpecification::VariableListName(MmsObjectName::AaSpecific("MyVariable".into())), vec![MmsServiceData::Boolean(true)]).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::Write(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        request.respond(vec![MmsWriteResult::Failure(MmsAccessError::Unknown(vec![]))]).await?;
+        match client_task.await? {
+            Err(MmsServiceError::ResponseRejected(MmsRejectReason::ConfirmedResponsePdu(MmsConfirmedResponseRejectCode::InvalidResult))) => (),
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        }
+
+        // The server cannot say which request a rejected unconfirmed PDU belongs to. This must not fail the request that is waiting.
+        let mut task_client = client.clone();
+        task_client.send_information_report(MmsVariableAccessSpecification::VariableListName(MmsObjectName::AaSpecific("MyVariable".into())), vec![MmsServiceAccessResult::Failure(MmsAccessError::Unknown(vec![]))]).await?;
+        let client_task = tokio::task::spawn(async move { task_client.write(MmsVariableAccessSpecification::VariableListName(MmsObjectName::AaSpecific("MyVariable".into())), vec![MmsServiceData::Boolean(true)]).await });
+        let request = match server.receive_message().await {
+            Ok(MmsServiceMessage::Write(x)) => x,
+            x => return Err(anyhow!("Test Failed: {:?}", x)),
+        };
+        request.respond(vec![MmsWriteResult::Success]).await?;
+        assert_eq!(client_task.await??, vec![MmsWriteResult::Success]);
+
         Ok(())
     }
 
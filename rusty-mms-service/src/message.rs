@@ -1,18 +1,73 @@
-use std::fmt;
+use std::{fmt, sync::Arc};
 
 use futures::future::BoxFuture;
 use num_bigint::BigInt;
-use rusty_mms::{ListOfVariablesItem, MmsAccessResult, MmsData, MmsError, MmsMessage, MmsObjectClass, MmsObjectName, MmsObjectScope, MmsVariableAccessSpecification, MmsWriteResult};
+use rusty_mms::{
+    ListOfVariablesItem, MmsAccessResult, MmsData, MmsDirectoryEntry, MmsEntryContent, MmsError, MmsEventConditionState, MmsJournalLimitSpecification, MmsMessage, MmsObjectClass, MmsObjectName, MmsObjectScope, MmsServiceErrorInfo,
+    MmsTypeSpecification, MmsVariableAccessSpecification, MmsWriteResult,
+};
+use tokio::sync::watch;
 
 use crate::data::{
-    InformationReportMmsServiceMessage, MmsServiceAccessResult, MmsServiceData, MmsServiceDeleteObjectScope, MmsServiceTypeDescription, convert_high_level_data_to_low_level_data, convert_high_level_data_types_to_low_level_data_types,
-    convert_low_level_data_to_high_level_data,
+    CapabilityList, DomainAttributes, DomainSegment, EventConditionAttributes, EventConditionDefinition, EventConditionMonitoring, EventEnrollmentDefinition, EventNotification, EventTime, FileAttributes, FileDirectory, FileSegment,
+    InformationReportMmsServiceMessage, JournalEntries, JournalEntryContent, JournalReadSpecification, JournalStatus, MmsServiceAccessResult, MmsServiceData, MmsServiceDeleteObjectScope, MmsServiceTypeDescription,
+    MmsServiceTypeSpecification, ProgramInvocationAttributes, ProgramInvocationMonitor, SemaphoreStatus, TakeControlOptions, UploadSequence, VmdStatus, convert_high_level_data_to_low_level_data,
+    convert_high_level_data_types_to_low_level_data_types, convert_high_level_file_attributes_to_low_level_file_attributes, convert_high_level_journal_entry_to_low_level_journal_entry, convert_high_level_status_to_low_level_status,
+    convert_high_level_type_specification_to_low_level_type_specification, convert_low_level_data_to_high_level_data, convert_low_level_entry_content_to_high_level_entry_content,
+    convert_low_level_type_specification_to_high_level_type_specification,
+};
+use crate::{
+    data::Identity,
+    error::{MmsServiceError, to_mms_error},
 };
-use crate::{data::Identity, error::MmsServiceError};
+
+/// Lets the handler of a confirmed request observe the client cancelling it. The client has already been answered once a request is cancelled, so any response sent afterwards is dropped.
+#[derive(Clone, Debug)]
+pub struct MmsServiceCancellation {
+    receiver: watch::Receiver<bool>,
+}
+impl MmsServiceCancellation {
+    pub(crate) fn new(receiver: watch::Receiver<bool>) -> Self {
+        Self { receiver }
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        *self.receiver.borrow()
+    }
+
+    /// Completes when the request is cancelled. This never completes if the request is answered first.
+    pub async fn cancelled(&self) {
+        let mut receiver = self.receiver.clone();
+        if receiver.wait_for(|cancelled| *cancelled).await.is_err() {
+            std::future::pending::<()>().await;
+        }
+    }
+}
+
+pub struct ConcludeMmsServiceMessage {
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+}
+impl fmt::Debug for ConcludeMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConcludeMmsServiceMessage").finish()
+    }
+}
+impl ConcludeMmsServiceMessage {
+    pub(crate) fn new(response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
+        Self { response }
+    }
+
+    /// Accepts the conclude. The client will then release the association, which is answered when the release request is received.
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConcludeResponse { request: rusty_mms::MmsConcludeResponse {} }).await?;
+        Ok(())
+    }
+}
 
 pub struct IdentifyMmsServiceMessage {
     pub invocation_id: u32,
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
 impl fmt::Debug for IdentifyMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -20,8 +75,12 @@ impl fmt::Debug for IdentifyMmsServiceMessage {
     }
 }
 impl IdentifyMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
-        Self { invocation_id, response }
+    pub(crate) fn new(invocation_id: u32, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
     pub async fn respond(self, identity: Identity) -> Result<(), MmsServiceError> {
@@ -34,12 +93,160 @@ impl IdentifyMmsServiceMessage {
     }
 }
 
+pub struct StatusMmsServiceMessage {
+    invocation_id: u32,
+    extended_derivation: bool,
+    default_status: VmdStatus,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for StatusMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StatusMmsServiceMessage").field("invocation_id", &self.invocation_id).field("extended_derivation", &self.extended_derivation).field("default_status", &self.default_status).finish()
+    }
+}
+impl StatusMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, extended_derivation: bool, default_status: VmdStatus, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, extended_derivation, default_status, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    /// The client is asking the VMD to run its self diagnostics before answering.
+    pub fn extended_derivation(&self) -> bool {
+        self.extended_derivation
+    }
+
+    /// The status configured on the server, updated by any unsolicited status the server has sent since.
+    pub fn default_status(&self) -> &VmdStatus {
+        &self.default_status
+    }
+
+    pub async fn respond(self, status: VmdStatus) -> Result<(), MmsServiceError> {
+        let status = convert_high_level_status_to_low_level_status(status)?;
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be(), response: rusty_mms::MmsConfirmedResponse::Status(status) }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_default(self) -> Result<(), MmsServiceError> {
+        let status = self.default_status.clone();
+        self.respond(status).await
+    }
+}
+
+pub struct GetCapabilityListMmsServiceMessage {
+    invocation_id: u32,
+    continue_after: Option<String>,
+    default_list_of_capabilities: Arc<Vec<String>>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for GetCapabilityListMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetCapabilityListMmsServiceMessage").field("invocation_id", &self.invocation_id).field("continue_after", &self.continue_after).field("default_list_of_capabilities", &self.default_list_of_capabilities).finish()
+    }
+}
+impl GetCapabilityListMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        continue_after: Option<String>,
+        default_list_of_capabilities: Arc<Vec<String>>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, continue_after, default_list_of_capabilities, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn continue_after(&self) -> &Option<String> {
+        &self.continue_after
+    }
+
+    /// The capabilities configured on the server.
+    pub fn default_list_of_capabilities(&self) -> &[String] {
+        &self.default_list_of_capabilities
+    }
+
+    pub async fn respond(self, capability_list: CapabilityList) -> Result<(), MmsServiceError> {
+        let more_follows = if capability_list.more_follows { None } else { Some(false) };
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be(),
+            response: rusty_mms::MmsConfirmedResponse::GetCapabilityList { list_of_capabilities: capability_list.list_of_capabilities, more_follows },
+        })
+        .await?;
+        Ok(())
+    }
+
+    /// Responds with the configured capabilities in a single response. When continue after is present, only the capabilities following it are sent, so nothing is sent if it is not one of the configured capabilities.
+    pub async fn respond_with_default(self) -> Result<(), MmsServiceError> {
+        let list_of_capabilities = match &self.continue_after {
+            Some(continue_after) => self.default_list_of_capabilities.iter().skip_while(|x| *x != continue_after).skip(1).cloned().collect(),
+            None => self.default_list_of_capabilities.to_vec(),
+        };
+        self.respond(CapabilityList { list_of_capabilities, more_follows: false }).await
+    }
+}
+
+pub struct RenameMmsServiceMessage {
+    invocation_id: u32,
+    object_class: MmsObjectClass,
+    current_name: MmsObjectName,
+    new_identifier: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for RenameMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RenameMmsServiceMessage").field("invocation_id", &self.invocation_id).field("object_class", &self.object_class).field("current_name", &self.current_name).field("new_identifier", &self.new_identifier).finish()
+    }
+}
+impl RenameMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        object_class: MmsObjectClass,
+        current_name: MmsObjectName,
+        new_identifier: String,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, object_class, current_name, new_identifier, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn object_class(&self) -> &MmsObjectClass {
+        &self.object_class
+    }
+
+    pub fn current_name(&self) -> &MmsObjectName {
+        &self.current_name
+    }
+
+    /// The new name keeps the scope of the current name.
+    pub fn new_identifier(&self) -> &str {
+        &self.new_identifier
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be(), response: rusty_mms::MmsConfirmedResponse::Rename }).await?;
+        Ok(())
+    }
+}
+
 pub struct GetNameListMmsServiceMessage {
     invocation_id: u32,
     object_class: MmsObjectClass,
     object_scope: MmsObjectScope,
     continue_after: Option<String>,
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
 impl fmt::Debug for GetNameListMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -47,8 +254,19 @@ impl fmt::Debug for GetNameListMmsServiceMessage {
     }
 }
 impl GetNameListMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, object_class: MmsObjectClass, object_scope: MmsObjectScope, continue_after: Option<String>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
-        Self { invocation_id, object_class, object_scope, continue_after, response }
+    pub(crate) fn new(
+        invocation_id: u32,
+        object_class: MmsObjectClass,
+        object_scope: MmsObjectScope,
+        continue_after: Option<String>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, object_class, object_scope, continue_after, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
     pub fn object_class(&self) -> &MmsObjectClass {
@@ -68,12 +286,18 @@ impl GetNameListMmsServiceMessage {
         (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::GetNameList { list_of_identifiers, more_follows } }).await?;
         Ok(())
     }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
 }
 
 pub struct GetVariableAccessAttributesMmsServiceMessage {
     invocation_id: u32,
     object_name: MmsObjectName,
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
 impl fmt::Debug for GetVariableAccessAttributesMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -81,8 +305,12 @@ impl fmt::Debug for GetVariableAccessAttributesMmsServiceMessage {
     }
 }
 impl GetVariableAccessAttributesMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, object_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
-        Self { invocation_id, object_name, response }
+    pub(crate) fn new(invocation_id: u32, object_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, object_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
     pub fn object_name(&self) -> &MmsObjectName {
@@ -104,6 +332,7 @@ pub struct DefineNamedVariableListMmsServiceMessage {
     variable_list_name: MmsObjectName,
     list_of_variables: Vec<ListOfVariablesItem>,
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
 impl fmt::Debug for DefineNamedVariableListMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -111,8 +340,18 @@ impl fmt::Debug for DefineNamedVariableListMmsServiceMessage {
     }
 }
 impl DefineNamedVariableListMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, variable_list_name: MmsObjectName, list_of_variables: Vec<ListOfVariablesItem>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
-        Self { invocation_id, variable_list_name, list_of_variables, response }
+    pub(crate) fn new(
+        invocation_id: u32,
+        variable_list_name: MmsObjectName,
+        list_of_variables: Vec<ListOfVariablesItem>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, variable_list_name, list_of_variables, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
     pub fn variable_list_name(&self) -> &MmsObjectName {
@@ -134,6 +373,7 @@ pub struct GetNamedVariableListAttributesMmsServiceMessage {
     variable_list_name: MmsObjectName,
 
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
 impl fmt::Debug for GetNamedVariableListAttributesMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -141,8 +381,12 @@ impl fmt::Debug for GetNamedVariableListAttributesMmsServiceMessage {
     }
 }
 impl GetNamedVariableListAttributesMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, variable_list_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
-        Self { invocation_id, variable_list_name, response }
+    pub(crate) fn new(invocation_id: u32, variable_list_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, variable_list_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
     pub fn variable_list_name(&self) -> &MmsObjectName {
@@ -163,6 +407,7 @@ pub struct DeleteNamedVariableListMmsServiceMessage {
     invocation_id: u32,
     scope_of_delete: MmsServiceDeleteObjectScope,
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
 impl fmt::Debug for DeleteNamedVariableListMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -170,8 +415,12 @@ impl fmt::Debug for DeleteNamedVariableListMmsServiceMessage {
     }
 }
 impl DeleteNamedVariableListMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, scope_of_delete: MmsServiceDeleteObjectScope, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
-        Self { invocation_id, scope_of_delete, response }
+    pub(crate) fn new(invocation_id: u32, scope_of_delete: MmsServiceDeleteObjectScope, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, scope_of_delete, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
     pub fn scope_of_delete(&self) -> &MmsServiceDeleteObjectScope {
@@ -187,71 +436,1862 @@ impl DeleteNamedVariableListMmsServiceMessage {
     }
 }
 
-pub struct ReadMmsServiceMessage {
+pub struct DefineNamedTypeMmsServiceMessage {
     invocation_id: u32,
-    specification_with_result: bool,
-    specification: MmsVariableAccessSpecification,
+    type_name: MmsObjectName,
+    type_specification: MmsServiceTypeSpecification,
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
-impl fmt::Debug for ReadMmsServiceMessage {
+impl fmt::Debug for DefineNamedTypeMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("ReadMmsServiceMessage").field("invocation_id", &self.invocation_id).field("specification_with_result", &self.specification_with_result).field("specification", &self.specification).finish()
+        f.debug_struct("DefineNamedTypeMmsServiceMessage").field("invocation_id", &self.invocation_id).field("type_name", &self.type_name).field("type_specification", &self.type_specification).finish()
     }
 }
-impl ReadMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, specification: MmsVariableAccessSpecification, specification_with_result: Option<bool>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Self {
-        Self {
-            invocation_id,
-            // TODO HIGH What is the default behaviour for this?
-            specification_with_result: specification_with_result.unwrap_or(true),
-            specification,
-            response,
-        }
+impl DefineNamedTypeMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        type_name: MmsObjectName,
+        type_specification: MmsTypeSpecification,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Result<Self, MmsServiceError> {
+        let type_specification = convert_low_level_type_specification_to_high_level_type_specification(&type_specification)?;
+        Ok(Self { invocation_id, type_name, type_specification, response, cancellation })
     }
 
-    pub fn specification(&self) -> &MmsVariableAccessSpecification {
-        &self.specification
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
-    pub async fn respond(self, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError> {
-        let variable_access_specification = if self.specification_with_result { Some(self.specification) } else { None };
+    pub fn type_name(&self) -> &MmsObjectName {
+        &self.type_name
+    }
+
+    pub fn type_specification(&self) -> &MmsServiceTypeSpecification {
+        &self.type_specification
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DefineNamedType }).await?;
+        Ok(())
+    }
+}
+
+pub struct GetNamedTypeAttributesMmsServiceMessage {
+    invocation_id: u32,
+    type_name: MmsObjectName,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for GetNamedTypeAttributesMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetNamedTypeAttributesMmsServiceMessage").field("invocation_id", &self.invocation_id).field("type_name", &self.type_name).finish()
+    }
+}
+impl GetNamedTypeAttributesMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, type_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, type_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn type_name(&self) -> &MmsObjectName {
+        &self.type_name
+    }
+
+    pub async fn respond(self, deletable: bool, type_specification: MmsServiceTypeSpecification) -> Result<(), MmsServiceError> {
         (self.response)(MmsMessage::ConfirmedResponse {
             invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
-            response: rusty_mms::MmsConfirmedResponse::Read {
-                variable_access_specification,
-                access_results: access_results
-                    .into_iter()
-                    .map(|x| match x {
-                        MmsServiceAccessResult::Success(mms_service_data) => Ok(MmsAccessResult::Success(convert_high_level_data_to_low_level_data(&mms_service_data)?)),
-                        MmsServiceAccessResult::Failure(mms_access_error) => Ok(MmsAccessResult::Failure(mms_access_error)),
-                    })
-                    .collect::<Result<Vec<MmsAccessResult>, MmsServiceError>>()?,
-            },
+            response: rusty_mms::MmsConfirmedResponse::GetNamedTypeAttributes { deletable, type_specification: convert_high_level_type_specification_to_low_level_type_specification(&type_specification)? },
         })
         .await?;
         Ok(())
     }
 }
 
-pub struct WriteMmsServiceMessage {
+pub struct DeleteNamedTypeMmsServiceMessage {
     invocation_id: u32,
-    specification: MmsVariableAccessSpecification,
-    values: Vec<MmsServiceData>,
+    scope_of_delete: MmsServiceDeleteObjectScope,
     response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
 }
-impl fmt::Debug for WriteMmsServiceMessage {
+impl fmt::Debug for DeleteNamedTypeMmsServiceMessage {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("WriteMmsServiceMessage").field("invocation_id", &self.invocation_id).field("specification", &self.specification).field("values", &self.values).finish()
+        f.debug_struct("DeleteNamedTypeMmsServiceMessage").field("invocation_id", &self.invocation_id).field("scope_of_delete", &self.scope_of_delete).finish()
     }
 }
-impl WriteMmsServiceMessage {
-    pub(crate) fn new(invocation_id: u32, specification: MmsVariableAccessSpecification, values: Vec<MmsData>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>) -> Result<Self, MmsServiceError> {
-        let mut high_level_values = vec![];
-        for value in values {
-            high_level_values.push(convert_low_level_data_to_high_level_data(&value)?);
-        }
+impl DeleteNamedTypeMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, scope_of_delete: MmsServiceDeleteObjectScope, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, scope_of_delete, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn scope_of_delete(&self) -> &MmsServiceDeleteObjectScope {
+        &self.scope_of_delete
+    }
+
+    pub async fn respond(self, number_matched: u32, number_deleted: u32) -> Result<(), MmsServiceError> {
+        let number_matched = BigInt::from(number_matched).to_signed_bytes_be();
+        let number_deleted = BigInt::from(number_deleted).to_signed_bytes_be();
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DeleteNamedType { number_matched, number_deleted } }).await?;
+        Ok(())
+    }
+}
+
+pub struct InitiateDownloadSequenceMmsServiceMessage {
+    invocation_id: u32,
+    domain_name: String,
+    list_of_capabilities: Vec<String>,
+    sharable: bool,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for InitiateDownloadSequenceMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitiateDownloadSequenceMmsServiceMessage")
+            .field("invocation_id", &self.invocation_id)
+            .field("domain_name", &self.domain_name)
+            .field("list_of_capabilities", &self.list_of_capabilities)
+            .field("sharable", &self.sharable)
+            .finish()
+    }
+}
+impl InitiateDownloadSequenceMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        domain_name: String,
+        list_of_capabilities: Vec<String>,
+        sharable: bool,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, domain_name, list_of_capabilities, sharable, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn domain_name(&self) -> &str {
+        &self.domain_name
+    }
+
+    pub fn list_of_capabilities(&self) -> &Vec<String> {
+        &self.list_of_capabilities
+    }
+
+    pub fn sharable(&self) -> bool {
+        self.sharable
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::InitiateDownloadSequence }).await?;
+        Ok(())
+    }
+}
+
+pub struct DownloadSegmentMmsServiceMessage {
+    invocation_id: u32,
+    domain_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DownloadSegmentMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DownloadSegmentMmsServiceMessage").field("invocation_id", &self.invocation_id).field("domain_name", &self.domain_name).finish()
+    }
+}
+impl DownloadSegmentMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, domain_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, domain_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn domain_name(&self) -> &str {
+        &self.domain_name
+    }
+
+    pub async fn respond(self, segment: DomainSegment) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::DownloadSegment { load_data: segment.load_data, more_follows: Some(segment.more_follows) },
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+pub struct TerminateDownloadSequenceMmsServiceMessage {
+    invocation_id: u32,
+    domain_name: String,
+    discard: Option<MmsServiceErrorInfo>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for TerminateDownloadSequenceMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TerminateDownloadSequenceMmsServiceMessage").field("invocation_id", &self.invocation_id).field("domain_name", &self.domain_name).field("discard", &self.discard).finish()
+    }
+}
+impl TerminateDownloadSequenceMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        domain_name: String,
+        discard: Option<MmsServiceErrorInfo>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, domain_name, discard, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn domain_name(&self) -> &str {
+        &self.domain_name
+    }
+
+    /// The reason the download is being discarded. The download completed successfully if this is not present.
+    pub fn discard(&self) -> &Option<MmsServiceErrorInfo> {
+        &self.discard
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::TerminateDownloadSequence }).await?;
+        Ok(())
+    }
+}
+
+pub struct InitiateUploadSequenceMmsServiceMessage {
+    invocation_id: u32,
+    domain_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for InitiateUploadSequenceMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitiateUploadSequenceMmsServiceMessage").field("invocation_id", &self.invocation_id).field("domain_name", &self.domain_name).finish()
+    }
+}
+impl InitiateUploadSequenceMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, domain_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, domain_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn domain_name(&self) -> &str {
+        &self.domain_name
+    }
+
+    pub async fn respond(self, upload_sequence: UploadSequence) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::InitiateUploadSequence { ulsm_id: BigInt::from(upload_sequence.ulsm_id).to_signed_bytes_be(), list_of_capabilities: upload_sequence.list_of_capabilities },
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+pub struct UploadSegmentMmsServiceMessage {
+    invocation_id: u32,
+    ulsm_id: i32,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for UploadSegmentMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UploadSegmentMmsServiceMessage").field("invocation_id", &self.invocation_id).field("ulsm_id", &self.ulsm_id).finish()
+    }
+}
+impl UploadSegmentMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, ulsm_id: Vec<u8>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Result<Self, MmsServiceError> {
+        let ulsm_id = BigInt::from_signed_bytes_be(&ulsm_id).try_into().map_err(to_mms_error("Failed to parse ULSM ID on Upload Segment"))?;
+        Ok(Self { invocation_id, ulsm_id, response, cancellation })
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn ulsm_id(&self) -> i32 {
+        self.ulsm_id
+    }
+
+    pub async fn respond(self, segment: DomainSegment) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::UploadSegment { load_data: segment.load_data, more_follows: Some(segment.more_follows) },
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+pub struct TerminateUploadSequenceMmsServiceMessage {
+    invocation_id: u32,
+    ulsm_id: i32,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for TerminateUploadSequenceMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TerminateUploadSequenceMmsServiceMessage").field("invocation_id", &self.invocation_id).field("ulsm_id", &self.ulsm_id).finish()
+    }
+}
+impl TerminateUploadSequenceMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, ulsm_id: Vec<u8>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Result<Self, MmsServiceError> {
+        let ulsm_id = BigInt::from_signed_bytes_be(&ulsm_id).try_into().map_err(to_mms_error("Failed to parse ULSM ID on Terminate Upload Sequence"))?;
+        Ok(Self { invocation_id, ulsm_id, response, cancellation })
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn ulsm_id(&self) -> i32 {
+        self.ulsm_id
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::TerminateUploadSequence }).await?;
+        Ok(())
+    }
+}
+
+pub struct DeleteDomainMmsServiceMessage {
+    invocation_id: u32,
+    domain_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DeleteDomainMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeleteDomainMmsServiceMessage").field("invocation_id", &self.invocation_id).field("domain_name", &self.domain_name).finish()
+    }
+}
+impl DeleteDomainMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, domain_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, domain_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn domain_name(&self) -> &str {
+        &self.domain_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DeleteDomain }).await?;
+        Ok(())
+    }
+}
+
+pub struct GetDomainAttributesMmsServiceMessage {
+    invocation_id: u32,
+    domain_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for GetDomainAttributesMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetDomainAttributesMmsServiceMessage").field("invocation_id", &self.invocation_id).field("domain_name", &self.domain_name).finish()
+    }
+}
+impl GetDomainAttributesMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, domain_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, domain_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn domain_name(&self) -> &str {
+        &self.domain_name
+    }
+
+    pub async fn respond(self, attributes: DomainAttributes) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::GetDomainAttributes {
+                list_of_capabilities: attributes.list_of_capabilities,
+                state: attributes.state,
+                mms_deletable: attributes.deletable,
+                sharable: attributes.sharable,
+                list_of_program_invocations: attributes.list_of_program_invocations,
+                upload_in_progress: BigInt::from(attributes.upload_in_progress).to_signed_bytes_be(),
+            },
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+pub struct CreateProgramInvocationMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    list_of_domain_names: Vec<String>,
+    reusable: bool,
+    monitor: Option<ProgramInvocationMonitor>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for CreateProgramInvocationMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateProgramInvocationMmsServiceMessage")
+            .field("invocation_id", &self.invocation_id)
+            .field("program_invocation_name", &self.program_invocation_name)
+            .field("list_of_domain_names", &self.list_of_domain_names)
+            .field("reusable", &self.reusable)
+            .field("monitor", &self.monitor)
+            .finish()
+    }
+}
+impl CreateProgramInvocationMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        program_invocation_name: String,
+        list_of_domain_names: Vec<String>,
+        reusable: bool,
+        monitor: Option<ProgramInvocationMonitor>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, program_invocation_name, list_of_domain_names, reusable, monitor, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub fn list_of_domain_names(&self) -> &[String] {
+        &self.list_of_domain_names
+    }
+
+    pub fn reusable(&self) -> bool {
+        self.reusable
+    }
+
+    /// The program invocation is not monitored if this is not present.
+    pub fn monitor(&self) -> &Option<ProgramInvocationMonitor> {
+        &self.monitor
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::CreateProgramInvocation }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct DeleteProgramInvocationMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DeleteProgramInvocationMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeleteProgramInvocationMmsServiceMessage").field("invocation_id", &self.invocation_id).field("program_invocation_name", &self.program_invocation_name).finish()
+    }
+}
+impl DeleteProgramInvocationMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, program_invocation_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, program_invocation_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DeleteProgramInvocation }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct StartMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    execution_argument: Option<String>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for StartMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StartMmsServiceMessage").field("invocation_id", &self.invocation_id).field("program_invocation_name", &self.program_invocation_name).field("execution_argument", &self.execution_argument).finish()
+    }
+}
+impl StartMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        program_invocation_name: String,
+        execution_argument: Option<String>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, program_invocation_name, execution_argument, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub fn execution_argument(&self) -> &Option<String> {
+        &self.execution_argument
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::Start }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct StopMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for StopMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StopMmsServiceMessage").field("invocation_id", &self.invocation_id).field("program_invocation_name", &self.program_invocation_name).finish()
+    }
+}
+impl StopMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, program_invocation_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, program_invocation_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::Stop }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct ResumeMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    execution_argument: Option<String>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for ResumeMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResumeMmsServiceMessage").field("invocation_id", &self.invocation_id).field("program_invocation_name", &self.program_invocation_name).field("execution_argument", &self.execution_argument).finish()
+    }
+}
+impl ResumeMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        program_invocation_name: String,
+        execution_argument: Option<String>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, program_invocation_name, execution_argument, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub fn execution_argument(&self) -> &Option<String> {
+        &self.execution_argument
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::Resume }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct ResetMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for ResetMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ResetMmsServiceMessage").field("invocation_id", &self.invocation_id).field("program_invocation_name", &self.program_invocation_name).finish()
+    }
+}
+impl ResetMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, program_invocation_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, program_invocation_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::Reset }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct KillMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for KillMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KillMmsServiceMessage").field("invocation_id", &self.invocation_id).field("program_invocation_name", &self.program_invocation_name).finish()
+    }
+}
+impl KillMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, program_invocation_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, program_invocation_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::Kill }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct GetProgramInvocationAttributesMmsServiceMessage {
+    invocation_id: u32,
+    program_invocation_name: String,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for GetProgramInvocationAttributesMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetProgramInvocationAttributesMmsServiceMessage").field("invocation_id", &self.invocation_id).field("program_invocation_name", &self.program_invocation_name).finish()
+    }
+}
+impl GetProgramInvocationAttributesMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, program_invocation_name: String, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, program_invocation_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn program_invocation_name(&self) -> &str {
+        &self.program_invocation_name
+    }
+
+    pub async fn respond(self, attributes: ProgramInvocationAttributes) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::GetProgramInvocationAttributes {
+                state: attributes.state,
+                list_of_domain_names: attributes.list_of_domain_names,
+                mms_deletable: attributes.deletable,
+                reusable: attributes.reusable,
+                monitor: attributes.monitor,
+                execution_argument: attributes.execution_argument,
+            },
+        })
+        .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct ObtainFileMmsServiceMessage {
+    invocation_id: u32,
+    source_file: Vec<String>,
+    destination_file: Vec<String>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for ObtainFileMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ObtainFileMmsServiceMessage").field("invocation_id", &self.invocation_id).field("source_file", &self.source_file).field("destination_file", &self.destination_file).finish()
+    }
+}
+impl ObtainFileMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, source_file: Vec<String>, destination_file: Vec<String>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, source_file, destination_file, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    /// The file to obtain from the requesting application.
+    pub fn source_file(&self) -> &Vec<String> {
+        &self.source_file
+    }
+
+    pub fn destination_file(&self) -> &Vec<String> {
+        &self.destination_file
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::ObtainFile }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct FileOpenMmsServiceMessage {
+    invocation_id: u32,
+    file_name: Vec<String>,
+    initial_position: u32,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for FileOpenMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileOpenMmsServiceMessage").field("invocation_id", &self.invocation_id).field("file_name", &self.file_name).field("initial_position", &self.initial_position).finish()
+    }
+}
+impl FileOpenMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        file_name: Vec<String>,
+        initial_position: Vec<u8>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Result<Self, MmsServiceError> {
+        let initial_position = BigInt::from_signed_bytes_be(&initial_position).try_into().map_err(to_mms_error("Failed to parse Initial Position on File Open"))?;
+        Ok(Self { invocation_id, file_name, initial_position, response, cancellation })
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn file_name(&self) -> &Vec<String> {
+        &self.file_name
+    }
+
+    pub fn initial_position(&self) -> u32 {
+        self.initial_position
+    }
+
+    pub async fn respond(self, frsm_id: i32, file_attributes: FileAttributes) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::FileOpen { frsm_id: BigInt::from(frsm_id).to_signed_bytes_be(), file_attributes: convert_high_level_file_attributes_to_low_level_file_attributes(file_attributes) },
+        })
+        .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct FileReadMmsServiceMessage {
+    invocation_id: u32,
+    frsm_id: i32,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for FileReadMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileReadMmsServiceMessage").field("invocation_id", &self.invocation_id).field("frsm_id", &self.frsm_id).finish()
+    }
+}
+impl FileReadMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, frsm_id: Vec<u8>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Result<Self, MmsServiceError> {
+        let frsm_id = BigInt::from_signed_bytes_be(&frsm_id).try_into().map_err(to_mms_error("Failed to parse FRSM ID on File Read"))?;
+        Ok(Self { invocation_id, frsm_id, response, cancellation })
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn frsm_id(&self) -> i32 {
+        self.frsm_id
+    }
+
+    pub async fn respond(self, segment: FileSegment) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::FileRead { file_data: segment.file_data, more_follows: Some(segment.more_follows) },
+        })
+        .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct FileCloseMmsServiceMessage {
+    invocation_id: u32,
+    frsm_id: i32,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for FileCloseMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileCloseMmsServiceMessage").field("invocation_id", &self.invocation_id).field("frsm_id", &self.frsm_id).finish()
+    }
+}
+impl FileCloseMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, frsm_id: Vec<u8>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Result<Self, MmsServiceError> {
+        let frsm_id = BigInt::from_signed_bytes_be(&frsm_id).try_into().map_err(to_mms_error("Failed to parse FRSM ID on File Close"))?;
+        Ok(Self { invocation_id, frsm_id, response, cancellation })
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn frsm_id(&self) -> i32 {
+        self.frsm_id
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::FileClose }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct FileRenameMmsServiceMessage {
+    invocation_id: u32,
+    current_file_name: Vec<String>,
+    new_file_name: Vec<String>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for FileRenameMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileRenameMmsServiceMessage").field("invocation_id", &self.invocation_id).field("current_file_name", &self.current_file_name).field("new_file_name", &self.new_file_name).finish()
+    }
+}
+impl FileRenameMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        current_file_name: Vec<String>,
+        new_file_name: Vec<String>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, current_file_name, new_file_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn current_file_name(&self) -> &Vec<String> {
+        &self.current_file_name
+    }
+
+    pub fn new_file_name(&self) -> &Vec<String> {
+        &self.new_file_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::FileRename }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct FileDeleteMmsServiceMessage {
+    invocation_id: u32,
+    file_name: Vec<String>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for FileDeleteMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileDeleteMmsServiceMessage").field("invocation_id", &self.invocation_id).field("file_name", &self.file_name).finish()
+    }
+}
+impl FileDeleteMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, file_name: Vec<String>, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, file_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn file_name(&self) -> &Vec<String> {
+        &self.file_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::FileDelete }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct FileDirectoryMmsServiceMessage {
+    invocation_id: u32,
+    file_specification: Option<Vec<String>>,
+    continue_after: Option<Vec<String>>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for FileDirectoryMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("FileDirectoryMmsServiceMessage").field("invocation_id", &self.invocation_id).field("file_specification", &self.file_specification).field("continue_after", &self.continue_after).finish()
+    }
+}
+impl FileDirectoryMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        file_specification: Option<Vec<String>>,
+        continue_after: Option<Vec<String>>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, file_specification, continue_after, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn file_specification(&self) -> &Option<Vec<String>> {
+        &self.file_specification
+    }
+
+    pub fn continue_after(&self) -> &Option<Vec<String>> {
+        &self.continue_after
+    }
+
+    pub async fn respond(self, directory: FileDirectory) -> Result<(), MmsServiceError> {
+        let list_of_directory_entry =
+            directory.list_of_directory_entry.into_iter().map(|entry| MmsDirectoryEntry { file_name: entry.file_name, file_attributes: convert_high_level_file_attributes_to_low_level_file_attributes(entry.file_attributes) }).collect();
+        let more_follows = if directory.more_follows { Some(true) } else { None };
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::FileDirectory { list_of_directory_entry, more_follows } })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct ReadJournalMmsServiceMessage {
+    invocation_id: u32,
+    journal_name: MmsObjectName,
+    specification: JournalReadSpecification,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for ReadJournalMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadJournalMmsServiceMessage").field("invocation_id", &self.invocation_id).field("journal_name", &self.journal_name).field("specification", &self.specification).finish()
+    }
+}
+impl ReadJournalMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        journal_name: MmsObjectName,
+        specification: JournalReadSpecification,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, journal_name, specification, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn journal_name(&self) -> &MmsObjectName {
+        &self.journal_name
+    }
+
+    pub fn specification(&self) -> &JournalReadSpecification {
+        &self.specification
+    }
+
+    pub async fn respond(self, entries: JournalEntries) -> Result<(), MmsServiceError> {
+        let list_of_journal_entry = entries.list_of_journal_entry.iter().map(convert_high_level_journal_entry_to_low_level_journal_entry).collect::<Result<Vec<_>, MmsError>>()?;
+        let more_follows = if entries.more_follows { Some(true) } else { None };
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::ReadJournal { list_of_journal_entry, more_follows } })
+            .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct WriteJournalMmsServiceMessage {
+    invocation_id: u32,
+    journal_name: MmsObjectName,
+    list_of_journal_entry: Vec<JournalEntryContent>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for WriteJournalMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriteJournalMmsServiceMessage").field("invocation_id", &self.invocation_id).field("journal_name", &self.journal_name).field("list_of_journal_entry", &self.list_of_journal_entry).finish()
+    }
+}
+impl WriteJournalMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        journal_name: MmsObjectName,
+        list_of_journal_entry: Vec<MmsEntryContent>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Result<Self, MmsServiceError> {
+        let list_of_journal_entry = list_of_journal_entry.iter().map(convert_low_level_entry_content_to_high_level_entry_content).collect::<Result<Vec<_>, MmsError>>()?;
+        Ok(Self { invocation_id, journal_name, list_of_journal_entry, response, cancellation })
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn journal_name(&self) -> &MmsObjectName {
+        &self.journal_name
+    }
+
+    pub fn list_of_journal_entry(&self) -> &Vec<JournalEntryContent> {
+        &self.list_of_journal_entry
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::WriteJournal }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct InitializeJournalMmsServiceMessage {
+    invocation_id: u32,
+    journal_name: MmsObjectName,
+    limit_specification: Option<MmsJournalLimitSpecification>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for InitializeJournalMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InitializeJournalMmsServiceMessage").field("invocation_id", &self.invocation_id).field("journal_name", &self.journal_name).field("limit_specification", &self.limit_specification).finish()
+    }
+}
+impl InitializeJournalMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        journal_name: MmsObjectName,
+        limit_specification: Option<MmsJournalLimitSpecification>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, journal_name, limit_specification, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn journal_name(&self) -> &MmsObjectName {
+        &self.journal_name
+    }
+
+    /// The entries to delete. Every entry is deleted if this is not present.
+    pub fn limit_specification(&self) -> &Option<MmsJournalLimitSpecification> {
+        &self.limit_specification
+    }
+
+    pub async fn respond(self, entries_deleted: u32) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::InitializeJournal { entries_deleted: BigInt::from(entries_deleted).to_signed_bytes_be() },
+        })
+        .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct ReportJournalStatusMmsServiceMessage {
+    invocation_id: u32,
+    journal_name: MmsObjectName,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for ReportJournalStatusMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReportJournalStatusMmsServiceMessage").field("invocation_id", &self.invocation_id).field("journal_name", &self.journal_name).finish()
+    }
+}
+impl ReportJournalStatusMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, journal_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, journal_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn journal_name(&self) -> &MmsObjectName {
+        &self.journal_name
+    }
+
+    pub async fn respond(self, status: JournalStatus) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::ReportJournalStatus { current_entries: BigInt::from(status.current_entries).to_signed_bytes_be(), mms_deletable: status.deletable },
+        })
+        .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct CreateJournalMmsServiceMessage {
+    invocation_id: u32,
+    journal_name: MmsObjectName,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for CreateJournalMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CreateJournalMmsServiceMessage").field("invocation_id", &self.invocation_id).field("journal_name", &self.journal_name).finish()
+    }
+}
+impl CreateJournalMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, journal_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, journal_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn journal_name(&self) -> &MmsObjectName {
+        &self.journal_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::CreateJournal }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct DeleteJournalMmsServiceMessage {
+    invocation_id: u32,
+    journal_name: MmsObjectName,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DeleteJournalMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeleteJournalMmsServiceMessage").field("invocation_id", &self.invocation_id).field("journal_name", &self.journal_name).finish()
+    }
+}
+impl DeleteJournalMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, journal_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, journal_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn journal_name(&self) -> &MmsObjectName {
+        &self.journal_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DeleteJournal }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct DefineEventConditionMmsServiceMessage {
+    invocation_id: u32,
+    event_condition_name: MmsObjectName,
+    definition: EventConditionDefinition,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DefineEventConditionMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DefineEventConditionMmsServiceMessage").field("invocation_id", &self.invocation_id).field("event_condition_name", &self.event_condition_name).field("definition", &self.definition).finish()
+    }
+}
+impl DefineEventConditionMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        event_condition_name: MmsObjectName,
+        definition: EventConditionDefinition,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, event_condition_name, definition, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn event_condition_name(&self) -> &MmsObjectName {
+        &self.event_condition_name
+    }
+
+    pub fn definition(&self) -> &EventConditionDefinition {
+        &self.definition
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DefineEventCondition }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct AlterEventConditionMonitoringMmsServiceMessage {
+    invocation_id: u32,
+    event_condition_name: MmsObjectName,
+    monitoring: EventConditionMonitoring,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for AlterEventConditionMonitoringMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlterEventConditionMonitoringMmsServiceMessage").field("invocation_id", &self.invocation_id).field("event_condition_name", &self.event_condition_name).field("monitoring", &self.monitoring).finish()
+    }
+}
+impl AlterEventConditionMonitoringMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        event_condition_name: MmsObjectName,
+        monitoring: EventConditionMonitoring,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, event_condition_name, monitoring, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn event_condition_name(&self) -> &MmsObjectName {
+        &self.event_condition_name
+    }
+
+    /// Only the attributes that are present are to be altered.
+    pub fn monitoring(&self) -> &EventConditionMonitoring {
+        &self.monitoring
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::AlterEventConditionMonitoring }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct GetEventConditionAttributesMmsServiceMessage {
+    invocation_id: u32,
+    event_condition_name: MmsObjectName,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for GetEventConditionAttributesMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("GetEventConditionAttributesMmsServiceMessage").field("invocation_id", &self.invocation_id).field("event_condition_name", &self.event_condition_name).finish()
+    }
+}
+impl GetEventConditionAttributesMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, event_condition_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, event_condition_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn event_condition_name(&self) -> &MmsObjectName {
+        &self.event_condition_name
+    }
+
+    pub async fn respond(self, attributes: EventConditionAttributes) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::GetEventConditionAttributes {
+                mms_deletable: if attributes.deletable { Some(true) } else { None },
+                class: attributes.class,
+                priority: Some(BigInt::from(attributes.priority).to_signed_bytes_be()),
+                severity: Some(BigInt::from(attributes.severity).to_signed_bytes_be()),
+                alarm_summary_reports: if attributes.alarm_summary_reports { Some(true) } else { None },
+                monitored_variable: attributes.monitored_variable,
+                evaluation_interval: attributes.evaluation_interval.map(|x| BigInt::from(x).to_signed_bytes_be()),
+            },
+        })
+        .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct DefineEventEnrollmentMmsServiceMessage {
+    invocation_id: u32,
+    event_enrollment_name: MmsObjectName,
+    definition: EventEnrollmentDefinition,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DefineEventEnrollmentMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DefineEventEnrollmentMmsServiceMessage").field("invocation_id", &self.invocation_id).field("event_enrollment_name", &self.event_enrollment_name).field("definition", &self.definition).finish()
+    }
+}
+impl DefineEventEnrollmentMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        event_enrollment_name: MmsObjectName,
+        definition: EventEnrollmentDefinition,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, event_enrollment_name, definition, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn event_enrollment_name(&self) -> &MmsObjectName {
+        &self.event_enrollment_name
+    }
+
+    pub fn definition(&self) -> &EventEnrollmentDefinition {
+        &self.definition
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DefineEventEnrollment }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct AcknowledgeEventNotificationMmsServiceMessage {
+    invocation_id: u32,
+    event_enrollment_name: MmsObjectName,
+    acknowledged_state: MmsEventConditionState,
+    time_of_acknowledged_transition: EventTime,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for AcknowledgeEventNotificationMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AcknowledgeEventNotificationMmsServiceMessage")
+            .field("invocation_id", &self.invocation_id)
+            .field("event_enrollment_name", &self.event_enrollment_name)
+            .field("acknowledged_state", &self.acknowledged_state)
+            .field("time_of_acknowledged_transition", &self.time_of_acknowledged_transition)
+            .finish()
+    }
+}
+impl AcknowledgeEventNotificationMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        event_enrollment_name: MmsObjectName,
+        acknowledged_state: MmsEventConditionState,
+        time_of_acknowledged_transition: EventTime,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, event_enrollment_name, acknowledged_state, time_of_acknowledged_transition, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn event_enrollment_name(&self) -> &MmsObjectName {
+        &self.event_enrollment_name
+    }
+
+    pub fn acknowledged_state(&self) -> &MmsEventConditionState {
+        &self.acknowledged_state
+    }
+
+    pub fn time_of_acknowledged_transition(&self) -> &EventTime {
+        &self.time_of_acknowledged_transition
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::AcknowledgeEventNotification }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct TakeControlMmsServiceMessage {
+    invocation_id: u32,
+    semaphore_name: MmsObjectName,
+    options: TakeControlOptions,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for TakeControlMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("TakeControlMmsServiceMessage").field("invocation_id", &self.invocation_id).field("semaphore_name", &self.semaphore_name).field("options", &self.options).finish()
+    }
+}
+impl TakeControlMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        semaphore_name: MmsObjectName,
+        options: TakeControlOptions,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, semaphore_name, options, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn semaphore_name(&self) -> &MmsObjectName {
+        &self.semaphore_name
+    }
+
+    pub fn options(&self) -> &TakeControlOptions {
+        &self.options
+    }
+
+    /// Grants control of the semaphore. A pool semaphore must name the token that was granted.
+    pub async fn respond(self, named_token: Option<String>) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::TakeControl { named_token } }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct RelinquishControlMmsServiceMessage {
+    invocation_id: u32,
+    semaphore_name: MmsObjectName,
+    named_token: Option<String>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for RelinquishControlMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RelinquishControlMmsServiceMessage").field("invocation_id", &self.invocation_id).field("semaphore_name", &self.semaphore_name).field("named_token", &self.named_token).finish()
+    }
+}
+impl RelinquishControlMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        semaphore_name: MmsObjectName,
+        named_token: Option<String>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self { invocation_id, semaphore_name, named_token, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn semaphore_name(&self) -> &MmsObjectName {
+        &self.semaphore_name
+    }
+
+    pub fn named_token(&self) -> &Option<String> {
+        &self.named_token
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::RelinquishControl }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct DefineSemaphoreMmsServiceMessage {
+    invocation_id: u32,
+    semaphore_name: MmsObjectName,
+    number_of_tokens: u16,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DefineSemaphoreMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DefineSemaphoreMmsServiceMessage").field("invocation_id", &self.invocation_id).field("semaphore_name", &self.semaphore_name).field("number_of_tokens", &self.number_of_tokens).finish()
+    }
+}
+impl DefineSemaphoreMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        semaphore_name: MmsObjectName,
+        number_of_tokens: Vec<u8>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Result<Self, MmsServiceError> {
+        let number_of_tokens = BigInt::from_signed_bytes_be(&number_of_tokens).try_into().map_err(to_mms_error("Failed to parse Number of Tokens"))?;
+        Ok(Self { invocation_id, semaphore_name, number_of_tokens, response, cancellation })
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn semaphore_name(&self) -> &MmsObjectName {
+        &self.semaphore_name
+    }
+
+    pub fn number_of_tokens(&self) -> u16 {
+        self.number_of_tokens
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DefineSemaphore }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct DeleteSemaphoreMmsServiceMessage {
+    invocation_id: u32,
+    semaphore_name: MmsObjectName,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for DeleteSemaphoreMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeleteSemaphoreMmsServiceMessage").field("invocation_id", &self.invocation_id).field("semaphore_name", &self.semaphore_name).finish()
+    }
+}
+impl DeleteSemaphoreMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, semaphore_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, semaphore_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn semaphore_name(&self) -> &MmsObjectName {
+        &self.semaphore_name
+    }
+
+    pub async fn respond(self) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), response: rusty_mms::MmsConfirmedResponse::DeleteSemaphore }).await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct ReportSemaphoreStatusMmsServiceMessage {
+    invocation_id: u32,
+    semaphore_name: MmsObjectName,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for ReportSemaphoreStatusMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReportSemaphoreStatusMmsServiceMessage").field("invocation_id", &self.invocation_id).field("semaphore_name", &self.semaphore_name).finish()
+    }
+}
+impl ReportSemaphoreStatusMmsServiceMessage {
+    pub(crate) fn new(invocation_id: u32, semaphore_name: MmsObjectName, response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>, cancellation: MmsServiceCancellation) -> Self {
+        Self { invocation_id, semaphore_name, response, cancellation }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn semaphore_name(&self) -> &MmsObjectName {
+        &self.semaphore_name
+    }
+
+    pub async fn respond(self, status: SemaphoreStatus) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::ReportSemaphoreStatus {
+                mms_deletable: status.deletable,
+                class: status.class,
+                number_of_tokens: BigInt::from(status.number_of_tokens).to_signed_bytes_be(),
+                number_of_owned_tokens: BigInt::from(status.number_of_owned_tokens).to_signed_bytes_be(),
+                number_of_hung_tokens: BigInt::from(status.number_of_hung_tokens).to_signed_bytes_be(),
+            },
+        })
+        .await?;
+        Ok(())
+    }
+
+    pub async fn respond_with_error(self, service_error: MmsServiceErrorInfo) -> Result<(), MmsServiceError> {
+        (self.response)(MmsMessage::ConfirmedError { invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(), service_error }).await?;
+        Ok(())
+    }
+}
+
+pub struct ReadMmsServiceMessage {
+    invocation_id: u32,
+    specification_with_result: bool,
+    specification: MmsVariableAccessSpecification,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for ReadMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReadMmsServiceMessage").field("invocation_id", &self.invocation_id).field("specification_with_result", &self.specification_with_result).field("specification", &self.specification).finish()
+    }
+}
+impl ReadMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        specification: MmsVariableAccessSpecification,
+        specification_with_result: Option<bool>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Self {
+        Self {
+            invocation_id,
+            // TODO HIGH What is the default behaviour for this?
+            specification_with_result: specification_with_result.unwrap_or(true),
+            specification,
+            response,
+            cancellation,
+        }
+    }
+
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
+    }
+
+    pub fn specification(&self) -> &MmsVariableAccessSpecification {
+        &self.specification
+    }
+
+    pub async fn respond(self, access_results: Vec<MmsServiceAccessResult>) -> Result<(), MmsServiceError> {
+        let variable_access_specification = if self.specification_with_result { Some(self.specification) } else { None };
+        (self.response)(MmsMessage::ConfirmedResponse {
+            invocation_id: BigInt::from(self.invocation_id).to_signed_bytes_be().to_vec(),
+            response: rusty_mms::MmsConfirmedResponse::Read {
+                variable_access_specification,
+                access_results: access_results
+                    .into_iter()
+                    .map(|x| match x {
+                        MmsServiceAccessResult::Success(mms_service_data) => Ok(MmsAccessResult::Success(convert_high_level_data_to_low_level_data(&mms_service_data)?)),
+                        MmsServiceAccessResult::Failure(mms_access_error) => Ok(MmsAccessResult::Failure(mms_access_error)),
+                    })
+                    .collect::<Result<Vec<MmsAccessResult>, MmsServiceError>>()?,
+            },
+        })
+        .await?;
+        Ok(())
+    }
+}
+
+pub struct WriteMmsServiceMessage {
+    invocation_id: u32,
+    specification: MmsVariableAccessSpecification,
+    values: Vec<MmsServiceData>,
+    response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+    cancellation: MmsServiceCancellation,
+}
+impl fmt::Debug for WriteMmsServiceMessage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WriteMmsServiceMessage").field("invocation_id", &self.invocation_id).field("specification", &self.specification).field("values", &self.values).finish()
+    }
+}
+impl WriteMmsServiceMessage {
+    pub(crate) fn new(
+        invocation_id: u32,
+        specification: MmsVariableAccessSpecification,
+        values: Vec<MmsData>,
+        response: Box<dyn Fn(MmsMessage) -> BoxFuture<'static, Result<(), MmsError>> + Send + Sync>,
+        cancellation: MmsServiceCancellation,
+    ) -> Result<Self, MmsServiceError> {
+        let mut high_level_values = vec![];
+        for value in values {
+            high_level_values.push(convert_low_level_data_to_high_level_data(&value)?);
+        }
+
+        Ok(Self { invocation_id, specification, values: high_level_values, response, cancellation })
+    }
 
-        Ok(Self { invocation_id, specification, values: high_level_values, response })
+    pub fn cancellation(&self) -> &MmsServiceCancellation {
+        &self.cancellation
     }
 
     pub fn specification(&self) -> &MmsVariableAccessSpecification {
@@ -271,14 +2311,62 @@ impl WriteMmsServiceMessage {
 // FIXME TODO Need to implement Drop so an error may be returned if a response is not provided.
 #[derive(Debug)]
 pub enum MmsServiceMessage {
+    Conclude(ConcludeMmsServiceMessage),
     Identify(IdentifyMmsServiceMessage),
+    Status(StatusMmsServiceMessage),
+    GetCapabilityList(GetCapabilityListMmsServiceMessage),
+    Rename(RenameMmsServiceMessage),
     GetNameList(GetNameListMmsServiceMessage),
     GetVariableAccessAttributes(GetVariableAccessAttributesMmsServiceMessage),
     DefineNamedVariableList(DefineNamedVariableListMmsServiceMessage),
     GetNamedVariableListAttributes(GetNamedVariableListAttributesMmsServiceMessage),
     DeleteNamedVariableList(DeleteNamedVariableListMmsServiceMessage),
+    DefineNamedType(DefineNamedTypeMmsServiceMessage),
+    GetNamedTypeAttributes(GetNamedTypeAttributesMmsServiceMessage),
+    DeleteNamedType(DeleteNamedTypeMmsServiceMessage),
+    InitiateDownloadSequence(InitiateDownloadSequenceMmsServiceMessage),
+    DownloadSegment(DownloadSegmentMmsServiceMessage),
+    TerminateDownloadSequence(TerminateDownloadSequenceMmsServiceMessage),
+    InitiateUploadSequence(InitiateUploadSequenceMmsServiceMessage),
+    UploadSegment(UploadSegmentMmsServiceMessage),
+    TerminateUploadSequence(TerminateUploadSequenceMmsServiceMessage),
+    DeleteDomain(DeleteDomainMmsServiceMessage),
+    GetDomainAttributes(GetDomainAttributesMmsServiceMessage),
+    CreateProgramInvocation(CreateProgramInvocationMmsServiceMessage),
+    DeleteProgramInvocation(DeleteProgramInvocationMmsServiceMessage),
+    Start(StartMmsServiceMessage),
+    Stop(StopMmsServiceMessage),
+    Resume(ResumeMmsServiceMessage),
+    Reset(ResetMmsServiceMessage),
+    Kill(KillMmsServiceMessage),
+    GetProgramInvocationAttributes(GetProgramInvocationAttributesMmsServiceMessage),
+    ObtainFile(ObtainFileMmsServiceMessage),
+    FileOpen(FileOpenMmsServiceMessage),
+    FileRead(FileReadMmsServiceMessage),
+    FileClose(FileCloseMmsServiceMessage),
+    FileRename(FileRenameMmsServiceMessage),
+    FileDelete(FileDeleteMmsServiceMessage),
+    FileDirectory(FileDirectoryMmsServiceMessage),
+    ReadJournal(ReadJournalMmsServiceMessage),
+    WriteJournal(WriteJournalMmsServiceMessage),
+    InitializeJournal(InitializeJournalMmsServiceMessage),
+    ReportJournalStatus(ReportJournalStatusMmsServiceMessage),
+    CreateJournal(CreateJournalMmsServiceMessage),
+    DeleteJournal(DeleteJournalMmsServiceMessage),
+    DefineEventCondition(DefineEventConditionMmsServiceMessage),
+    AlterEventConditionMonitoring(AlterEventConditionMonitoringMmsServiceMessage),
+    GetEventConditionAttributes(GetEventConditionAttributesMmsServiceMessage),
+    DefineEventEnrollment(DefineEventEnrollmentMmsServiceMessage),
+    AcknowledgeEventNotification(AcknowledgeEventNotificationMmsServiceMessage),
+    TakeControl(TakeControlMmsServiceMessage),
+    RelinquishControl(RelinquishControlMmsServiceMessage),
+    DefineSemaphore(DefineSemaphoreMmsServiceMessage),
+    DeleteSemaphore(DeleteSemaphoreMmsServiceMessage),
+    ReportSemaphoreStatus(ReportSemaphoreStatusMmsServiceMessage),
     Read(ReadMmsServiceMessage),
     Write(WriteMmsServiceMessage),
 
     InformationReport(InformationReportMmsServiceMessage),
+    EventNotification(EventNotification),
+    UnsolicitedStatus(VmdStatus),
 }
@@ -0,0 +1,55 @@
+use rusty_mms::MmsObjectName;
+use tracing::warn;
+
+use crate::{RustyMmsServiceClient, data::TakeControlOptions, error::MmsServiceError};
+
+/// Takes control of a semaphore. Control is relinquished when the returned guard is dropped.
+pub async fn take_semaphore_control<C: RustyMmsServiceClient + ?Sized>(client: &mut C, semaphore_name: MmsObjectName, options: TakeControlOptions) -> Result<SemaphoreControl, MmsServiceError> {
+    let named_token = client.take_control(semaphore_name.clone(), options).await?;
+    Ok(SemaphoreControl { client: Some(client.clone()), semaphore_name, named_token })
+}
+
+/// Holds control of a semaphore until it is relinquished.
+///
+/// Dropping the guard relinquishes control in the background, so a failure to relinquish is not reported. Use relinquish to find out whether the server released control.
+pub struct SemaphoreControl {
+    client: Option<Box<dyn RustyMmsServiceClient>>,
+    semaphore_name: MmsObjectName,
+    named_token: Option<String>,
+}
+
+impl SemaphoreControl {
+    pub fn semaphore_name(&self) -> &MmsObjectName {
+        &self.semaphore_name
+    }
+
+    /// The token granted by a pool semaphore.
+    pub fn named_token(&self) -> &Option<String> {
+        &self.named_token
+    }
+
+    pub async fn relinquish(mut self) -> Result<(), MmsServiceError> {
+        match self.client.take() {
+            Some(mut client) => client.relinquish_control(self.semaphore_name.clone(), self.named_token.take()).await,
+            None => Ok(()),
+        }
+    }
+}
+
+impl Drop for SemaphoreControl {
+    fn drop(&mut self) {
+        if let Some(mut client) = self.client.take() {
+            // The guard may be dropped outside of a runtime, such as after the runtime has shut down.
+            let Ok(runtime) = tokio::runtime::Handle::try_current() else {
+                warn!("Control of semaphore {:?} was not relinquished as there is no runtime to send the request on.", self.semaphore_name);
+                return;
+            };
+            let semaphore_name = self.semaphore_name.clone();
+            let named_token = self.named_token.take();
+            runtime.spawn(async move {
+                // Ignore errors here. The association may already be gone, in which case the server relinquishes control unless asked not to.
+                let _ = client.relinquish_control(semaphore_name, named_token).await;
+            });
+        }
+    }
+}
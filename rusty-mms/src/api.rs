@@ -1,9 +1,11 @@
 use std::collections::VecDeque;
 
 use der_parser::Oid;
-use rusty_acse::AcseError;
+use rusty_acse::{AcseError, AcseResponseInformation, ReleaseResult};
 use thiserror::Error;
 
+use crate::{MmsBinaryTime, MmsUtcTime, parameters::ServiceSupportOption};
+
 /**
  * This MMS stack is designed to be used with ICCP/TASE2.
  * The Packet API itself is left intentionally low-level.
@@ -15,12 +17,16 @@ use thiserror::Error;
  * - str1
  * - str2
  * - vnam
- * - valt -- Optional. Leaving out for now.
+ * - valt
  * - vlis
  *
  * VMD Support
+ * - Status
+ * - UnsolicitedStatus
  * - GetNameList
  * - Identify
+ * - Rename
+ * - GetCapabilityList
  *    
  * Variable Access
  * - Read
@@ -95,13 +101,41 @@ pub enum MmsScope {
 pub enum MmsVariableAccessSpecification {
     ListOfVariables(Vec<ListOfVariablesItem>),
     VariableListName(MmsObjectName),
-    // AlternateAccess, TODO Part of valt
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct ListOfVariablesItem {
     pub variable_specification: VariableSpecification,
-    // Using a struct as this has an optional part that is currently not supported.
+    /// Selects part of the variable instead of the whole variable. Requires the valt parameter CBB.
+    pub alternate_access: Option<Vec<MmsAlternateAccessItem>>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsAlternateAccessItem {
+    Unnamed(MmsAlternateAccessSelection),
+    /// The selected part is named in the result so it may be told apart from the other selections.
+    Named {
+        component_name: String,
+        access: MmsAlternateAccessSelection,
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsAlternateAccessSelection {
+    /// Selects part of the variable and then applies further alternate access to that part.
+    SelectAlternateAccess {
+        access_selection: MmsAccessSelection,
+        alternate_access: Vec<MmsAlternateAccessItem>,
+    },
+    SelectAccess(MmsAccessSelection),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsAccessSelection {
+    Component(String),
+    Index(Vec<u8> /* u32 */),
+    IndexRange { low_index: Vec<u8> /* u32 */, number_of_elements: Vec<u8> /* u32 */ },
+    AllElements,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -152,11 +186,12 @@ pub enum MmsData {
     OctetString(Vec<u8>),
     VisibleString(String),
     GeneralizedTime(String),
-    BinaryTime(Vec<u8>),
+    BinaryTime(MmsBinaryTime),
     Bcd(Vec<u8>),
     BooleanArray(u8, Vec<u8>),
     ObjectId(Oid<'static>),
     MmsString(String),
+    UtcTime(MmsUtcTime),
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -181,6 +216,7 @@ pub enum MmsTypeDescription {
     Bcd(Vec<u8> /* u8 */),
     ObjId,
     MmsString(Vec<u8> /* i32 */),
+    UtcTime,
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -193,18 +229,60 @@ pub struct MmsTypeDescriptionComponent {
 pub enum MmsRecvResult {
     Closed,
     Message(MmsMessage),
+    /// The remote side requested the release of the association, typically after a conclude. This must be answered with a release response on the writer.
+    ReleaseRequest,
+    /// The remote side answered the release request. The reader and writer may be dropped if it was affirmative, otherwise the association stays open.
+    ReleaseResponse(ReleaseResult),
+    /// A response or error to a local confirmed request could not be processed. A reject has already been sent to the peer so the request will not be answered.
+    ResponseRejected {
+        original_invocation_id: Vec<u8>,
+        reject_reason: MmsRejectReason,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum MmsMessage {
     // InitiateRequest, Handled internally
     // InitiateResponse, Handled internally
-    ConfirmedRequest { invocation_id: Vec<u8>, request: MmsConfirmedRequest },
-    ConfirmedResponse { invocation_id: Vec<u8>, response: MmsConfirmedResponse },
-    Unconfirmed { unconfirmed_service: MmsUnconfirmedService },
+    ConfirmedRequest {
+        invocation_id: Vec<u8>,
+        request: MmsConfirmedRequest,
+    },
+    ConfirmedResponse {
+        invocation_id: Vec<u8>,
+        response: MmsConfirmedResponse,
+    },
+    ConfirmedError {
+        invocation_id: Vec<u8>,
+        service_error: MmsServiceErrorInfo,
+    },
+    /// Sent in place of a response when a PDU could not be processed. The invocation id is present when it could be recovered from the rejected PDU.
+    Reject {
+        original_invocation_id: Option<Vec<u8>>,
+        reject_reason: MmsRejectReason,
+    },
+    /// Asks the peer to abandon the outstanding confirmed request with this invocation id.
+    CancelRequest {
+        invocation_id: Vec<u8>,
+    },
+    /// The request was cancelled. The cancelled request itself is answered with a service preempt error.
+    CancelResponse {
+        invocation_id: Vec<u8>,
+    },
+    CancelError {
+        invocation_id: Vec<u8>,
+        service_error: MmsServiceErrorInfo,
+    },
+    Unconfirmed {
+        unconfirmed_service: MmsUnconfirmedService,
+    },
 
-    ConcludeRequest { request: MmsConcludeRequest },
-    ConcludeResponse { request: MmsConcludeResponse },
+    ConcludeRequest {
+        request: MmsConcludeRequest,
+    },
+    ConcludeResponse {
+        request: MmsConcludeResponse,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -221,6 +299,17 @@ pub enum MmsConfirmedRequest {
         continue_after: Option<String>, // MMS Identifier
     },
     Identify,
+    Status {
+        extended_derivation: bool,
+    },
+    GetCapabilityList {
+        continue_after: Option<String>,
+    },
+    Rename {
+        object_class: MmsObjectClass,
+        current_name: MmsObjectName,
+        new_identifier: String,
+    },
     Read {
         specification_with_result: Option<bool>,
         variable_access_specification: MmsVariableAccessSpecification,
@@ -244,6 +333,180 @@ pub enum MmsConfirmedRequest {
         list_of_variable_list_names: Option<Vec<MmsObjectName>>,
         domain_name: Option<String>,
     },
+    DefineNamedType {
+        type_name: MmsObjectName,
+        type_specification: MmsTypeSpecification,
+    },
+    GetNamedTypeAttributes {
+        type_name: MmsObjectName,
+    },
+    DeleteNamedType {
+        scope_of_delete: Option<MmsScope>, // Default: Specific
+        list_of_type_names: Option<Vec<MmsObjectName>>,
+        domain_name: Option<String>,
+    },
+    InitiateDownloadSequence {
+        domain_name: String, // MMS Identifier
+        list_of_capabilities: Vec<String>,
+        sharable: bool,
+    },
+    DownloadSegment {
+        domain_name: String, // MMS Identifier
+    },
+    TerminateDownloadSequence {
+        domain_name: String, // MMS Identifier
+        discard: Option<MmsServiceErrorInfo>,
+    },
+    InitiateUploadSequence {
+        domain_name: String, // MMS Identifier
+    },
+    UploadSegment {
+        ulsm_id: Vec<u8>, // i32
+    },
+    TerminateUploadSequence {
+        ulsm_id: Vec<u8>, // i32
+    },
+    DeleteDomain {
+        domain_name: String, // MMS Identifier
+    },
+    GetDomainAttributes {
+        domain_name: String, // MMS Identifier
+    },
+    CreateProgramInvocation {
+        program_invocation_name: String,   // MMS Identifier
+        list_of_domain_names: Vec<String>, // MMS Identifiers
+        reusable: Option<bool>,            // Defaults to true if not present
+        monitor_type: Option<bool>,        // True is permanent and false is current monitoring. The program invocation is not monitored if not present.
+    },
+    DeleteProgramInvocation {
+        program_invocation_name: String, // MMS Identifier
+    },
+    Start {
+        program_invocation_name: String,    // MMS Identifier
+        execution_argument: Option<String>, // Only the simple string is supported
+    },
+    Stop {
+        program_invocation_name: String, // MMS Identifier
+    },
+    Resume {
+        program_invocation_name: String,    // MMS Identifier
+        execution_argument: Option<String>, // Only the simple string is supported
+    },
+    Reset {
+        program_invocation_name: String, // MMS Identifier
+    },
+    Kill {
+        program_invocation_name: String, // MMS Identifier
+    },
+    GetProgramInvocationAttributes {
+        program_invocation_name: String, // MMS Identifier
+    },
+    ObtainFile {
+        // The source file server is not supported. The file is obtained from the requesting application.
+        source_file: Vec<String>,      // File Name
+        destination_file: Vec<String>, // File Name
+    },
+    FileOpen {
+        file_name: Vec<String>,
+        initial_position: Vec<u8>, // u32
+    },
+    FileRead {
+        frsm_id: Vec<u8>, // i32
+    },
+    FileClose {
+        frsm_id: Vec<u8>, // i32
+    },
+    FileRename {
+        current_file_name: Vec<String>,
+        new_file_name: Vec<String>,
+    },
+    FileDelete {
+        file_name: Vec<String>,
+    },
+    FileDirectory {
+        file_specification: Option<Vec<String>>,
+        continue_after: Option<Vec<String>>,
+    },
+    ReadJournal {
+        journal_name: MmsObjectName,
+        range_start_specification: Option<MmsJournalRangeStart>,
+        range_stop_specification: Option<MmsJournalRangeStop>,
+        list_of_variables: Option<Vec<String>>, // MMS255Strings
+        entry_to_start_after: Option<Box<MmsJournalEntryToStartAfter>>,
+    },
+    WriteJournal {
+        journal_name: MmsObjectName,
+        list_of_journal_entry: Vec<MmsEntryContent>,
+    },
+    InitializeJournal {
+        journal_name: MmsObjectName,
+        limit_specification: Option<MmsJournalLimitSpecification>,
+    },
+    ReportJournalStatus {
+        journal_name: MmsObjectName,
+    },
+    CreateJournal {
+        journal_name: MmsObjectName,
+    },
+    DeleteJournal {
+        journal_name: MmsObjectName,
+    },
+    DefineEventCondition {
+        event_condition_name: MmsObjectName,
+        class: MmsEventConditionClass,
+        priority: Option<Vec<u8>>, // u8, Default: 64
+        severity: Option<Vec<u8>>, // u8, Default: 64
+        alarm_summary_reports: Option<bool>,
+        monitored_variable: Option<Box<VariableSpecification>>,
+        evaluation_interval: Option<Vec<u8>>, // u32
+    },
+    AlterEventConditionMonitoring {
+        event_condition_name: MmsObjectName,
+        enabled: Option<bool>,
+        priority: Option<Vec<u8>>, // u8
+        alarm_summary_reports: Option<bool>,
+        evaluation_interval: Option<Vec<u8>>, // u32
+    },
+    GetEventConditionAttributes {
+        event_condition_name: MmsObjectName,
+    },
+    DefineEventEnrollment {
+        event_enrollment_name: MmsObjectName,
+        event_condition_name: MmsObjectName,
+        event_condition_transitions: Vec<MmsEventTransition>,
+        alarm_acknowledgment_rule: MmsAlarmAcknowledgmentRule,
+        event_action_name: Option<Box<MmsObjectName>>,
+        client_application: Option<Box<MmsApplicationReference>>,
+    },
+    AcknowledgeEventNotification {
+        event_enrollment_name: MmsObjectName,
+        acknowledged_state: MmsEventConditionState,
+        time_of_acknowledged_transition: MmsEventTime,
+    },
+    /// The application to preempt is not supported. It is skipped when received.
+    TakeControl {
+        semaphore_name: MmsObjectName,
+        named_token: Option<String>,
+        priority: Option<Vec<u8>>,         // u8, Default: 64
+        acceptable_delay: Option<Vec<u8>>, // u32
+        control_timeout: Option<Vec<u8>>,  // u32
+        abort_on_timeout: Option<bool>,
+        relinquish_if_connection_lost: Option<bool>, // Defaults to true if not present
+    },
+    RelinquishControl {
+        semaphore_name: MmsObjectName,
+        named_token: Option<String>,
+    },
+    DefineSemaphore {
+        semaphore_name: MmsObjectName,
+        number_of_tokens: Vec<u8>, // u16
+    },
+    DeleteSemaphore {
+        semaphore_name: MmsObjectName,
+    },
+    ReportSemaphoreStatus {
+        semaphore_name: MmsObjectName,
+    },
 }
 
 #[derive(Debug, PartialEq, Eq)]
@@ -258,6 +521,12 @@ pub enum MmsConfirmedResponse {
         revision: String,
         abstract_syntaxes: Option<Vec<Oid<'static>>>,
     },
+    Status(MmsStatus),
+    GetCapabilityList {
+        list_of_capabilities: Vec<String>,
+        more_follows: Option<bool>, // Defaults to true if not present
+    },
+    Rename,
     Read {
         variable_access_specification: Option<MmsVariableAccessSpecification>,
         access_results: Vec<MmsAccessResult>,
@@ -278,15 +547,595 @@ pub enum MmsConfirmedResponse {
         number_matched: Vec<u8>,
         number_deleted: Vec<u8>,
     },
+    DefineNamedType,
+    GetNamedTypeAttributes {
+        deletable: bool,
+        type_specification: MmsTypeSpecification,
+    },
+    DeleteNamedType {
+        number_matched: Vec<u8>,
+        number_deleted: Vec<u8>,
+    },
+    InitiateDownloadSequence,
+    DownloadSegment {
+        load_data: Vec<u8>,         // Only non-coded load data is supported
+        more_follows: Option<bool>, // Defaults to true if not present
+    },
+    TerminateDownloadSequence,
+    InitiateUploadSequence {
+        ulsm_id: Vec<u8>, // i32
+        list_of_capabilities: Vec<String>,
+    },
+    UploadSegment {
+        load_data: Vec<u8>,         // Only non-coded load data is supported
+        more_follows: Option<bool>, // Defaults to true if not present
+    },
+    TerminateUploadSequence,
+    DeleteDomain,
+    GetDomainAttributes {
+        list_of_capabilities: Vec<String>,
+        state: MmsDomainState,
+        mms_deletable: bool,
+        sharable: bool,
+        list_of_program_invocations: Vec<String>, // MMS Identifiers
+        upload_in_progress: Vec<u8>,              // i8
+    },
+    CreateProgramInvocation,
+    DeleteProgramInvocation,
+    Start,
+    Stop,
+    Resume,
+    Reset,
+    Kill,
+    GetProgramInvocationAttributes {
+        state: MmsProgramInvocationState,
+        list_of_domain_names: Vec<String>, // MMS Identifiers
+        mms_deletable: bool,
+        reusable: bool,
+        monitor: bool,
+        execution_argument: String, // Only the simple string is supported
+    },
+    ObtainFile,
+    FileOpen {
+        frsm_id: Vec<u8>, // i32
+        file_attributes: MmsFileAttributes,
+    },
+    FileRead {
+        file_data: Vec<u8>,
+        more_follows: Option<bool>, // Defaults to true if not present
+    },
+    FileClose,
+    FileRename,
+    FileDelete,
+    FileDirectory {
+        list_of_directory_entry: Vec<MmsDirectoryEntry>,
+        more_follows: Option<bool>, // Defaults to false if not present
+    },
+    ReadJournal {
+        list_of_journal_entry: Vec<MmsJournalEntry>,
+        more_follows: Option<bool>, // Defaults to false if not present
+    },
+    WriteJournal,
+    InitializeJournal {
+        entries_deleted: Vec<u8>, // u32
+    },
+    ReportJournalStatus {
+        current_entries: Vec<u8>, // u32
+        mms_deletable: bool,
+    },
+    CreateJournal,
+    DeleteJournal,
+    DefineEventCondition,
+    AlterEventConditionMonitoring,
+    GetEventConditionAttributes {
+        mms_deletable: Option<bool>, // Defaults to false if not present
+        class: MmsEventConditionClass,
+        priority: Option<Vec<u8>>,           // u8, Default: 64
+        severity: Option<Vec<u8>>,           // u8, Default: 64
+        alarm_summary_reports: Option<bool>, // Defaults to false if not present
+        monitored_variable: Option<MmsMonitoredVariable>,
+        evaluation_interval: Option<Vec<u8>>, // u32
+    },
+    DefineEventEnrollment,
+    AcknowledgeEventNotification,
+    TakeControl {
+        named_token: Option<String>, // Only present for pool semaphores
+    },
+    RelinquishControl,
+    DefineSemaphore,
+    DeleteSemaphore,
+    ReportSemaphoreStatus {
+        mms_deletable: bool,
+        class: MmsSemaphoreClass,
+        number_of_tokens: Vec<u8>,       // u16
+        number_of_owned_tokens: Vec<u8>, // u16
+        number_of_hung_tokens: Vec<u8>,  // u16
+    },
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsFileAttributes {
+    pub size_of_file: Vec<u8>,         // u32
+    pub last_modified: Option<String>, // GeneralizedTime
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsDirectoryEntry {
+    pub file_name: Vec<String>,
+    pub file_attributes: MmsFileAttributes,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsJournalRangeStart {
+    StartingTime(MmsBinaryTime),
+    StartingEntry(Vec<u8>), // Entry Identifier
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsJournalRangeStop {
+    EndingTime(MmsBinaryTime),
+    NumberOfEntries(Vec<u8>), // i32
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsJournalEntryToStartAfter {
+    pub time_specification: MmsBinaryTime,
+    pub entry_specification: Vec<u8>, // Entry Identifier
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsJournalLimitSpecification {
+    pub limiting_time: MmsBinaryTime,
+    pub limiting_entry: Option<Vec<u8>>, // Entry Identifier
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MmsJournalEntry {
+    pub entry_identifier: Vec<u8>,
+    pub originating_application: MmsApplicationReference,
+    pub entry_content: MmsEntryContent,
+}
+
+/// Only the object identifier form of the AP title and the integer form of the AE qualifier are supported.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MmsApplicationReference {
+    pub ap_title: Option<Oid<'static>>,
+    pub ap_invocation_id: Option<Vec<u8>>, // Integer
+    pub ae_qualifier: Option<Vec<u8>>,     // Integer
+    pub ae_invocation_id: Option<Vec<u8>>, // Integer
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MmsEntryContent {
+    pub occurrence_time: MmsBinaryTime,
+    pub entry_form: MmsEntryForm,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum MmsEntryForm {
+    Data { event: Option<MmsJournalEvent>, list_of_variables: Option<Vec<MmsJournalVariable>> },
+    Annotation(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsJournalEvent {
+    pub event_condition_name: MmsObjectName,
+    pub current_state: MmsEventConditionState,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct MmsJournalVariable {
+    pub variable_tag: String, // MMS255String
+    pub value_specification: MmsData,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsEventConditionState {
+    Disabled,
+    Idle,
+    Active,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsEventConditionClass {
+    NetworkTriggered,
+    Monitored,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsMonitoredVariable {
+    VariableReference(VariableSpecification),
+    Undefined,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MmsEventTransition {
+    IdleToDisabled,
+    ActiveToDisabled,
+    DisabledToIdle,
+    ActiveToIdle,
+    DisabledToActive,
+    IdleToActive,
+    AnyToDeleted,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsAlarmAcknowledgmentRule {
+    None,
+    Simple,
+    AckActive,
+    AckAll,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsEventTime {
+    TimeOfDay(MmsBinaryTime),
+    TimeSequenceIdentifier(Vec<u8>), // u32
+    Undefined,
+}
+
+/// The action result is not supported. It is skipped when received.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsEventNotification {
+    pub event_enrollment_name: MmsObjectName,
+    pub event_condition_name: MmsObjectName,
+    pub severity: Vec<u8>, // u8
+    pub current_state: Option<MmsEventConditionState>,
+    pub transition_time: MmsEventTime,
+    pub notification_lost: Option<bool>, // Defaults to false if not present
+    pub alarm_acknowledgment_rule: Option<MmsAlarmAcknowledgmentRule>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsLogicalStatus {
+    StateChangesAllowed,
+    NoStateChangesAllowed,
+    LimitedServicesPermitted,
+    SupportServicesAllowed,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsPhysicalStatus {
+    Operational,
+    PartiallyOperational,
+    Inoperable,
+    NeedsCommissioning,
+    Unknown(Vec<u8>),
+}
+
+/// The status of a VMD. The meaning of the local detail is defined by the VMD. It holds up to 128 bits.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsStatus {
+    pub logical_status: MmsLogicalStatus,
+    pub physical_status: MmsPhysicalStatus,
+    pub local_detail: Option<(u8, Vec<u8>)>, // Padding, Bits
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsSemaphoreClass {
+    Token,
+    Pool,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsDomainState {
+    NonExistent,
+    Loading,
+    Ready,
+    InUse,
+    Complete,
+    Incomplete,
+    D1,
+    D2,
+    D3,
+    D4,
+    D5,
+    D6,
+    D7,
+    D8,
+    D9,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsProgramInvocationState {
+    NonExistent,
+    Unrunnable,
+    Idle,
+    Running,
+    Stopped,
+    Starting,
+    Stopping,
+    Resuming,
+    Resetting,
+    Unknown(Vec<u8>),
+}
+
+/// The ServiceError returned when a confirmed service could not be performed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsServiceErrorInfo {
+    pub error_class: MmsErrorClass,
+    pub additional_code: Option<Vec<u8>>, // i32
+    pub additional_description: Option<String>,
+}
+
+impl MmsServiceErrorInfo {
+    pub fn new(error_class: MmsErrorClass) -> Self {
+        MmsServiceErrorInfo { error_class, additional_code: None, additional_description: None }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsErrorClass {
+    VmdState(MmsVmdStateError),
+    ApplicationReference(MmsApplicationReferenceError),
+    Definition(MmsDefinitionError),
+    Resource(MmsResourceError),
+    Service(MmsServiceProblem),
+    ServicePreempt(MmsServicePreemptError),
+    TimeResolution(MmsTimeResolutionError),
+    Access(MmsObjectAccessError),
+    Initiate(MmsInitiateError),
+    Conclude(MmsConcludeError),
+    Cancel(MmsCancelError),
+    File(MmsFileError),
+    Others(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsVmdStateError {
+    Other,
+    VmdStateConflict,
+    VmdOperationalProblem,
+    DomainTransferProblem,
+    StateMachineIdInvalid,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsApplicationReferenceError {
+    Other,
+    ApplicationUnreachable,
+    ConnectionLost,
+    ApplicationReferenceInvalid,
+    ContextUnsupported,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsDefinitionError {
+    Other,
+    ObjectUndefined,
+    InvalidAddress,
+    TypeUnsupported,
+    TypeInconsistent,
+    ObjectExists,
+    ObjectAttributeInconsistent,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsResourceError {
+    Other,
+    MemoryUnavailable,
+    ProcessorResourceUnavailable,
+    MassStorageUnavailable,
+    CapabilityUnavailable,
+    CapabilityUnknown,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsServiceProblem {
+    Other,
+    PrimitivesOutOfSequence,
+    ObjectStateConflict,
+    // 3 (PDU Size) is reserved
+    ContinuationInvalid,
+    ObjectConstraintConflict,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsServicePreemptError {
+    Other,
+    Timeout,
+    Deadlock,
+    Cancel,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsTimeResolutionError {
+    Other,
+    UnsupportableTimeResolution,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsObjectAccessError {
+    Other,
+    ObjectAccessUnsupported,
+    ObjectNonExistent,
+    ObjectAccessDenied,
+    ObjectInvalidated,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsInitiateError {
+    Other,
+    // 1 (Version Incompatible) is reserved
+    MaxSegmentInsufficient,
+    MaxServicesOutstandingCallingInsufficient,
+    MaxServicesOutstandingCalledInsufficient,
+    ServiceCbbInsufficient,
+    ParameterCbbInsufficient,
+    NestingLevelInsufficient,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsConcludeError {
+    Other,
+    FurtherCommunicationRequired,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsCancelError {
+    Other,
+    InvokeIdUnknown,
+    CancelNotPossible,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsFileError {
+    Other,
+    FilenameAmbiguous,
+    FileBusy,
+    FilenameSyntaxError,
+    ContentTypeInvalid,
+    PositionInvalid,
+    FileAccessDenied,
+    FileNonExistent,
+    DuplicateFilename,
+    InsufficientSpaceInFilestore,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsRejectReason {
+    ConfirmedRequestPdu(MmsConfirmedRequestRejectCode),
+    ConfirmedResponsePdu(MmsConfirmedResponseRejectCode),
+    ConfirmedErrorPdu(MmsConfirmedErrorRejectCode),
+    UnconfirmedPdu(MmsUnconfirmedRejectCode),
+    PduError(MmsPduErrorRejectCode),
+    CancelRequestPdu(MmsCancelRequestRejectCode),
+    CancelResponsePdu(MmsCancelResponseRejectCode),
+    CancelErrorPdu(MmsCancelErrorRejectCode),
+    ConcludeRequestPdu(MmsConcludeRequestRejectCode),
+    ConcludeResponsePdu(MmsConcludeResponseRejectCode),
+    ConcludeErrorPdu(MmsConcludeErrorRejectCode),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsConfirmedRequestRejectCode {
+    Other,
+    UnrecognizedService,
+    UnrecognizedModifier,
+    InvalidInvokeId,
+    InvalidArgument,
+    InvalidModifier,
+    MaxServicesOutstandingExceeded,
+    // 7 is reserved
+    MaxRecursionExceeded,
+    ValueOutOfRange,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsConfirmedResponseRejectCode {
+    Other,
+    UnrecognizedService,
+    InvalidInvokeId,
+    InvalidResult,
+    // 4 is reserved
+    MaxRecursionExceeded,
+    ValueOutOfRange,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsConfirmedErrorRejectCode {
+    Other,
+    UnrecognizedService,
+    InvalidInvokeId,
+    InvalidServiceError,
+    ValueOutOfRange,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsUnconfirmedRejectCode {
+    Other,
+    UnrecognizedService,
+    InvalidArgument,
+    MaxRecursionExceeded,
+    ValueOutOfRange,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsPduErrorRejectCode {
+    UnknownPduType,
+    InvalidPdu,
+    IllegalAcseMapping,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsCancelRequestRejectCode {
+    Other,
+    InvalidInvokeId,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsCancelResponseRejectCode {
+    Other,
+    InvalidInvokeId,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsCancelErrorRejectCode {
+    Other,
+    InvalidInvokeId,
+    InvalidServiceError,
+    ValueOutOfRange,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsConcludeRequestRejectCode {
+    Other,
+    InvalidArgument,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsConcludeResponseRejectCode {
+    Other,
+    InvalidResult,
+    Unknown(Vec<u8>),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MmsConcludeErrorRejectCode {
+    Other,
+    InvalidServiceError,
+    ValueOutOfRange,
+    Unknown(Vec<u8>),
 }
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum MmsUnconfirmedService {
     InformationReport { variable_access_specification: MmsVariableAccessSpecification, access_results: Vec<MmsAccessResult> },
+    // Boxed as it would otherwise be the largest MMS message.
+    EventNotification(Box<MmsEventNotification>),
+    UnsolicitedStatus(MmsStatus),
 }
 
 pub trait MmsInitiator: Send {
-    fn initiate(self) -> impl std::future::Future<Output = Result<impl MmsConnection, MmsError>> + Send;
+    /// Creates the association. The ACSE response holds the identity the peer responded with.
+    fn initiate(self) -> impl std::future::Future<Output = Result<(impl MmsConnection, AcseResponseInformation), MmsError>> + Send;
 }
 
 pub trait MmsListener: Send {
@@ -298,6 +1147,9 @@ pub trait MmsResponder: Send {
 }
 
 pub trait MmsConnection: Send {
+    /// The services the peer said it supports when the association was created.
+    fn services_supported(&self) -> &[ServiceSupportOption];
+
     fn split(self) -> impl std::future::Future<Output = Result<(impl MmsReader, impl MmsWriter), MmsError>> + Send;
 }
 
@@ -307,4 +1159,11 @@ pub trait MmsReader: Send {
 
 pub trait MmsWriter: Send {
     fn send(&mut self, message: &mut VecDeque<MmsMessage>) -> impl std::future::Future<Output = Result<(), MmsError>> + Send;
+
+    /// Requests the release of the association. This should follow a successful conclude. No more messages can be sent once the release is accepted.
+    fn release(&mut self) -> impl std::future::Future<Output = Result<(), MmsError>> + Send;
+
+    /// Answers a release request received on the reader. No more messages can be sent after an affirmative response.
+    /// A negative response keeps the association open and needs the negotiated release session functional unit.
+    fn release_response(&mut self, result: ReleaseResult) -> impl std::future::Future<Output = Result<(), MmsError>> + Send;
 }
@@ -4,6 +4,7 @@ pub mod parameters;
 pub(crate) mod parsers;
 pub(crate) mod pdu;
 pub mod service;
+pub mod time;
 
 use std::marker::PhantomData;
 
@@ -13,18 +14,19 @@ use rusty_acse::{
     RustyOsiSingleValueAcseReaderIsoStack, RustyOsiSingleValueAcseResponderIsoStack, RustyOsiSingleValueAcseWriterIsoStack,
 };
 use rusty_copp::{CoppConnectionInformation, RustyCoppInitiatorIsoStack, RustyCoppListenerIsoStack};
-use rusty_cosp::{CospConnectionParameters, CospProtocolInformation, RustyCospAcceptor, RustyCospInitiator};
+use rusty_cosp::{CospConnectionParameters, CospFunctionalUnits, CospProtocolInformation, RustyCospAcceptor, RustyCospInitiator};
 use rusty_cotp::{CotpProtocolInformation, CotpResponder, RustyCotpConnection, RustyCotpReader, RustyCotpResponder, RustyCotpWriter};
 use rusty_tpkt::{TpktConnection, TpktReader, TpktWriter};
 pub use service::*;
+pub use time::*;
 
 use crate::error::to_mms_error;
 
-pub type RustyMmsConnectionIsoStack<R, W> = RustyMmsConnection<RustyOsiSingleValueAcseReaderIsoStack<R>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
-pub type RustyMmsInitiatorIsoStack<R, W> = RustyMmsInitiator<RustyOsiSingleValueAcseInitiatorIsoStack<R, W>, RustyOsiSingleValueAcseReaderIsoStack<R>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
-pub type RustyMmsListenerIsoStack<R, W> = RustyMmsListener<RustyOsiSingleValueAcseResponderIsoStack<R, W>, RustyOsiSingleValueAcseReaderIsoStack<R>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
-pub type RustyMmsResponderIsoStack<R, W> = RustyMmsResponder<RustyOsiSingleValueAcseResponderIsoStack<R, W>, RustyOsiSingleValueAcseReaderIsoStack<R>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
-pub type RustyMmsReaderIsoStack<R> = RustyMmsReader<RustyOsiSingleValueAcseReaderIsoStack<R>>;
+pub type RustyMmsConnectionIsoStack<R, W> = RustyMmsConnection<RustyOsiSingleValueAcseReaderIsoStack<R, W>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
+pub type RustyMmsInitiatorIsoStack<R, W> = RustyMmsInitiator<RustyOsiSingleValueAcseInitiatorIsoStack<R, W>, RustyOsiSingleValueAcseReaderIsoStack<R, W>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
+pub type RustyMmsListenerIsoStack<R, W> = RustyMmsListener<RustyOsiSingleValueAcseResponderIsoStack<R, W>, RustyOsiSingleValueAcseReaderIsoStack<R, W>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
+pub type RustyMmsResponderIsoStack<R, W> = RustyMmsResponder<RustyOsiSingleValueAcseResponderIsoStack<R, W>, RustyOsiSingleValueAcseReaderIsoStack<R, W>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
+pub type RustyMmsReaderIsoStack<R, W> = RustyMmsReader<RustyOsiSingleValueAcseReaderIsoStack<R, W>, RustyOsiSingleValueAcseWriterIsoStack<W>>;
 pub type RustyMmsWriterIsoStack<W> = RustyMmsWriter<RustyOsiSingleValueAcseWriterIsoStack<W>>;
 
 pub struct OsiMmsInitiatorConnectionFactory<T: TpktConnection, R: TpktReader, W: TpktWriter> {
@@ -44,11 +46,12 @@ impl<T: TpktConnection, R: TpktReader, W: TpktWriter> OsiMmsInitiatorConnectionF
     ) -> Result<impl MmsConnection, MmsError> {
         let cotp_client = RustyCotpConnection::<R, W>::initiate(tpkt_connection, cotp_information, Default::default()).await.map_err(to_mms_error("Failed to establish a COTP connection when creating an MMS association"))?;
         let cosp_client =
-            RustyCospInitiator::<RustyCotpReader<R>, RustyCotpWriter<W>>::new(cotp_client, cosp_information, Default::default()).await.map_err(to_mms_error("Failed to establish a COSP connection when creating an MMS association"))?;
-        let copp_client = RustyCoppInitiatorIsoStack::<R, W>::new(cosp_client, copp_information);
+            RustyCospInitiator::<RustyCotpReader<R>, RustyCotpWriter<W>>::new(cotp_client, cosp_information, cosp_parameters()).await.map_err(to_mms_error("Failed to establish a COSP connection when creating an MMS association"))?;
+        let copp_client = RustyCoppInitiatorIsoStack::<R, W>::new(cosp_client, copp_information, Default::default());
         let acse_client = RustyOsiSingleValueAcseInitiatorIsoStack::<R, W>::new(copp_client, acse_information);
         let mms_client = RustyMmsInitiatorIsoStack::<R, W>::new(acse_client, mms_information);
-        mms_client.initiate().await
+        let (connection, _) = mms_client.initiate().await?;
+        Ok(connection)
     }
 }
 
@@ -62,18 +65,21 @@ impl<T: TpktConnection, R: TpktReader, W: TpktWriter> OsiMmsMirrorResponderConne
     pub async fn accept(tpkt_connection: T) -> Result<impl MmsConnection, MmsError> {
         let (cotp_listener, init_info) = RustyCotpResponder::<R, W>::new(tpkt_connection, Default::default()).await.map_err(to_mms_error("Failed to create COTP connection when creating an MMS association"))?;
         let cotp_connection = cotp_listener.accept(init_info.responder()).await.map_err(to_mms_error("Failed to create a COSP connection when creating an MMS association"))?;
-        let (cosp_listener, _) =
-            RustyCospAcceptor::<RustyCotpReader<R>, RustyCotpWriter<W>>::new(cotp_connection, CospConnectionParameters::default()).await.map_err(to_mms_error("Failed to create a COSP connection when creating an MMS association"))?;
-        let (copp_listener, _) = RustyCoppListenerIsoStack::<R, W>::new(cosp_listener).await.map_err(to_mms_error("Failed to create COPP listener"))?;
+        let (cosp_listener, _) = RustyCospAcceptor::<RustyCotpReader<R>, RustyCotpWriter<W>>::new(cotp_connection, cosp_parameters()).await.map_err(to_mms_error("Failed to create a COSP connection when creating an MMS association"))?;
+        let (copp_listener, _) = RustyCoppListenerIsoStack::<R, W>::new(cosp_listener, Default::default()).await.map_err(to_mms_error("Failed to create COPP listener"))?;
         let (mut acse_listener, acse_request_information) = RustyOsiSingleValueAcseListenerIsoStack::<R, W>::new(copp_listener).await.map_err(to_mms_error("Failed to create a COPP connection when creating an MMS association"))?;
         acse_listener.set_response(Some(AcseResponseInformation {
-            application_context_name: acse_request_information.application_context_name, // TODO: Should verify it is MMS
+            // The listener only accepts the MMS application context by default.
+            application_context_name: acse_request_information.application_context_name,
             associate_result: AssociateResult::Accepted,
             associate_source_diagnostic: AssociateSourceDiagnostic::User(AssociateSourceDiagnosticUserCategory::Null),
             responding_ap_title: acse_request_information.called_ap_title,
             responding_ae_qualifier: acse_request_information.called_ae_qualifier,
             responding_ap_invocation_identifier: acse_request_information.called_ap_invocation_identifier,
             responding_ae_invocation_identifier: acse_request_information.called_ae_invocation_identifier,
+            responder_acse_requirements: None,
+            mechanism_name: None,
+            responding_authentication_value: None,
             implementation_information: None,
         }));
         let mms_listener = RustyMmsListenerIsoStack::<R, W>::new(acse_listener).await?;
@@ -82,11 +88,17 @@ impl<T: TpktConnection, R: TpktReader, W: TpktWriter> OsiMmsMirrorResponderConne
     }
 }
 
+/// Negotiates the release session functional unit so the release that follows a conclude can be refused.
+fn cosp_parameters() -> CospConnectionParameters {
+    CospConnectionParameters { functional_units: CospFunctionalUnits { negotiated_release: true, ..Default::default() }, ..Default::default() }
+}
+
 #[cfg(test)]
 mod tests {
     use std::{collections::VecDeque, time::Duration};
 
     use der_parser::{Oid, num_bigint::BigInt};
+    use rusty_acse::ReleaseResult;
     use rusty_tpkt::{TcpTpktConnection, TcpTpktReader, TcpTpktServer, TcpTpktWriter};
     use tokio::{join, time::sleep};
     use tracing_test::traced_test;
@@ -106,7 +118,7 @@ mod tests {
                 CotpProtocolInformation::initiator(None, None),
                 CospProtocolInformation::new(None, None),
                 CoppConnectionInformation::default(),
-                AcseRequestInformation { application_context_name: Oid::from(&[1, 2, 3])?, ..Default::default() },
+                AcseRequestInformation::default(),
                 MmsRequestInformation::default(),
             )
             .await?;
@@ -128,15 +140,33 @@ mod tests {
         let (mut mms_client_reader, mut mms_client_writer) = mms_client.split().await?;
         let (mut mms_server_reader, mut mms_server_writer) = mms_server.split().await?;
 
+        // Selects the first two elements of the Values component and the named Quality component of the third element.
+        let alternate_access = vec![
+            MmsAlternateAccessItem::Unnamed(MmsAlternateAccessSelection::SelectAlternateAccess {
+                access_selection: MmsAccessSelection::Component("Values".into()),
+                alternate_access: vec![MmsAlternateAccessItem::Unnamed(MmsAlternateAccessSelection::SelectAccess(MmsAccessSelection::IndexRange {
+                    low_index: vec![0],
+                    number_of_elements: vec![2],
+                }))],
+            }),
+            MmsAlternateAccessItem::Named {
+                component_name: "Quality".into(),
+                access: MmsAlternateAccessSelection::SelectAlternateAccess {
+                    access_selection: MmsAccessSelection::Index(vec![2]),
+                    alternate_access: vec![MmsAlternateAccessItem::Unnamed(MmsAlternateAccessSelection::SelectAccess(MmsAccessSelection::Component("Quality".into())))],
+                },
+            },
+            MmsAlternateAccessItem::Unnamed(MmsAlternateAccessSelection::SelectAccess(MmsAccessSelection::AllElements)),
+        ];
         test_buffer.push_back(MmsMessage::ConfirmedRequest {
             invocation_id: BigInt::from(1).to_signed_bytes_be(),
             request: MmsConfirmedRequest::Read {
                 specification_with_result: None,
                 variable_access_specification: MmsVariableAccessSpecification::ListOfVariables(vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("Does not exist".into())) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())), alternate_access: Some(alternate_access.clone()) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("Does not exist".into())), alternate_access: None },
                 ]),
             },
         });
@@ -151,15 +181,15 @@ mod tests {
                 },
                 _ => panic!(),
             },
-            MmsRecvResult::Closed => panic!(),
+            _ => panic!(),
         };
         assert_eq!(
             read_request,
             MmsVariableAccessSpecification::ListOfVariables(vec![
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())) },
-                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("Does not exist".into())) },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())), alternate_access: None },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())), alternate_access: Some(alternate_access.clone()) },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())), alternate_access: None },
+                ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("Does not exist".into())), alternate_access: None },
             ])
         );
 
@@ -227,9 +257,9 @@ mod tests {
             invocation_id: vec![3],
             request: MmsConfirmedRequest::Write {
                 variable_access_specification: MmsVariableAccessSpecification::ListOfVariables(vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())), alternate_access: None },
                 ]),
                 list_of_data: vec![
                     MmsData::Boolean(true),
@@ -246,9 +276,9 @@ mod tests {
                     request,
                     MmsConfirmedRequest::Write {
                         variable_access_specification: MmsVariableAccessSpecification::ListOfVariables(vec![
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())) },
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())) },
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())) },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())), alternate_access: None },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())), alternate_access: None },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())), alternate_access: None },
                         ]),
                         list_of_data: vec![
                             MmsData::Boolean(true),
@@ -275,9 +305,9 @@ mod tests {
         test_buffer.push_back(MmsMessage::Unconfirmed {
             unconfirmed_service: MmsUnconfirmedService::InformationReport {
                 variable_access_specification: MmsVariableAccessSpecification::ListOfVariables(vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())), alternate_access: Some(alternate_access.clone()) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())), alternate_access: None },
                 ]),
                 access_results: vec![
                     MmsAccessResult::Success(MmsData::Boolean(true)),
@@ -293,9 +323,9 @@ mod tests {
                     unconfirmed_service,
                     MmsUnconfirmedService::InformationReport {
                         variable_access_specification: MmsVariableAccessSpecification::ListOfVariables(vec![
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())) },
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())) },
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())) },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Hello".into())), alternate_access: None },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Foo".into(), "Bar".into())), alternate_access: Some(alternate_access.clone()) },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("There".into())), alternate_access: None },
                         ]),
                         access_results: vec![
                             MmsAccessResult::Success(MmsData::Boolean(true)),
@@ -398,9 +428,12 @@ mod tests {
             request: MmsConfirmedRequest::DefineNamedVariableList {
                 variable_list_name: MmsObjectName::VmdSpecific("Test VMD".into()),
                 list_of_variables: vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("I".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Want".into(), "That".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("One".into())) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("I".into())), alternate_access: None },
+                    ListOfVariablesItem {
+                        variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Want".into(), "That".into())),
+                        alternate_access: Some(vec![MmsAlternateAccessItem::Unnamed(MmsAlternateAccessSelection::SelectAccess(MmsAccessSelection::Index(vec![1])))]),
+                    },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("One".into())), alternate_access: None },
                 ],
             },
         });
@@ -413,9 +446,12 @@ mod tests {
                     MmsConfirmedRequest::DefineNamedVariableList {
                         variable_list_name: MmsObjectName::VmdSpecific("Test VMD".into()),
                         list_of_variables: vec![
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("I".into())) },
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Want".into(), "That".into())) },
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("One".into())) },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::AaSpecific("I".into())), alternate_access: None },
+                            ListOfVariablesItem {
+                                variable_specification: VariableSpecification::Name(MmsObjectName::DomainSpecific("Want".into(), "That".into())),
+                                alternate_access: Some(vec![MmsAlternateAccessItem::Unnamed(MmsAlternateAccessSelection::SelectAccess(MmsAccessSelection::Index(vec![1])))]),
+                            },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("One".into())), alternate_access: None },
                         ],
                     }
                 );
@@ -446,8 +482,8 @@ mod tests {
             response: MmsConfirmedResponse::GetNamedVariableListAttributes {
                 deletable: true,
                 list_of_variables: vec![
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Yup".into())) },
-                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Nope".into())) },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Yup".into())), alternate_access: None },
+                    ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Nope".into())), alternate_access: None },
                 ],
             },
         });
@@ -460,8 +496,8 @@ mod tests {
                     MmsConfirmedResponse::GetNamedVariableListAttributes {
                         deletable: true,
                         list_of_variables: vec![
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Yup".into())) },
-                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Nope".into())) },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Yup".into())), alternate_access: None },
+                            ListOfVariablesItem { variable_specification: VariableSpecification::Name(MmsObjectName::VmdSpecific("Nope".into())), alternate_access: None },
                         ],
                     }
                 );
@@ -502,6 +538,594 @@ mod tests {
             _ => panic!(),
         }
 
+        let data_real_q = || {
+            MmsTypeSpecification::TypeDescription(MmsTypeDescription::Structure {
+                packed: None,
+                components: vec![
+                    MmsTypeDescriptionComponent { component_name: Some("Value".into()), component_type: MmsTypeSpecification::TypeDescription(MmsTypeDescription::FloatingPoint { format_width: vec![32], exponent_width: vec![8] }) },
+                    MmsTypeDescriptionComponent { component_name: Some("Flags".into()), component_type: MmsTypeSpecification::ObjectName(MmsObjectName::VmdSpecific("Data_Flags".into())) },
+                ],
+            })
+        };
+        test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request: MmsConfirmedRequest::DefineNamedType { type_name: MmsObjectName::VmdSpecific("Data_RealQ".into()), type_specification: data_real_q() } });
+        mms_client_writer.send(&mut test_buffer).await?;
+        match mms_server_reader.recv().await? {
+            MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id, request }) => {
+                assert_eq!(invocation_id, vec![5]);
+                assert_eq!(request, MmsConfirmedRequest::DefineNamedType { type_name: MmsObjectName::VmdSpecific("Data_RealQ".into()), type_specification: data_real_q() });
+            }
+            _ => panic!(),
+        }
+        test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response: MmsConfirmedResponse::DefineNamedType });
+        mms_server_writer.send(&mut test_buffer).await?;
+        match mms_client_reader.recv().await? {
+            MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id, response }) => {
+                assert_eq!(invocation_id, vec![5]);
+                assert_eq!(response, MmsConfirmedResponse::DefineNamedType);
+            }
+            _ => panic!(),
+        }
+
+        test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request: MmsConfirmedRequest::GetNamedTypeAttributes { type_name: MmsObjectName::DomainSpecific("ICC1".into(), "Data_RealQ".into()) } });
+        mms_client_writer.send(&mut test_buffer).await?;
+        match mms_server_reader.recv().await? {
+            MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id, request }) => {
+                assert_eq!(invocation_id, vec![5]);
+                assert_eq!(request, MmsConfirmedRequest::GetNamedTypeAttributes { type_name: MmsObjectName::DomainSpecific("ICC1".into(), "Data_RealQ".into()) });
+            }
+            _ => panic!(),
+        }
+        for type_specification in [data_real_q(), MmsTypeSpecification::ObjectName(MmsObjectName::AaSpecific("Data_Real".into()))] {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response: MmsConfirmedResponse::GetNamedTypeAttributes { deletable: true, type_specification } });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        match mms_client_reader.recv().await? {
+            MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id, response }) => {
+                assert_eq!(invocation_id, vec![5]);
+                assert_eq!(response, MmsConfirmedResponse::GetNamedTypeAttributes { deletable: true, type_specification: data_real_q() });
+            }
+            _ => panic!(),
+        }
+        match mms_client_reader.recv().await? {
+            MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id, response }) => {
+                assert_eq!(invocation_id, vec![5]);
+                assert_eq!(response, MmsConfirmedResponse::GetNamedTypeAttributes { deletable: true, type_specification: MmsTypeSpecification::ObjectName(MmsObjectName::AaSpecific("Data_Real".into())) });
+            }
+            _ => panic!(),
+        }
+
+        test_buffer.push_back(MmsMessage::ConfirmedRequest {
+            invocation_id: vec![5],
+            request: MmsConfirmedRequest::DeleteNamedType { scope_of_delete: Some(MmsScope::Specific), list_of_type_names: Some(vec![MmsObjectName::VmdSpecific("Data_RealQ".into())]), domain_name: None },
+        });
+        mms_client_writer.send(&mut test_buffer).await?;
+        match mms_server_reader.recv().await? {
+            MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id, request }) => {
+                assert_eq!(invocation_id, vec![5]);
+                assert_eq!(request, MmsConfirmedRequest::DeleteNamedType { scope_of_delete: Some(MmsScope::Specific), list_of_type_names: Some(vec![MmsObjectName::VmdSpecific("Data_RealQ".into())]), domain_name: None });
+            }
+            _ => panic!(),
+        }
+        test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response: MmsConfirmedResponse::DeleteNamedType { number_matched: vec![1], number_deleted: vec![1] } });
+        mms_server_writer.send(&mut test_buffer).await?;
+        match mms_client_reader.recv().await? {
+            MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id, response }) => {
+                assert_eq!(invocation_id, vec![5]);
+                assert_eq!(response, MmsConfirmedResponse::DeleteNamedType { number_matched: vec![1], number_deleted: vec![1] });
+            }
+            _ => panic!(),
+        }
+
+        let domain_requests = || {
+            vec![
+                MmsConfirmedRequest::InitiateDownloadSequence { domain_name: "ICC1".into(), list_of_capabilities: vec!["Capability".into()], sharable: true },
+                MmsConfirmedRequest::DownloadSegment { domain_name: "ICC1".into() },
+                MmsConfirmedRequest::TerminateDownloadSequence { domain_name: "ICC1".into(), discard: None },
+                MmsConfirmedRequest::TerminateDownloadSequence { domain_name: "ICC1".into(), discard: Some(MmsServiceErrorInfo::new(MmsErrorClass::Resource(MmsResourceError::MemoryUnavailable))) },
+                MmsConfirmedRequest::InitiateUploadSequence { domain_name: "ICC1".into() },
+                MmsConfirmedRequest::UploadSegment { ulsm_id: vec![0x01, 0x00] },
+                MmsConfirmedRequest::TerminateUploadSequence { ulsm_id: vec![0x01, 0x00] },
+                MmsConfirmedRequest::DeleteDomain { domain_name: "ICC1".into() },
+                MmsConfirmedRequest::GetDomainAttributes { domain_name: "ICC1".into() },
+            ]
+        };
+        let domain_responses = || {
+            vec![
+                MmsConfirmedResponse::InitiateDownloadSequence,
+                MmsConfirmedResponse::DownloadSegment { load_data: vec![1, 2, 3], more_follows: None },
+                MmsConfirmedResponse::DownloadSegment { load_data: vec![], more_follows: Some(false) },
+                MmsConfirmedResponse::TerminateDownloadSequence,
+                MmsConfirmedResponse::InitiateUploadSequence { ulsm_id: vec![0x01, 0x00], list_of_capabilities: vec![] },
+                MmsConfirmedResponse::UploadSegment { load_data: vec![4, 5, 6], more_follows: Some(true) },
+                MmsConfirmedResponse::TerminateUploadSequence,
+                MmsConfirmedResponse::DeleteDomain,
+                MmsConfirmedResponse::GetDomainAttributes {
+                    list_of_capabilities: vec!["Capability".into()],
+                    state: MmsDomainState::Ready,
+                    mms_deletable: false,
+                    sharable: true,
+                    list_of_program_invocations: vec!["Program".into()],
+                    upload_in_progress: vec![0],
+                },
+                MmsConfirmedResponse::GetDomainAttributes { list_of_capabilities: vec![], state: MmsDomainState::Unknown(vec![6]), mms_deletable: true, sharable: false, list_of_program_invocations: vec![], upload_in_progress: vec![0x7f] },
+            ]
+        };
+        for request in domain_requests() {
+            test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request });
+        }
+        mms_client_writer.send(&mut test_buffer).await?;
+        for request in domain_requests() {
+            assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }));
+        }
+        for response in domain_responses() {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        for response in domain_responses() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response }));
+        }
+
+        let file_requests = || {
+            vec![
+                MmsConfirmedRequest::ObtainFile { source_file: vec!["remote.log".into()], destination_file: vec!["logs".into(), "local.log".into()] },
+                MmsConfirmedRequest::FileOpen { file_name: vec!["COMTRADE".into(), "record.cfg".into()], initial_position: vec![0] },
+                MmsConfirmedRequest::FileRead { frsm_id: vec![0x01, 0x00] },
+                MmsConfirmedRequest::FileClose { frsm_id: vec![0x01, 0x00] },
+                MmsConfirmedRequest::FileRename { current_file_name: vec!["old.log".into()], new_file_name: vec!["new.log".into()] },
+                MmsConfirmedRequest::FileDelete { file_name: vec!["new.log".into()] },
+                MmsConfirmedRequest::FileDirectory { file_specification: None, continue_after: None },
+                MmsConfirmedRequest::FileDirectory { file_specification: Some(vec!["COMTRADE".into()]), continue_after: Some(vec!["record.cfg".into()]) },
+            ]
+        };
+        let file_responses = || {
+            vec![
+                MmsConfirmedResponse::ObtainFile,
+                MmsConfirmedResponse::FileOpen { frsm_id: vec![0x01, 0x00], file_attributes: MmsFileAttributes { size_of_file: vec![0x00, 0xff, 0xff], last_modified: Some("20240102030405.678Z".into()) } },
+                MmsConfirmedResponse::FileRead { file_data: vec![1, 2, 3], more_follows: None },
+                MmsConfirmedResponse::FileRead { file_data: (0..=255).collect(), more_follows: Some(false) },
+                MmsConfirmedResponse::FileClose,
+                MmsConfirmedResponse::FileRename,
+                MmsConfirmedResponse::FileDelete,
+                MmsConfirmedResponse::FileDirectory { list_of_directory_entry: vec![], more_follows: None },
+                MmsConfirmedResponse::FileDirectory {
+                    list_of_directory_entry: vec![
+                        MmsDirectoryEntry { file_name: vec!["record.cfg".into()], file_attributes: MmsFileAttributes { size_of_file: vec![0x10], last_modified: None } },
+                        MmsDirectoryEntry { file_name: vec!["record.dat".into()], file_attributes: MmsFileAttributes { size_of_file: vec![0x01, 0x00], last_modified: Some("20240102030405Z".into()) } },
+                    ],
+                    more_follows: Some(true),
+                },
+            ]
+        };
+        for request in file_requests() {
+            test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request });
+        }
+        mms_client_writer.send(&mut test_buffer).await?;
+        for request in file_requests() {
+            assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }));
+        }
+        for response in file_responses() {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        for response in file_responses() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response }));
+        }
+
+        let journal_name = || MmsObjectName::DomainSpecific("ICCP".into(), "Block4Log".into());
+        let entry_contents = || {
+            vec![
+                MmsEntryContent {
+                    occurrence_time: MmsBinaryTime::with_date(43_200_000, 14_610),
+                    entry_form: MmsEntryForm::Data {
+                        event: Some(MmsJournalEvent { event_condition_name: MmsObjectName::VmdSpecific("Alarm".into()), current_state: MmsEventConditionState::Active }),
+                        list_of_variables: Some(vec![MmsJournalVariable { variable_tag: "Message".into(), value_specification: MmsData::Structure(vec![MmsData::Integer(vec![1]), MmsData::VisibleString("Trip".into())]) }]),
+                    },
+                },
+                MmsEntryContent { occurrence_time: MmsBinaryTime::time_of_day(1_000), entry_form: MmsEntryForm::Data { event: None, list_of_variables: None } },
+                MmsEntryContent { occurrence_time: MmsBinaryTime::with_date(0, 1), entry_form: MmsEntryForm::Annotation("Operator note".into()) },
+            ]
+        };
+        let journal_requests = || {
+            vec![
+                MmsConfirmedRequest::ReadJournal { journal_name: journal_name(), range_start_specification: None, range_stop_specification: None, list_of_variables: None, entry_to_start_after: None },
+                MmsConfirmedRequest::ReadJournal {
+                    journal_name: journal_name(),
+                    range_start_specification: Some(MmsJournalRangeStart::StartingTime(MmsBinaryTime::with_date(0, 14_610))),
+                    range_stop_specification: Some(MmsJournalRangeStop::EndingTime(MmsBinaryTime::with_date(86_399_999, 14_610))),
+                    list_of_variables: Some(vec!["Message".into()]),
+                    entry_to_start_after: Some(Box::new(MmsJournalEntryToStartAfter { time_specification: MmsBinaryTime::with_date(1_000, 14_610), entry_specification: vec![0, 0, 0, 7] })),
+                },
+                MmsConfirmedRequest::ReadJournal {
+                    journal_name: MmsObjectName::VmdSpecific("Log".into()),
+                    range_start_specification: Some(MmsJournalRangeStart::StartingEntry(vec![0, 0, 0, 1])),
+                    range_stop_specification: Some(MmsJournalRangeStop::NumberOfEntries(vec![0x01, 0x00])),
+                    list_of_variables: None,
+                    entry_to_start_after: None,
+                },
+                MmsConfirmedRequest::WriteJournal { journal_name: journal_name(), list_of_journal_entry: entry_contents() },
+                MmsConfirmedRequest::InitializeJournal { journal_name: journal_name(), limit_specification: None },
+                MmsConfirmedRequest::InitializeJournal { journal_name: journal_name(), limit_specification: Some(MmsJournalLimitSpecification { limiting_time: MmsBinaryTime::with_date(0, 14_610), limiting_entry: Some(vec![0, 0, 0, 7]) }) },
+                MmsConfirmedRequest::ReportJournalStatus { journal_name: journal_name() },
+                MmsConfirmedRequest::CreateJournal { journal_name: journal_name() },
+                MmsConfirmedRequest::DeleteJournal { journal_name: MmsObjectName::AaSpecific("Log".into()) },
+            ]
+        };
+        let journal_responses = || {
+            vec![
+                MmsConfirmedResponse::ReadJournal { list_of_journal_entry: vec![], more_follows: None },
+                MmsConfirmedResponse::ReadJournal {
+                    list_of_journal_entry: entry_contents()
+                        .into_iter()
+                        .enumerate()
+                        .map(|(i, entry_content)| MmsJournalEntry {
+                            entry_identifier: vec![0, 0, 0, i as u8],
+                            originating_application: match i {
+                                0 => MmsApplicationReference::default(),
+                                _ => MmsApplicationReference { ap_title: Some(Oid::from(&[1, 1, 999, 1]).unwrap()), ap_invocation_id: Some(vec![1]), ae_qualifier: Some(vec![12]), ae_invocation_id: Some(vec![0x01, 0x00]) },
+                            },
+                            entry_content,
+                        })
+                        .collect(),
+                    more_follows: Some(true),
+                },
+                MmsConfirmedResponse::WriteJournal,
+                MmsConfirmedResponse::InitializeJournal { entries_deleted: vec![0x01, 0x00] },
+                MmsConfirmedResponse::ReportJournalStatus { current_entries: vec![0x7f], mms_deletable: true },
+                MmsConfirmedResponse::CreateJournal,
+                MmsConfirmedResponse::DeleteJournal,
+            ]
+        };
+        for request in journal_requests() {
+            test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request });
+        }
+        mms_client_writer.send(&mut test_buffer).await?;
+        for request in journal_requests() {
+            assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }));
+        }
+        for response in journal_responses() {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        for response in journal_responses() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response }));
+        }
+
+        let event_condition_name = || MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_Trip".into());
+        let event_enrollment_name = || MmsObjectName::AaSpecific("Breaker_Trip_Enrollment".into());
+        let event_requests = || {
+            vec![
+                MmsConfirmedRequest::DefineEventCondition {
+                    event_condition_name: event_condition_name(),
+                    class: MmsEventConditionClass::NetworkTriggered,
+                    priority: None,
+                    severity: None,
+                    alarm_summary_reports: None,
+                    monitored_variable: None,
+                    evaluation_interval: None,
+                },
+                MmsConfirmedRequest::DefineEventCondition {
+                    event_condition_name: event_condition_name(),
+                    class: MmsEventConditionClass::Monitored,
+                    priority: Some(vec![10]),
+                    severity: Some(vec![0x7f]),
+                    alarm_summary_reports: Some(true),
+                    monitored_variable: Some(Box::new(VariableSpecification::Name(MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_State".into())))),
+                    evaluation_interval: Some(vec![0x03, 0xe8]),
+                },
+                MmsConfirmedRequest::AlterEventConditionMonitoring { event_condition_name: event_condition_name(), enabled: Some(false), priority: None, alarm_summary_reports: None, evaluation_interval: None },
+                MmsConfirmedRequest::AlterEventConditionMonitoring { event_condition_name: event_condition_name(), enabled: None, priority: Some(vec![64]), alarm_summary_reports: Some(false), evaluation_interval: Some(vec![100]) },
+                MmsConfirmedRequest::GetEventConditionAttributes { event_condition_name: event_condition_name() },
+                MmsConfirmedRequest::DefineEventEnrollment {
+                    event_enrollment_name: event_enrollment_name(),
+                    event_condition_name: event_condition_name(),
+                    event_condition_transitions: vec![MmsEventTransition::ActiveToIdle, MmsEventTransition::IdleToActive],
+                    alarm_acknowledgment_rule: MmsAlarmAcknowledgmentRule::None,
+                    event_action_name: None,
+                    client_application: None,
+                },
+                MmsConfirmedRequest::DefineEventEnrollment {
+                    event_enrollment_name: event_enrollment_name(),
+                    event_condition_name: event_condition_name(),
+                    event_condition_transitions: vec![
+                        MmsEventTransition::IdleToDisabled,
+                        MmsEventTransition::ActiveToDisabled,
+                        MmsEventTransition::DisabledToIdle,
+                        MmsEventTransition::ActiveToIdle,
+                        MmsEventTransition::DisabledToActive,
+                        MmsEventTransition::IdleToActive,
+                        MmsEventTransition::AnyToDeleted,
+                    ],
+                    alarm_acknowledgment_rule: MmsAlarmAcknowledgmentRule::AckAll,
+                    event_action_name: Some(Box::new(MmsObjectName::VmdSpecific("Report".into()))),
+                    client_application: Some(Box::new(MmsApplicationReference { ap_title: Some(Oid::from(&[1, 1, 999, 1]).unwrap()), ap_invocation_id: None, ae_qualifier: Some(vec![12]), ae_invocation_id: None })),
+                },
+                MmsConfirmedRequest::AcknowledgeEventNotification {
+                    event_enrollment_name: event_enrollment_name(),
+                    acknowledged_state: MmsEventConditionState::Active,
+                    time_of_acknowledged_transition: MmsEventTime::TimeOfDay(MmsBinaryTime::with_date(43_200_000, 14_610)),
+                },
+                MmsConfirmedRequest::AcknowledgeEventNotification {
+                    event_enrollment_name: event_enrollment_name(),
+                    acknowledged_state: MmsEventConditionState::Idle,
+                    time_of_acknowledged_transition: MmsEventTime::TimeSequenceIdentifier(vec![0x01, 0x00]),
+                },
+                MmsConfirmedRequest::AcknowledgeEventNotification { event_enrollment_name: event_enrollment_name(), acknowledged_state: MmsEventConditionState::Disabled, time_of_acknowledged_transition: MmsEventTime::Undefined },
+            ]
+        };
+        let event_responses = || {
+            vec![
+                MmsConfirmedResponse::DefineEventCondition,
+                MmsConfirmedResponse::AlterEventConditionMonitoring,
+                MmsConfirmedResponse::GetEventConditionAttributes {
+                    mms_deletable: None,
+                    class: MmsEventConditionClass::NetworkTriggered,
+                    priority: None,
+                    severity: None,
+                    alarm_summary_reports: None,
+                    monitored_variable: None,
+                    evaluation_interval: None,
+                },
+                MmsConfirmedResponse::GetEventConditionAttributes {
+                    mms_deletable: Some(true),
+                    class: MmsEventConditionClass::Monitored,
+                    priority: Some(vec![10]),
+                    severity: Some(vec![0x7f]),
+                    alarm_summary_reports: Some(true),
+                    monitored_variable: Some(MmsMonitoredVariable::VariableReference(VariableSpecification::Name(MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_State".into())))),
+                    evaluation_interval: Some(vec![0x03, 0xe8]),
+                },
+                MmsConfirmedResponse::GetEventConditionAttributes {
+                    mms_deletable: Some(false),
+                    class: MmsEventConditionClass::Unknown(vec![5]),
+                    priority: None,
+                    severity: None,
+                    alarm_summary_reports: None,
+                    monitored_variable: Some(MmsMonitoredVariable::Undefined),
+                    evaluation_interval: None,
+                },
+                MmsConfirmedResponse::DefineEventEnrollment,
+                MmsConfirmedResponse::AcknowledgeEventNotification,
+            ]
+        };
+        for request in event_requests() {
+            test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request });
+        }
+        mms_client_writer.send(&mut test_buffer).await?;
+        for request in event_requests() {
+            assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }));
+        }
+        for response in event_responses() {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        for response in event_responses() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response }));
+        }
+
+        let event_notifications = || {
+            vec![
+                MmsEventNotification {
+                    event_enrollment_name: event_enrollment_name(),
+                    event_condition_name: event_condition_name(),
+                    severity: vec![64],
+                    current_state: None,
+                    transition_time: MmsEventTime::Undefined,
+                    notification_lost: None,
+                    alarm_acknowledgment_rule: None,
+                },
+                MmsEventNotification {
+                    event_enrollment_name: event_enrollment_name(),
+                    event_condition_name: event_condition_name(),
+                    severity: vec![0x7f],
+                    current_state: Some(MmsEventConditionState::Active),
+                    transition_time: MmsEventTime::TimeOfDay(MmsBinaryTime::time_of_day(1_000)),
+                    notification_lost: Some(true),
+                    alarm_acknowledgment_rule: Some(MmsAlarmAcknowledgmentRule::Simple),
+                },
+            ]
+        };
+        for event_notification in event_notifications() {
+            test_buffer.push_back(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::EventNotification(Box::new(event_notification)) });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        for event_notification in event_notifications() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::EventNotification(Box::new(event_notification)) }));
+        }
+
+        let semaphore_name = || MmsObjectName::DomainSpecific("ICCP".into(), "Breaker_Control".into());
+        let semaphore_requests = || {
+            vec![
+                MmsConfirmedRequest::TakeControl { semaphore_name: semaphore_name(), named_token: None, priority: None, acceptable_delay: None, control_timeout: None, abort_on_timeout: None, relinquish_if_connection_lost: None },
+                MmsConfirmedRequest::TakeControl {
+                    semaphore_name: semaphore_name(),
+                    named_token: Some("Operator1".into()),
+                    priority: Some(vec![10]),
+                    acceptable_delay: Some(vec![0x13, 0x88]),
+                    control_timeout: Some(vec![0x00, 0xea, 0x60]),
+                    abort_on_timeout: Some(true),
+                    relinquish_if_connection_lost: Some(false),
+                },
+                MmsConfirmedRequest::RelinquishControl { semaphore_name: semaphore_name(), named_token: None },
+                MmsConfirmedRequest::RelinquishControl { semaphore_name: semaphore_name(), named_token: Some("Operator1".into()) },
+                MmsConfirmedRequest::DefineSemaphore { semaphore_name: semaphore_name(), number_of_tokens: vec![2] },
+                MmsConfirmedRequest::DeleteSemaphore { semaphore_name: semaphore_name() },
+                MmsConfirmedRequest::ReportSemaphoreStatus { semaphore_name: semaphore_name() },
+            ]
+        };
+        let semaphore_responses = || {
+            vec![
+                MmsConfirmedResponse::TakeControl { named_token: None },
+                MmsConfirmedResponse::TakeControl { named_token: Some("Operator1".into()) },
+                MmsConfirmedResponse::RelinquishControl,
+                MmsConfirmedResponse::DefineSemaphore,
+                MmsConfirmedResponse::DeleteSemaphore,
+                MmsConfirmedResponse::ReportSemaphoreStatus { mms_deletable: true, class: MmsSemaphoreClass::Token, number_of_tokens: vec![2], number_of_owned_tokens: vec![1], number_of_hung_tokens: vec![0] },
+                MmsConfirmedResponse::ReportSemaphoreStatus { mms_deletable: false, class: MmsSemaphoreClass::Pool, number_of_tokens: vec![3], number_of_owned_tokens: vec![0], number_of_hung_tokens: vec![0] },
+            ]
+        };
+        for request in semaphore_requests() {
+            test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request });
+        }
+        mms_client_writer.send(&mut test_buffer).await?;
+        for request in semaphore_requests() {
+            assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }));
+        }
+        for response in semaphore_responses() {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        for response in semaphore_responses() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response }));
+        }
+
+        let maintenance_status = || MmsStatus { logical_status: MmsLogicalStatus::NoStateChangesAllowed, physical_status: MmsPhysicalStatus::NeedsCommissioning, local_detail: Some((4, vec![0xa5, 0xf0])) };
+        let vmd_requests = || {
+            vec![
+                MmsConfirmedRequest::Status { extended_derivation: false },
+                MmsConfirmedRequest::Status { extended_derivation: true },
+                MmsConfirmedRequest::GetCapabilityList { continue_after: None },
+                MmsConfirmedRequest::GetCapabilityList { continue_after: Some("Block1".into()) },
+                MmsConfirmedRequest::Rename { object_class: MmsObjectClass::Basic(MmsBasicObjectClass::NamedVariableList), current_name: MmsObjectName::DomainSpecific("ICCP".into(), "DS_Old".into()), new_identifier: "DS_New".into() },
+            ]
+        };
+        let vmd_responses = || {
+            vec![
+                MmsConfirmedResponse::Status(MmsStatus { logical_status: MmsLogicalStatus::StateChangesAllowed, physical_status: MmsPhysicalStatus::Operational, local_detail: None }),
+                MmsConfirmedResponse::Status(maintenance_status()),
+                MmsConfirmedResponse::GetCapabilityList { list_of_capabilities: vec![], more_follows: None },
+                MmsConfirmedResponse::GetCapabilityList { list_of_capabilities: vec!["Block2".into(), "Block5".into()], more_follows: Some(false) },
+                MmsConfirmedResponse::Rename,
+            ]
+        };
+        for request in vmd_requests() {
+            test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request });
+        }
+        mms_client_writer.send(&mut test_buffer).await?;
+        for request in vmd_requests() {
+            assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }));
+        }
+        for response in vmd_responses() {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response });
+        }
+        test_buffer.push_back(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::UnsolicitedStatus(maintenance_status()) });
+        mms_server_writer.send(&mut test_buffer).await?;
+        for response in vmd_responses() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response }));
+        }
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::Unconfirmed { unconfirmed_service: MmsUnconfirmedService::UnsolicitedStatus(maintenance_status()) }));
+
+        let program_requests = || {
+            vec![
+                MmsConfirmedRequest::CreateProgramInvocation { program_invocation_name: "PI1".into(), list_of_domain_names: vec!["ICC1".into(), "ICC2".into()], reusable: None, monitor_type: None },
+                MmsConfirmedRequest::CreateProgramInvocation { program_invocation_name: "PI2".into(), list_of_domain_names: vec![], reusable: Some(false), monitor_type: Some(true) },
+                MmsConfirmedRequest::DeleteProgramInvocation { program_invocation_name: "PI2".into() },
+                MmsConfirmedRequest::Start { program_invocation_name: "PI1".into(), execution_argument: None },
+                MmsConfirmedRequest::Start { program_invocation_name: "PI1".into(), execution_argument: Some("--fast".into()) },
+                MmsConfirmedRequest::Stop { program_invocation_name: "PI1".into() },
+                MmsConfirmedRequest::Resume { program_invocation_name: "PI1".into(), execution_argument: Some("--slow".into()) },
+                MmsConfirmedRequest::Reset { program_invocation_name: "PI1".into() },
+                MmsConfirmedRequest::Kill { program_invocation_name: "PI1".into() },
+                MmsConfirmedRequest::GetProgramInvocationAttributes { program_invocation_name: "PI1".into() },
+            ]
+        };
+        let program_responses = || {
+            vec![
+                MmsConfirmedResponse::CreateProgramInvocation,
+                MmsConfirmedResponse::DeleteProgramInvocation,
+                MmsConfirmedResponse::Start,
+                MmsConfirmedResponse::Stop,
+                MmsConfirmedResponse::Resume,
+                MmsConfirmedResponse::Reset,
+                MmsConfirmedResponse::Kill,
+                MmsConfirmedResponse::GetProgramInvocationAttributes {
+                    state: MmsProgramInvocationState::Running,
+                    list_of_domain_names: vec!["ICC1".into(), "ICC2".into()],
+                    mms_deletable: true,
+                    reusable: true,
+                    monitor: false,
+                    execution_argument: "--fast".into(),
+                },
+                MmsConfirmedResponse::GetProgramInvocationAttributes { state: MmsProgramInvocationState::Unknown(vec![9]), list_of_domain_names: vec![], mms_deletable: false, reusable: false, monitor: true, execution_argument: "".into() },
+            ]
+        };
+        for request in program_requests() {
+            test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request });
+        }
+        mms_client_writer.send(&mut test_buffer).await?;
+        for request in program_requests() {
+            assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }));
+        }
+        for response in program_responses() {
+            test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response });
+        }
+        mms_server_writer.send(&mut test_buffer).await?;
+        for response in program_responses() {
+            assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedResponse { invocation_id: vec![5], response }));
+        }
+        let request = MmsConfirmedRequest::Start { program_invocation_name: "PI1".into(), execution_argument: Some("--fäst".into()) };
+        assert!(mms_client_writer.send(&mut VecDeque::from(vec![MmsMessage::ConfirmedRequest { invocation_id: vec![5], request }])).await.is_err());
+
+        test_buffer.push_back(MmsMessage::ConfirmedRequest { invocation_id: vec![6], request: MmsConfirmedRequest::GetVariableAccessAttributes { object_name: MmsObjectName::VmdSpecific("Missing".into()) } });
+        mms_client_writer.send(&mut test_buffer).await?;
+        assert_eq!(
+            mms_server_reader.recv().await?,
+            MmsRecvResult::Message(MmsMessage::ConfirmedRequest { invocation_id: vec![6], request: MmsConfirmedRequest::GetVariableAccessAttributes { object_name: MmsObjectName::VmdSpecific("Missing".into()) } })
+        );
+        let service_error = MmsServiceErrorInfo { error_class: MmsErrorClass::Definition(MmsDefinitionError::ObjectUndefined), additional_code: Some(vec![0x01, 0x00]), additional_description: Some("Not Found".into()) };
+        test_buffer.push_back(MmsMessage::ConfirmedError { invocation_id: vec![6], service_error: service_error.clone() });
+        test_buffer.push_back(MmsMessage::ConfirmedError { invocation_id: vec![7], service_error: MmsServiceErrorInfo::new(MmsErrorClass::Access(MmsObjectAccessError::Unknown(vec![0x20]))) });
+        mms_server_writer.send(&mut test_buffer).await?;
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedError { invocation_id: vec![6], service_error }));
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedError { invocation_id: vec![7], service_error: MmsServiceErrorInfo::new(MmsErrorClass::Access(MmsObjectAccessError::Unknown(vec![0x20]))) }));
+
+        // The empty access error cannot be parsed so the client rejects the response, reports it for the local request and carries on with the next PDU.
+        test_buffer.push_back(MmsMessage::ConfirmedResponse { invocation_id: vec![8], response: MmsConfirmedResponse::Write { write_results: vec![MmsWriteResult::Failure(MmsAccessError::Unknown(vec![]))] } });
+        test_buffer.push_back(MmsMessage::Reject { original_invocation_id: Some(vec![9]), reject_reason: MmsRejectReason::ConfirmedRequestPdu(MmsConfirmedRequestRejectCode::UnrecognizedService) });
+        mms_server_writer.send(&mut test_buffer).await?;
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::ResponseRejected { original_invocation_id: vec![8], reject_reason: MmsRejectReason::ConfirmedResponsePdu(MmsConfirmedResponseRejectCode::InvalidResult) });
+        assert_eq!(
+            mms_client_reader.recv().await?,
+            MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id: Some(vec![9]), reject_reason: MmsRejectReason::ConfirmedRequestPdu(MmsConfirmedRequestRejectCode::UnrecognizedService) })
+        );
+        assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::Reject { original_invocation_id: Some(vec![8]), reject_reason: MmsRejectReason::ConfirmedResponsePdu(MmsConfirmedResponseRejectCode::InvalidResult) }));
+
+        test_buffer.push_back(MmsMessage::CancelRequest { invocation_id: vec![10] });
+        test_buffer.push_back(MmsMessage::CancelRequest { invocation_id: vec![11] });
+        mms_client_writer.send(&mut test_buffer).await?;
+        assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::CancelRequest { invocation_id: vec![10] }));
+        assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::CancelRequest { invocation_id: vec![11] }));
+        let service_error = MmsServiceErrorInfo::new(MmsErrorClass::Cancel(MmsCancelError::InvokeIdUnknown));
+        test_buffer.push_back(MmsMessage::ConfirmedError { invocation_id: vec![10], service_error: MmsServiceErrorInfo::new(MmsErrorClass::ServicePreempt(MmsServicePreemptError::Cancel)) });
+        test_buffer.push_back(MmsMessage::CancelResponse { invocation_id: vec![10] });
+        test_buffer.push_back(MmsMessage::CancelError { invocation_id: vec![11], service_error: service_error.clone() });
+        mms_server_writer.send(&mut test_buffer).await?;
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConfirmedError { invocation_id: vec![10], service_error: MmsServiceErrorInfo::new(MmsErrorClass::ServicePreempt(MmsServicePreemptError::Cancel)) }));
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::CancelResponse { invocation_id: vec![10] }));
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::CancelError { invocation_id: vec![11], service_error }));
+
+        test_buffer.push_back(MmsMessage::ConcludeRequest { request: MmsConcludeRequest {} });
+        mms_client_writer.send(&mut test_buffer).await?;
+        assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConcludeRequest { request: MmsConcludeRequest {} }));
+        test_buffer.push_back(MmsMessage::ConcludeResponse { request: MmsConcludeResponse {} });
+        mms_server_writer.send(&mut test_buffer).await?;
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConcludeResponse { request: MmsConcludeResponse {} }));
+
+        mms_client_writer.release().await?;
+        assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::ReleaseRequest);
+        mms_server_writer.release_response(ReleaseResult::Negative).await?;
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::ReleaseResponse(ReleaseResult::Negative));
+
+        // The association stays open after a refused release.
+        test_buffer.push_back(MmsMessage::ConcludeRequest { request: MmsConcludeRequest {} });
+        mms_client_writer.send(&mut test_buffer).await?;
+        assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::Message(MmsMessage::ConcludeRequest { request: MmsConcludeRequest {} }));
+
+        mms_client_writer.release().await?;
+        assert_eq!(mms_server_reader.recv().await?, MmsRecvResult::ReleaseRequest);
+        mms_server_writer.release_response(ReleaseResult::Affirmative).await?;
+        assert_eq!(mms_client_reader.recv().await?, MmsRecvResult::ReleaseResponse(ReleaseResult::Affirmative));
+        assert!(mms_client_writer.send(&mut VecDeque::from(vec![MmsMessage::ConcludeRequest { request: MmsConcludeRequest {} }])).await.is_err());
+
         sleep(Duration::from_millis(1000)).await;
 
         Ok(())
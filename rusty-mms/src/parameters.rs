@@ -22,7 +22,7 @@ pub enum ParameterSupportOption {
     Str1, // Bit 0
     Str2, // Bit 1
     Vnam, // Bit 2
-    // Valt, // Bit 3 - Not Supported
+    Valt, // Bit 3
     Vlis, // Bit 7
     Unsupported(u8),
 }
@@ -47,9 +47,9 @@ impl<'a> ParameterSupportOptionsBerObject<'a> {
                 ParameterSupportOption::Vnam => {
                     obj.data[0] |= 0x20;
                 }
-                // ParameterSupportOption::Valt => {
-                //     obj.data[0] |= 0x10;
-                // }
+                ParameterSupportOption::Valt => {
+                    obj.data[0] |= 0x10;
+                }
                 ParameterSupportOption::Vlis => {
                     obj.data[0] |= 0x01;
                 }
@@ -72,16 +72,64 @@ pub(crate) struct ServiceSupportOptions {
 
 #[derive(Clone, Copy)]
 pub enum ServiceSupportOption {
-    GetNameList,                   // Bit 1
-    Identify,                      // Bit 2
-    Read,                          // Bit 4
-    Write,                         // Bit 5
-    GetVariableAccessAttributes,   // Bit 6
-    DefineNamedVariableList,       // Bit 11
-    GetNamedVariableListAttribute, // Bit 12
-    DeleteNamedVariableList,       // Bit 13
-    InformationReport,             // Bit 79
-    Conclude,                      // Bit 83
+    Status,                         // Bit 0
+    GetNameList,                    // Bit 1
+    Identify,                       // Bit 2
+    Rename,                         // Bit 3
+    Read,                           // Bit 4
+    Write,                          // Bit 5
+    GetVariableAccessAttributes,    // Bit 6
+    DefineNamedVariableList,        // Bit 11
+    GetNamedVariableListAttribute,  // Bit 12
+    DeleteNamedVariableList,        // Bit 13
+    DefineNamedType,                // Bit 14
+    GetNamedTypeAttributes,         // Bit 15
+    DeleteNamedType,                // Bit 16
+    TakeControl,                    // Bit 19
+    RelinquishControl,              // Bit 20
+    DefineSemaphore,                // Bit 21
+    DeleteSemaphore,                // Bit 22
+    ReportSemaphoreStatus,          // Bit 23
+    InitiateDownloadSequence,       // Bit 26
+    DownloadSegment,                // Bit 27
+    TerminateDownloadSequence,      // Bit 28
+    InitiateUploadSequence,         // Bit 29
+    UploadSegment,                  // Bit 30
+    TerminateUploadSequence,        // Bit 31
+    DeleteDomain,                   // Bit 36
+    GetDomainAttributes,            // Bit 37
+    CreateProgramInvocation,        // Bit 38
+    DeleteProgramInvocation,        // Bit 39
+    Start,                          // Bit 40
+    Stop,                           // Bit 41
+    Resume,                         // Bit 42
+    Reset,                          // Bit 43
+    Kill,                           // Bit 44
+    GetProgramInvocationAttributes, // Bit 45
+    ObtainFile,                     // Bit 46
+    DefineEventCondition,           // Bit 47
+    GetEventConditionAttributes,    // Bit 49
+    AlterEventConditionMonitoring,  // Bit 51
+    DefineEventEnrollment,          // Bit 57
+    AcknowledgeEventNotification,   // Bit 62
+    ReadJournal,                    // Bit 65
+    WriteJournal,                   // Bit 66
+    InitializeJournal,              // Bit 67
+    ReportJournalStatus,            // Bit 68
+    CreateJournal,                  // Bit 69
+    DeleteJournal,                  // Bit 70
+    GetCapabilityList,              // Bit 71
+    FileOpen,                       // Bit 72
+    FileRead,                       // Bit 73
+    FileClose,                      // Bit 74
+    FileRename,                     // Bit 75
+    FileDelete,                     // Bit 76
+    FileDirectory,                  // Bit 77
+    UnsolicitedStatus,              // Bit 78
+    InformationReport,              // Bit 79
+    EventNotification,              // Bit 80
+    Conclude,                       // Bit 83
+    Cancel,                         // Bit 84
     Unsupported(u8),
 }
 
@@ -96,12 +144,18 @@ impl<'a> ServiceSupportOptionsBerObject<'a> {
 
         for option in service_support_options.options {
             match option {
+                ServiceSupportOption::Status => {
+                    obj.data[0] |= 0x80;
+                }
                 ServiceSupportOption::GetNameList => {
                     obj.data[0] |= 0x40;
                 }
                 ServiceSupportOption::Identify => {
                     obj.data[0] |= 0x20;
                 }
+                ServiceSupportOption::Rename => {
+                    obj.data[0] |= 0x10;
+                }
                 ServiceSupportOption::Read => {
                     obj.data[0] |= 0x08;
                 }
@@ -120,12 +174,150 @@ impl<'a> ServiceSupportOptionsBerObject<'a> {
                 ServiceSupportOption::DeleteNamedVariableList => {
                     obj.data[1] |= 0x04;
                 }
+                ServiceSupportOption::DefineNamedType => {
+                    obj.data[1] |= 0x02;
+                }
+                ServiceSupportOption::GetNamedTypeAttributes => {
+                    obj.data[1] |= 0x01;
+                }
+                ServiceSupportOption::DeleteNamedType => {
+                    obj.data[2] |= 0x80;
+                }
+                ServiceSupportOption::TakeControl => {
+                    obj.data[2] |= 0x10;
+                }
+                ServiceSupportOption::RelinquishControl => {
+                    obj.data[2] |= 0x08;
+                }
+                ServiceSupportOption::DefineSemaphore => {
+                    obj.data[2] |= 0x04;
+                }
+                ServiceSupportOption::DeleteSemaphore => {
+                    obj.data[2] |= 0x02;
+                }
+                ServiceSupportOption::ReportSemaphoreStatus => {
+                    obj.data[2] |= 0x01;
+                }
+                ServiceSupportOption::InitiateDownloadSequence => {
+                    obj.data[3] |= 0x20;
+                }
+                ServiceSupportOption::DownloadSegment => {
+                    obj.data[3] |= 0x10;
+                }
+                ServiceSupportOption::TerminateDownloadSequence => {
+                    obj.data[3] |= 0x08;
+                }
+                ServiceSupportOption::InitiateUploadSequence => {
+                    obj.data[3] |= 0x04;
+                }
+                ServiceSupportOption::UploadSegment => {
+                    obj.data[3] |= 0x02;
+                }
+                ServiceSupportOption::TerminateUploadSequence => {
+                    obj.data[3] |= 0x01;
+                }
+                ServiceSupportOption::DeleteDomain => {
+                    obj.data[4] |= 0x08;
+                }
+                ServiceSupportOption::GetDomainAttributes => {
+                    obj.data[4] |= 0x04;
+                }
+                ServiceSupportOption::CreateProgramInvocation => {
+                    obj.data[4] |= 0x02;
+                }
+                ServiceSupportOption::DeleteProgramInvocation => {
+                    obj.data[4] |= 0x01;
+                }
+                ServiceSupportOption::Start => {
+                    obj.data[5] |= 0x80;
+                }
+                ServiceSupportOption::Stop => {
+                    obj.data[5] |= 0x40;
+                }
+                ServiceSupportOption::Resume => {
+                    obj.data[5] |= 0x20;
+                }
+                ServiceSupportOption::Reset => {
+                    obj.data[5] |= 0x10;
+                }
+                ServiceSupportOption::Kill => {
+                    obj.data[5] |= 0x08;
+                }
+                ServiceSupportOption::GetProgramInvocationAttributes => {
+                    obj.data[5] |= 0x04;
+                }
+                ServiceSupportOption::ObtainFile => {
+                    obj.data[5] |= 0x02;
+                }
+                ServiceSupportOption::DefineEventCondition => {
+                    obj.data[5] |= 0x01;
+                }
+                ServiceSupportOption::GetEventConditionAttributes => {
+                    obj.data[6] |= 0x40;
+                }
+                ServiceSupportOption::AlterEventConditionMonitoring => {
+                    obj.data[6] |= 0x10;
+                }
+                ServiceSupportOption::DefineEventEnrollment => {
+                    obj.data[7] |= 0x40;
+                }
+                ServiceSupportOption::AcknowledgeEventNotification => {
+                    obj.data[7] |= 0x02;
+                }
+                ServiceSupportOption::ReadJournal => {
+                    obj.data[8] |= 0x40;
+                }
+                ServiceSupportOption::WriteJournal => {
+                    obj.data[8] |= 0x20;
+                }
+                ServiceSupportOption::InitializeJournal => {
+                    obj.data[8] |= 0x10;
+                }
+                ServiceSupportOption::ReportJournalStatus => {
+                    obj.data[8] |= 0x08;
+                }
+                ServiceSupportOption::CreateJournal => {
+                    obj.data[8] |= 0x04;
+                }
+                ServiceSupportOption::DeleteJournal => {
+                    obj.data[8] |= 0x02;
+                }
+                ServiceSupportOption::GetCapabilityList => {
+                    obj.data[8] |= 0x01;
+                }
+                ServiceSupportOption::FileOpen => {
+                    obj.data[9] |= 0x80;
+                }
+                ServiceSupportOption::FileRead => {
+                    obj.data[9] |= 0x40;
+                }
+                ServiceSupportOption::FileClose => {
+                    obj.data[9] |= 0x20;
+                }
+                ServiceSupportOption::FileRename => {
+                    obj.data[9] |= 0x10;
+                }
+                ServiceSupportOption::FileDelete => {
+                    obj.data[9] |= 0x08;
+                }
+                ServiceSupportOption::FileDirectory => {
+                    obj.data[9] |= 0x04;
+                }
+                ServiceSupportOption::UnsolicitedStatus => {
+                    obj.data[9] |= 0x02;
+                }
                 ServiceSupportOption::InformationReport => {
                     obj.data[9] |= 0x01;
                 }
+                ServiceSupportOption::EventNotification => {
+                    obj.data[10] |= 0x80;
+                }
                 ServiceSupportOption::Conclude => {
                     obj.data[10] |= 0x10;
                 }
+                ServiceSupportOption::Cancel => {
+                    obj.data[10] |= 0x08;
+                }
                 _ => (),
             }
         }
@@ -281,7 +473,7 @@ mod tests {
             (0, 5, vec![131, 3, 5, 128, 0], ParameterSupportOption::Str1),
             (1, 5, vec![131, 3, 5, 64, 0], ParameterSupportOption::Str2),
             (2, 5, vec![131, 3, 5, 32, 0], ParameterSupportOption::Vnam),
-            // (3, 4, vec![131, 3, 5, 16, 0], ParameterSupportOption::Valt),
+            (3, 5, vec![131, 3, 5, 16, 0], ParameterSupportOption::Valt),
             (7, 5, vec![131, 3, 5, 1, 0], ParameterSupportOption::Vlis),
         ];
 
@@ -329,16 +521,64 @@ mod tests {
     #[test]
     fn it_serialises_service_support_options() -> Result<(), anyhow::Error> {
         let subject_bits = vec![
+            (0, 3, vec![131, 12, 3, 128, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::Status),
             (1, 3, vec![131, 12, 3, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::GetNameList),
             (2, 3, vec![131, 12, 3, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::Identify),
+            (3, 3, vec![131, 12, 3, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::Rename),
             (4, 3, vec![131, 12, 3, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::Read),
             (5, 3, vec![131, 12, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::Write),
             (6, 3, vec![131, 12, 3, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::GetVariableAccessAttributes),
             (11, 3, vec![131, 12, 3, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DefineNamedVariableList),
             (12, 3, vec![131, 12, 3, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::GetNamedVariableListAttribute),
             (13, 3, vec![131, 12, 3, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DeleteNamedVariableList),
+            (14, 3, vec![131, 12, 3, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DefineNamedType),
+            (15, 3, vec![131, 12, 3, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::GetNamedTypeAttributes),
+            (16, 3, vec![131, 12, 3, 0, 0, 128, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DeleteNamedType),
+            (19, 3, vec![131, 12, 3, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::TakeControl),
+            (20, 3, vec![131, 12, 3, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::RelinquishControl),
+            (21, 3, vec![131, 12, 3, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DefineSemaphore),
+            (22, 3, vec![131, 12, 3, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DeleteSemaphore),
+            (23, 3, vec![131, 12, 3, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::ReportSemaphoreStatus),
+            (26, 3, vec![131, 12, 3, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::InitiateDownloadSequence),
+            (27, 3, vec![131, 12, 3, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DownloadSegment),
+            (28, 3, vec![131, 12, 3, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::TerminateDownloadSequence),
+            (29, 3, vec![131, 12, 3, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::InitiateUploadSequence),
+            (30, 3, vec![131, 12, 3, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::UploadSegment),
+            (31, 3, vec![131, 12, 3, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0, 0], ServiceSupportOption::TerminateUploadSequence),
+            (36, 3, vec![131, 12, 3, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DeleteDomain),
+            (37, 3, vec![131, 12, 3, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0], ServiceSupportOption::GetDomainAttributes),
+            (38, 3, vec![131, 12, 3, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0], ServiceSupportOption::CreateProgramInvocation),
+            (39, 3, vec![131, 12, 3, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 0], ServiceSupportOption::DeleteProgramInvocation),
+            (40, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 128, 0, 0, 0, 0, 0], ServiceSupportOption::Start),
+            (41, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0, 0], ServiceSupportOption::Stop),
+            (42, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0], ServiceSupportOption::Resume),
+            (43, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0], ServiceSupportOption::Reset),
+            (44, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 8, 0, 0, 0, 0, 0], ServiceSupportOption::Kill),
+            (45, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0], ServiceSupportOption::GetProgramInvocationAttributes),
+            (46, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0], ServiceSupportOption::ObtainFile),
+            (47, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0], ServiceSupportOption::DefineEventCondition),
+            (49, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0, 0], ServiceSupportOption::GetEventConditionAttributes),
+            (51, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0], ServiceSupportOption::AlterEventConditionMonitoring),
+            (57, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0, 0], ServiceSupportOption::DefineEventEnrollment),
+            (62, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0, 0], ServiceSupportOption::AcknowledgeEventNotification),
+            (65, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0, 0], ServiceSupportOption::ReadJournal),
+            (66, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0], ServiceSupportOption::WriteJournal),
+            (67, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0], ServiceSupportOption::InitializeJournal),
+            (68, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0], ServiceSupportOption::ReportJournalStatus),
+            (69, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0, 0], ServiceSupportOption::CreateJournal),
+            (70, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0, 0], ServiceSupportOption::DeleteJournal),
+            (71, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0, 0], ServiceSupportOption::GetCapabilityList),
+            (72, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128, 0], ServiceSupportOption::FileOpen),
+            (73, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 64, 0], ServiceSupportOption::FileRead),
+            (74, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0], ServiceSupportOption::FileClose),
+            (75, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0], ServiceSupportOption::FileRename),
+            (76, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0], ServiceSupportOption::FileDelete),
+            (77, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 4, 0], ServiceSupportOption::FileDirectory),
+            (78, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 2, 0], ServiceSupportOption::UnsolicitedStatus),
             (79, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 1, 0], ServiceSupportOption::InformationReport),
+            (80, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 128], ServiceSupportOption::EventNotification),
             (83, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16], ServiceSupportOption::Conclude),
+            (84, 3, vec![131, 12, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8], ServiceSupportOption::Cancel),
         ];
 
         for (subject_bit, expected_ignored_bits, expected_serilised_form, subject_option) in subject_bits {
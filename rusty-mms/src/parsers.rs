@@ -28,6 +28,10 @@ pub(crate) fn process_constructed_data<'a>(data: &'a [u8]) -> Result<Vec<Any<'a>
 
 // Pretty much visible string as we are not supporting the MMS char feature.
 pub(crate) fn process_mms_string<'a>(npm_object: &Any<'a>, error_message: &str) -> Result<String, MmsError> {
+    // The content length is passed as the maximum depth below, which rejects empty content.
+    if npm_object.data.is_empty() {
+        return Ok(String::new());
+    }
     let (_, inner_object) = parse_ber_content(Tag::VisibleString)(npm_object.data, &npm_object.header, npm_object.data.len()).map_err(to_mms_error(error_message))?;
 
     match inner_object {
@@ -142,7 +146,7 @@ pub(crate) fn process_mms_parameter_support_options<'a>(npm_object: &Any<'a>, er
             if bit_string_obj.is_set(0) { Some(ParameterSupportOption::Str1) } else { None },
             if bit_string_obj.is_set(1) { Some(ParameterSupportOption::Str2) } else { None },
             if bit_string_obj.is_set(2) { Some(ParameterSupportOption::Vnam) } else { None },
-            // if bit_string_obj.is_set(3) { Some(ParameterSupportOption::Valt) } else { None },
+            if bit_string_obj.is_set(3) { Some(ParameterSupportOption::Valt) } else { None },
             if bit_string_obj.is_set(7) { Some(ParameterSupportOption::Vlis) } else { None },
         ]
         .into_iter()
@@ -161,16 +165,64 @@ pub(crate) fn process_mms_service_support_option<'a>(npm_object: &Any<'a>, error
 
     Ok(ServiceSupportOptions {
         options: vec![
+            if bit_string_obj.is_set(0) { Some(ServiceSupportOption::Status) } else { None },
             if bit_string_obj.is_set(1) { Some(ServiceSupportOption::GetNameList) } else { None },
             if bit_string_obj.is_set(2) { Some(ServiceSupportOption::Identify) } else { None },
+            if bit_string_obj.is_set(3) { Some(ServiceSupportOption::Rename) } else { None },
             if bit_string_obj.is_set(4) { Some(ServiceSupportOption::Read) } else { None },
             if bit_string_obj.is_set(5) { Some(ServiceSupportOption::Write) } else { None },
             if bit_string_obj.is_set(6) { Some(ServiceSupportOption::GetVariableAccessAttributes) } else { None },
             if bit_string_obj.is_set(7) { Some(ServiceSupportOption::GetNamedVariableListAttribute) } else { None },
             if bit_string_obj.is_set(11) { Some(ServiceSupportOption::DefineNamedVariableList) } else { None },
             if bit_string_obj.is_set(13) { Some(ServiceSupportOption::DeleteNamedVariableList) } else { None },
+            if bit_string_obj.is_set(14) { Some(ServiceSupportOption::DefineNamedType) } else { None },
+            if bit_string_obj.is_set(15) { Some(ServiceSupportOption::GetNamedTypeAttributes) } else { None },
+            if bit_string_obj.is_set(16) { Some(ServiceSupportOption::DeleteNamedType) } else { None },
+            if bit_string_obj.is_set(19) { Some(ServiceSupportOption::TakeControl) } else { None },
+            if bit_string_obj.is_set(20) { Some(ServiceSupportOption::RelinquishControl) } else { None },
+            if bit_string_obj.is_set(21) { Some(ServiceSupportOption::DefineSemaphore) } else { None },
+            if bit_string_obj.is_set(22) { Some(ServiceSupportOption::DeleteSemaphore) } else { None },
+            if bit_string_obj.is_set(23) { Some(ServiceSupportOption::ReportSemaphoreStatus) } else { None },
+            if bit_string_obj.is_set(26) { Some(ServiceSupportOption::InitiateDownloadSequence) } else { None },
+            if bit_string_obj.is_set(27) { Some(ServiceSupportOption::DownloadSegment) } else { None },
+            if bit_string_obj.is_set(28) { Some(ServiceSupportOption::TerminateDownloadSequence) } else { None },
+            if bit_string_obj.is_set(29) { Some(ServiceSupportOption::InitiateUploadSequence) } else { None },
+            if bit_string_obj.is_set(30) { Some(ServiceSupportOption::UploadSegment) } else { None },
+            if bit_string_obj.is_set(31) { Some(ServiceSupportOption::TerminateUploadSequence) } else { None },
+            if bit_string_obj.is_set(36) { Some(ServiceSupportOption::DeleteDomain) } else { None },
+            if bit_string_obj.is_set(37) { Some(ServiceSupportOption::GetDomainAttributes) } else { None },
+            if bit_string_obj.is_set(38) { Some(ServiceSupportOption::CreateProgramInvocation) } else { None },
+            if bit_string_obj.is_set(39) { Some(ServiceSupportOption::DeleteProgramInvocation) } else { None },
+            if bit_string_obj.is_set(40) { Some(ServiceSupportOption::Start) } else { None },
+            if bit_string_obj.is_set(41) { Some(ServiceSupportOption::Stop) } else { None },
+            if bit_string_obj.is_set(42) { Some(ServiceSupportOption::Resume) } else { None },
+            if bit_string_obj.is_set(43) { Some(ServiceSupportOption::Reset) } else { None },
+            if bit_string_obj.is_set(44) { Some(ServiceSupportOption::Kill) } else { None },
+            if bit_string_obj.is_set(45) { Some(ServiceSupportOption::GetProgramInvocationAttributes) } else { None },
+            if bit_string_obj.is_set(46) { Some(ServiceSupportOption::ObtainFile) } else { None },
+            if bit_string_obj.is_set(47) { Some(ServiceSupportOption::DefineEventCondition) } else { None },
+            if bit_string_obj.is_set(49) { Some(ServiceSupportOption::GetEventConditionAttributes) } else { None },
+            if bit_string_obj.is_set(51) { Some(ServiceSupportOption::AlterEventConditionMonitoring) } else { None },
+            if bit_string_obj.is_set(57) { Some(ServiceSupportOption::DefineEventEnrollment) } else { None },
+            if bit_string_obj.is_set(62) { Some(ServiceSupportOption::AcknowledgeEventNotification) } else { None },
+            if bit_string_obj.is_set(65) { Some(ServiceSupportOption::ReadJournal) } else { None },
+            if bit_string_obj.is_set(66) { Some(ServiceSupportOption::WriteJournal) } else { None },
+            if bit_string_obj.is_set(67) { Some(ServiceSupportOption::InitializeJournal) } else { None },
+            if bit_string_obj.is_set(68) { Some(ServiceSupportOption::ReportJournalStatus) } else { None },
+            if bit_string_obj.is_set(69) { Some(ServiceSupportOption::CreateJournal) } else { None },
+            if bit_string_obj.is_set(70) { Some(ServiceSupportOption::DeleteJournal) } else { None },
+            if bit_string_obj.is_set(71) { Some(ServiceSupportOption::GetCapabilityList) } else { None },
+            if bit_string_obj.is_set(72) { Some(ServiceSupportOption::FileOpen) } else { None },
+            if bit_string_obj.is_set(73) { Some(ServiceSupportOption::FileRead) } else { None },
+            if bit_string_obj.is_set(74) { Some(ServiceSupportOption::FileClose) } else { None },
+            if bit_string_obj.is_set(75) { Some(ServiceSupportOption::FileRename) } else { None },
+            if bit_string_obj.is_set(76) { Some(ServiceSupportOption::FileDelete) } else { None },
+            if bit_string_obj.is_set(77) { Some(ServiceSupportOption::FileDirectory) } else { None },
+            if bit_string_obj.is_set(78) { Some(ServiceSupportOption::UnsolicitedStatus) } else { None },
             if bit_string_obj.is_set(79) { Some(ServiceSupportOption::InformationReport) } else { None },
+            if bit_string_obj.is_set(80) { Some(ServiceSupportOption::EventNotification) } else { None },
             if bit_string_obj.is_set(83) { Some(ServiceSupportOption::Conclude) } else { None },
+            if bit_string_obj.is_set(84) { Some(ServiceSupportOption::Cancel) } else { None },
         ]
         .into_iter()
         .filter_map(|i| i)
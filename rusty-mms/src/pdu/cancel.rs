@@ -0,0 +1,64 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsError, MmsMessage, MmsServiceErrorInfo,
+    error::to_mms_error,
+    parsers::process_constructed_data,
+    pdu::serviceerror::{parse_service_error, service_error_to_ber},
+};
+
+pub(crate) fn parse_cancel_request(payload: &Any<'_>) -> Result<MmsMessage, MmsError> {
+    Ok(MmsMessage::CancelRequest { invocation_id: parse_cancel_invocation_id(payload, "Cancel Request")? })
+}
+
+pub(crate) fn parse_cancel_response(payload: &Any<'_>) -> Result<MmsMessage, MmsError> {
+    Ok(MmsMessage::CancelResponse { invocation_id: parse_cancel_invocation_id(payload, "Cancel Response")? })
+}
+
+fn parse_cancel_invocation_id(payload: &Any<'_>, pdu: &str) -> Result<Vec<u8>, MmsError> {
+    if payload.data.is_empty() {
+        return Err(MmsError::ProtocolError(format!("No invocation id on {}", pdu)));
+    }
+    Ok(payload.data.to_vec())
+}
+
+pub(crate) fn parse_cancel_error(payload: Any<'_>) -> Result<MmsMessage, MmsError> {
+    let mut invocation_id = None;
+    let mut service_error = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Cancel Error Payload"))? {
+        match item.header.raw_tag() {
+            Some(&[128]) => invocation_id = Some(item.data.to_vec()),
+            Some(&[161]) => service_error = Some(parse_service_error(&item)?),
+            x => warn!("Failed to parse unknown MMS Cancel Error Item: {:?}", x),
+        }
+    }
+
+    Ok(MmsMessage::CancelError {
+        invocation_id: invocation_id.ok_or_else(|| MmsError::ProtocolError("No invocation id on cancel error".into()))?,
+        service_error: service_error.ok_or_else(|| MmsError::ProtocolError("No service error on cancel error".into()))?,
+    })
+}
+
+pub(crate) fn cancel_request_to_ber<'a>(invocation_id: &'a [u8]) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(5), Length::Definite(0)), BerObjectContent::Integer(invocation_id))
+}
+
+pub(crate) fn cancel_response_to_ber<'a>(invocation_id: &'a [u8]) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(6), Length::Definite(0)), BerObjectContent::Integer(invocation_id))
+}
+
+pub(crate) fn cancel_error_to_ber<'a>(invocation_id: &'a [u8], service_error: &'a MmsServiceErrorInfo) -> BerObject<'a> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(7), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(invocation_id)),
+            service_error_to_ber(1, service_error),
+        ]),
+    )
+}
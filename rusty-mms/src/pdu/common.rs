@@ -1,5 +1,90 @@
-use crate::MmsError;
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+
+use crate::{MmsError, error::to_mms_error, parsers::process_constructed_data};
 
 pub(crate) fn expect_value<T>(pdu: &str, field: &str, value: Option<T>) -> Result<T, MmsError> {
     value.ok_or_else(|| MmsError::ProtocolError(format!("MMS Payload '{}' must container the field '{}' but was not found.", pdu, field)))
 }
+
+// Unwraps an explicitly tagged item.
+pub(crate) fn parse_explicit<'a>(payload: &Any<'a>, error_message: &str) -> Result<Any<'a>, MmsError> {
+    process_constructed_data(payload.data).map_err(to_mms_error(error_message))?.into_iter().next().ok_or_else(|| MmsError::ProtocolError(error_message.into()))
+}
+
+pub(crate) fn explicit_to_ber(tag: u32, item: BerObject<'_>) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)), BerObjectContent::Sequence(vec![item]))
+}
+
+// Maps the named numbers of an error or reject code onto its enum. Any value not in the list is kept as Unknown.
+// A visibility may be given for codes shared between PDUs.
+macro_rules! error_codes {
+    ($vis: vis $code_type: ident, $($variant: ident => $value: literal),* $(,)?) => {
+        impl $code_type {
+            $vis fn parse(value: &[u8]) -> $code_type {
+                match value {
+                    $([$value] => $code_type::$variant,)*
+                    x => $code_type::Unknown(x.to_vec()),
+                }
+            }
+
+            $vis fn to_ber(&self) -> &[u8] {
+                match self {
+                    $($code_type::$variant => &[$value],)*
+                    $code_type::Unknown(x) => x.as_slice(),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use error_codes;
+
+// der-parser cannot serialise tags of 30 and above, which most MMS services use. Constructed objects are assembled here and only primitive content is handed to der-parser.
+pub(crate) fn ber_object_to_vec(object: &BerObject<'_>) -> Result<Vec<u8>, MmsError> {
+    let content = match &object.content {
+        BerObjectContent::Sequence(items) | BerObjectContent::Set(items) => {
+            let mut content = vec![];
+            for item in items {
+                content.extend(ber_object_to_vec(item)?);
+            }
+            content
+        }
+        content => {
+            let low_tag_header = Header::new(object.header.class(), object.header.constructed(), Tag::from(0), Length::Definite(0));
+            let encoded = BerObject::from_header_and_content(low_tag_header, content.clone()).to_vec().map_err(to_mms_error("Failed to serialise message"))?;
+            let header_length = match encoded.get(1) {
+                Some(x) if x & 0x80 != 0 => 2 + (x & 0x7f) as usize,
+                _ => 2,
+            };
+            encoded.get(header_length..).ok_or_else(|| MmsError::InternalError("Failed to serialise message. This is a bug.".into()))?.to_vec()
+        }
+    };
+
+    let mut data = vec![((object.header.class() as u8) << 6) | if object.header.constructed() { 0x20 } else { 0 }];
+    match object.header.tag().0 {
+        tag if tag < 31 => data[0] |= tag as u8,
+        tag => {
+            data[0] |= 0x1f;
+            let mut tag_octets = vec![(tag & 0x7f) as u8];
+            let mut remaining = tag >> 7;
+            while remaining > 0 {
+                tag_octets.push((remaining & 0x7f) as u8 | 0x80);
+                remaining >>= 7;
+            }
+            data.extend(tag_octets.iter().rev());
+        }
+    }
+    if content.len() < 128 {
+        data.push(content.len() as u8);
+    } else {
+        let length_octets: Vec<u8> = content.len().to_be_bytes().into_iter().skip_while(|x| *x == 0).collect();
+        data.push(0x80 | length_octets.len() as u8);
+        data.extend(length_octets);
+    }
+    data.extend(content);
+    Ok(data)
+}
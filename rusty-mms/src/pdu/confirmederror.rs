@@ -0,0 +1,42 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsError, MmsMessage, MmsServiceErrorInfo,
+    error::to_mms_error,
+    parsers::process_constructed_data,
+    pdu::serviceerror::{parse_service_error, service_error_to_ber},
+};
+
+pub(crate) fn parse_confirmed_error(payload: Any<'_>) -> Result<MmsMessage, MmsError> {
+    let mut invocation_id = None;
+    let mut service_error = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Confirmed Error Payload"))? {
+        match item.header.raw_tag() {
+            Some(&[128]) => invocation_id = Some(item.data.to_vec()),
+            Some(&[162]) => service_error = Some(parse_service_error(&item)?),
+            // Modifier position is not supported as modifiers are not supported.
+            x => warn!("Failed to parse unknown MMS Confirmed Error Item: {:?}", x),
+        }
+    }
+
+    Ok(MmsMessage::ConfirmedError {
+        invocation_id: invocation_id.ok_or_else(|| MmsError::ProtocolError("No invocation id on confirmed error".into()))?,
+        service_error: service_error.ok_or_else(|| MmsError::ProtocolError("No service error on confirmed error".into()))?,
+    })
+}
+
+pub(crate) fn confirmed_error_to_ber<'a>(invocation_id: &'a [u8], service_error: &'a MmsServiceErrorInfo) -> BerObject<'a> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(2), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(invocation_id)),
+            service_error_to_ber(2, service_error),
+        ]),
+    )
+}
@@ -10,13 +10,43 @@ use crate::{
     error::to_mms_error,
     parsers::process_constructed_data,
     pdu::{
+        definenamedtyperequest::{define_named_type_request_to_ber, parse_define_named_type_request},
         definenamedvariablelistrequest::{define_named_variable_list_reqeust_to_ber, parse_define_named_variable_list_reqeust},
+        deletenamedtyperequest::{delete_named_type_request_to_ber, parse_delete_named_type_request},
         deletenamedvariablelistrequest::{delete_named_variable_list_reqeust_to_ber, parse_delete_named_variable_list_reqeust},
+        domainrequest::{
+            delete_domain_request_to_ber, download_segment_request_to_ber, get_domain_attributes_request_to_ber, initiate_download_sequence_request_to_ber, initiate_upload_sequence_request_to_ber, parse_delete_domain_request,
+            parse_download_segment_request, parse_get_domain_attributes_request, parse_initiate_download_sequence_request, parse_initiate_upload_sequence_request, parse_terminate_download_sequence_request,
+            parse_terminate_upload_sequence_request, parse_upload_segment_request, terminate_download_sequence_request_to_ber, terminate_upload_sequence_request_to_ber, upload_segment_request_to_ber,
+        },
+        eventrequest::{
+            acknowledge_event_notification_request_to_ber, alter_event_condition_monitoring_request_to_ber, define_event_condition_request_to_ber, define_event_enrollment_request_to_ber, get_event_condition_attributes_request_to_ber,
+            parse_acknowledge_event_notification_request, parse_alter_event_condition_monitoring_request, parse_define_event_condition_request, parse_define_event_enrollment_request, parse_get_event_condition_attributes_request,
+        },
+        filerequest::{
+            file_close_request_to_ber, file_delete_request_to_ber, file_directory_request_to_ber, file_open_request_to_ber, file_read_request_to_ber, file_rename_request_to_ber, obtain_file_request_to_ber, parse_file_close_request,
+            parse_file_delete_request, parse_file_directory_request, parse_file_open_request, parse_file_read_request, parse_file_rename_request, parse_obtain_file_request,
+        },
+        getnamedtypeattributesrequest::{get_named_type_attributes_request_to_ber, parse_get_named_type_attributes_request},
         getnamedvariablelistattributerequest::{get_named_variable_list_attributes_reqeust_to_ber, parse_get_named_variable_list_attributes_reqeust},
         getnamelistrequest::{get_name_list_request_to_ber, parse_get_name_list_request},
         getvariableaccessattributesrequest::{get_variable_access_attributes_reqeust_to_ber, parse_get_variable_access_attributes_reqeust},
         identifyrequest::{identify_request_to_ber, parse_identify_request},
+        journalrequest::{
+            create_journal_request_to_ber, delete_journal_request_to_ber, initialize_journal_request_to_ber, parse_create_journal_request, parse_delete_journal_request, parse_initialize_journal_request, parse_read_journal_request,
+            parse_report_journal_status_request, parse_write_journal_request, read_journal_request_to_ber, report_journal_status_request_to_ber, write_journal_request_to_ber,
+        },
+        programrequest::{
+            create_program_invocation_request_to_ber, delete_program_invocation_request_to_ber, get_program_invocation_attributes_request_to_ber, kill_request_to_ber, parse_create_program_invocation_request,
+            parse_delete_program_invocation_request, parse_get_program_invocation_attributes_request, parse_kill_request, parse_reset_request, parse_resume_request, parse_start_request, parse_stop_request, reset_request_to_ber,
+            resume_request_to_ber, start_request_to_ber, stop_request_to_ber,
+        },
         readrequest::{parse_read_request, read_request_to_ber},
+        semaphorerequest::{
+            define_semaphore_request_to_ber, delete_semaphore_request_to_ber, parse_define_semaphore_request, parse_delete_semaphore_request, parse_relinquish_control_request, parse_report_semaphore_status_request,
+            parse_take_control_request, relinquish_control_request_to_ber, report_semaphore_status_request_to_ber, take_control_request_to_ber,
+        },
+        vmdrequest::{get_capability_list_request_to_ber, parse_get_capability_list_request, parse_rename_request, parse_status_request, rename_request_to_ber, status_request_to_ber},
         writerequest::{parse_write_request, write_request_to_ber},
     },
 };
@@ -28,16 +58,11 @@ pub(crate) fn parse_confirmed_request(payload: Any<'_>) -> Result<MmsMessage, Mm
     for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Confirmed Request Payload"))? {
         match item.header.raw_tag() {
             Some(&[2]) => invocation_id = Some(item.data.to_vec()),
-            Some(&[161]) => confirmed_payload = Some(parse_get_name_list_request(&item)?),
-            Some(&[130]) => confirmed_payload = Some(parse_identify_request(&item)?),
-            Some(&[164]) => confirmed_payload = Some(parse_read_request(&item)?),
-            Some(&[165]) => confirmed_payload = Some(parse_write_request(&item)?),
-            Some(&[166]) => confirmed_payload = Some(parse_get_variable_access_attributes_reqeust(&item)?),
-            Some(&[171]) => confirmed_payload = Some(parse_define_named_variable_list_reqeust(&item)?),
-            Some(&[172]) => confirmed_payload = Some(parse_get_named_variable_list_attributes_reqeust(&item)?),
-            Some(&[173]) => confirmed_payload = Some(parse_delete_named_variable_list_reqeust(&item)?),
-            // TODO Moar!!!
-            x => warn!("Failed to parse unknown MMS Confirmed Request Item: {:?}", x),
+            x => match parse_confirmed_service_request(&item) {
+                Some(request) => confirmed_payload = Some(request?),
+                // TODO Moar!!!
+                None => warn!("Failed to parse unknown MMS Confirmed Request Item: {:?}", x),
+            },
         }
     }
 
@@ -47,6 +72,66 @@ pub(crate) fn parse_confirmed_request(payload: Any<'_>) -> Result<MmsMessage, Mm
     })
 }
 
+/// Returns None if the service is not supported so the request can be rejected as unrecognised.
+pub(crate) fn parse_confirmed_service_request(item: &Any<'_>) -> Option<Result<MmsConfirmedRequest, MmsError>> {
+    Some(match item.header.raw_tag() {
+        Some(&[161]) => parse_get_name_list_request(item),
+        Some(&[128]) => parse_status_request(item),
+        Some(&[130]) => parse_identify_request(item),
+        Some(&[163]) => parse_rename_request(item),
+        Some(&[191, 71]) => parse_get_capability_list_request(item),
+        Some(&[164]) => parse_read_request(item),
+        Some(&[165]) => parse_write_request(item),
+        Some(&[166]) => parse_get_variable_access_attributes_reqeust(item),
+        Some(&[171]) => parse_define_named_variable_list_reqeust(item),
+        Some(&[172]) => parse_get_named_variable_list_attributes_reqeust(item),
+        Some(&[173]) => parse_delete_named_variable_list_reqeust(item),
+        Some(&[174]) => parse_define_named_type_request(item),
+        Some(&[175]) => parse_get_named_type_attributes_request(item),
+        Some(&[176]) => parse_delete_named_type_request(item),
+        Some(&[179]) => parse_take_control_request(item),
+        Some(&[180]) => parse_relinquish_control_request(item),
+        Some(&[181]) => parse_define_semaphore_request(item),
+        Some(&[182]) => parse_delete_semaphore_request(item),
+        Some(&[183]) => parse_report_semaphore_status_request(item),
+        Some(&[186]) => parse_initiate_download_sequence_request(item),
+        Some(&[155]) => parse_download_segment_request(item),
+        Some(&[188]) => parse_terminate_download_sequence_request(item),
+        Some(&[157]) => parse_initiate_upload_sequence_request(item),
+        Some(&[158]) => parse_upload_segment_request(item),
+        Some(&[159, 31]) => parse_terminate_upload_sequence_request(item),
+        Some(&[159, 36]) => parse_delete_domain_request(item),
+        Some(&[159, 37]) => parse_get_domain_attributes_request(item),
+        Some(&[191, 38]) => parse_create_program_invocation_request(item),
+        Some(&[159, 39]) => parse_delete_program_invocation_request(item),
+        Some(&[191, 40]) => parse_start_request(item),
+        Some(&[191, 41]) => parse_stop_request(item),
+        Some(&[191, 42]) => parse_resume_request(item),
+        Some(&[191, 43]) => parse_reset_request(item),
+        Some(&[191, 44]) => parse_kill_request(item),
+        Some(&[159, 45]) => parse_get_program_invocation_attributes_request(item),
+        Some(&[191, 46]) => parse_obtain_file_request(item),
+        Some(&[191, 72]) => parse_file_open_request(item),
+        Some(&[159, 73]) => parse_file_read_request(item),
+        Some(&[159, 74]) => parse_file_close_request(item),
+        Some(&[191, 75]) => parse_file_rename_request(item),
+        Some(&[191, 76]) => parse_file_delete_request(item),
+        Some(&[191, 77]) => parse_file_directory_request(item),
+        Some(&[191, 65]) => parse_read_journal_request(item),
+        Some(&[191, 66]) => parse_write_journal_request(item),
+        Some(&[191, 67]) => parse_initialize_journal_request(item),
+        Some(&[191, 68]) => parse_report_journal_status_request(item),
+        Some(&[191, 69]) => parse_create_journal_request(item),
+        Some(&[191, 70]) => parse_delete_journal_request(item),
+        Some(&[191, 47]) => parse_define_event_condition_request(item),
+        Some(&[191, 51]) => parse_alter_event_condition_monitoring_request(item),
+        Some(&[191, 49]) => parse_get_event_condition_attributes_request(item),
+        Some(&[191, 57]) => parse_define_event_enrollment_request(item),
+        Some(&[191, 62]) => parse_acknowledge_event_notification_request(item),
+        _ => return None,
+    })
+}
+
 pub(crate) fn confirmed_request_to_ber<'a>(invocation_id: &'a [u8], payload: &'a MmsConfirmedRequest) -> Result<BerObject<'a>, MmsError> {
     Ok(BerObject::from_header_and_content(
         Header::new(Class::ContextSpecific, true, Tag::from(0), Length::Definite(0)),
@@ -55,12 +140,71 @@ pub(crate) fn confirmed_request_to_ber<'a>(invocation_id: &'a [u8], payload: &'a
             match payload {
                 MmsConfirmedRequest::GetNameList { object_class, object_scope, continue_after } => get_name_list_request_to_ber(object_class, object_scope, continue_after)?,
                 MmsConfirmedRequest::Identify => identify_request_to_ber(),
+                MmsConfirmedRequest::Status { extended_derivation } => status_request_to_ber(*extended_derivation)?,
+                MmsConfirmedRequest::GetCapabilityList { continue_after } => get_capability_list_request_to_ber(continue_after)?,
+                MmsConfirmedRequest::Rename { object_class, current_name, new_identifier } => rename_request_to_ber(object_class, current_name, new_identifier)?,
                 MmsConfirmedRequest::Read { specification_with_result, variable_access_specification } => read_request_to_ber(specification_with_result, variable_access_specification),
                 MmsConfirmedRequest::Write { variable_access_specification, list_of_data } => write_request_to_ber(variable_access_specification, list_of_data)?,
                 MmsConfirmedRequest::GetVariableAccessAttributes { object_name } => get_variable_access_attributes_reqeust_to_ber(object_name)?,
                 MmsConfirmedRequest::DefineNamedVariableList { variable_list_name, list_of_variables } => define_named_variable_list_reqeust_to_ber(variable_list_name, list_of_variables)?,
                 MmsConfirmedRequest::GetNamedVariableListAttributes { object_name } => get_named_variable_list_attributes_reqeust_to_ber(object_name)?,
                 MmsConfirmedRequest::DeleteNamedVariableList { scope_of_delete, list_of_variable_list_names, domain_name } => delete_named_variable_list_reqeust_to_ber(scope_of_delete, list_of_variable_list_names, domain_name)?,
+                MmsConfirmedRequest::DefineNamedType { type_name, type_specification } => define_named_type_request_to_ber(type_name, type_specification)?,
+                MmsConfirmedRequest::GetNamedTypeAttributes { type_name } => get_named_type_attributes_request_to_ber(type_name)?,
+                MmsConfirmedRequest::DeleteNamedType { scope_of_delete, list_of_type_names, domain_name } => delete_named_type_request_to_ber(scope_of_delete, list_of_type_names, domain_name)?,
+                MmsConfirmedRequest::InitiateDownloadSequence { domain_name, list_of_capabilities, sharable } => initiate_download_sequence_request_to_ber(domain_name, list_of_capabilities, *sharable)?,
+                MmsConfirmedRequest::DownloadSegment { domain_name } => download_segment_request_to_ber(domain_name)?,
+                MmsConfirmedRequest::TerminateDownloadSequence { domain_name, discard } => terminate_download_sequence_request_to_ber(domain_name, discard)?,
+                MmsConfirmedRequest::InitiateUploadSequence { domain_name } => initiate_upload_sequence_request_to_ber(domain_name)?,
+                MmsConfirmedRequest::UploadSegment { ulsm_id } => upload_segment_request_to_ber(ulsm_id)?,
+                MmsConfirmedRequest::TerminateUploadSequence { ulsm_id } => terminate_upload_sequence_request_to_ber(ulsm_id)?,
+                MmsConfirmedRequest::DeleteDomain { domain_name } => delete_domain_request_to_ber(domain_name)?,
+                MmsConfirmedRequest::GetDomainAttributes { domain_name } => get_domain_attributes_request_to_ber(domain_name)?,
+                MmsConfirmedRequest::CreateProgramInvocation { program_invocation_name, list_of_domain_names, reusable, monitor_type } => {
+                    create_program_invocation_request_to_ber(program_invocation_name, list_of_domain_names, reusable, monitor_type)?
+                }
+                MmsConfirmedRequest::DeleteProgramInvocation { program_invocation_name } => delete_program_invocation_request_to_ber(program_invocation_name)?,
+                MmsConfirmedRequest::Start { program_invocation_name, execution_argument } => start_request_to_ber(program_invocation_name, execution_argument)?,
+                MmsConfirmedRequest::Stop { program_invocation_name } => stop_request_to_ber(program_invocation_name)?,
+                MmsConfirmedRequest::Resume { program_invocation_name, execution_argument } => resume_request_to_ber(program_invocation_name, execution_argument)?,
+                MmsConfirmedRequest::Reset { program_invocation_name } => reset_request_to_ber(program_invocation_name)?,
+                MmsConfirmedRequest::Kill { program_invocation_name } => kill_request_to_ber(program_invocation_name)?,
+                MmsConfirmedRequest::GetProgramInvocationAttributes { program_invocation_name } => get_program_invocation_attributes_request_to_ber(program_invocation_name)?,
+                MmsConfirmedRequest::ObtainFile { source_file, destination_file } => obtain_file_request_to_ber(source_file, destination_file)?,
+                MmsConfirmedRequest::FileOpen { file_name, initial_position } => file_open_request_to_ber(file_name, initial_position)?,
+                MmsConfirmedRequest::FileRead { frsm_id } => file_read_request_to_ber(frsm_id)?,
+                MmsConfirmedRequest::FileClose { frsm_id } => file_close_request_to_ber(frsm_id)?,
+                MmsConfirmedRequest::FileRename { current_file_name, new_file_name } => file_rename_request_to_ber(current_file_name, new_file_name)?,
+                MmsConfirmedRequest::FileDelete { file_name } => file_delete_request_to_ber(file_name)?,
+                MmsConfirmedRequest::FileDirectory { file_specification, continue_after } => file_directory_request_to_ber(file_specification, continue_after)?,
+                MmsConfirmedRequest::ReadJournal { journal_name, range_start_specification, range_stop_specification, list_of_variables, entry_to_start_after } => {
+                    read_journal_request_to_ber(journal_name, range_start_specification, range_stop_specification, list_of_variables, entry_to_start_after)?
+                }
+                MmsConfirmedRequest::WriteJournal { journal_name, list_of_journal_entry } => write_journal_request_to_ber(journal_name, list_of_journal_entry)?,
+                MmsConfirmedRequest::InitializeJournal { journal_name, limit_specification } => initialize_journal_request_to_ber(journal_name, limit_specification)?,
+                MmsConfirmedRequest::ReportJournalStatus { journal_name } => report_journal_status_request_to_ber(journal_name)?,
+                MmsConfirmedRequest::CreateJournal { journal_name } => create_journal_request_to_ber(journal_name)?,
+                MmsConfirmedRequest::DeleteJournal { journal_name } => delete_journal_request_to_ber(journal_name)?,
+                MmsConfirmedRequest::DefineEventCondition { event_condition_name, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval } => {
+                    define_event_condition_request_to_ber(event_condition_name, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval)?
+                }
+                MmsConfirmedRequest::AlterEventConditionMonitoring { event_condition_name, enabled, priority, alarm_summary_reports, evaluation_interval } => {
+                    alter_event_condition_monitoring_request_to_ber(event_condition_name, enabled, priority, alarm_summary_reports, evaluation_interval)?
+                }
+                MmsConfirmedRequest::GetEventConditionAttributes { event_condition_name } => get_event_condition_attributes_request_to_ber(event_condition_name)?,
+                MmsConfirmedRequest::DefineEventEnrollment { event_enrollment_name, event_condition_name, event_condition_transitions, alarm_acknowledgment_rule, event_action_name, client_application } => {
+                    define_event_enrollment_request_to_ber(event_enrollment_name, event_condition_name, event_condition_transitions, alarm_acknowledgment_rule, event_action_name, client_application)?
+                }
+                MmsConfirmedRequest::AcknowledgeEventNotification { event_enrollment_name, acknowledged_state, time_of_acknowledged_transition } => {
+                    acknowledge_event_notification_request_to_ber(event_enrollment_name, acknowledged_state, time_of_acknowledged_transition)?
+                }
+                MmsConfirmedRequest::TakeControl { semaphore_name, named_token, priority, acceptable_delay, control_timeout, abort_on_timeout, relinquish_if_connection_lost } => {
+                    take_control_request_to_ber(semaphore_name, named_token, priority, acceptable_delay, control_timeout, abort_on_timeout, relinquish_if_connection_lost)?
+                }
+                MmsConfirmedRequest::RelinquishControl { semaphore_name, named_token } => relinquish_control_request_to_ber(semaphore_name, named_token)?,
+                MmsConfirmedRequest::DefineSemaphore { semaphore_name, number_of_tokens } => define_semaphore_request_to_ber(semaphore_name, number_of_tokens)?,
+                MmsConfirmedRequest::DeleteSemaphore { semaphore_name } => delete_semaphore_request_to_ber(semaphore_name)?,
+                MmsConfirmedRequest::ReportSemaphoreStatus { semaphore_name } => report_semaphore_status_request_to_ber(semaphore_name)?,
             },
         ]),
     ))
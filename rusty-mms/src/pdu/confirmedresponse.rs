@@ -6,12 +6,42 @@ use der_parser::{
 use tracing::warn;
 
 use crate::pdu::{
+    definenamedtyperesponse::{define_named_type_response_to_ber, parse_define_named_type_response},
     definenamedvariablelistresponse::{define_named_variable_list_response_to_ber, parse_define_named_variable_list_response},
+    deletenamedtyperesponse::{delete_named_type_response_to_ber, parse_delete_named_type_response},
     deletenamedvariablelistresponse::{delete_named_variable_list_response_to_ber, parse_delete_named_variable_list_response},
+    domainresponse::{
+        delete_domain_response_to_ber, download_segment_response_to_ber, get_domain_attributes_response_to_ber, initiate_download_sequence_response_to_ber, initiate_upload_sequence_response_to_ber, parse_delete_domain_response,
+        parse_download_segment_response, parse_get_domain_attributes_response, parse_initiate_download_sequence_response, parse_initiate_upload_sequence_response, parse_terminate_download_sequence_response,
+        parse_terminate_upload_sequence_response, parse_upload_segment_response, terminate_download_sequence_response_to_ber, terminate_upload_sequence_response_to_ber, upload_segment_response_to_ber,
+    },
+    eventresponse::{
+        acknowledge_event_notification_response_to_ber, alter_event_condition_monitoring_response_to_ber, define_event_condition_response_to_ber, define_event_enrollment_response_to_ber, get_event_condition_attributes_response_to_ber,
+        parse_acknowledge_event_notification_response, parse_alter_event_condition_monitoring_response, parse_define_event_condition_response, parse_define_event_enrollment_response, parse_get_event_condition_attributes_response,
+    },
+    fileresponse::{
+        file_close_response_to_ber, file_delete_response_to_ber, file_directory_response_to_ber, file_open_response_to_ber, file_read_response_to_ber, file_rename_response_to_ber, obtain_file_response_to_ber, parse_file_close_response,
+        parse_file_delete_response, parse_file_directory_response, parse_file_open_response, parse_file_read_response, parse_file_rename_response, parse_obtain_file_response,
+    },
+    getnamedtypeattributesresponse::{get_named_type_attributes_response_to_ber, parse_get_named_type_attributes_response},
     getnamedvariablelistattributesresponse::{get_named_variable_list_attributes_response_to_ber, parse_get_named_variable_list_attributes_response},
     getnamelistresponse::{get_name_list_response_to_ber, parse_get_name_list_response},
     getvariableaccessattributesresponse::{get_variable_access_attributes_response_to_ber, parse_get_variable_access_attributes_response},
     identifyresponse::{identify_response_to_ber, parse_identify_response},
+    journalresponse::{
+        create_journal_response_to_ber, delete_journal_response_to_ber, initialize_journal_response_to_ber, parse_create_journal_response, parse_delete_journal_response, parse_initialize_journal_response, parse_read_journal_response,
+        parse_report_journal_status_response, parse_write_journal_response, read_journal_response_to_ber, report_journal_status_response_to_ber, write_journal_response_to_ber,
+    },
+    programresponse::{
+        create_program_invocation_response_to_ber, delete_program_invocation_response_to_ber, get_program_invocation_attributes_response_to_ber, kill_response_to_ber, parse_create_program_invocation_response,
+        parse_delete_program_invocation_response, parse_get_program_invocation_attributes_response, parse_kill_response, parse_reset_response, parse_resume_response, parse_start_response, parse_stop_response, reset_response_to_ber,
+        resume_response_to_ber, start_response_to_ber, stop_response_to_ber,
+    },
+    semaphoreresponse::{
+        define_semaphore_response_to_ber, delete_semaphore_response_to_ber, parse_define_semaphore_response, parse_delete_semaphore_response, parse_relinquish_control_response, parse_report_semaphore_status_response,
+        parse_take_control_response, relinquish_control_response_to_ber, report_semaphore_status_response_to_ber, take_control_response_to_ber,
+    },
+    vmdresponse::{get_capability_list_response_to_ber, parse_get_capability_list_response, parse_rename_response, parse_status_response, rename_response_to_ber, status_response_to_ber},
     writeresponse::{parse_write_response, write_response_to_ber},
 };
 use crate::{
@@ -28,16 +58,10 @@ pub(crate) fn parse_confirmed_response(payload: Any<'_>) -> Result<MmsMessage, M
     for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Confirmed Response Payload"))? {
         match item.header.raw_tag() {
             Some(&[2]) => invocation_id = Some(item.data.to_vec()),
-            Some(&[161]) => confirmed_payload = Some(parse_get_name_list_response(&item)?),
-            Some(&[162]) => confirmed_payload = Some(parse_identify_response(&item)?),
-            Some(&[164]) => confirmed_payload = Some(parse_read_response(&item)?),
-            Some(&[165]) => confirmed_payload = Some(parse_write_response(&item)?),
-            Some(&[166]) => confirmed_payload = Some(parse_get_variable_access_attributes_response(&item)?),
-            Some(&[139]) => confirmed_payload = Some(parse_define_named_variable_list_response(&item)?),
-            Some(&[172]) => confirmed_payload = Some(parse_get_named_variable_list_attributes_response(&item)?),
-            Some(&[173]) => confirmed_payload = Some(parse_delete_named_variable_list_response(&item)?),
-            // TODO Send an error if the message is not known
-            x => warn!("Failed to parse unknown MMS Confirmed Response Item: {:?}", x),
+            x => match parse_confirmed_service_response(&item) {
+                Some(response) => confirmed_payload = Some(response?),
+                None => warn!("Failed to parse unknown MMS Confirmed Response Item: {:?}", x),
+            },
         }
     }
 
@@ -47,6 +71,66 @@ pub(crate) fn parse_confirmed_response(payload: Any<'_>) -> Result<MmsMessage, M
     })
 }
 
+/// Returns None if the service is not supported so the response can be rejected as unrecognised.
+pub(crate) fn parse_confirmed_service_response(item: &Any<'_>) -> Option<Result<MmsConfirmedResponse, MmsError>> {
+    Some(match item.header.raw_tag() {
+        Some(&[161]) => parse_get_name_list_response(item),
+        Some(&[160]) => parse_status_response(item),
+        Some(&[162]) => parse_identify_response(item),
+        Some(&[131]) => parse_rename_response(item),
+        Some(&[191, 71]) => parse_get_capability_list_response(item),
+        Some(&[164]) => parse_read_response(item),
+        Some(&[165]) => parse_write_response(item),
+        Some(&[166]) => parse_get_variable_access_attributes_response(item),
+        Some(&[139]) => parse_define_named_variable_list_response(item),
+        Some(&[172]) => parse_get_named_variable_list_attributes_response(item),
+        Some(&[173]) => parse_delete_named_variable_list_response(item),
+        Some(&[142]) => parse_define_named_type_response(item),
+        Some(&[175]) => parse_get_named_type_attributes_response(item),
+        Some(&[176]) => parse_delete_named_type_response(item),
+        Some(&[179]) => parse_take_control_response(item),
+        Some(&[148]) => parse_relinquish_control_response(item),
+        Some(&[149]) => parse_define_semaphore_response(item),
+        Some(&[150]) => parse_delete_semaphore_response(item),
+        Some(&[183]) => parse_report_semaphore_status_response(item),
+        Some(&[154]) => parse_initiate_download_sequence_response(item),
+        Some(&[187]) => parse_download_segment_response(item),
+        Some(&[156]) => parse_terminate_download_sequence_response(item),
+        Some(&[189]) => parse_initiate_upload_sequence_response(item),
+        Some(&[190]) => parse_upload_segment_response(item),
+        Some(&[159, 31]) => parse_terminate_upload_sequence_response(item),
+        Some(&[159, 36]) => parse_delete_domain_response(item),
+        Some(&[191, 37]) => parse_get_domain_attributes_response(item),
+        Some(&[159, 38]) => parse_create_program_invocation_response(item),
+        Some(&[159, 39]) => parse_delete_program_invocation_response(item),
+        Some(&[159, 40]) => parse_start_response(item),
+        Some(&[159, 41]) => parse_stop_response(item),
+        Some(&[159, 42]) => parse_resume_response(item),
+        Some(&[159, 43]) => parse_reset_response(item),
+        Some(&[159, 44]) => parse_kill_response(item),
+        Some(&[191, 45]) => parse_get_program_invocation_attributes_response(item),
+        Some(&[159, 46]) => parse_obtain_file_response(item),
+        Some(&[191, 72]) => parse_file_open_response(item),
+        Some(&[191, 73]) => parse_file_read_response(item),
+        Some(&[159, 74]) => parse_file_close_response(item),
+        Some(&[159, 75]) => parse_file_rename_response(item),
+        Some(&[159, 76]) => parse_file_delete_response(item),
+        Some(&[191, 77]) => parse_file_directory_response(item),
+        Some(&[191, 65]) => parse_read_journal_response(item),
+        Some(&[159, 66]) => parse_write_journal_response(item),
+        Some(&[159, 67]) => parse_initialize_journal_response(item),
+        Some(&[191, 68]) => parse_report_journal_status_response(item),
+        Some(&[159, 69]) => parse_create_journal_response(item),
+        Some(&[159, 70]) => parse_delete_journal_response(item),
+        Some(&[159, 47]) => parse_define_event_condition_response(item),
+        Some(&[159, 51]) => parse_alter_event_condition_monitoring_response(item),
+        Some(&[191, 49]) => parse_get_event_condition_attributes_response(item),
+        Some(&[159, 57]) => parse_define_event_enrollment_response(item),
+        Some(&[159, 62]) => parse_acknowledge_event_notification_response(item),
+        _ => return None,
+    })
+}
+
 pub(crate) fn confirmed_response_to_ber<'a>(invocation_id: &'a [u8], payload: &'a MmsConfirmedResponse) -> Result<BerObject<'a>, MmsError> {
     Ok(BerObject::from_header_and_content(
         Header::new(Class::ContextSpecific, true, Tag::from(1), Length::Definite(0)),
@@ -55,12 +139,65 @@ pub(crate) fn confirmed_response_to_ber<'a>(invocation_id: &'a [u8], payload: &'
             match payload {
                 MmsConfirmedResponse::GetNameList { list_of_identifiers, more_follows } => get_name_list_response_to_ber(list_of_identifiers, more_follows)?,
                 MmsConfirmedResponse::Identify { vendor_name, model_name, revision, abstract_syntaxes } => identify_response_to_ber(vendor_name, model_name, revision, abstract_syntaxes),
+                MmsConfirmedResponse::Status(status) => status_response_to_ber(status)?,
+                MmsConfirmedResponse::GetCapabilityList { list_of_capabilities, more_follows } => get_capability_list_response_to_ber(list_of_capabilities, more_follows)?,
+                MmsConfirmedResponse::Rename => rename_response_to_ber()?,
                 MmsConfirmedResponse::Read { variable_access_specification, access_results } => read_response_to_ber(variable_access_specification, access_results)?,
                 MmsConfirmedResponse::Write { write_results } => write_response_to_ber(write_results)?,
                 MmsConfirmedResponse::GetVariableAccessAttributes { deletable, type_description } => get_variable_access_attributes_response_to_ber(*deletable, type_description)?,
                 MmsConfirmedResponse::DefineNamedVariableList => define_named_variable_list_response_to_ber()?,
                 MmsConfirmedResponse::GetNamedVariableListAttributes { deletable, list_of_variables } => get_named_variable_list_attributes_response_to_ber(*deletable, list_of_variables)?,
                 MmsConfirmedResponse::DeleteNamedVariableList { number_matched, number_deleted } => delete_named_variable_list_response_to_ber(number_matched, number_deleted)?,
+                MmsConfirmedResponse::DefineNamedType => define_named_type_response_to_ber()?,
+                MmsConfirmedResponse::GetNamedTypeAttributes { deletable, type_specification } => get_named_type_attributes_response_to_ber(*deletable, type_specification)?,
+                MmsConfirmedResponse::DeleteNamedType { number_matched, number_deleted } => delete_named_type_response_to_ber(number_matched, number_deleted)?,
+                MmsConfirmedResponse::InitiateDownloadSequence => initiate_download_sequence_response_to_ber()?,
+                MmsConfirmedResponse::DownloadSegment { load_data, more_follows } => download_segment_response_to_ber(load_data, more_follows)?,
+                MmsConfirmedResponse::TerminateDownloadSequence => terminate_download_sequence_response_to_ber()?,
+                MmsConfirmedResponse::InitiateUploadSequence { ulsm_id, list_of_capabilities } => initiate_upload_sequence_response_to_ber(ulsm_id, list_of_capabilities)?,
+                MmsConfirmedResponse::UploadSegment { load_data, more_follows } => upload_segment_response_to_ber(load_data, more_follows)?,
+                MmsConfirmedResponse::TerminateUploadSequence => terminate_upload_sequence_response_to_ber()?,
+                MmsConfirmedResponse::DeleteDomain => delete_domain_response_to_ber()?,
+                MmsConfirmedResponse::GetDomainAttributes { list_of_capabilities, state, mms_deletable, sharable, list_of_program_invocations, upload_in_progress } => {
+                    get_domain_attributes_response_to_ber(list_of_capabilities, state, *mms_deletable, *sharable, list_of_program_invocations, upload_in_progress)?
+                }
+                MmsConfirmedResponse::CreateProgramInvocation => create_program_invocation_response_to_ber()?,
+                MmsConfirmedResponse::DeleteProgramInvocation => delete_program_invocation_response_to_ber()?,
+                MmsConfirmedResponse::Start => start_response_to_ber()?,
+                MmsConfirmedResponse::Stop => stop_response_to_ber()?,
+                MmsConfirmedResponse::Resume => resume_response_to_ber()?,
+                MmsConfirmedResponse::Reset => reset_response_to_ber()?,
+                MmsConfirmedResponse::Kill => kill_response_to_ber()?,
+                MmsConfirmedResponse::GetProgramInvocationAttributes { state, list_of_domain_names, mms_deletable, reusable, monitor, execution_argument } => {
+                    get_program_invocation_attributes_response_to_ber(state, list_of_domain_names, *mms_deletable, *reusable, *monitor, execution_argument)?
+                }
+                MmsConfirmedResponse::ObtainFile => obtain_file_response_to_ber()?,
+                MmsConfirmedResponse::FileOpen { frsm_id, file_attributes } => file_open_response_to_ber(frsm_id, file_attributes)?,
+                MmsConfirmedResponse::FileRead { file_data, more_follows } => file_read_response_to_ber(file_data, more_follows)?,
+                MmsConfirmedResponse::FileClose => file_close_response_to_ber()?,
+                MmsConfirmedResponse::FileRename => file_rename_response_to_ber()?,
+                MmsConfirmedResponse::FileDelete => file_delete_response_to_ber()?,
+                MmsConfirmedResponse::FileDirectory { list_of_directory_entry, more_follows } => file_directory_response_to_ber(list_of_directory_entry, more_follows)?,
+                MmsConfirmedResponse::ReadJournal { list_of_journal_entry, more_follows } => read_journal_response_to_ber(list_of_journal_entry, more_follows)?,
+                MmsConfirmedResponse::WriteJournal => write_journal_response_to_ber()?,
+                MmsConfirmedResponse::InitializeJournal { entries_deleted } => initialize_journal_response_to_ber(entries_deleted)?,
+                MmsConfirmedResponse::ReportJournalStatus { current_entries, mms_deletable } => report_journal_status_response_to_ber(current_entries, *mms_deletable)?,
+                MmsConfirmedResponse::CreateJournal => create_journal_response_to_ber()?,
+                MmsConfirmedResponse::DeleteJournal => delete_journal_response_to_ber()?,
+                MmsConfirmedResponse::DefineEventCondition => define_event_condition_response_to_ber()?,
+                MmsConfirmedResponse::AlterEventConditionMonitoring => alter_event_condition_monitoring_response_to_ber()?,
+                MmsConfirmedResponse::GetEventConditionAttributes { mms_deletable, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval } => {
+                    get_event_condition_attributes_response_to_ber(mms_deletable, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval)?
+                }
+                MmsConfirmedResponse::DefineEventEnrollment => define_event_enrollment_response_to_ber()?,
+                MmsConfirmedResponse::AcknowledgeEventNotification => acknowledge_event_notification_response_to_ber()?,
+                MmsConfirmedResponse::TakeControl { named_token } => take_control_response_to_ber(named_token)?,
+                MmsConfirmedResponse::RelinquishControl => relinquish_control_response_to_ber()?,
+                MmsConfirmedResponse::DefineSemaphore => define_semaphore_response_to_ber()?,
+                MmsConfirmedResponse::DeleteSemaphore => delete_semaphore_response_to_ber()?,
+                MmsConfirmedResponse::ReportSemaphoreStatus { mms_deletable, class, number_of_tokens, number_of_owned_tokens, number_of_hung_tokens } => {
+                    report_semaphore_status_response_to_ber(*mms_deletable, class, number_of_tokens, number_of_owned_tokens, number_of_hung_tokens)?
+                }
             },
         ]),
     ))
@@ -0,0 +1,26 @@
+use der_parser::{
+    asn1_rs::{Any, ToDer},
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+
+use crate::{MmsConfirmedRequest, MmsError, MmsObjectName, MmsTypeSpecification, error::to_mms_error, parsers::process_constructed_data};
+
+pub(crate) fn parse_define_named_type_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    // Order Matters Here
+    let items = process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse MMS DefineNamedType PDU"))?;
+    let mut items_iter = items.iter();
+
+    let type_name_payload = items_iter.next().ok_or_else(|| MmsError::ProtocolError("Failed to parse MMS DefineNamedType PDU - No Type Name found".into()))?;
+    let type_name_data = type_name_payload.to_der_vec().map_err(to_mms_error("Failed to parse MMS DefineNamedType PDU - Failed to parse Type Name"))?;
+    let type_name = MmsObjectName::parse("MMS DefineNamedType", &type_name_data)?;
+
+    let type_specification_payload = items_iter.next().ok_or_else(|| MmsError::ProtocolError("Failed to parse MMS DefineNamedType PDU - No Type Specification found".into()))?;
+    let type_specification = MmsTypeSpecification::parse_untagged("MMS DefineNamedType", type_specification_payload)?;
+
+    Ok(MmsConfirmedRequest::DefineNamedType { type_name, type_specification })
+}
+
+pub(crate) fn define_named_type_request_to_ber<'a>(type_name: &'a MmsObjectName, type_specification: &'a MmsTypeSpecification) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(14), Length::Definite(0)), BerObjectContent::Sequence(vec![type_name.to_ber(), type_specification.to_ber()?])))
+}
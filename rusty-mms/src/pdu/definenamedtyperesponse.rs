@@ -0,0 +1,16 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+
+use crate::{MmsConfirmedResponse, MmsError};
+
+pub(crate) fn parse_define_named_type_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DefineNamedType)
+}
+
+pub(crate) fn define_named_type_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(14), Length::Definite(0)), BerObjectContent::Null))
+}
@@ -0,0 +1,55 @@
+use der_parser::{
+    asn1_rs::{Any, ToDer},
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedRequest, MmsError, MmsObjectName, MmsScope,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_mms_string},
+};
+
+pub(crate) fn parse_delete_named_type_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut scope_of_delete = None;
+    let mut list_of_type_names = None;
+    let mut domain_name = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse DeleteNamedType"))? {
+        match item.header.raw_tag() {
+            Some([128]) => scope_of_delete = Some(MmsScope::parse(&item)?),
+            Some([161]) => {
+                list_of_type_names = Some({
+                    let mut list = vec![];
+                    for type_name in process_constructed_data(item.data).map_err(to_mms_error("Failed to parse List of Type Names on DeleteNamedType"))? {
+                        list.push(MmsObjectName::parse("DeleteNamedType", &type_name.to_der_vec().map_err(to_mms_error("Failed to process ObjectName on DeleteNamedType"))?)?);
+                    }
+                    list
+                })
+            }
+            Some([130]) => domain_name = Some(process_mms_string(&item, "Failed to process Domain on DeleteNamedType")?),
+            x => warn!("Unknown item on DeleteNamedType: {:?}", x),
+        };
+    }
+
+    Ok(MmsConfirmedRequest::DeleteNamedType { scope_of_delete, list_of_type_names, domain_name })
+}
+
+pub(crate) fn delete_named_type_request_to_ber<'a>(scope_of_delete: &Option<MmsScope>, list_of_type_names: &'a Option<Vec<MmsObjectName>>, domain: &'a Option<String>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(16), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                scope_of_delete.as_ref().map(|scope_of_delete| scope_of_delete.to_ber(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)))),
+                list_of_type_names
+                    .as_ref()
+                    .map(|items| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(1), Length::Definite(0)), BerObjectContent::Sequence(items.iter().map(|item| item.to_ber()).collect()))),
+                domain.as_ref().map(|domain| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::VisibleString(domain))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
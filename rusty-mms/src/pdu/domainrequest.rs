@@ -0,0 +1,141 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedRequest, MmsError, MmsServiceErrorInfo,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content, process_mms_string},
+    pdu::{
+        common::expect_value,
+        serviceerror::{parse_service_error, service_error_to_ber},
+    },
+};
+
+pub(crate) fn parse_initiate_download_sequence_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut domain_name = None;
+    let mut list_of_capabilities = None;
+    let mut sharable = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Initiate Download Sequence Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => domain_name = Some(process_mms_string(&item, "Failed to parse Domain Name on Initiate Download Sequence Request PDU")?),
+            Some([161]) => list_of_capabilities = Some(parse_list_of_capabilities(&item, "Initiate Download Sequence Request PDU")?),
+            Some([130]) => sharable = Some(process_mms_boolean_content(&item, "Failed to parse Sharable on Initiate Download Sequence Request PDU")?),
+            x => warn!("Unsupported tag in MMS Initiate Download Sequence Request PDU: {:?}", x),
+        }
+    }
+
+    let domain_name = expect_value("InitiateDownloadSequence", "DomainName", domain_name)?;
+    let list_of_capabilities = expect_value("InitiateDownloadSequence", "ListOfCapabilities", list_of_capabilities)?;
+    let sharable = expect_value("InitiateDownloadSequence", "Sharable", sharable)?;
+
+    Ok(MmsConfirmedRequest::InitiateDownloadSequence { domain_name, list_of_capabilities, sharable })
+}
+
+pub(crate) fn parse_download_segment_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::DownloadSegment { domain_name: process_mms_string(payload, "Failed to parse Domain Name on Download Segment Request PDU")? })
+}
+
+pub(crate) fn parse_terminate_download_sequence_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut domain_name = None;
+    let mut discard = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Terminate Download Sequence Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => domain_name = Some(process_mms_string(&item, "Failed to parse Domain Name on Terminate Download Sequence Request PDU")?),
+            Some([161]) => discard = Some(parse_service_error(&item)?),
+            x => warn!("Unsupported tag in MMS Terminate Download Sequence Request PDU: {:?}", x),
+        }
+    }
+
+    let domain_name = expect_value("TerminateDownloadSequence", "DomainName", domain_name)?;
+
+    Ok(MmsConfirmedRequest::TerminateDownloadSequence { domain_name, discard })
+}
+
+pub(crate) fn parse_initiate_upload_sequence_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::InitiateUploadSequence { domain_name: process_mms_string(payload, "Failed to parse Domain Name on Initiate Upload Sequence Request PDU")? })
+}
+
+pub(crate) fn parse_upload_segment_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::UploadSegment { ulsm_id: process_integer_content(payload, "Failed to parse ULSM ID on Upload Segment Request PDU")? })
+}
+
+pub(crate) fn parse_terminate_upload_sequence_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::TerminateUploadSequence { ulsm_id: process_integer_content(payload, "Failed to parse ULSM ID on Terminate Upload Sequence Request PDU")? })
+}
+
+pub(crate) fn parse_delete_domain_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::DeleteDomain { domain_name: process_mms_string(payload, "Failed to parse Domain Name on Delete Domain Request PDU")? })
+}
+
+pub(crate) fn parse_get_domain_attributes_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::GetDomainAttributes { domain_name: process_mms_string(payload, "Failed to parse Domain Name on Get Domain Attributes Request PDU")? })
+}
+
+pub(crate) fn parse_list_of_capabilities(payload: &Any<'_>, pdu: &str) -> Result<Vec<String>, MmsError> {
+    process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse List of Capabilities on {}", pdu)))?.into_iter().map(|x| process_mms_string(&x, &format!("Failed to parse Capability on {}", pdu))).collect()
+}
+
+pub(crate) fn list_of_capabilities_to_ber(tag: u32, list_of_capabilities: &[String]) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            list_of_capabilities.iter().map(|capability| BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::VisibleString, Length::Definite(0)), BerObjectContent::VisibleString(capability.as_str()))).collect(),
+        ),
+    )
+}
+
+pub(crate) fn initiate_download_sequence_request_to_ber<'a>(domain_name: &'a str, list_of_capabilities: &'a [String], sharable: bool) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(26), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::VisibleString(domain_name)),
+            list_of_capabilities_to_ber(1, list_of_capabilities),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::Boolean(sharable)),
+        ]),
+    ))
+}
+
+pub(crate) fn download_segment_request_to_ber(domain_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(27), Length::Definite(0)), BerObjectContent::VisibleString(domain_name)))
+}
+
+pub(crate) fn terminate_download_sequence_request_to_ber<'a>(domain_name: &'a str, discard: &'a Option<MmsServiceErrorInfo>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(28), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::VisibleString(domain_name))),
+                discard.as_ref().map(|discard| service_error_to_ber(1, discard)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn initiate_upload_sequence_request_to_ber(domain_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(29), Length::Definite(0)), BerObjectContent::VisibleString(domain_name)))
+}
+
+pub(crate) fn upload_segment_request_to_ber(ulsm_id: &[u8]) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(30), Length::Definite(0)), BerObjectContent::Integer(ulsm_id)))
+}
+
+pub(crate) fn terminate_upload_sequence_request_to_ber(ulsm_id: &[u8]) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(31), Length::Definite(0)), BerObjectContent::Integer(ulsm_id)))
+}
+
+pub(crate) fn delete_domain_request_to_ber(domain_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(36), Length::Definite(0)), BerObjectContent::VisibleString(domain_name)))
+}
+
+pub(crate) fn get_domain_attributes_request_to_ber(domain_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(37), Length::Definite(0)), BerObjectContent::VisibleString(domain_name)))
+}
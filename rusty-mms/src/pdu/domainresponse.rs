@@ -0,0 +1,200 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedResponse, MmsDomainState, MmsError,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content, process_mms_string},
+    pdu::{
+        common::{error_codes, expect_value},
+        domainrequest::{list_of_capabilities_to_ber, parse_list_of_capabilities},
+    },
+};
+
+error_codes!(MmsDomainState, NonExistent => 0, Loading => 1, Ready => 2, InUse => 3, Complete => 4, Incomplete => 5, D1 => 7, D2 => 8, D3 => 9, D4 => 10, D5 => 11, D6 => 12, D7 => 13, D8 => 14, D9 => 15);
+
+pub(crate) fn parse_initiate_download_sequence_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::InitiateDownloadSequence)
+}
+
+pub(crate) fn parse_download_segment_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let (load_data, more_follows) = parse_segment(payload, "Download Segment Response PDU")?;
+    Ok(MmsConfirmedResponse::DownloadSegment { load_data, more_follows })
+}
+
+pub(crate) fn parse_terminate_download_sequence_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::TerminateDownloadSequence)
+}
+
+pub(crate) fn parse_initiate_upload_sequence_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut ulsm_id = None;
+    let mut list_of_capabilities = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Initiate Upload Sequence Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => ulsm_id = Some(process_integer_content(&item, "Failed to parse ULSM ID on Initiate Upload Sequence Response PDU")?),
+            Some([161]) => list_of_capabilities = Some(parse_list_of_capabilities(&item, "Initiate Upload Sequence Response PDU")?),
+            x => warn!("Unsupported tag in MMS Initiate Upload Sequence Response PDU: {:?}", x),
+        }
+    }
+
+    let ulsm_id = expect_value("InitiateUploadSequence", "UlsmID", ulsm_id)?;
+    let list_of_capabilities = expect_value("InitiateUploadSequence", "ListOfCapabilities", list_of_capabilities)?;
+
+    Ok(MmsConfirmedResponse::InitiateUploadSequence { ulsm_id, list_of_capabilities })
+}
+
+pub(crate) fn parse_upload_segment_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let (load_data, more_follows) = parse_segment(payload, "Upload Segment Response PDU")?;
+    Ok(MmsConfirmedResponse::UploadSegment { load_data, more_follows })
+}
+
+pub(crate) fn parse_terminate_upload_sequence_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::TerminateUploadSequence)
+}
+
+pub(crate) fn parse_delete_domain_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DeleteDomain)
+}
+
+pub(crate) fn parse_get_domain_attributes_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut list_of_capabilities = None;
+    let mut state = None;
+    let mut mms_deletable = None;
+    let mut sharable = None;
+    let mut list_of_program_invocations = None;
+    let mut upload_in_progress = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Get Domain Attributes Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => list_of_capabilities = Some(parse_list_of_capabilities(&item, "Get Domain Attributes Response PDU")?),
+            Some([129]) => state = Some(MmsDomainState::parse(item.data)),
+            Some([130]) => mms_deletable = Some(process_mms_boolean_content(&item, "Failed to parse MMS Deletable on Get Domain Attributes Response PDU")?),
+            Some([131]) => sharable = Some(process_mms_boolean_content(&item, "Failed to parse Sharable on Get Domain Attributes Response PDU")?),
+            Some([164]) => {
+                list_of_program_invocations = Some(
+                    process_constructed_data(item.data)
+                        .map_err(to_mms_error("Failed to parse List of Program Invocations on Get Domain Attributes Response PDU"))?
+                        .into_iter()
+                        .map(|x| process_mms_string(&x, "Failed to parse Program Invocation on Get Domain Attributes Response PDU"))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )
+            }
+            Some([133]) => upload_in_progress = Some(process_integer_content(&item, "Failed to parse Upload In Progress on Get Domain Attributes Response PDU")?),
+            x => warn!("Unsupported tag in MMS Get Domain Attributes Response PDU: {:?}", x),
+        }
+    }
+
+    let list_of_capabilities = expect_value("GetDomainAttributes", "ListOfCapabilities", list_of_capabilities)?;
+    let state = expect_value("GetDomainAttributes", "State", state)?;
+    let mms_deletable = expect_value("GetDomainAttributes", "MmsDeletable", mms_deletable)?;
+    let sharable = expect_value("GetDomainAttributes", "Sharable", sharable)?;
+    let list_of_program_invocations = expect_value("GetDomainAttributes", "ListOfProgramInvocations", list_of_program_invocations)?;
+    let upload_in_progress = expect_value("GetDomainAttributes", "UploadInProgress", upload_in_progress)?;
+
+    Ok(MmsConfirmedResponse::GetDomainAttributes { list_of_capabilities, state, mms_deletable, sharable, list_of_program_invocations, upload_in_progress })
+}
+
+// Download and upload segments share the same shape. Coded load data is an EXTERNAL which is not supported.
+fn parse_segment(payload: &Any<'_>, pdu: &str) -> Result<(Vec<u8>, Option<bool>), MmsError> {
+    let mut load_data = None;
+    let mut more_follows = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([128]) => load_data = Some(item.data.to_vec()),
+            Some([40]) => return Err(MmsError::ProtocolError(format!("Coded Load Data on {} is not supported", pdu))),
+            Some([129]) => more_follows = Some(process_mms_boolean_content(&item, &format!("Failed to parse More Follows on {}", pdu))?),
+            x => warn!("Unsupported tag in MMS {}: {:?}", pdu, x),
+        }
+    }
+
+    Ok((load_data.ok_or_else(|| MmsError::ProtocolError(format!("No Load Data on {}", pdu)))?, more_follows))
+}
+
+fn segment_to_ber<'a>(tag: u32, load_data: &'a [u8], more_follows: &Option<bool>) -> BerObject<'a> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::OctetString(load_data))),
+                more_follows.map(|more_follows| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Boolean(more_follows))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    )
+}
+
+fn null_response_to_ber<'a>(tag: u32) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Null)
+}
+
+pub(crate) fn initiate_download_sequence_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(26))
+}
+
+pub(crate) fn download_segment_response_to_ber<'a>(load_data: &'a [u8], more_follows: &Option<bool>) -> Result<BerObject<'a>, MmsError> {
+    Ok(segment_to_ber(27, load_data, more_follows))
+}
+
+pub(crate) fn terminate_download_sequence_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(28))
+}
+
+pub(crate) fn initiate_upload_sequence_response_to_ber<'a>(ulsm_id: &'a [u8], list_of_capabilities: &'a [String]) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(29), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(ulsm_id)),
+            list_of_capabilities_to_ber(1, list_of_capabilities),
+        ]),
+    ))
+}
+
+pub(crate) fn upload_segment_response_to_ber<'a>(load_data: &'a [u8], more_follows: &Option<bool>) -> Result<BerObject<'a>, MmsError> {
+    Ok(segment_to_ber(30, load_data, more_follows))
+}
+
+pub(crate) fn terminate_upload_sequence_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(31))
+}
+
+pub(crate) fn delete_domain_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(36))
+}
+
+pub(crate) fn get_domain_attributes_response_to_ber<'a>(
+    list_of_capabilities: &'a [String],
+    state: &'a MmsDomainState,
+    mms_deletable: bool,
+    sharable: bool,
+    list_of_program_invocations: &'a [String],
+    upload_in_progress: &'a [u8],
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(37), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            list_of_capabilities_to_ber(0, list_of_capabilities),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(state.to_ber())),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::Boolean(mms_deletable)),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(3), Length::Definite(0)), BerObjectContent::Boolean(sharable)),
+            BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(4), Length::Definite(0)),
+                BerObjectContent::Sequence(
+                    list_of_program_invocations.iter().map(|name| BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::VisibleString, Length::Definite(0)), BerObjectContent::VisibleString(name.as_str()))).collect(),
+                ),
+            ),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(5), Length::Definite(0)), BerObjectContent::Integer(upload_in_progress)),
+        ]),
+    ))
+}
@@ -0,0 +1,66 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsAlarmAcknowledgmentRule, MmsError, MmsEventConditionState, MmsEventNotification, MmsObjectName, MmsUnconfirmedService,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content},
+    pdu::{
+        common::{expect_value, explicit_to_ber},
+        eventrequest::{event_time_to_ber, parse_event_time},
+    },
+};
+
+pub(crate) fn parse_event_notification(payload: &Any<'_>) -> Result<MmsUnconfirmedService, MmsError> {
+    let mut event_enrollment_name = None;
+    let mut event_condition_name = None;
+    let mut severity = None;
+    let mut current_state = None;
+    let mut transition_time = None;
+    let mut notification_lost = None;
+    let mut alarm_acknowledgment_rule = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Event Notification PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => event_enrollment_name = Some(MmsObjectName::parse("Event Notification PDU", item.data)?),
+            Some([161]) => event_condition_name = Some(MmsObjectName::parse("Event Notification PDU", item.data)?),
+            Some([130]) => severity = Some(process_integer_content(&item, "Failed to parse Severity on Event Notification PDU")?),
+            Some([131]) => current_state = Some(MmsEventConditionState::parse(item.data)),
+            Some([164]) => transition_time = Some(parse_event_time(&item, "Failed to parse Transition Time on Event Notification PDU")?),
+            Some([134]) => notification_lost = Some(process_mms_boolean_content(&item, "Failed to parse Notification Lost on Event Notification PDU")?),
+            Some([135]) => alarm_acknowledgment_rule = Some(MmsAlarmAcknowledgmentRule::parse(item.data)),
+            x => warn!("Unsupported tag in MMS Event Notification PDU: {:?}", x),
+        }
+    }
+
+    let event_enrollment_name = expect_value("EventNotification", "EventEnrollmentName", event_enrollment_name)?;
+    let event_condition_name = expect_value("EventNotification", "EventConditionName", event_condition_name)?;
+    let severity = expect_value("EventNotification", "Severity", severity)?;
+    let transition_time = expect_value("EventNotification", "TransitionTime", transition_time)?;
+
+    Ok(MmsUnconfirmedService::EventNotification(Box::new(MmsEventNotification { event_enrollment_name, event_condition_name, severity, current_state, transition_time, notification_lost, alarm_acknowledgment_rule })))
+}
+
+pub(crate) fn event_notification_to_ber(event_notification: &MmsEventNotification) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(2), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(explicit_to_ber(0, event_notification.event_enrollment_name.to_ber())),
+                Some(explicit_to_ber(1, event_notification.event_condition_name.to_ber())),
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::Integer(&event_notification.severity))),
+                event_notification.current_state.as_ref().map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(3), Length::Definite(0)), BerObjectContent::Integer(x.to_ber()))),
+                Some(event_time_to_ber(4, &event_notification.transition_time)),
+                event_notification.notification_lost.map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(6), Length::Definite(0)), BerObjectContent::Boolean(x))),
+                event_notification.alarm_acknowledgment_rule.as_ref().map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(7), Length::Definite(0)), BerObjectContent::Integer(x.to_ber()))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
@@ -0,0 +1,285 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, BitStringObject, Length, parse_ber_content},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsAlarmAcknowledgmentRule, MmsApplicationReference, MmsBinaryTime, MmsConfirmedRequest, MmsError, MmsEventConditionClass, MmsEventConditionState, MmsEventTime, MmsEventTransition, MmsObjectName, VariableSpecification,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content},
+    pdu::{
+        common::{error_codes, expect_value, explicit_to_ber, parse_explicit},
+        journalresponse::{application_reference_to_ber, parse_application_reference},
+    },
+};
+
+error_codes!(pub(crate) MmsEventConditionState, Disabled => 0, Idle => 1, Active => 2);
+error_codes!(pub(crate) MmsEventConditionClass, NetworkTriggered => 0, Monitored => 1);
+error_codes!(pub(crate) MmsAlarmAcknowledgmentRule, None => 0, Simple => 1, AckActive => 2, AckAll => 3);
+
+// Ordered by their bit in the Transitions bit string.
+const EVENT_TRANSITIONS: [MmsEventTransition; 7] = [
+    MmsEventTransition::IdleToDisabled,
+    MmsEventTransition::ActiveToDisabled,
+    MmsEventTransition::DisabledToIdle,
+    MmsEventTransition::ActiveToIdle,
+    MmsEventTransition::DisabledToActive,
+    MmsEventTransition::IdleToActive,
+    MmsEventTransition::AnyToDeleted,
+];
+
+// BER objects borrow their content, so the encoded transitions are taken from a table holding every possible byte.
+static TRANSITION_BYTES: [u8; 256] = {
+    let mut bytes = [0; 256];
+    let mut i = 0;
+    while i < 256 {
+        bytes[i] = i as u8;
+        i += 1;
+    }
+    bytes
+};
+
+pub(crate) fn parse_define_event_condition_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut event_condition_name = None;
+    let mut class = None;
+    let mut priority = None;
+    let mut severity = None;
+    let mut alarm_summary_reports = None;
+    let mut monitored_variable = None;
+    let mut evaluation_interval = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Define Event Condition Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => event_condition_name = Some(MmsObjectName::parse("Define Event Condition Request PDU", item.data)?),
+            Some([129]) => class = Some(MmsEventConditionClass::parse(item.data)),
+            Some([130]) => priority = Some(process_integer_content(&item, "Failed to parse Priority on Define Event Condition Request PDU")?),
+            Some([131]) => severity = Some(process_integer_content(&item, "Failed to parse Severity on Define Event Condition Request PDU")?),
+            Some([132]) => alarm_summary_reports = Some(process_mms_boolean_content(&item, "Failed to parse Alarm Summary Reports on Define Event Condition Request PDU")?),
+            Some([166]) => monitored_variable = Some(Box::new(VariableSpecification::parse(item.data)?)),
+            Some([135]) => evaluation_interval = Some(process_integer_content(&item, "Failed to parse Evaluation Interval on Define Event Condition Request PDU")?),
+            x => warn!("Unsupported tag in MMS Define Event Condition Request PDU: {:?}", x),
+        }
+    }
+
+    let event_condition_name = expect_value("DefineEventCondition", "EventConditionName", event_condition_name)?;
+    let class = expect_value("DefineEventCondition", "Class", class)?;
+
+    Ok(MmsConfirmedRequest::DefineEventCondition { event_condition_name, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval })
+}
+
+pub(crate) fn parse_alter_event_condition_monitoring_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut event_condition_name = None;
+    let mut enabled = None;
+    let mut priority = None;
+    let mut alarm_summary_reports = None;
+    let mut evaluation_interval = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Alter Event Condition Monitoring Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => event_condition_name = Some(MmsObjectName::parse("Alter Event Condition Monitoring Request PDU", item.data)?),
+            Some([129]) => enabled = Some(process_mms_boolean_content(&item, "Failed to parse Enabled on Alter Event Condition Monitoring Request PDU")?),
+            Some([130]) => priority = Some(process_integer_content(&item, "Failed to parse Priority on Alter Event Condition Monitoring Request PDU")?),
+            Some([131]) => alarm_summary_reports = Some(process_mms_boolean_content(&item, "Failed to parse Alarm Summary Reports on Alter Event Condition Monitoring Request PDU")?),
+            Some([132]) => evaluation_interval = Some(process_integer_content(&item, "Failed to parse Evaluation Interval on Alter Event Condition Monitoring Request PDU")?),
+            x => warn!("Unsupported tag in MMS Alter Event Condition Monitoring Request PDU: {:?}", x),
+        }
+    }
+
+    let event_condition_name = expect_value("AlterEventConditionMonitoring", "EventConditionName", event_condition_name)?;
+
+    Ok(MmsConfirmedRequest::AlterEventConditionMonitoring { event_condition_name, enabled, priority, alarm_summary_reports, evaluation_interval })
+}
+
+pub(crate) fn parse_get_event_condition_attributes_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::GetEventConditionAttributes { event_condition_name: MmsObjectName::parse("Get Event Condition Attributes Request PDU", payload.data)? })
+}
+
+pub(crate) fn parse_define_event_enrollment_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut event_enrollment_name = None;
+    let mut event_condition_name = None;
+    let mut event_condition_transitions = None;
+    let mut alarm_acknowledgment_rule = None;
+    let mut event_action_name = None;
+    let mut client_application = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Define Event Enrollment Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => event_enrollment_name = Some(MmsObjectName::parse("Define Event Enrollment Request PDU", item.data)?),
+            Some([161]) => event_condition_name = Some(MmsObjectName::parse("Define Event Enrollment Request PDU", item.data)?),
+            Some([130]) => event_condition_transitions = Some(parse_event_transitions(&item, "Failed to parse Event Condition Transitions on Define Event Enrollment Request PDU")?),
+            Some([131]) => alarm_acknowledgment_rule = Some(MmsAlarmAcknowledgmentRule::parse(item.data)),
+            Some([164]) => event_action_name = Some(Box::new(MmsObjectName::parse("Define Event Enrollment Request PDU", item.data)?)),
+            Some([165]) => client_application = Some(Box::new(parse_application_reference(&item, "Failed to parse Client Application on Define Event Enrollment Request PDU")?)),
+            x => warn!("Unsupported tag in MMS Define Event Enrollment Request PDU: {:?}", x),
+        }
+    }
+
+    let event_enrollment_name = expect_value("DefineEventEnrollment", "EventEnrollmentName", event_enrollment_name)?;
+    let event_condition_name = expect_value("DefineEventEnrollment", "EventConditionName", event_condition_name)?;
+    let event_condition_transitions = expect_value("DefineEventEnrollment", "EventConditionTransitions", event_condition_transitions)?;
+    let alarm_acknowledgment_rule = expect_value("DefineEventEnrollment", "AlarmAcknowledgmentRule", alarm_acknowledgment_rule)?;
+
+    Ok(MmsConfirmedRequest::DefineEventEnrollment { event_enrollment_name, event_condition_name, event_condition_transitions, alarm_acknowledgment_rule, event_action_name, client_application })
+}
+
+pub(crate) fn parse_acknowledge_event_notification_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut event_enrollment_name = None;
+    let mut acknowledged_state = None;
+    let mut time_of_acknowledged_transition = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Acknowledge Event Notification Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => event_enrollment_name = Some(MmsObjectName::parse("Acknowledge Event Notification Request PDU", item.data)?),
+            Some([130]) => acknowledged_state = Some(MmsEventConditionState::parse(item.data)),
+            Some([163]) => time_of_acknowledged_transition = Some(parse_event_time(&item, "Failed to parse Time of Acknowledged Transition on Acknowledge Event Notification Request PDU")?),
+            x => warn!("Unsupported tag in MMS Acknowledge Event Notification Request PDU: {:?}", x),
+        }
+    }
+
+    let event_enrollment_name = expect_value("AcknowledgeEventNotification", "EventEnrollmentName", event_enrollment_name)?;
+    let acknowledged_state = expect_value("AcknowledgeEventNotification", "AcknowledgedState", acknowledged_state)?;
+    let time_of_acknowledged_transition = expect_value("AcknowledgeEventNotification", "TimeOfAcknowledgedTransition", time_of_acknowledged_transition)?;
+
+    Ok(MmsConfirmedRequest::AcknowledgeEventNotification { event_enrollment_name, acknowledged_state, time_of_acknowledged_transition })
+}
+
+fn parse_event_transitions(payload: &Any<'_>, error_message: &str) -> Result<Vec<MmsEventTransition>, MmsError> {
+    match parse_ber_content(Tag::BitString)(payload.data, &payload.header, payload.data.len()).map_err(to_mms_error(error_message))? {
+        (_, BerObjectContent::BitString(_, bits)) => Ok(EVENT_TRANSITIONS.into_iter().enumerate().filter(|(bit, _)| bits.is_set(*bit)).map(|(_, transition)| transition).collect()),
+        (_, x) => Err(MmsError::ProtocolError(format!("{}: {:?}", error_message, x))),
+    }
+}
+
+fn event_transitions_to_ber(tag: u32, transitions: &[MmsEventTransition]) -> BerObject<'static> {
+    let byte = transitions.iter().fold(0u8, |byte, transition| byte | (0x80 >> *transition as u8)) as usize;
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::BitString(1, BitStringObject { data: &TRANSITION_BYTES[byte..byte + 1] }))
+}
+
+// The event time is explicitly tagged by the services using it.
+pub(crate) fn parse_event_time(payload: &Any<'_>, error_message: &str) -> Result<MmsEventTime, MmsError> {
+    let choice = parse_explicit(payload, error_message)?;
+    match choice.header.raw_tag() {
+        Some([128]) => Ok(MmsEventTime::TimeOfDay(MmsBinaryTime::parse(choice.data)?)),
+        Some([129]) => Ok(MmsEventTime::TimeSequenceIdentifier(process_integer_content(&choice, error_message)?)),
+        Some([130]) => Ok(MmsEventTime::Undefined),
+        x => Err(MmsError::ProtocolError(format!("{}: {:?}", error_message, x))),
+    }
+}
+
+pub(crate) fn event_time_to_ber(tag: u32, event_time: &MmsEventTime) -> BerObject<'_> {
+    explicit_to_ber(
+        tag,
+        match event_time {
+            MmsEventTime::TimeOfDay(time) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::OctetString(time.data())),
+            MmsEventTime::TimeSequenceIdentifier(identifier) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(identifier)),
+            MmsEventTime::Undefined => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::Null),
+        },
+    )
+}
+
+fn integer_to_ber(tag: u32, value: &[u8]) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Integer(value))
+}
+
+fn boolean_to_ber<'a>(tag: u32, value: bool) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Boolean(value))
+}
+
+pub(crate) fn define_event_condition_request_to_ber<'a>(
+    event_condition_name: &'a MmsObjectName,
+    class: &'a MmsEventConditionClass,
+    priority: &'a Option<Vec<u8>>,
+    severity: &'a Option<Vec<u8>>,
+    alarm_summary_reports: &Option<bool>,
+    monitored_variable: &'a Option<Box<VariableSpecification>>,
+    evaluation_interval: &'a Option<Vec<u8>>,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(47), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(explicit_to_ber(0, event_condition_name.to_ber())),
+                Some(integer_to_ber(1, class.to_ber())),
+                priority.as_ref().map(|x| integer_to_ber(2, x)),
+                severity.as_ref().map(|x| integer_to_ber(3, x)),
+                alarm_summary_reports.map(|x| boolean_to_ber(4, x)),
+                monitored_variable.as_ref().map(|x| explicit_to_ber(6, x.to_ber())),
+                evaluation_interval.as_ref().map(|x| integer_to_ber(7, x)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn alter_event_condition_monitoring_request_to_ber<'a>(
+    event_condition_name: &'a MmsObjectName,
+    enabled: &Option<bool>,
+    priority: &'a Option<Vec<u8>>,
+    alarm_summary_reports: &Option<bool>,
+    evaluation_interval: &'a Option<Vec<u8>>,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(51), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(explicit_to_ber(0, event_condition_name.to_ber())),
+                enabled.map(|x| boolean_to_ber(1, x)),
+                priority.as_ref().map(|x| integer_to_ber(2, x)),
+                alarm_summary_reports.map(|x| boolean_to_ber(3, x)),
+                evaluation_interval.as_ref().map(|x| integer_to_ber(4, x)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn get_event_condition_attributes_request_to_ber(event_condition_name: &MmsObjectName) -> Result<BerObject<'_>, MmsError> {
+    Ok(explicit_to_ber(49, event_condition_name.to_ber()))
+}
+
+pub(crate) fn define_event_enrollment_request_to_ber<'a>(
+    event_enrollment_name: &'a MmsObjectName,
+    event_condition_name: &'a MmsObjectName,
+    event_condition_transitions: &[MmsEventTransition],
+    alarm_acknowledgment_rule: &'a MmsAlarmAcknowledgmentRule,
+    event_action_name: &'a Option<Box<MmsObjectName>>,
+    client_application: &'a Option<Box<MmsApplicationReference>>,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(57), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(explicit_to_ber(0, event_enrollment_name.to_ber())),
+                Some(explicit_to_ber(1, event_condition_name.to_ber())),
+                Some(event_transitions_to_ber(2, event_condition_transitions)),
+                Some(integer_to_ber(3, alarm_acknowledgment_rule.to_ber())),
+                event_action_name.as_ref().map(|x| explicit_to_ber(4, x.to_ber())),
+                client_application.as_ref().map(|x| application_reference_to_ber(5, x)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn acknowledge_event_notification_request_to_ber<'a>(
+    event_enrollment_name: &'a MmsObjectName,
+    acknowledged_state: &'a MmsEventConditionState,
+    time_of_acknowledged_transition: &'a MmsEventTime,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(62), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            explicit_to_ber(0, event_enrollment_name.to_ber()),
+            integer_to_ber(2, acknowledged_state.to_ber()),
+            event_time_to_ber(3, time_of_acknowledged_transition),
+        ]),
+    ))
+}
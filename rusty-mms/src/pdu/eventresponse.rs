@@ -0,0 +1,124 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedResponse, MmsError, MmsEventConditionClass, MmsMonitoredVariable, VariableSpecification,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content},
+    pdu::common::{expect_value, explicit_to_ber, parse_explicit},
+};
+
+pub(crate) fn parse_define_event_condition_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DefineEventCondition)
+}
+
+pub(crate) fn parse_alter_event_condition_monitoring_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::AlterEventConditionMonitoring)
+}
+
+pub(crate) fn parse_get_event_condition_attributes_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut mms_deletable = None;
+    let mut class = None;
+    let mut priority = None;
+    let mut severity = None;
+    let mut alarm_summary_reports = None;
+    let mut monitored_variable = None;
+    let mut evaluation_interval = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Get Event Condition Attributes Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => mms_deletable = Some(process_mms_boolean_content(&item, "Failed to parse MMS Deletable on Get Event Condition Attributes Response PDU")?),
+            Some([129]) => class = Some(MmsEventConditionClass::parse(item.data)),
+            Some([130]) => priority = Some(process_integer_content(&item, "Failed to parse Priority on Get Event Condition Attributes Response PDU")?),
+            Some([131]) => severity = Some(process_integer_content(&item, "Failed to parse Severity on Get Event Condition Attributes Response PDU")?),
+            Some([132]) => alarm_summary_reports = Some(process_mms_boolean_content(&item, "Failed to parse Alarm Summary Reports on Get Event Condition Attributes Response PDU")?),
+            Some([166]) => {
+                let error_message = "Failed to parse Monitored Variable on Get Event Condition Attributes Response PDU";
+                let choice = parse_explicit(&item, error_message)?;
+                monitored_variable = Some(match choice.header.raw_tag() {
+                    Some([160]) => MmsMonitoredVariable::VariableReference(VariableSpecification::parse(choice.data)?),
+                    Some([129]) => MmsMonitoredVariable::Undefined,
+                    x => return Err(MmsError::ProtocolError(format!("{}: {:?}", error_message, x))),
+                });
+            }
+            Some([135]) => evaluation_interval = Some(process_integer_content(&item, "Failed to parse Evaluation Interval on Get Event Condition Attributes Response PDU")?),
+            x => warn!("Unsupported tag in MMS Get Event Condition Attributes Response PDU: {:?}", x),
+        }
+    }
+
+    let class = expect_value("GetEventConditionAttributes", "Class", class)?;
+
+    Ok(MmsConfirmedResponse::GetEventConditionAttributes { mms_deletable, class, priority, severity, alarm_summary_reports, monitored_variable, evaluation_interval })
+}
+
+pub(crate) fn parse_define_event_enrollment_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DefineEventEnrollment)
+}
+
+pub(crate) fn parse_acknowledge_event_notification_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::AcknowledgeEventNotification)
+}
+
+fn null_response_to_ber<'a>(tag: u32) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Null)
+}
+
+pub(crate) fn define_event_condition_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(47))
+}
+
+pub(crate) fn alter_event_condition_monitoring_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(51))
+}
+
+pub(crate) fn get_event_condition_attributes_response_to_ber<'a>(
+    mms_deletable: &Option<bool>,
+    class: &'a MmsEventConditionClass,
+    priority: &'a Option<Vec<u8>>,
+    severity: &'a Option<Vec<u8>>,
+    alarm_summary_reports: &Option<bool>,
+    monitored_variable: &'a Option<MmsMonitoredVariable>,
+    evaluation_interval: &'a Option<Vec<u8>>,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(49), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                mms_deletable.map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Boolean(x))),
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(class.to_ber()))),
+                priority.as_ref().map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::Integer(x))),
+                severity.as_ref().map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(3), Length::Definite(0)), BerObjectContent::Integer(x))),
+                alarm_summary_reports.map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(4), Length::Definite(0)), BerObjectContent::Boolean(x))),
+                monitored_variable.as_ref().map(|x| {
+                    explicit_to_ber(
+                        6,
+                        match x {
+                            MmsMonitoredVariable::VariableReference(variable_specification) => explicit_to_ber(0, variable_specification.to_ber()),
+                            MmsMonitoredVariable::Undefined => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Null),
+                        },
+                    )
+                }),
+                evaluation_interval.as_ref().map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(7), Length::Definite(0)), BerObjectContent::Integer(x))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn define_event_enrollment_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(57))
+}
+
+pub(crate) fn acknowledge_event_notification_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(62))
+}
@@ -0,0 +1,152 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length, parse_ber_content},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedRequest, MmsError,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content},
+    pdu::common::expect_value,
+};
+
+pub(crate) fn parse_obtain_file_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut source_file = None;
+    let mut destination_file = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Obtain File Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => return Err(MmsError::ProtocolError("Source File Server on Obtain File Request PDU is not supported".into())),
+            Some([161]) => source_file = Some(parse_file_name(&item, "Obtain File Request PDU")?),
+            Some([162]) => destination_file = Some(parse_file_name(&item, "Obtain File Request PDU")?),
+            x => warn!("Unsupported tag in MMS Obtain File Request PDU: {:?}", x),
+        }
+    }
+
+    let source_file = expect_value("ObtainFile", "SourceFile", source_file)?;
+    let destination_file = expect_value("ObtainFile", "DestinationFile", destination_file)?;
+
+    Ok(MmsConfirmedRequest::ObtainFile { source_file, destination_file })
+}
+
+pub(crate) fn parse_file_open_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut file_name = None;
+    let mut initial_position = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse File Open Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => file_name = Some(parse_file_name(&item, "File Open Request PDU")?),
+            Some([129]) => initial_position = Some(process_integer_content(&item, "Failed to parse Initial Position on File Open Request PDU")?),
+            x => warn!("Unsupported tag in MMS File Open Request PDU: {:?}", x),
+        }
+    }
+
+    let file_name = expect_value("FileOpen", "FileName", file_name)?;
+    let initial_position = expect_value("FileOpen", "InitialPosition", initial_position)?;
+
+    Ok(MmsConfirmedRequest::FileOpen { file_name, initial_position })
+}
+
+pub(crate) fn parse_file_read_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::FileRead { frsm_id: process_integer_content(payload, "Failed to parse FRSM ID on File Read Request PDU")? })
+}
+
+pub(crate) fn parse_file_close_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::FileClose { frsm_id: process_integer_content(payload, "Failed to parse FRSM ID on File Close Request PDU")? })
+}
+
+pub(crate) fn parse_file_rename_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut current_file_name = None;
+    let mut new_file_name = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse File Rename Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => current_file_name = Some(parse_file_name(&item, "File Rename Request PDU")?),
+            Some([161]) => new_file_name = Some(parse_file_name(&item, "File Rename Request PDU")?),
+            x => warn!("Unsupported tag in MMS File Rename Request PDU: {:?}", x),
+        }
+    }
+
+    let current_file_name = expect_value("FileRename", "CurrentFileName", current_file_name)?;
+    let new_file_name = expect_value("FileRename", "NewFileName", new_file_name)?;
+
+    Ok(MmsConfirmedRequest::FileRename { current_file_name, new_file_name })
+}
+
+pub(crate) fn parse_file_delete_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::FileDelete { file_name: parse_file_name(payload, "File Delete Request PDU")? })
+}
+
+pub(crate) fn parse_file_directory_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut file_specification = None;
+    let mut continue_after = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse File Directory Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => file_specification = Some(parse_file_name(&item, "File Directory Request PDU")?),
+            Some([161]) => continue_after = Some(parse_file_name(&item, "File Directory Request PDU")?),
+            x => warn!("Unsupported tag in MMS File Directory Request PDU: {:?}", x),
+        }
+    }
+
+    Ok(MmsConfirmedRequest::FileDirectory { file_specification, continue_after })
+}
+
+// A file name is a sequence of graphic strings.
+pub(crate) fn parse_file_name(payload: &Any<'_>, pdu: &str) -> Result<Vec<String>, MmsError> {
+    let error_message = format!("Failed to parse File Name on {}", pdu);
+    process_constructed_data(payload.data)
+        .map_err(to_mms_error(&error_message))?
+        .into_iter()
+        .map(|item| match parse_ber_content(Tag::GraphicString)(item.data, &item.header, item.data.len()).map_err(to_mms_error(&error_message))? {
+            (_, BerObjectContent::GraphicString(value)) => Ok(value.to_string()),
+            _ => Err(MmsError::ProtocolError(error_message.clone())),
+        })
+        .collect()
+}
+
+pub(crate) fn file_name_to_ber(tag: u32, file_name: &[String]) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(file_name.iter().map(|x| BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::GraphicString, Length::Definite(0)), BerObjectContent::GraphicString(x.as_str()))).collect()),
+    )
+}
+
+pub(crate) fn obtain_file_request_to_ber<'a>(source_file: &'a [String], destination_file: &'a [String]) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(46), Length::Definite(0)), BerObjectContent::Sequence(vec![file_name_to_ber(1, source_file), file_name_to_ber(2, destination_file)])))
+}
+
+pub(crate) fn file_open_request_to_ber<'a>(file_name: &'a [String], initial_position: &'a [u8]) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(72), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            file_name_to_ber(0, file_name),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(initial_position)),
+        ]),
+    ))
+}
+
+pub(crate) fn file_read_request_to_ber(frsm_id: &[u8]) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(73), Length::Definite(0)), BerObjectContent::Integer(frsm_id)))
+}
+
+pub(crate) fn file_close_request_to_ber(frsm_id: &[u8]) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(74), Length::Definite(0)), BerObjectContent::Integer(frsm_id)))
+}
+
+pub(crate) fn file_rename_request_to_ber<'a>(current_file_name: &'a [String], new_file_name: &'a [String]) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(75), Length::Definite(0)), BerObjectContent::Sequence(vec![file_name_to_ber(0, current_file_name), file_name_to_ber(1, new_file_name)])))
+}
+
+pub(crate) fn file_delete_request_to_ber(file_name: &[String]) -> Result<BerObject<'_>, MmsError> {
+    Ok(file_name_to_ber(76, file_name))
+}
+
+pub(crate) fn file_directory_request_to_ber<'a>(file_specification: &'a Option<Vec<String>>, continue_after: &'a Option<Vec<String>>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(77), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![file_specification.as_ref().map(|x| file_name_to_ber(0, x)), continue_after.as_ref().map(|x| file_name_to_ber(1, x))].into_iter().flatten().collect()),
+    ))
+}
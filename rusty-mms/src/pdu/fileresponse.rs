@@ -0,0 +1,217 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedResponse, MmsDirectoryEntry, MmsError, MmsFileAttributes,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content, process_mms_string},
+    pdu::{
+        common::expect_value,
+        filerequest::{file_name_to_ber, parse_file_name},
+    },
+};
+
+pub(crate) fn parse_obtain_file_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::ObtainFile)
+}
+
+pub(crate) fn parse_file_open_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut frsm_id = None;
+    let mut file_attributes = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse File Open Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => frsm_id = Some(process_integer_content(&item, "Failed to parse FRSM ID on File Open Response PDU")?),
+            Some([161]) => file_attributes = Some(parse_file_attributes(&item, "File Open Response PDU")?),
+            x => warn!("Unsupported tag in MMS File Open Response PDU: {:?}", x),
+        }
+    }
+
+    let frsm_id = expect_value("FileOpen", "FrsmID", frsm_id)?;
+    let file_attributes = expect_value("FileOpen", "FileAttributes", file_attributes)?;
+
+    Ok(MmsConfirmedResponse::FileOpen { frsm_id, file_attributes })
+}
+
+pub(crate) fn parse_file_read_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut file_data = None;
+    let mut more_follows = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse File Read Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => file_data = Some(item.data.to_vec()),
+            Some([129]) => more_follows = Some(process_mms_boolean_content(&item, "Failed to parse More Follows on File Read Response PDU")?),
+            x => warn!("Unsupported tag in MMS File Read Response PDU: {:?}", x),
+        }
+    }
+
+    let file_data = expect_value("FileRead", "FileData", file_data)?;
+
+    Ok(MmsConfirmedResponse::FileRead { file_data, more_follows })
+}
+
+pub(crate) fn parse_file_close_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::FileClose)
+}
+
+pub(crate) fn parse_file_rename_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::FileRename)
+}
+
+pub(crate) fn parse_file_delete_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::FileDelete)
+}
+
+pub(crate) fn parse_file_directory_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut list_of_directory_entry = None;
+    let mut more_follows = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse File Directory Response PDU"))? {
+        match item.header.raw_tag() {
+            // The list of directory entries is explicitly tagged.
+            Some([160]) => {
+                let inner = process_constructed_data(item.data).map_err(to_mms_error("Failed to parse List of Directory Entry on File Directory Response PDU"))?;
+                let entries = inner.first().ok_or_else(|| MmsError::ProtocolError("No content in List of Directory Entry on File Directory Response PDU".into()))?;
+                list_of_directory_entry =
+                    Some(process_constructed_data(entries.data).map_err(to_mms_error("Failed to parse List of Directory Entry on File Directory Response PDU"))?.iter().map(parse_directory_entry).collect::<Result<Vec<_>, _>>()?);
+            }
+            Some([129]) => more_follows = Some(process_mms_boolean_content(&item, "Failed to parse More Follows on File Directory Response PDU")?),
+            x => warn!("Unsupported tag in MMS File Directory Response PDU: {:?}", x),
+        }
+    }
+
+    let list_of_directory_entry = expect_value("FileDirectory", "ListOfDirectoryEntry", list_of_directory_entry)?;
+
+    Ok(MmsConfirmedResponse::FileDirectory { list_of_directory_entry, more_follows })
+}
+
+fn parse_directory_entry(payload: &Any<'_>) -> Result<MmsDirectoryEntry, MmsError> {
+    let mut file_name = None;
+    let mut file_attributes = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Directory Entry on File Directory Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => file_name = Some(parse_file_name(&item, "File Directory Response PDU")?),
+            Some([161]) => file_attributes = Some(parse_file_attributes(&item, "File Directory Response PDU")?),
+            x => warn!("Unsupported tag in MMS Directory Entry: {:?}", x),
+        }
+    }
+
+    let file_name = expect_value("DirectoryEntry", "FileName", file_name)?;
+    let file_attributes = expect_value("DirectoryEntry", "FileAttributes", file_attributes)?;
+
+    Ok(MmsDirectoryEntry { file_name, file_attributes })
+}
+
+fn parse_file_attributes(payload: &Any<'_>, pdu: &str) -> Result<MmsFileAttributes, MmsError> {
+    let mut size_of_file = None;
+    let mut last_modified = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse File Attributes on {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([128]) => size_of_file = Some(process_integer_content(&item, &format!("Failed to parse Size of File on {}", pdu))?),
+            Some([129]) => last_modified = Some(process_mms_string(&item, &format!("Failed to parse Last Modified on {}", pdu))?),
+            x => warn!("Unsupported tag in MMS File Attributes on {}: {:?}", pdu, x),
+        }
+    }
+
+    let size_of_file = expect_value("FileAttributes", "SizeOfFile", size_of_file)?;
+
+    Ok(MmsFileAttributes { size_of_file, last_modified })
+}
+
+fn file_attributes_to_ber(tag: u32, file_attributes: &MmsFileAttributes) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(&file_attributes.size_of_file))),
+                file_attributes.last_modified.as_ref().map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::VisibleString(x.as_str()))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    )
+}
+
+fn null_response_to_ber<'a>(tag: u32) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Null)
+}
+
+pub(crate) fn obtain_file_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(46))
+}
+
+pub(crate) fn file_open_response_to_ber<'a>(frsm_id: &'a [u8], file_attributes: &'a MmsFileAttributes) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(72), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(frsm_id)),
+            file_attributes_to_ber(1, file_attributes),
+        ]),
+    ))
+}
+
+pub(crate) fn file_read_response_to_ber<'a>(file_data: &'a [u8], more_follows: &Option<bool>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(73), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::OctetString(file_data))),
+                more_follows.map(|more_follows| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Boolean(more_follows))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn file_close_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(74))
+}
+
+pub(crate) fn file_rename_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(75))
+}
+
+pub(crate) fn file_delete_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(76))
+}
+
+pub(crate) fn file_directory_response_to_ber<'a>(list_of_directory_entry: &'a [MmsDirectoryEntry], more_follows: &Option<bool>) -> Result<BerObject<'a>, MmsError> {
+    let entries = list_of_directory_entry
+        .iter()
+        .map(|entry| {
+            BerObject::from_header_and_content(
+                Header::new(Class::Universal, true, Tag::Sequence, Length::Definite(0)),
+                BerObjectContent::Sequence(vec![file_name_to_ber(0, &entry.file_name), file_attributes_to_ber(1, &entry.file_attributes)]),
+            )
+        })
+        .collect();
+
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(77), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(
+                    Header::new(Class::ContextSpecific, true, Tag::from(0), Length::Definite(0)),
+                    BerObjectContent::Sequence(vec![BerObject::from_header_and_content(Header::new(Class::Universal, true, Tag::Sequence, Length::Definite(0)), BerObjectContent::Sequence(entries))]),
+                )),
+                more_follows.map(|more_follows| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Boolean(more_follows))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
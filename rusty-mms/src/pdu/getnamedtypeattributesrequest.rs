@@ -0,0 +1,16 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+
+use crate::{MmsConfirmedRequest, MmsError, MmsObjectName};
+
+pub(crate) fn parse_get_named_type_attributes_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let type_name = MmsObjectName::parse("MMS GetNamedTypeAttributes", payload.data)?;
+    Ok(MmsConfirmedRequest::GetNamedTypeAttributes { type_name })
+}
+
+pub(crate) fn get_named_type_attributes_request_to_ber<'a>(type_name: &'a MmsObjectName) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(15), Length::Definite(0)), BerObjectContent::Sequence(vec![type_name.to_ber()])))
+}
@@ -0,0 +1,41 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedResponse, MmsError, MmsTypeSpecification,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_mms_boolean_content},
+};
+
+pub(crate) fn parse_get_named_type_attributes_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut deletable = None;
+    let mut type_specification = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse MMS Get Named Type Attributes PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => deletable = Some(process_mms_boolean_content(&item, "Get Named Type Attributes Response PDU - Deletable Flag")?),
+            // The type specification is an untagged choice so it is whatever follows the deletable flag.
+            _ if type_specification.is_none() => type_specification = Some(MmsTypeSpecification::parse_untagged("Get Named Type Attributes Response PDU - Type Specification", &item)?),
+            x => warn!("Unsupported tag in MMS Get Named Type Attributes Response PDU: {:?}", x),
+        }
+    }
+
+    let deletable = deletable.ok_or_else(|| MmsError::ProtocolError("No Deletable Flag on Get Named Type Attributes Response PDU".into()))?;
+    let type_specification = type_specification.ok_or_else(|| MmsError::ProtocolError("No Type Specification on Get Named Type Attributes Response PDU".into()))?;
+
+    Ok(MmsConfirmedResponse::GetNamedTypeAttributes { deletable, type_specification })
+}
+
+pub(crate) fn get_named_type_attributes_response_to_ber<'a>(deletable: bool, type_specification: &'a MmsTypeSpecification) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(15), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Boolean(deletable)),
+            type_specification.to_ber()?,
+        ]),
+    ))
+}
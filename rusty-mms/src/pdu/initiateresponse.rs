@@ -101,4 +101,8 @@ impl InitiateResponsePdu {
             init_response_details: expect_value("InitiateResponse", "InitResponseDetails", init_response_details)?,
         })
     }
+
+    pub(crate) fn init_response_details(&self) -> &InitRequestResponseDetails {
+        &self.init_response_details
+    }
 }
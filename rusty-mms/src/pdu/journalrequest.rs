@@ -0,0 +1,368 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsBinaryTime, MmsConfirmedRequest, MmsData, MmsEntryContent, MmsEntryForm, MmsError, MmsEventConditionState, MmsJournalEntryToStartAfter, MmsJournalEvent, MmsJournalLimitSpecification, MmsJournalRangeStart, MmsJournalRangeStop,
+    MmsJournalVariable, MmsObjectName,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_string},
+    pdu::common::{expect_value, explicit_to_ber, parse_explicit},
+};
+
+pub(crate) fn parse_read_journal_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut journal_name = None;
+    let mut range_start_specification = None;
+    let mut range_stop_specification = None;
+    let mut list_of_variables = None;
+    let mut entry_to_start_after = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Read Journal Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => journal_name = Some(MmsObjectName::parse("Read Journal Request PDU", item.data)?),
+            Some([161]) => {
+                let choice = parse_explicit(&item, "Failed to parse Range Start Specification on Read Journal Request PDU")?;
+                range_start_specification = Some(match choice.header.raw_tag() {
+                    Some([128]) => MmsJournalRangeStart::StartingTime(MmsBinaryTime::parse(choice.data)?),
+                    Some([129]) => MmsJournalRangeStart::StartingEntry(choice.data.to_vec()),
+                    x => return Err(MmsError::ProtocolError(format!("Unsupported Range Start Specification on Read Journal Request PDU: {:?}", x))),
+                });
+            }
+            Some([162]) => {
+                let choice = parse_explicit(&item, "Failed to parse Range Stop Specification on Read Journal Request PDU")?;
+                range_stop_specification = Some(match choice.header.raw_tag() {
+                    Some([128]) => MmsJournalRangeStop::EndingTime(MmsBinaryTime::parse(choice.data)?),
+                    Some([129]) => MmsJournalRangeStop::NumberOfEntries(process_integer_content(&choice, "Failed to parse Number of Entries on Read Journal Request PDU")?),
+                    x => return Err(MmsError::ProtocolError(format!("Unsupported Range Stop Specification on Read Journal Request PDU: {:?}", x))),
+                });
+            }
+            Some([164]) => {
+                let mut variables = vec![];
+                for variable in process_constructed_data(item.data).map_err(to_mms_error("Failed to parse List of Variables on Read Journal Request PDU"))? {
+                    variables.push(process_mms_string(&variable, "Failed to parse List of Variables on Read Journal Request PDU")?);
+                }
+                list_of_variables = Some(variables);
+            }
+            Some([165]) => {
+                let mut time_specification = None;
+                let mut entry_specification = None;
+                for inner in process_constructed_data(item.data).map_err(to_mms_error("Failed to parse Entry to Start After on Read Journal Request PDU"))? {
+                    match inner.header.raw_tag() {
+                        Some([128]) => time_specification = Some(MmsBinaryTime::parse(inner.data)?),
+                        Some([129]) => entry_specification = Some(inner.data.to_vec()),
+                        x => warn!("Unsupported tag in MMS Entry to Start After on Read Journal Request PDU: {:?}", x),
+                    }
+                }
+                let time_specification = expect_value("EntryToStartAfter", "TimeSpecification", time_specification)?;
+                let entry_specification = expect_value("EntryToStartAfter", "EntrySpecification", entry_specification)?;
+                entry_to_start_after = Some(Box::new(MmsJournalEntryToStartAfter { time_specification, entry_specification }));
+            }
+            x => warn!("Unsupported tag in MMS Read Journal Request PDU: {:?}", x),
+        }
+    }
+
+    let journal_name = expect_value("ReadJournal", "JournalName", journal_name)?;
+
+    Ok(MmsConfirmedRequest::ReadJournal { journal_name, range_start_specification, range_stop_specification, list_of_variables, entry_to_start_after })
+}
+
+pub(crate) fn parse_write_journal_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut journal_name = None;
+    let mut list_of_journal_entry = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Write Journal Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => journal_name = Some(MmsObjectName::parse("Write Journal Request PDU", item.data)?),
+            Some([161]) => {
+                let mut entries = vec![];
+                for entry in process_constructed_data(item.data).map_err(to_mms_error("Failed to parse List of Journal Entry on Write Journal Request PDU"))? {
+                    entries.push(parse_entry_content(&entry, "Write Journal Request PDU")?);
+                }
+                list_of_journal_entry = Some(entries);
+            }
+            x => warn!("Unsupported tag in MMS Write Journal Request PDU: {:?}", x),
+        }
+    }
+
+    let journal_name = expect_value("WriteJournal", "JournalName", journal_name)?;
+    let list_of_journal_entry = expect_value("WriteJournal", "ListOfJournalEntry", list_of_journal_entry)?;
+
+    Ok(MmsConfirmedRequest::WriteJournal { journal_name, list_of_journal_entry })
+}
+
+pub(crate) fn parse_initialize_journal_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut journal_name = None;
+    let mut limit_specification = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Initialize Journal Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => journal_name = Some(MmsObjectName::parse("Initialize Journal Request PDU", item.data)?),
+            Some([161]) => {
+                let mut limiting_time = None;
+                let mut limiting_entry = None;
+                for inner in process_constructed_data(item.data).map_err(to_mms_error("Failed to parse Limit Specification on Initialize Journal Request PDU"))? {
+                    match inner.header.raw_tag() {
+                        Some([128]) => limiting_time = Some(MmsBinaryTime::parse(inner.data)?),
+                        Some([129]) => limiting_entry = Some(inner.data.to_vec()),
+                        x => warn!("Unsupported tag in MMS Limit Specification on Initialize Journal Request PDU: {:?}", x),
+                    }
+                }
+                let limiting_time = expect_value("LimitSpecification", "LimitingTime", limiting_time)?;
+                limit_specification = Some(MmsJournalLimitSpecification { limiting_time, limiting_entry });
+            }
+            x => warn!("Unsupported tag in MMS Initialize Journal Request PDU: {:?}", x),
+        }
+    }
+
+    let journal_name = expect_value("InitializeJournal", "JournalName", journal_name)?;
+
+    Ok(MmsConfirmedRequest::InitializeJournal { journal_name, limit_specification })
+}
+
+pub(crate) fn parse_report_journal_status_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::ReportJournalStatus { journal_name: MmsObjectName::parse("Report Journal Status Request PDU", payload.data)? })
+}
+
+pub(crate) fn parse_create_journal_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::CreateJournal { journal_name: parse_journal_name(payload, "Create Journal Request PDU")? })
+}
+
+pub(crate) fn parse_delete_journal_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::DeleteJournal { journal_name: parse_journal_name(payload, "Delete Journal Request PDU")? })
+}
+
+fn parse_journal_name(payload: &Any<'_>, pdu: &str) -> Result<MmsObjectName, MmsError> {
+    let mut journal_name = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([160]) => journal_name = Some(MmsObjectName::parse(pdu, item.data)?),
+            x => warn!("Unsupported tag in MMS {}: {:?}", pdu, x),
+        }
+    }
+
+    expect_value(pdu, "JournalName", journal_name)
+}
+
+pub(crate) fn parse_entry_content(payload: &Any<'_>, pdu: &str) -> Result<MmsEntryContent, MmsError> {
+    let mut occurrence_time = None;
+    let mut entry_form = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse Entry Content on {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([128]) => occurrence_time = Some(MmsBinaryTime::parse(item.data)?),
+            Some([162]) => {
+                let mut event = None;
+                let mut list_of_variables = None;
+                for inner in process_constructed_data(item.data).map_err(to_mms_error(&format!("Failed to parse Entry Data on {}", pdu)))? {
+                    match inner.header.raw_tag() {
+                        Some([160]) => event = Some(parse_journal_event(&inner, pdu)?),
+                        Some([161]) => {
+                            let mut variables = vec![];
+                            for variable in process_constructed_data(inner.data).map_err(to_mms_error(&format!("Failed to parse List of Variables on {}", pdu)))? {
+                                variables.push(parse_journal_variable(&variable, pdu)?);
+                            }
+                            list_of_variables = Some(variables);
+                        }
+                        x => warn!("Unsupported tag in MMS Entry Data on {}: {:?}", pdu, x),
+                    }
+                }
+                entry_form = Some(MmsEntryForm::Data { event, list_of_variables });
+            }
+            // The annotation is explicitly tagged but some peers send it implicitly tagged.
+            Some([163]) => {
+                let error_message = format!("Failed to parse Annotation on {}", pdu);
+                entry_form = Some(MmsEntryForm::Annotation(process_mms_string(&parse_explicit(&item, &error_message)?, &error_message)?));
+            }
+            Some([131]) => entry_form = Some(MmsEntryForm::Annotation(process_mms_string(&item, &format!("Failed to parse Annotation on {}", pdu))?)),
+            x => warn!("Unsupported tag in MMS Entry Content on {}: {:?}", pdu, x),
+        }
+    }
+
+    let occurrence_time = expect_value("EntryContent", "OccurrenceTime", occurrence_time)?;
+    let entry_form = expect_value("EntryContent", "EntryForm", entry_form)?;
+
+    Ok(MmsEntryContent { occurrence_time, entry_form })
+}
+
+fn parse_journal_event(payload: &Any<'_>, pdu: &str) -> Result<MmsJournalEvent, MmsError> {
+    let mut event_condition_name = None;
+    let mut current_state = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse Event on {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([160]) => event_condition_name = Some(MmsObjectName::parse(pdu, item.data)?),
+            Some([129]) => current_state = Some(MmsEventConditionState::parse(&process_integer_content(&item, &format!("Failed to parse Current State on {}", pdu))?)),
+            x => warn!("Unsupported tag in MMS Event on {}: {:?}", pdu, x),
+        }
+    }
+
+    let event_condition_name = expect_value("Event", "EventConditionName", event_condition_name)?;
+    let current_state = expect_value("Event", "CurrentState", current_state)?;
+
+    Ok(MmsJournalEvent { event_condition_name, current_state })
+}
+
+fn parse_journal_variable(payload: &Any<'_>, pdu: &str) -> Result<MmsJournalVariable, MmsError> {
+    let mut variable_tag = None;
+    let mut value_specification = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse Journal Variable on {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([128]) => variable_tag = Some(process_mms_string(&item, &format!("Failed to parse Variable Tag on {}", pdu))?),
+            Some([161]) => {
+                value_specification = Some(MmsData::parse(pdu, &parse_explicit(&item, &format!("Failed to parse Value Specification on {}", pdu))?)?);
+            }
+            x => warn!("Unsupported tag in MMS Journal Variable on {}: {:?}", pdu, x),
+        }
+    }
+
+    let variable_tag = expect_value("JournalVariable", "VariableTag", variable_tag)?;
+    let value_specification = expect_value("JournalVariable", "ValueSpecification", value_specification)?;
+
+    Ok(MmsJournalVariable { variable_tag, value_specification })
+}
+
+pub(crate) fn entry_content_to_ber<'a>(header: Header<'a>, entry_content: &'a MmsEntryContent) -> Result<BerObject<'a>, MmsError> {
+    let entry_form = match &entry_content.entry_form {
+        MmsEntryForm::Data { event, list_of_variables } => {
+            let event = event.as_ref().map(|event| {
+                BerObject::from_header_and_content(
+                    Header::new(Class::ContextSpecific, true, Tag::from(0), Length::Definite(0)),
+                    BerObjectContent::Sequence(vec![
+                        explicit_to_ber(0, event.event_condition_name.to_ber()),
+                        BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(event.current_state.to_ber())),
+                    ]),
+                )
+            });
+            let list_of_variables = match list_of_variables {
+                Some(list_of_variables) => {
+                    let mut variables = vec![];
+                    for variable in list_of_variables {
+                        variables.push(BerObject::from_header_and_content(
+                            Header::new(Class::Universal, true, Tag::Sequence, Length::Definite(0)),
+                            BerObjectContent::Sequence(vec![
+                                BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::VisibleString(variable.variable_tag.as_str())),
+                                explicit_to_ber(1, variable.value_specification.serialise(true, true)?),
+                            ]),
+                        ));
+                    }
+                    Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(1), Length::Definite(0)), BerObjectContent::Sequence(variables)))
+                }
+                None => None,
+            };
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(2), Length::Definite(0)), BerObjectContent::Sequence(vec![event, list_of_variables].into_iter().flatten().collect()))
+        }
+        MmsEntryForm::Annotation(annotation) => explicit_to_ber(3, BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Utf8String, Length::Definite(0)), BerObjectContent::UTF8String(annotation.as_str()))),
+    };
+
+    Ok(BerObject::from_header_and_content(
+        header,
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::OctetString(entry_content.occurrence_time.data())),
+            entry_form,
+        ]),
+    ))
+}
+
+fn time_to_ber(tag: u32, time: &MmsBinaryTime) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::OctetString(time.data()))
+}
+
+fn entry_identifier_to_ber(tag: u32, entry_identifier: &[u8]) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::OctetString(entry_identifier))
+}
+
+pub(crate) fn read_journal_request_to_ber<'a>(
+    journal_name: &'a MmsObjectName,
+    range_start_specification: &'a Option<MmsJournalRangeStart>,
+    range_stop_specification: &'a Option<MmsJournalRangeStop>,
+    list_of_variables: &'a Option<Vec<String>>,
+    entry_to_start_after: &'a Option<Box<MmsJournalEntryToStartAfter>>,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(65), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(explicit_to_ber(0, journal_name.to_ber())),
+                range_start_specification.as_ref().map(|x| match x {
+                    MmsJournalRangeStart::StartingTime(time) => explicit_to_ber(1, time_to_ber(0, time)),
+                    MmsJournalRangeStart::StartingEntry(entry) => explicit_to_ber(1, entry_identifier_to_ber(1, entry)),
+                }),
+                range_stop_specification.as_ref().map(|x| match x {
+                    MmsJournalRangeStop::EndingTime(time) => explicit_to_ber(2, time_to_ber(0, time)),
+                    MmsJournalRangeStop::NumberOfEntries(number_of_entries) => {
+                        explicit_to_ber(2, BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(number_of_entries)))
+                    }
+                }),
+                list_of_variables.as_ref().map(|x| {
+                    BerObject::from_header_and_content(
+                        Header::new(Class::ContextSpecific, true, Tag::from(4), Length::Definite(0)),
+                        BerObjectContent::Sequence(
+                            x.iter().map(|variable| BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::VisibleString, Length::Definite(0)), BerObjectContent::VisibleString(variable.as_str()))).collect(),
+                        ),
+                    )
+                }),
+                entry_to_start_after.as_ref().map(|x| {
+                    BerObject::from_header_and_content(
+                        Header::new(Class::ContextSpecific, true, Tag::from(5), Length::Definite(0)),
+                        BerObjectContent::Sequence(vec![time_to_ber(0, &x.time_specification), entry_identifier_to_ber(1, &x.entry_specification)]),
+                    )
+                }),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn write_journal_request_to_ber<'a>(journal_name: &'a MmsObjectName, list_of_journal_entry: &'a [MmsEntryContent]) -> Result<BerObject<'a>, MmsError> {
+    let mut entries = vec![];
+    for entry in list_of_journal_entry {
+        entries.push(entry_content_to_ber(Header::new(Class::Universal, true, Tag::Sequence, Length::Definite(0)), entry)?);
+    }
+
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(66), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            explicit_to_ber(0, journal_name.to_ber()),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(1), Length::Definite(0)), BerObjectContent::Sequence(entries)),
+        ]),
+    ))
+}
+
+pub(crate) fn initialize_journal_request_to_ber<'a>(journal_name: &'a MmsObjectName, limit_specification: &'a Option<MmsJournalLimitSpecification>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(67), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(explicit_to_ber(0, journal_name.to_ber())),
+                limit_specification.as_ref().map(|x| {
+                    BerObject::from_header_and_content(
+                        Header::new(Class::ContextSpecific, true, Tag::from(1), Length::Definite(0)),
+                        BerObjectContent::Sequence(vec![Some(time_to_ber(0, &x.limiting_time)), x.limiting_entry.as_ref().map(|entry| entry_identifier_to_ber(1, entry))].into_iter().flatten().collect()),
+                    )
+                }),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn report_journal_status_request_to_ber(journal_name: &MmsObjectName) -> Result<BerObject<'_>, MmsError> {
+    Ok(explicit_to_ber(68, journal_name.to_ber()))
+}
+
+pub(crate) fn create_journal_request_to_ber(journal_name: &MmsObjectName) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(69), Length::Definite(0)), BerObjectContent::Sequence(vec![explicit_to_ber(0, journal_name.to_ber())])))
+}
+
+pub(crate) fn delete_journal_request_to_ber(journal_name: &MmsObjectName) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(70), Length::Definite(0)), BerObjectContent::Sequence(vec![explicit_to_ber(0, journal_name.to_ber())])))
+}
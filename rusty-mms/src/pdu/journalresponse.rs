@@ -0,0 +1,201 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length, parse_ber_content},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsApplicationReference, MmsConfirmedResponse, MmsError, MmsJournalEntry,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content},
+    pdu::{
+        common::{expect_value, explicit_to_ber, parse_explicit},
+        journalrequest::{entry_content_to_ber, parse_entry_content},
+    },
+};
+
+pub(crate) fn parse_read_journal_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut list_of_journal_entry = None;
+    let mut more_follows = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Read Journal Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => {
+                list_of_journal_entry = Some(process_constructed_data(item.data).map_err(to_mms_error("Failed to parse List of Journal Entry on Read Journal Response PDU"))?.iter().map(parse_journal_entry).collect::<Result<Vec<_>, _>>()?)
+            }
+            Some([129]) => more_follows = Some(process_mms_boolean_content(&item, "Failed to parse More Follows on Read Journal Response PDU")?),
+            x => warn!("Unsupported tag in MMS Read Journal Response PDU: {:?}", x),
+        }
+    }
+
+    let list_of_journal_entry = expect_value("ReadJournal", "ListOfJournalEntry", list_of_journal_entry)?;
+
+    Ok(MmsConfirmedResponse::ReadJournal { list_of_journal_entry, more_follows })
+}
+
+fn parse_journal_entry(payload: &Any<'_>) -> Result<MmsJournalEntry, MmsError> {
+    let mut entry_identifier = None;
+    let mut originating_application = None;
+    let mut entry_content = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Journal Entry on Read Journal Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => entry_identifier = Some(item.data.to_vec()),
+            Some([161]) => originating_application = Some(parse_application_reference(&item, "Failed to parse Originating Application on Read Journal Response PDU")?),
+            Some([162]) => entry_content = Some(parse_entry_content(&item, "Read Journal Response PDU")?),
+            x => warn!("Unsupported tag in MMS Journal Entry: {:?}", x),
+        }
+    }
+
+    let entry_identifier = expect_value("JournalEntry", "EntryIdentifier", entry_identifier)?;
+    let originating_application = expect_value("JournalEntry", "OriginatingApplication", originating_application)?;
+    let entry_content = expect_value("JournalEntry", "EntryContent", entry_content)?;
+
+    Ok(MmsJournalEntry { entry_identifier, originating_application, entry_content })
+}
+
+// The application reference is explicitly tagged, but peers are known to send an empty implicitly tagged reference.
+pub(crate) fn parse_application_reference(payload: &Any<'_>, error_message: &str) -> Result<MmsApplicationReference, MmsError> {
+    let mut items = process_constructed_data(payload.data).map_err(to_mms_error(error_message))?;
+    if let [item] = items.as_slice()
+        && item.header.raw_tag() == Some(&[48])
+    {
+        items = process_constructed_data(item.data).map_err(to_mms_error(error_message))?;
+    }
+
+    let mut application_reference = MmsApplicationReference::default();
+    for item in items {
+        let inner = parse_explicit(&item, error_message)?;
+        match (item.header.raw_tag(), inner.header.raw_tag()) {
+            (Some([160]), Some([6])) => match parse_ber_content(Tag::Oid)(inner.data, &inner.header, inner.data.len()).map_err(to_mms_error(error_message))? {
+                (_, BerObjectContent::OID(oid)) => application_reference.ap_title = Some(oid.to_owned()),
+                _ => return Err(MmsError::ProtocolError(error_message.into())),
+            },
+            (Some([161]), _) => application_reference.ap_invocation_id = Some(process_integer_content(&inner, error_message)?),
+            (Some([162]), Some([2])) => application_reference.ae_qualifier = Some(process_integer_content(&inner, error_message)?),
+            (Some([163]), _) => application_reference.ae_invocation_id = Some(process_integer_content(&inner, error_message)?),
+            x => warn!("Unsupported tag in MMS Application Reference: {:?}", x),
+        }
+    }
+
+    Ok(application_reference)
+}
+
+pub(crate) fn parse_write_journal_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::WriteJournal)
+}
+
+pub(crate) fn parse_initialize_journal_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    Ok(MmsConfirmedResponse::InitializeJournal { entries_deleted: process_integer_content(payload, "Failed to parse Entries Deleted on Initialize Journal Response PDU")? })
+}
+
+pub(crate) fn parse_report_journal_status_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut current_entries = None;
+    let mut mms_deletable = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Report Journal Status Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => current_entries = Some(process_integer_content(&item, "Failed to parse Current Entries on Report Journal Status Response PDU")?),
+            Some([129]) => mms_deletable = Some(process_mms_boolean_content(&item, "Failed to parse MMS Deletable on Report Journal Status Response PDU")?),
+            x => warn!("Unsupported tag in MMS Report Journal Status Response PDU: {:?}", x),
+        }
+    }
+
+    let current_entries = expect_value("ReportJournalStatus", "CurrentEntries", current_entries)?;
+    let mms_deletable = expect_value("ReportJournalStatus", "MmsDeletable", mms_deletable)?;
+
+    Ok(MmsConfirmedResponse::ReportJournalStatus { current_entries, mms_deletable })
+}
+
+pub(crate) fn parse_create_journal_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::CreateJournal)
+}
+
+pub(crate) fn parse_delete_journal_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DeleteJournal)
+}
+
+pub(crate) fn application_reference_to_ber(tag: u32, application_reference: &MmsApplicationReference) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
+            Header::new(Class::Universal, true, Tag::Sequence, Length::Definite(0)),
+            BerObjectContent::Sequence(
+                vec![
+                    application_reference.ap_title.as_ref().map(|x| explicit_to_ber(0, BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Oid, Length::Definite(0)), BerObjectContent::OID(x.to_owned())))),
+                    application_reference.ap_invocation_id.as_ref().map(|x| explicit_to_ber(1, integer_to_ber(x))),
+                    application_reference.ae_qualifier.as_ref().map(|x| explicit_to_ber(2, integer_to_ber(x))),
+                    application_reference.ae_invocation_id.as_ref().map(|x| explicit_to_ber(3, integer_to_ber(x))),
+                ]
+                .into_iter()
+                .flatten()
+                .collect(),
+            ),
+        )]),
+    )
+}
+
+fn integer_to_ber(value: &[u8]) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::Integer, Length::Definite(0)), BerObjectContent::Integer(value))
+}
+
+fn null_response_to_ber<'a>(tag: u32) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Null)
+}
+
+pub(crate) fn read_journal_response_to_ber<'a>(list_of_journal_entry: &'a [MmsJournalEntry], more_follows: &Option<bool>) -> Result<BerObject<'a>, MmsError> {
+    let mut entries = vec![];
+    for entry in list_of_journal_entry {
+        entries.push(BerObject::from_header_and_content(
+            Header::new(Class::Universal, true, Tag::Sequence, Length::Definite(0)),
+            BerObjectContent::Sequence(vec![
+                BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::OctetString(&entry.entry_identifier)),
+                application_reference_to_ber(1, &entry.originating_application),
+                entry_content_to_ber(Header::new(Class::ContextSpecific, true, Tag::from(2), Length::Definite(0)), &entry.entry_content)?,
+            ]),
+        ));
+    }
+
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(65), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(0), Length::Definite(0)), BerObjectContent::Sequence(entries))),
+                more_follows.map(|more_follows| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Boolean(more_follows))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn write_journal_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(66))
+}
+
+pub(crate) fn initialize_journal_response_to_ber(entries_deleted: &[u8]) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(67), Length::Definite(0)), BerObjectContent::Integer(entries_deleted)))
+}
+
+pub(crate) fn report_journal_status_response_to_ber(current_entries: &[u8], mms_deletable: bool) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(68), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(current_entries)),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Boolean(mms_deletable)),
+        ]),
+    ))
+}
+
+pub(crate) fn create_journal_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(69))
+}
+
+pub(crate) fn delete_journal_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(70))
+}
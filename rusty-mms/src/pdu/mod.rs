@@ -1,12 +1,27 @@
+pub(crate) mod cancel;
 pub(crate) mod common;
 pub(crate) mod concluderequest;
 pub(crate) mod concluderesponse;
+pub(crate) mod confirmederror;
 pub(crate) mod confirmedrequest;
 pub(crate) mod confirmedresponse;
+pub(crate) mod definenamedtyperequest;
+pub(crate) mod definenamedtyperesponse;
 pub(crate) mod definenamedvariablelistrequest;
 pub(crate) mod definenamedvariablelistresponse;
+pub(crate) mod deletenamedtyperequest;
+pub(crate) mod deletenamedtyperesponse;
 pub(crate) mod deletenamedvariablelistrequest;
 pub(crate) mod deletenamedvariablelistresponse;
+pub(crate) mod domainrequest;
+pub(crate) mod domainresponse;
+pub(crate) mod eventnotification;
+pub(crate) mod eventrequest;
+pub(crate) mod eventresponse;
+pub(crate) mod filerequest;
+pub(crate) mod fileresponse;
+pub(crate) mod getnamedtypeattributesrequest;
+pub(crate) mod getnamedtypeattributesresponse;
 pub(crate) mod getnamedvariablelistattributerequest;
 pub(crate) mod getnamedvariablelistattributesresponse;
 pub(crate) mod getnamelistrequest;
@@ -18,8 +33,19 @@ pub(crate) mod identifyresponse;
 pub(crate) mod informationreport;
 pub(crate) mod initiaterequest;
 pub(crate) mod initiateresponse;
+pub(crate) mod journalrequest;
+pub(crate) mod journalresponse;
+pub(crate) mod programrequest;
+pub(crate) mod programresponse;
 pub(crate) mod readrequest;
 pub(crate) mod readresponse;
+pub(crate) mod reject;
+pub(crate) mod semaphorerequest;
+pub(crate) mod semaphoreresponse;
+pub(crate) mod serviceerror;
 pub(crate) mod unconfirmed;
+pub(crate) mod unsolicitedstatus;
+pub(crate) mod vmdrequest;
+pub(crate) mod vmdresponse;
 pub(crate) mod writerequest;
 pub(crate) mod writeresponse;
@@ -0,0 +1,180 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedRequest, MmsError,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_mms_boolean_content, process_mms_string},
+    pdu::common::{expect_value, explicit_to_ber},
+};
+
+pub(crate) fn parse_list_of_domain_names(payload: &Any<'_>, pdu: &str) -> Result<Vec<String>, MmsError> {
+    process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse List of Domain Names on {}", pdu)))?.into_iter().map(|x| process_mms_string(&x, &format!("Failed to parse Domain Name on {}", pdu))).collect()
+}
+
+pub(crate) fn list_of_domain_names_to_ber(tag: u32, list_of_domain_names: &[String]) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            list_of_domain_names.iter().map(|name| BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::VisibleString, Length::Definite(0)), BerObjectContent::VisibleString(name.as_str()))).collect(),
+        ),
+    )
+}
+
+// Only the simple string form of the execution argument is supported. The encoded string is an EXTERNAL.
+pub(crate) fn execution_argument_to_ber(tag: u32, execution_argument: &str) -> Result<BerObject<'_>, MmsError> {
+    if !execution_argument.bytes().all(|x| (0x20..=0x7e).contains(&x)) {
+        return Err(MmsError::ProtocolError(format!("Execution Argument is not a valid VisibleString: {:?}", execution_argument)));
+    }
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::VisibleString(execution_argument)))
+}
+
+pub(crate) fn parse_create_program_invocation_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut program_invocation_name = None;
+    let mut list_of_domain_names = None;
+    let mut reusable = None;
+    let mut monitor_type = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Create Program Invocation Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => program_invocation_name = Some(process_mms_string(&item, "Failed to parse Program Invocation Name on Create Program Invocation Request PDU")?),
+            Some([161]) => list_of_domain_names = Some(parse_list_of_domain_names(&item, "Create Program Invocation Request PDU")?),
+            Some([130]) => reusable = Some(process_mms_boolean_content(&item, "Failed to parse Reusable on Create Program Invocation Request PDU")?),
+            Some([131]) => monitor_type = Some(process_mms_boolean_content(&item, "Failed to parse Monitor Type on Create Program Invocation Request PDU")?),
+            x => warn!("Unsupported tag in MMS Create Program Invocation Request PDU: {:?}", x),
+        }
+    }
+
+    let program_invocation_name = expect_value("CreateProgramInvocation", "ProgramInvocationName", program_invocation_name)?;
+    let list_of_domain_names = expect_value("CreateProgramInvocation", "ListOfDomainNames", list_of_domain_names)?;
+
+    Ok(MmsConfirmedRequest::CreateProgramInvocation { program_invocation_name, list_of_domain_names, reusable, monitor_type })
+}
+
+pub(crate) fn parse_delete_program_invocation_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::DeleteProgramInvocation { program_invocation_name: process_mms_string(payload, "Failed to parse Program Invocation Name on Delete Program Invocation Request PDU")? })
+}
+
+// Start and resume share the same shape.
+fn parse_execution_request(payload: &Any<'_>, service: &str, pdu: &str) -> Result<(String, Option<String>), MmsError> {
+    let mut program_invocation_name = None;
+    let mut execution_argument = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([128]) => program_invocation_name = Some(process_mms_string(&item, &format!("Failed to parse Program Invocation Name on {}", pdu))?),
+            Some([129]) => execution_argument = Some(process_mms_string(&item, &format!("Failed to parse Execution Argument on {}", pdu))?),
+            x => warn!("Unsupported tag in MMS {}: {:?}", pdu, x),
+        }
+    }
+
+    Ok((expect_value(service, "ProgramInvocationName", program_invocation_name)?, execution_argument))
+}
+
+// Stop, reset and kill only carry the program invocation name.
+fn parse_program_invocation_name(payload: &Any<'_>, service: &str, pdu: &str) -> Result<String, MmsError> {
+    let mut program_invocation_name = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse {}", pdu)))? {
+        match item.header.raw_tag() {
+            Some([128]) => program_invocation_name = Some(process_mms_string(&item, &format!("Failed to parse Program Invocation Name on {}", pdu))?),
+            x => warn!("Unsupported tag in MMS {}: {:?}", pdu, x),
+        }
+    }
+
+    expect_value(service, "ProgramInvocationName", program_invocation_name)
+}
+
+pub(crate) fn parse_start_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let (program_invocation_name, execution_argument) = parse_execution_request(payload, "Start", "Start Request PDU")?;
+    Ok(MmsConfirmedRequest::Start { program_invocation_name, execution_argument })
+}
+
+pub(crate) fn parse_stop_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::Stop { program_invocation_name: parse_program_invocation_name(payload, "Stop", "Stop Request PDU")? })
+}
+
+pub(crate) fn parse_resume_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let (program_invocation_name, execution_argument) = parse_execution_request(payload, "Resume", "Resume Request PDU")?;
+    Ok(MmsConfirmedRequest::Resume { program_invocation_name, execution_argument })
+}
+
+pub(crate) fn parse_reset_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::Reset { program_invocation_name: parse_program_invocation_name(payload, "Reset", "Reset Request PDU")? })
+}
+
+pub(crate) fn parse_kill_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::Kill { program_invocation_name: parse_program_invocation_name(payload, "Kill", "Kill Request PDU")? })
+}
+
+pub(crate) fn parse_get_program_invocation_attributes_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::GetProgramInvocationAttributes { program_invocation_name: process_mms_string(payload, "Failed to parse Program Invocation Name on Get Program Invocation Attributes Request PDU")? })
+}
+
+pub(crate) fn create_program_invocation_request_to_ber<'a>(program_invocation_name: &'a str, list_of_domain_names: &'a [String], reusable: &Option<bool>, monitor_type: &Option<bool>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(38), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::VisibleString(program_invocation_name))),
+                Some(list_of_domain_names_to_ber(1, list_of_domain_names)),
+                reusable.map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::Boolean(x))),
+                monitor_type.map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(3), Length::Definite(0)), BerObjectContent::Boolean(x))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn delete_program_invocation_request_to_ber(program_invocation_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(39), Length::Definite(0)), BerObjectContent::VisibleString(program_invocation_name)))
+}
+
+fn execution_request_to_ber<'a>(tag: u32, program_invocation_name: &'a str, execution_argument: &'a Option<String>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::VisibleString(program_invocation_name))),
+                execution_argument.as_ref().map(|x| execution_argument_to_ber(1, x)).transpose()?,
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+fn program_invocation_name_to_ber(tag: u32, program_invocation_name: &str) -> BerObject<'_> {
+    explicit_to_ber(tag, BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::VisibleString(program_invocation_name)))
+}
+
+pub(crate) fn start_request_to_ber<'a>(program_invocation_name: &'a str, execution_argument: &'a Option<String>) -> Result<BerObject<'a>, MmsError> {
+    execution_request_to_ber(40, program_invocation_name, execution_argument)
+}
+
+pub(crate) fn stop_request_to_ber(program_invocation_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(program_invocation_name_to_ber(41, program_invocation_name))
+}
+
+pub(crate) fn resume_request_to_ber<'a>(program_invocation_name: &'a str, execution_argument: &'a Option<String>) -> Result<BerObject<'a>, MmsError> {
+    execution_request_to_ber(42, program_invocation_name, execution_argument)
+}
+
+pub(crate) fn reset_request_to_ber(program_invocation_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(program_invocation_name_to_ber(43, program_invocation_name))
+}
+
+pub(crate) fn kill_request_to_ber(program_invocation_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(program_invocation_name_to_ber(44, program_invocation_name))
+}
+
+pub(crate) fn get_program_invocation_attributes_request_to_ber(program_invocation_name: &str) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(45), Length::Definite(0)), BerObjectContent::VisibleString(program_invocation_name)))
+}
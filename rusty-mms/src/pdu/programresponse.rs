@@ -0,0 +1,136 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedResponse, MmsError, MmsProgramInvocationState,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_mms_boolean_content, process_mms_string},
+    pdu::{
+        common::{error_codes, expect_value},
+        programrequest::{execution_argument_to_ber, list_of_domain_names_to_ber, parse_list_of_domain_names},
+    },
+};
+
+error_codes!(MmsProgramInvocationState, NonExistent => 0, Unrunnable => 1, Idle => 2, Running => 3, Stopped => 4, Starting => 5, Stopping => 6, Resuming => 7, Resetting => 8);
+
+pub(crate) fn parse_create_program_invocation_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::CreateProgramInvocation)
+}
+
+pub(crate) fn parse_delete_program_invocation_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DeleteProgramInvocation)
+}
+
+pub(crate) fn parse_start_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::Start)
+}
+
+pub(crate) fn parse_stop_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::Stop)
+}
+
+pub(crate) fn parse_resume_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::Resume)
+}
+
+pub(crate) fn parse_reset_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::Reset)
+}
+
+pub(crate) fn parse_kill_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::Kill)
+}
+
+pub(crate) fn parse_get_program_invocation_attributes_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut state = None;
+    let mut list_of_domain_names = None;
+    let mut mms_deletable = None;
+    let mut reusable = None;
+    let mut monitor = None;
+    let mut execution_argument = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Get Program Invocation Attributes Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => state = Some(MmsProgramInvocationState::parse(item.data)),
+            Some([161]) => list_of_domain_names = Some(parse_list_of_domain_names(&item, "Get Program Invocation Attributes Response PDU")?),
+            Some([130]) => mms_deletable = Some(process_mms_boolean_content(&item, "Failed to parse MMS Deletable on Get Program Invocation Attributes Response PDU")?),
+            Some([131]) => reusable = Some(process_mms_boolean_content(&item, "Failed to parse Reusable on Get Program Invocation Attributes Response PDU")?),
+            Some([132]) => monitor = Some(process_mms_boolean_content(&item, "Failed to parse Monitor on Get Program Invocation Attributes Response PDU")?),
+            Some([133]) => execution_argument = Some(process_mms_string(&item, "Failed to parse Execution Argument on Get Program Invocation Attributes Response PDU")?),
+            x => warn!("Unsupported tag in MMS Get Program Invocation Attributes Response PDU: {:?}", x),
+        }
+    }
+
+    let state = expect_value("GetProgramInvocationAttributes", "State", state)?;
+    let list_of_domain_names = expect_value("GetProgramInvocationAttributes", "ListOfDomainNames", list_of_domain_names)?;
+    let mms_deletable = expect_value("GetProgramInvocationAttributes", "MmsDeletable", mms_deletable)?;
+    let reusable = expect_value("GetProgramInvocationAttributes", "Reusable", reusable)?;
+    let monitor = expect_value("GetProgramInvocationAttributes", "Monitor", monitor)?;
+    let execution_argument = expect_value("GetProgramInvocationAttributes", "ExecutionArgument", execution_argument)?;
+
+    Ok(MmsConfirmedResponse::GetProgramInvocationAttributes { state, list_of_domain_names, mms_deletable, reusable, monitor, execution_argument })
+}
+
+fn null_response_to_ber<'a>(tag: u32) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Null)
+}
+
+pub(crate) fn create_program_invocation_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(38))
+}
+
+pub(crate) fn delete_program_invocation_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(39))
+}
+
+pub(crate) fn start_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(40))
+}
+
+pub(crate) fn stop_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(41))
+}
+
+pub(crate) fn resume_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(42))
+}
+
+pub(crate) fn reset_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(43))
+}
+
+pub(crate) fn kill_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(44))
+}
+
+pub(crate) fn get_program_invocation_attributes_response_to_ber<'a>(
+    state: &'a MmsProgramInvocationState,
+    list_of_domain_names: &'a [String],
+    mms_deletable: bool,
+    reusable: bool,
+    monitor: bool,
+    execution_argument: &'a str,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(45), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(state.to_ber())),
+            list_of_domain_names_to_ber(1, list_of_domain_names),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::Boolean(mms_deletable)),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(3), Length::Definite(0)), BerObjectContent::Boolean(reusable)),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(4), Length::Definite(0)), BerObjectContent::Boolean(monitor)),
+            execution_argument_to_ber(5, execution_argument)?,
+        ]),
+    ))
+}
@@ -0,0 +1,200 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length, parse_ber_any},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsCancelErrorRejectCode, MmsCancelRequestRejectCode, MmsCancelResponseRejectCode, MmsConcludeErrorRejectCode, MmsConcludeRequestRejectCode, MmsConcludeResponseRejectCode, MmsConfirmedErrorRejectCode, MmsConfirmedRequestRejectCode,
+    MmsConfirmedResponseRejectCode, MmsError, MmsMessage, MmsPduErrorRejectCode, MmsRejectReason, MmsUnconfirmedRejectCode,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content},
+    pdu::{common::error_codes, confirmedrequest::parse_confirmed_service_request, confirmedresponse::parse_confirmed_service_response, unconfirmed::parse_unconfirmed_service},
+};
+
+error_codes!(
+    MmsConfirmedRequestRejectCode,
+    Other => 0,
+    UnrecognizedService => 1,
+    UnrecognizedModifier => 2,
+    InvalidInvokeId => 3,
+    InvalidArgument => 4,
+    InvalidModifier => 5,
+    MaxServicesOutstandingExceeded => 6,
+    MaxRecursionExceeded => 8,
+    ValueOutOfRange => 9,
+);
+error_codes!(MmsConfirmedResponseRejectCode, Other => 0, UnrecognizedService => 1, InvalidInvokeId => 2, InvalidResult => 3, MaxRecursionExceeded => 5, ValueOutOfRange => 6);
+error_codes!(MmsConfirmedErrorRejectCode, Other => 0, UnrecognizedService => 1, InvalidInvokeId => 2, InvalidServiceError => 3, ValueOutOfRange => 4);
+error_codes!(MmsUnconfirmedRejectCode, Other => 0, UnrecognizedService => 1, InvalidArgument => 2, MaxRecursionExceeded => 3, ValueOutOfRange => 4);
+error_codes!(MmsPduErrorRejectCode, UnknownPduType => 0, InvalidPdu => 1, IllegalAcseMapping => 2);
+error_codes!(MmsCancelRequestRejectCode, Other => 0, InvalidInvokeId => 1);
+error_codes!(MmsCancelResponseRejectCode, Other => 0, InvalidInvokeId => 1);
+error_codes!(MmsCancelErrorRejectCode, Other => 0, InvalidInvokeId => 1, InvalidServiceError => 2, ValueOutOfRange => 3);
+error_codes!(MmsConcludeRequestRejectCode, Other => 0, InvalidArgument => 1);
+error_codes!(MmsConcludeResponseRejectCode, Other => 0, InvalidResult => 1);
+error_codes!(MmsConcludeErrorRejectCode, Other => 0, InvalidServiceError => 1, ValueOutOfRange => 2);
+
+impl MmsRejectReason {
+    fn parse(item: &Any<'_>) -> Result<Option<MmsRejectReason>, MmsError> {
+        let code = process_integer_content(item, "Failed to parse MMS Reject Reason")?;
+
+        Ok(Some(match item.header.raw_tag() {
+            Some([129]) => MmsRejectReason::ConfirmedRequestPdu(MmsConfirmedRequestRejectCode::parse(&code)),
+            Some([130]) => MmsRejectReason::ConfirmedResponsePdu(MmsConfirmedResponseRejectCode::parse(&code)),
+            Some([131]) => MmsRejectReason::ConfirmedErrorPdu(MmsConfirmedErrorRejectCode::parse(&code)),
+            Some([132]) => MmsRejectReason::UnconfirmedPdu(MmsUnconfirmedRejectCode::parse(&code)),
+            Some([133]) => MmsRejectReason::PduError(MmsPduErrorRejectCode::parse(&code)),
+            Some([134]) => MmsRejectReason::CancelRequestPdu(MmsCancelRequestRejectCode::parse(&code)),
+            Some([135]) => MmsRejectReason::CancelResponsePdu(MmsCancelResponseRejectCode::parse(&code)),
+            Some([136]) => MmsRejectReason::CancelErrorPdu(MmsCancelErrorRejectCode::parse(&code)),
+            Some([137]) => MmsRejectReason::ConcludeRequestPdu(MmsConcludeRequestRejectCode::parse(&code)),
+            Some([138]) => MmsRejectReason::ConcludeResponsePdu(MmsConcludeResponseRejectCode::parse(&code)),
+            Some([139]) => MmsRejectReason::ConcludeErrorPdu(MmsConcludeErrorRejectCode::parse(&code)),
+            _ => return Ok(None),
+        }))
+    }
+
+    fn to_ber(&self) -> BerObject<'_> {
+        let (tag, code) = match self {
+            MmsRejectReason::ConfirmedRequestPdu(code) => (1, code.to_ber()),
+            MmsRejectReason::ConfirmedResponsePdu(code) => (2, code.to_ber()),
+            MmsRejectReason::ConfirmedErrorPdu(code) => (3, code.to_ber()),
+            MmsRejectReason::UnconfirmedPdu(code) => (4, code.to_ber()),
+            MmsRejectReason::PduError(code) => (5, code.to_ber()),
+            MmsRejectReason::CancelRequestPdu(code) => (6, code.to_ber()),
+            MmsRejectReason::CancelResponsePdu(code) => (7, code.to_ber()),
+            MmsRejectReason::CancelErrorPdu(code) => (8, code.to_ber()),
+            MmsRejectReason::ConcludeRequestPdu(code) => (9, code.to_ber()),
+            MmsRejectReason::ConcludeResponsePdu(code) => (10, code.to_ber()),
+            MmsRejectReason::ConcludeErrorPdu(code) => (11, code.to_ber()),
+        };
+        BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Integer(code))
+    }
+}
+
+pub(crate) fn parse_reject(payload: Any<'_>) -> Result<MmsMessage, MmsError> {
+    let mut original_invocation_id = None;
+    let mut reject_reason = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Reject Payload"))? {
+        match item.header.raw_tag() {
+            Some(&[128]) => original_invocation_id = Some(item.data.to_vec()),
+            x => match MmsRejectReason::parse(&item)? {
+                Some(reason) => reject_reason = Some(reason),
+                None => warn!("Failed to parse unknown MMS Reject Item: {:?}", x),
+            },
+        }
+    }
+
+    Ok(MmsMessage::Reject { original_invocation_id, reject_reason: reject_reason.ok_or_else(|| MmsError::ProtocolError("No reject reason on reject".into()))? })
+}
+
+pub(crate) fn reject_to_ber<'a>(original_invocation_id: &'a Option<Vec<u8>>, reject_reason: &'a MmsRejectReason) -> BerObject<'a> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(4), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                original_invocation_id.as_ref().map(|id| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(id))),
+                Some(reject_reason.to_ber()),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    )
+}
+
+/// Works out how to reject a PDU that could not be processed. The invocation id is recovered where possible so the peer can match the reject to its request.
+/// Returns None if the PDU must not be rejected, which is the case for a reject.
+pub(crate) fn reject_reason_for_pdu(data: &[u8]) -> Option<(Option<Vec<u8>>, MmsRejectReason)> {
+    let pdu = match parse_ber_any(data) {
+        Ok((_, pdu)) => pdu,
+        Err(_) => return Some((None, MmsRejectReason::PduError(MmsPduErrorRejectCode::InvalidPdu))),
+    };
+    let items = if pdu.header.is_constructed() { process_constructed_data(pdu.data).unwrap_or_default() } else { vec![] };
+    let invocation_id = |tag: u8| items.iter().find(|item| item.header.raw_tag() == Some(&[tag])).map(|item| item.data.to_vec());
+    // The service is the last item as it follows the invocation id and any modifiers.
+    let service = items.iter().rfind(|item| item.header.raw_tag() != Some(&[2]));
+
+    Some(match pdu.header.raw_tag() {
+        Some([160]) => {
+            let invocation_id = invocation_id(2);
+            let reason = match (&invocation_id, service) {
+                (None, _) => MmsConfirmedRequestRejectCode::InvalidInvokeId,
+                (_, None) => MmsConfirmedRequestRejectCode::Other,
+                (_, Some(service)) if parse_confirmed_service_request(service).is_none() => MmsConfirmedRequestRejectCode::UnrecognizedService,
+                (_, Some(_)) => MmsConfirmedRequestRejectCode::InvalidArgument,
+            };
+            (invocation_id, MmsRejectReason::ConfirmedRequestPdu(reason))
+        }
+        Some([161]) => {
+            let invocation_id = invocation_id(2);
+            let reason = match (&invocation_id, service) {
+                (None, _) => MmsConfirmedResponseRejectCode::InvalidInvokeId,
+                (_, None) => MmsConfirmedResponseRejectCode::Other,
+                (_, Some(service)) if parse_confirmed_service_response(service).is_none() => MmsConfirmedResponseRejectCode::UnrecognizedService,
+                (_, Some(_)) => MmsConfirmedResponseRejectCode::InvalidResult,
+            };
+            (invocation_id, MmsRejectReason::ConfirmedResponsePdu(reason))
+        }
+        Some([162]) => {
+            let invocation_id = invocation_id(128);
+            let reason = match invocation_id {
+                None => MmsConfirmedErrorRejectCode::InvalidInvokeId,
+                Some(_) => MmsConfirmedErrorRejectCode::InvalidServiceError,
+            };
+            (invocation_id, MmsRejectReason::ConfirmedErrorPdu(reason))
+        }
+        Some([163]) => {
+            let reason = match service {
+                Some(service) if parse_unconfirmed_service(service).is_none() => MmsUnconfirmedRejectCode::UnrecognizedService,
+                Some(_) => MmsUnconfirmedRejectCode::InvalidArgument,
+                None => MmsUnconfirmedRejectCode::Other,
+            };
+            (None, MmsRejectReason::UnconfirmedPdu(reason))
+        }
+        Some([164]) => return None,
+        Some([133]) => (None, MmsRejectReason::CancelRequestPdu(MmsCancelRequestRejectCode::InvalidInvokeId)),
+        Some([134]) => (None, MmsRejectReason::CancelResponsePdu(MmsCancelResponseRejectCode::InvalidInvokeId)),
+        Some([167]) => {
+            let invocation_id = invocation_id(128);
+            let reason = match invocation_id {
+                None => MmsCancelErrorRejectCode::InvalidInvokeId,
+                Some(_) => MmsCancelErrorRejectCode::InvalidServiceError,
+            };
+            (invocation_id, MmsRejectReason::CancelErrorPdu(reason))
+        }
+        Some([139]) => (None, MmsRejectReason::ConcludeRequestPdu(MmsConcludeRequestRejectCode::InvalidArgument)),
+        Some([140]) => (None, MmsRejectReason::ConcludeResponsePdu(MmsConcludeResponseRejectCode::InvalidResult)),
+        _ => (None, MmsRejectReason::PduError(MmsPduErrorRejectCode::UnknownPduType)),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_rejects_an_unrecognised_confirmed_service() -> Result<(), anyhow::Error> {
+        // Confirmed request with invocation id 7 and an unsupported service tagged [63].
+        assert_eq!(reject_reason_for_pdu(&[0xa0, 0x06, 0x02, 0x01, 0x07, 0xbf, 0x3f, 0x00]), Some((Some(vec![0x07]), MmsRejectReason::ConfirmedRequestPdu(MmsConfirmedRequestRejectCode::UnrecognizedService))));
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_confirmed_request_argument() -> Result<(), anyhow::Error> {
+        // Confirmed request with invocation id 7 and a get name list without any content.
+        assert_eq!(reject_reason_for_pdu(&[0xa0, 0x05, 0x02, 0x01, 0x07, 0xa1, 0x00]), Some((Some(vec![0x07]), MmsRejectReason::ConfirmedRequestPdu(MmsConfirmedRequestRejectCode::InvalidArgument))));
+        Ok(())
+    }
+
+    #[test]
+    fn it_rejects_unknown_and_invalid_pdus() -> Result<(), anyhow::Error> {
+        assert_eq!(reject_reason_for_pdu(&[0xbf, 0x3f, 0x00]), Some((None, MmsRejectReason::PduError(MmsPduErrorRejectCode::UnknownPduType))));
+        assert_eq!(reject_reason_for_pdu(&[0xa0, 0x05, 0x02]), Some((None, MmsRejectReason::PduError(MmsPduErrorRejectCode::InvalidPdu))));
+        assert_eq!(reject_reason_for_pdu(&[0xa4, 0x00]), None);
+        Ok(())
+    }
+}
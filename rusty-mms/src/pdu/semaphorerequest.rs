@@ -0,0 +1,142 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedRequest, MmsError, MmsObjectName,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content, process_mms_string},
+    pdu::common::{expect_value, explicit_to_ber},
+};
+
+pub(crate) fn parse_take_control_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut semaphore_name = None;
+    let mut named_token = None;
+    let mut priority = None;
+    let mut acceptable_delay = None;
+    let mut control_timeout = None;
+    let mut abort_on_timeout = None;
+    let mut relinquish_if_connection_lost = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Take Control Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => semaphore_name = Some(MmsObjectName::parse("Take Control Request PDU", item.data)?),
+            Some([129]) => named_token = Some(process_mms_string(&item, "Failed to parse Named Token on Take Control Request PDU")?),
+            Some([130]) => priority = Some(process_integer_content(&item, "Failed to parse Priority on Take Control Request PDU")?),
+            Some([131]) => acceptable_delay = Some(process_integer_content(&item, "Failed to parse Acceptable Delay on Take Control Request PDU")?),
+            Some([132]) => control_timeout = Some(process_integer_content(&item, "Failed to parse Control Timeout on Take Control Request PDU")?),
+            Some([133]) => abort_on_timeout = Some(process_mms_boolean_content(&item, "Failed to parse Abort On Timeout on Take Control Request PDU")?),
+            Some([134]) => relinquish_if_connection_lost = Some(process_mms_boolean_content(&item, "Failed to parse Relinquish If Connection Lost on Take Control Request PDU")?),
+            x => warn!("Unsupported tag in MMS Take Control Request PDU: {:?}", x),
+        }
+    }
+
+    let semaphore_name = expect_value("TakeControl", "SemaphoreName", semaphore_name)?;
+
+    Ok(MmsConfirmedRequest::TakeControl { semaphore_name, named_token, priority, acceptable_delay, control_timeout, abort_on_timeout, relinquish_if_connection_lost })
+}
+
+pub(crate) fn parse_relinquish_control_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut semaphore_name = None;
+    let mut named_token = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Relinquish Control Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => semaphore_name = Some(MmsObjectName::parse("Relinquish Control Request PDU", item.data)?),
+            Some([129]) => named_token = Some(process_mms_string(&item, "Failed to parse Named Token on Relinquish Control Request PDU")?),
+            x => warn!("Unsupported tag in MMS Relinquish Control Request PDU: {:?}", x),
+        }
+    }
+
+    let semaphore_name = expect_value("RelinquishControl", "SemaphoreName", semaphore_name)?;
+
+    Ok(MmsConfirmedRequest::RelinquishControl { semaphore_name, named_token })
+}
+
+pub(crate) fn parse_define_semaphore_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut semaphore_name = None;
+    let mut number_of_tokens = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Define Semaphore Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => semaphore_name = Some(MmsObjectName::parse("Define Semaphore Request PDU", item.data)?),
+            Some([129]) => number_of_tokens = Some(process_integer_content(&item, "Failed to parse Number of Tokens on Define Semaphore Request PDU")?),
+            x => warn!("Unsupported tag in MMS Define Semaphore Request PDU: {:?}", x),
+        }
+    }
+
+    let semaphore_name = expect_value("DefineSemaphore", "SemaphoreName", semaphore_name)?;
+    let number_of_tokens = expect_value("DefineSemaphore", "NumberOfTokens", number_of_tokens)?;
+
+    Ok(MmsConfirmedRequest::DefineSemaphore { semaphore_name, number_of_tokens })
+}
+
+pub(crate) fn parse_delete_semaphore_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::DeleteSemaphore { semaphore_name: MmsObjectName::parse("Delete Semaphore Request PDU", payload.data)? })
+}
+
+pub(crate) fn parse_report_semaphore_status_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::ReportSemaphoreStatus { semaphore_name: MmsObjectName::parse("Report Semaphore Status Request PDU", payload.data)? })
+}
+
+fn integer_to_ber(tag: u32, value: &[u8]) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Integer(value))
+}
+
+fn boolean_to_ber<'a>(tag: u32, value: bool) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Boolean(value))
+}
+
+pub(crate) fn named_token_to_ber(tag: u32, named_token: &str) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::VisibleString(named_token))
+}
+
+pub(crate) fn take_control_request_to_ber<'a>(
+    semaphore_name: &'a MmsObjectName,
+    named_token: &'a Option<String>,
+    priority: &'a Option<Vec<u8>>,
+    acceptable_delay: &'a Option<Vec<u8>>,
+    control_timeout: &'a Option<Vec<u8>>,
+    abort_on_timeout: &Option<bool>,
+    relinquish_if_connection_lost: &Option<bool>,
+) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(19), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(explicit_to_ber(0, semaphore_name.to_ber())),
+                named_token.as_ref().map(|x| named_token_to_ber(1, x)),
+                priority.as_ref().map(|x| integer_to_ber(2, x)),
+                acceptable_delay.as_ref().map(|x| integer_to_ber(3, x)),
+                control_timeout.as_ref().map(|x| integer_to_ber(4, x)),
+                abort_on_timeout.map(|x| boolean_to_ber(5, x)),
+                relinquish_if_connection_lost.map(|x| boolean_to_ber(6, x)),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn relinquish_control_request_to_ber<'a>(semaphore_name: &'a MmsObjectName, named_token: &'a Option<String>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(20), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![Some(explicit_to_ber(0, semaphore_name.to_ber())), named_token.as_ref().map(|x| named_token_to_ber(1, x))].into_iter().flatten().collect()),
+    ))
+}
+
+pub(crate) fn define_semaphore_request_to_ber<'a>(semaphore_name: &'a MmsObjectName, number_of_tokens: &'a [u8]) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(21), Length::Definite(0)), BerObjectContent::Sequence(vec![explicit_to_ber(0, semaphore_name.to_ber()), integer_to_ber(1, number_of_tokens)])))
+}
+
+pub(crate) fn delete_semaphore_request_to_ber(semaphore_name: &MmsObjectName) -> Result<BerObject<'_>, MmsError> {
+    Ok(explicit_to_ber(22, semaphore_name.to_ber()))
+}
+
+pub(crate) fn report_semaphore_status_request_to_ber(semaphore_name: &MmsObjectName) -> Result<BerObject<'_>, MmsError> {
+    Ok(explicit_to_ber(23, semaphore_name.to_ber()))
+}
@@ -0,0 +1,113 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedResponse, MmsError, MmsSemaphoreClass,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_boolean_content, process_mms_string},
+    pdu::{
+        common::{error_codes, expect_value, explicit_to_ber, parse_explicit},
+        semaphorerequest::named_token_to_ber,
+    },
+};
+
+error_codes!(MmsSemaphoreClass, Token => 0, Pool => 1);
+
+pub(crate) fn parse_take_control_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let error_message = "Failed to parse Take Control Response PDU";
+    let choice = parse_explicit(payload, error_message)?;
+    match choice.header.raw_tag() {
+        Some([128]) => Ok(MmsConfirmedResponse::TakeControl { named_token: None }),
+        Some([129]) => Ok(MmsConfirmedResponse::TakeControl { named_token: Some(process_mms_string(&choice, "Failed to parse Named Token on Take Control Response PDU")?) }),
+        x => Err(MmsError::ProtocolError(format!("{}: {:?}", error_message, x))),
+    }
+}
+
+pub(crate) fn parse_relinquish_control_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::RelinquishControl)
+}
+
+pub(crate) fn parse_define_semaphore_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DefineSemaphore)
+}
+
+pub(crate) fn parse_delete_semaphore_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::DeleteSemaphore)
+}
+
+pub(crate) fn parse_report_semaphore_status_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut mms_deletable = None;
+    let mut class = None;
+    let mut number_of_tokens = None;
+    let mut number_of_owned_tokens = None;
+    let mut number_of_hung_tokens = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Report Semaphore Status Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([128]) => mms_deletable = Some(process_mms_boolean_content(&item, "Failed to parse MMS Deletable on Report Semaphore Status Response PDU")?),
+            Some([129]) => class = Some(MmsSemaphoreClass::parse(item.data)),
+            Some([130]) => number_of_tokens = Some(process_integer_content(&item, "Failed to parse Number of Tokens on Report Semaphore Status Response PDU")?),
+            Some([131]) => number_of_owned_tokens = Some(process_integer_content(&item, "Failed to parse Number of Owned Tokens on Report Semaphore Status Response PDU")?),
+            Some([132]) => number_of_hung_tokens = Some(process_integer_content(&item, "Failed to parse Number of Hung Tokens on Report Semaphore Status Response PDU")?),
+            x => warn!("Unsupported tag in MMS Report Semaphore Status Response PDU: {:?}", x),
+        }
+    }
+
+    let mms_deletable = expect_value("ReportSemaphoreStatus", "MmsDeletable", mms_deletable)?;
+    let class = expect_value("ReportSemaphoreStatus", "Class", class)?;
+    let number_of_tokens = expect_value("ReportSemaphoreStatus", "NumberOfTokens", number_of_tokens)?;
+    let number_of_owned_tokens = expect_value("ReportSemaphoreStatus", "NumberOfOwnedTokens", number_of_owned_tokens)?;
+    let number_of_hung_tokens = expect_value("ReportSemaphoreStatus", "NumberOfHungTokens", number_of_hung_tokens)?;
+
+    Ok(MmsConfirmedResponse::ReportSemaphoreStatus { mms_deletable, class, number_of_tokens, number_of_owned_tokens, number_of_hung_tokens })
+}
+
+fn null_response_to_ber<'a>(tag: u32) -> BerObject<'a> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Null)
+}
+
+fn integer_to_ber(tag: u32, value: &[u8]) -> BerObject<'_> {
+    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)), BerObjectContent::Integer(value))
+}
+
+pub(crate) fn take_control_response_to_ber(named_token: &Option<String>) -> Result<BerObject<'_>, MmsError> {
+    Ok(explicit_to_ber(
+        19,
+        match named_token {
+            Some(named_token) => named_token_to_ber(1, named_token),
+            None => null_response_to_ber(0),
+        },
+    ))
+}
+
+pub(crate) fn relinquish_control_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(20))
+}
+
+pub(crate) fn define_semaphore_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(21))
+}
+
+pub(crate) fn delete_semaphore_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(null_response_to_ber(22))
+}
+
+pub(crate) fn report_semaphore_status_response_to_ber<'a>(mms_deletable: bool, class: &'a MmsSemaphoreClass, number_of_tokens: &'a [u8], number_of_owned_tokens: &'a [u8], number_of_hung_tokens: &'a [u8]) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(23), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Boolean(mms_deletable)),
+            integer_to_ber(1, class.to_ber()),
+            integer_to_ber(2, number_of_tokens),
+            integer_to_ber(3, number_of_owned_tokens),
+            integer_to_ber(4, number_of_hung_tokens),
+        ]),
+    ))
+}
@@ -0,0 +1,127 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsApplicationReferenceError, MmsCancelError, MmsConcludeError, MmsDefinitionError, MmsError, MmsErrorClass, MmsFileError, MmsInitiateError, MmsObjectAccessError, MmsResourceError, MmsServiceErrorInfo, MmsServicePreemptError,
+    MmsServiceProblem, MmsTimeResolutionError, MmsVmdStateError,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_integer_content, process_mms_string},
+    pdu::common::error_codes,
+};
+
+error_codes!(MmsVmdStateError, Other => 0, VmdStateConflict => 1, VmdOperationalProblem => 2, DomainTransferProblem => 3, StateMachineIdInvalid => 4);
+error_codes!(MmsApplicationReferenceError, Other => 0, ApplicationUnreachable => 1, ConnectionLost => 2, ApplicationReferenceInvalid => 3, ContextUnsupported => 4);
+error_codes!(MmsDefinitionError, Other => 0, ObjectUndefined => 1, InvalidAddress => 2, TypeUnsupported => 3, TypeInconsistent => 4, ObjectExists => 5, ObjectAttributeInconsistent => 6);
+error_codes!(MmsResourceError, Other => 0, MemoryUnavailable => 1, ProcessorResourceUnavailable => 2, MassStorageUnavailable => 3, CapabilityUnavailable => 4, CapabilityUnknown => 5);
+error_codes!(MmsServiceProblem, Other => 0, PrimitivesOutOfSequence => 1, ObjectStateConflict => 2, ContinuationInvalid => 4, ObjectConstraintConflict => 5);
+error_codes!(MmsServicePreemptError, Other => 0, Timeout => 1, Deadlock => 2, Cancel => 3);
+error_codes!(MmsTimeResolutionError, Other => 0, UnsupportableTimeResolution => 1);
+error_codes!(MmsObjectAccessError, Other => 0, ObjectAccessUnsupported => 1, ObjectNonExistent => 2, ObjectAccessDenied => 3, ObjectInvalidated => 4);
+error_codes!(MmsInitiateError, Other => 0, MaxSegmentInsufficient => 2, MaxServicesOutstandingCallingInsufficient => 3, MaxServicesOutstandingCalledInsufficient => 4, ServiceCbbInsufficient => 5, ParameterCbbInsufficient => 6, NestingLevelInsufficient => 7);
+error_codes!(MmsConcludeError, Other => 0, FurtherCommunicationRequired => 1);
+error_codes!(MmsCancelError, Other => 0, InvokeIdUnknown => 1, CancelNotPossible => 2);
+error_codes!(
+    MmsFileError,
+    Other => 0,
+    FilenameAmbiguous => 1,
+    FileBusy => 2,
+    FilenameSyntaxError => 3,
+    ContentTypeInvalid => 4,
+    PositionInvalid => 5,
+    FileAccessDenied => 6,
+    FileNonExistent => 7,
+    DuplicateFilename => 8,
+    InsufficientSpaceInFilestore => 9,
+);
+
+impl MmsErrorClass {
+    fn parse(payload: &Any<'_>) -> Result<MmsErrorClass, MmsError> {
+        let items = process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse MMS Error Class"))?;
+        let item = items.first().ok_or_else(|| MmsError::ProtocolError("No content in MMS Error Class".into()))?;
+        let code = process_integer_content(item, "Failed to parse MMS Error Class code")?;
+
+        Ok(match item.header.raw_tag() {
+            Some([128]) => MmsErrorClass::VmdState(MmsVmdStateError::parse(&code)),
+            Some([129]) => MmsErrorClass::ApplicationReference(MmsApplicationReferenceError::parse(&code)),
+            Some([130]) => MmsErrorClass::Definition(MmsDefinitionError::parse(&code)),
+            Some([131]) => MmsErrorClass::Resource(MmsResourceError::parse(&code)),
+            Some([132]) => MmsErrorClass::Service(MmsServiceProblem::parse(&code)),
+            Some([133]) => MmsErrorClass::ServicePreempt(MmsServicePreemptError::parse(&code)),
+            Some([134]) => MmsErrorClass::TimeResolution(MmsTimeResolutionError::parse(&code)),
+            Some([135]) => MmsErrorClass::Access(MmsObjectAccessError::parse(&code)),
+            Some([136]) => MmsErrorClass::Initiate(MmsInitiateError::parse(&code)),
+            Some([137]) => MmsErrorClass::Conclude(MmsConcludeError::parse(&code)),
+            Some([138]) => MmsErrorClass::Cancel(MmsCancelError::parse(&code)),
+            Some([139]) => MmsErrorClass::File(MmsFileError::parse(&code)),
+            Some([140]) => MmsErrorClass::Others(code),
+            x => return Err(MmsError::ProtocolError(format!("Unsupported MMS Error Class tag: {:?}", x))),
+        })
+    }
+
+    fn to_ber(&self) -> BerObject<'_> {
+        let (tag, code) = match self {
+            MmsErrorClass::VmdState(code) => (0, code.to_ber()),
+            MmsErrorClass::ApplicationReference(code) => (1, code.to_ber()),
+            MmsErrorClass::Definition(code) => (2, code.to_ber()),
+            MmsErrorClass::Resource(code) => (3, code.to_ber()),
+            MmsErrorClass::Service(code) => (4, code.to_ber()),
+            MmsErrorClass::ServicePreempt(code) => (5, code.to_ber()),
+            MmsErrorClass::TimeResolution(code) => (6, code.to_ber()),
+            MmsErrorClass::Access(code) => (7, code.to_ber()),
+            MmsErrorClass::Initiate(code) => (8, code.to_ber()),
+            MmsErrorClass::Conclude(code) => (9, code.to_ber()),
+            MmsErrorClass::Cancel(code) => (10, code.to_ber()),
+            MmsErrorClass::File(code) => (11, code.to_ber()),
+            MmsErrorClass::Others(code) => (12, code.as_slice()),
+        };
+
+        BerObject::from_header_and_content(
+            Header::new(Class::ContextSpecific, true, Tag::from(0), Length::Definite(0)),
+            BerObjectContent::Sequence(vec![BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, false, Tag::from(tag), Length::Definite(0)),
+                BerObjectContent::Integer(code),
+            )]),
+        )
+    }
+}
+
+pub(crate) fn parse_service_error(payload: &Any<'_>) -> Result<MmsServiceErrorInfo, MmsError> {
+    let mut error_class = None;
+    let mut additional_code = None;
+    let mut additional_description = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse MMS Service Error"))? {
+        match item.header.raw_tag() {
+            Some([160]) => error_class = Some(MmsErrorClass::parse(&item)?),
+            Some([129]) => additional_code = Some(process_integer_content(&item, "Failed to parse MMS Service Error Additional Code")?),
+            Some([130]) => additional_description = Some(process_mms_string(&item, "Failed to parse MMS Service Error Additional Description")?),
+            // Service specific information, such as the program invocation state on a failed start, is not supported.
+            x => warn!("Unsupported tag in MMS Service Error: {:?}", x),
+        }
+    }
+
+    Ok(MmsServiceErrorInfo { error_class: error_class.ok_or_else(|| MmsError::ProtocolError("No error class on MMS Service Error".into()))?, additional_code, additional_description })
+}
+
+pub(crate) fn service_error_to_ber(tag: u32, service_error: &MmsServiceErrorInfo) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(service_error.error_class.to_ber()),
+                service_error.additional_code.as_ref().map(|code| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(code))),
+                service_error
+                    .additional_description
+                    .as_ref()
+                    .map(|description| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::VisibleString(description))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    )
+}
@@ -8,27 +8,43 @@ use tracing::warn;
 use crate::{MmsError, MmsMessage, error::to_mms_error, parsers::process_constructed_data};
 use crate::{
     MmsUnconfirmedService,
-    pdu::informationreport::{information_report_to_ber, parse_information_report},
+    pdu::{
+        eventnotification::{event_notification_to_ber, parse_event_notification},
+        informationreport::{information_report_to_ber, parse_information_report},
+        unsolicitedstatus::{parse_unsolicited_status, unsolicited_status_to_ber},
+    },
 };
 
 pub(crate) fn parse_unconfirmed(payload: Any<'_>) -> Result<MmsMessage, MmsError> {
     let mut unconfirmed_payload = None;
 
     for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Confirmed Response Payload"))? {
-        match item.header.raw_tag() {
-            Some(&[160]) => unconfirmed_payload = Some(parse_information_report(&item)?),
-            x => warn!("Failed to parse unknown MMS Unconfirmed Item: {:?}", x),
+        match parse_unconfirmed_service(&item) {
+            Some(service) => unconfirmed_payload = Some(service?),
+            None => warn!("Failed to parse unknown MMS Unconfirmed Item: {:?}", item.header.raw_tag()),
         }
     }
 
     Ok(MmsMessage::Unconfirmed { unconfirmed_service: unconfirmed_payload.ok_or_else(|| MmsError::ProtocolError("No payload on confirmed response".into()))? })
 }
 
+/// Returns None if the service is not supported so the PDU can be rejected as unrecognised.
+pub(crate) fn parse_unconfirmed_service(item: &Any<'_>) -> Option<Result<MmsUnconfirmedService, MmsError>> {
+    Some(match item.header.raw_tag() {
+        Some(&[160]) => parse_information_report(item),
+        Some(&[161]) => parse_unsolicited_status(item),
+        Some(&[162]) => parse_event_notification(item),
+        _ => return None,
+    })
+}
+
 pub(crate) fn unconfirmed_to_ber<'a>(payload: &'a MmsUnconfirmedService) -> Result<BerObject<'a>, MmsError> {
     Ok(BerObject::from_header_and_content(
         Header::new(Class::ContextSpecific, true, Tag::from(3), Length::Definite(0)),
         BerObjectContent::Sequence(vec![match payload {
             MmsUnconfirmedService::InformationReport { variable_access_specification, access_results } => information_report_to_ber(variable_access_specification, access_results)?,
+            MmsUnconfirmedService::EventNotification(event_notification) => event_notification_to_ber(event_notification)?,
+            MmsUnconfirmedService::UnsolicitedStatus(status) => unsolicited_status_to_ber(status)?,
         }]),
     ))
 }
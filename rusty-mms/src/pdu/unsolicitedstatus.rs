@@ -0,0 +1,14 @@
+use der_parser::{asn1_rs::Any, ber::BerObject};
+
+use crate::{
+    MmsError, MmsStatus, MmsUnconfirmedService,
+    pdu::vmdresponse::{parse_status, status_to_ber},
+};
+
+pub(crate) fn parse_unsolicited_status(payload: &Any<'_>) -> Result<MmsUnconfirmedService, MmsError> {
+    Ok(MmsUnconfirmedService::UnsolicitedStatus(parse_status(payload, "Unsolicited Status PDU")?))
+}
+
+pub(crate) fn unsolicited_status_to_ber(status: &MmsStatus) -> Result<BerObject<'_>, MmsError> {
+    Ok(status_to_ber(1, status))
+}
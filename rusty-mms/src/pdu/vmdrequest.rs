@@ -0,0 +1,73 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, Length},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedRequest, MmsError, MmsObjectClass, MmsObjectName,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_mms_boolean_content, process_mms_string},
+    pdu::common::{expect_value, explicit_to_ber},
+};
+
+pub(crate) fn parse_status_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    Ok(MmsConfirmedRequest::Status { extended_derivation: process_mms_boolean_content(payload, "Failed to parse Status Request PDU")? })
+}
+
+pub(crate) fn parse_get_capability_list_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut continue_after = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Get Capability List Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([26]) => continue_after = Some(process_mms_string(&item, "Failed to parse Continue After on Get Capability List Request PDU")?),
+            x => warn!("Unsupported tag in MMS Get Capability List Request PDU: {:?}", x),
+        }
+    }
+
+    Ok(MmsConfirmedRequest::GetCapabilityList { continue_after })
+}
+
+pub(crate) fn parse_rename_request(payload: &Any<'_>) -> Result<MmsConfirmedRequest, MmsError> {
+    let mut object_class = None;
+    let mut current_name = None;
+    let mut new_identifier = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Rename Request PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => object_class = Some(MmsObjectClass::parse("Rename Request PDU - Object Class", &item)?),
+            Some([161]) => current_name = Some(MmsObjectName::parse("Rename Request PDU", item.data)?),
+            Some([130]) => new_identifier = Some(process_mms_string(&item, "Failed to parse New Identifier on Rename Request PDU")?),
+            x => warn!("Unsupported tag in MMS Rename Request PDU: {:?}", x),
+        }
+    }
+
+    let object_class = expect_value("Rename", "ObjectClass", object_class)?;
+    let current_name = expect_value("Rename", "CurrentName", current_name)?;
+    let new_identifier = expect_value("Rename", "NewIdentifier", new_identifier)?;
+
+    Ok(MmsConfirmedRequest::Rename { object_class, current_name, new_identifier })
+}
+
+pub(crate) fn status_request_to_ber<'a>(extended_derivation: bool) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Boolean(extended_derivation)))
+}
+
+pub(crate) fn get_capability_list_request_to_ber(continue_after: &Option<String>) -> Result<BerObject<'_>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(71), Length::Definite(0)),
+        BerObjectContent::Sequence(continue_after.iter().map(|x| BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::VisibleString, Length::Definite(0)), BerObjectContent::VisibleString(x))).collect()),
+    ))
+}
+
+pub(crate) fn rename_request_to_ber<'a>(object_class: &'a MmsObjectClass, current_name: &'a MmsObjectName, new_identifier: &'a str) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(3), Length::Definite(0)),
+        BerObjectContent::Sequence(vec![
+            explicit_to_ber(0, object_class.to_ber()),
+            explicit_to_ber(1, current_name.to_ber()),
+            BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::VisibleString(new_identifier)),
+        ]),
+    ))
+}
@@ -0,0 +1,121 @@
+use der_parser::{
+    asn1_rs::Any,
+    ber::{BerObject, BerObjectContent, BitStringObject, Length, parse_ber_content},
+    der::{Class, Header, Tag},
+};
+use tracing::warn;
+
+use crate::{
+    MmsConfirmedResponse, MmsError, MmsLogicalStatus, MmsPhysicalStatus, MmsStatus,
+    error::to_mms_error,
+    parsers::{process_constructed_data, process_mms_boolean_content, process_mms_string},
+    pdu::common::{error_codes, expect_value},
+};
+
+error_codes!(MmsLogicalStatus, StateChangesAllowed => 0, NoStateChangesAllowed => 1, LimitedServicesPermitted => 2, SupportServicesAllowed => 3);
+error_codes!(MmsPhysicalStatus, Operational => 0, PartiallyOperational => 1, Inoperable => 2, NeedsCommissioning => 3);
+
+// The status response and the unsolicited status share the same content.
+pub(crate) fn parse_status(payload: &Any<'_>, context: &str) -> Result<MmsStatus, MmsError> {
+    let mut logical_status = None;
+    let mut physical_status = None;
+    let mut local_detail = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error(&format!("Failed to parse {}", context)))? {
+        match item.header.raw_tag() {
+            Some([128]) => logical_status = Some(MmsLogicalStatus::parse(item.data)),
+            Some([129]) => physical_status = Some(MmsPhysicalStatus::parse(item.data)),
+            Some([130]) => match parse_ber_content(Tag::BitString)(item.data, &item.header, item.data.len()).map_err(to_mms_error(&format!("Failed to parse Local Detail on {}", context)))? {
+                (_, BerObjectContent::BitString(padding, bits)) => local_detail = Some((padding, bits.data.to_vec())),
+                _ => return Err(MmsError::ProtocolError(format!("Failed to parse Local Detail on {}", context))),
+            },
+            x => warn!("Unsupported tag in MMS {}: {:?}", context, x),
+        }
+    }
+
+    let logical_status = expect_value("Status", "LogicalStatus", logical_status)?;
+    let physical_status = expect_value("Status", "PhysicalStatus", physical_status)?;
+
+    Ok(MmsStatus { logical_status, physical_status, local_detail })
+}
+
+pub(crate) fn status_to_ber(tag: u32, status: &MmsStatus) -> BerObject<'_> {
+    BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(tag), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(status.logical_status.to_ber()))),
+                Some(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(status.physical_status.to_ber()))),
+                status
+                    .local_detail
+                    .as_ref()
+                    .map(|(padding, bits)| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(2), Length::Definite(0)), BerObjectContent::BitString(*padding, BitStringObject { data: bits }))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    )
+}
+
+pub(crate) fn parse_status_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    Ok(MmsConfirmedResponse::Status(parse_status(payload, "Status Response PDU")?))
+}
+
+pub(crate) fn parse_get_capability_list_response(payload: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    let mut list_of_capabilities = None;
+    let mut more_follows = None;
+
+    for item in process_constructed_data(payload.data).map_err(to_mms_error("Failed to parse Get Capability List Response PDU"))? {
+        match item.header.raw_tag() {
+            Some([160]) => {
+                list_of_capabilities = Some(
+                    process_constructed_data(item.data)
+                        .map_err(to_mms_error("Failed to parse List of Capabilities on Get Capability List Response PDU"))?
+                        .into_iter()
+                        .map(|x| process_mms_string(&x, "Failed to parse Capability on Get Capability List Response PDU"))
+                        .collect::<Result<Vec<_>, _>>()?,
+                )
+            }
+            Some([129]) => more_follows = Some(process_mms_boolean_content(&item, "Failed to parse More Follows on Get Capability List Response PDU")?),
+            x => warn!("Unsupported tag in MMS Get Capability List Response PDU: {:?}", x),
+        }
+    }
+
+    let list_of_capabilities = expect_value("GetCapabilityList", "ListOfCapabilities", list_of_capabilities)?;
+
+    Ok(MmsConfirmedResponse::GetCapabilityList { list_of_capabilities, more_follows })
+}
+
+pub(crate) fn parse_rename_response(_: &Any<'_>) -> Result<MmsConfirmedResponse, MmsError> {
+    // This is a Null payload.
+    Ok(MmsConfirmedResponse::Rename)
+}
+
+pub(crate) fn status_response_to_ber(status: &MmsStatus) -> Result<BerObject<'_>, MmsError> {
+    Ok(status_to_ber(0, status))
+}
+
+pub(crate) fn get_capability_list_response_to_ber<'a>(list_of_capabilities: &'a [String], more_follows: &Option<bool>) -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(
+        Header::new(Class::ContextSpecific, true, Tag::from(71), Length::Definite(0)),
+        BerObjectContent::Sequence(
+            vec![
+                Some(BerObject::from_header_and_content(
+                    Header::new(Class::ContextSpecific, true, Tag::from(0), Length::Definite(0)),
+                    BerObjectContent::Sequence(
+                        list_of_capabilities.iter().map(|x| BerObject::from_header_and_content(Header::new(Class::Universal, false, Tag::VisibleString, Length::Definite(0)), BerObjectContent::VisibleString(x))).collect(),
+                    ),
+                )),
+                more_follows.map(|x| BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Boolean(x))),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        ),
+    ))
+}
+
+pub(crate) fn rename_response_to_ber<'a>() -> Result<BerObject<'a>, MmsError> {
+    Ok(BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(3), Length::Definite(0)), BerObjectContent::Null))
+}
@@ -1,26 +1,32 @@
 use std::collections::VecDeque;
 use std::marker::PhantomData;
+use std::sync::Arc;
 
 use der_parser::Oid;
 use der_parser::asn1_rs::{Any, ToDer};
 use der_parser::ber::compat::BerObjectHeader;
 use der_parser::ber::{BerObject, BerObjectContent, BitStringObject, Length, parse_ber_any};
 use der_parser::der::{Class, Header, Tag};
-use rusty_acse::{AcseRecvResult, OsiSingleValueAcseConnection};
+use rusty_acse::{AcseRecvResult, AcseResponseInformation, OsiSingleValueAcseConnection, ReleaseRequestReason, ReleaseResponseReason, ReleaseResult};
 use rusty_acse::{OsiSingleValueAcseInitiator, OsiSingleValueAcseListener, OsiSingleValueAcseReader, OsiSingleValueAcseResponder, OsiSingleValueAcseWriter};
+use tokio::sync::Mutex;
 use tracing::warn;
 
 use crate::parsers::{process_constructed_data, process_integer_content, process_mms_bitstring_content, process_mms_boolean_content, process_mms_string};
-use crate::pdu::common::expect_value;
+use crate::pdu::cancel::{cancel_error_to_ber, cancel_request_to_ber, cancel_response_to_ber, parse_cancel_error, parse_cancel_request, parse_cancel_response};
+use crate::pdu::common::{ber_object_to_vec, expect_value};
 use crate::pdu::concluderequest::{conclude_request_to_ber, parse_conclude_request};
 use crate::pdu::concluderesponse::{conclude_response_to_ber, parse_conclude_response};
+use crate::pdu::confirmederror::{confirmed_error_to_ber, parse_confirmed_error};
 use crate::pdu::confirmedrequest::{confirmed_request_to_ber, parse_confirmed_request};
 use crate::pdu::confirmedresponse::{confirmed_response_to_ber, parse_confirmed_response};
 use crate::pdu::initiaterequest::{InitRequestResponseDetails, InitiateRequestPdu};
 use crate::pdu::initiateresponse::InitiateResponsePdu;
+use crate::pdu::reject::{parse_reject, reject_reason_for_pdu, reject_to_ber};
 use crate::pdu::unconfirmed::{parse_unconfirmed, unconfirmed_to_ber};
 use crate::{
-    ListOfVariablesItem, MmsConnection, MmsData, MmsMessage, MmsObjectName, MmsReader, MmsRecvResult, MmsTypeDescription, MmsTypeDescriptionComponent, MmsTypeSpecification, MmsVariableAccessSpecification, MmsWriter, VariableSpecification,
+    ListOfVariablesItem, MmsAccessSelection, MmsAlternateAccessItem, MmsAlternateAccessSelection, MmsBinaryTime, MmsConnection, MmsData, MmsMessage, MmsObjectName, MmsReader, MmsRecvResult, MmsRejectReason, MmsTypeDescription,
+    MmsTypeDescriptionComponent, MmsTypeSpecification, MmsUtcTime, MmsVariableAccessSpecification, MmsWriter, VariableSpecification,
 };
 use crate::{
     MmsError, MmsInitiator, MmsListener, MmsResponder,
@@ -96,17 +102,135 @@ impl MmsVariableAccessSpecification {
 
 impl ListOfVariablesItem {
     pub(crate) fn to_ber(&self) -> BerObject<'_> {
-        BerObject::from_seq(vec![self.variable_specification.to_ber()])
+        BerObject::from_seq(
+            vec![
+                Some(self.variable_specification.to_ber()),
+                self.alternate_access.as_ref().map(|alternate_access| {
+                    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, true, Tag::from(5), Length::Definite(0)), BerObjectContent::Sequence(alternate_access.iter().map(|x| x.to_ber()).collect()))
+                }),
+            ]
+            .into_iter()
+            .flatten()
+            .collect(),
+        )
     }
 
     pub(crate) fn parse(data: &Any<'_>, error_message: &str) -> Result<ListOfVariablesItem, MmsError> {
         let items = process_constructed_data(data.data).map_err(to_mms_error(error_message))?;
-        let variable_specification = match items.iter().next() {
+        let mut items_iter = items.iter();
+        let variable_specification = match items_iter.next() {
             Some(item) => Some(VariableSpecification::parse(&item.to_der_vec().map_err(to_mms_error("Failed to parse ListOfVariablesItem"))?)?),
             None => None,
         };
         let variable_specification = expect_value(error_message, "Variable Specification", variable_specification)?;
-        Ok(ListOfVariablesItem { variable_specification })
+
+        let mut alternate_access = None;
+        for item in items_iter {
+            match item.header.raw_tag() {
+                Some([165]) => alternate_access = Some(MmsAlternateAccessItem::parse_list(item.data)?),
+                x => warn!("Unknown ListOfVariablesItem item {:?}", x),
+            }
+        }
+        Ok(ListOfVariablesItem { variable_specification, alternate_access })
+    }
+}
+
+impl MmsAlternateAccessItem {
+    pub(crate) fn to_ber(&self) -> BerObject<'_> {
+        match &self {
+            MmsAlternateAccessItem::Unnamed(selection) => selection.to_ber(),
+            MmsAlternateAccessItem::Named { component_name, access } => BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(5), Length::Definite(0)),
+                BerObjectContent::Sequence(vec![
+                    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::VisibleString(component_name.as_str())),
+                    access.to_ber(),
+                ]),
+            ),
+        }
+    }
+
+    pub(crate) fn parse_list(data: &[u8]) -> Result<Vec<MmsAlternateAccessItem>, MmsError> {
+        let mut alternate_access = vec![];
+        for item in process_constructed_data(data).map_err(to_mms_error("Failed to parse Alternate Access"))? {
+            alternate_access.push(match item.header.raw_tag() {
+                Some([165]) => {
+                    let values = process_constructed_data(item.data).map_err(to_mms_error("Failed to parse Named Alternate Access"))?;
+                    let mut values_iter = values.iter();
+                    let component_name = process_mms_string(expect_value("Alternate Access", "Component Name", values_iter.next())?, "Failed to parse Alternate Access Component Name")?;
+                    let access = MmsAlternateAccessSelection::parse(expect_value("Alternate Access", "Access", values_iter.next())?)?;
+                    MmsAlternateAccessItem::Named { component_name, access }
+                }
+                _ => MmsAlternateAccessItem::Unnamed(MmsAlternateAccessSelection::parse(&item)?),
+            });
+        }
+        Ok(alternate_access)
+    }
+}
+
+impl MmsAlternateAccessSelection {
+    pub(crate) fn to_ber(&self) -> BerObject<'_> {
+        match &self {
+            MmsAlternateAccessSelection::SelectAlternateAccess { access_selection, alternate_access } => BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(0), Length::Definite(0)),
+                BerObjectContent::Sequence(vec![access_selection.to_ber(0), BerObject::from_seq(alternate_access.iter().map(|x| x.to_ber()).collect())]),
+            ),
+            MmsAlternateAccessSelection::SelectAccess(access_selection) => access_selection.to_ber(1),
+        }
+    }
+
+    pub(crate) fn parse(data: &Any<'_>) -> Result<MmsAlternateAccessSelection, MmsError> {
+        match data.header.raw_tag() {
+            Some([160]) => {
+                let values = process_constructed_data(data.data).map_err(to_mms_error("Failed to parse Select Alternate Access"))?;
+                let mut values_iter = values.iter();
+                let access_selection = MmsAccessSelection::parse(expect_value("Select Alternate Access", "Access Selection", values_iter.next())?, 0)?;
+                let alternate_access = MmsAlternateAccessItem::parse_list(expect_value("Select Alternate Access", "Alternate Access", values_iter.next())?.data)?;
+                Ok(MmsAlternateAccessSelection::SelectAlternateAccess { access_selection, alternate_access })
+            }
+            _ => Ok(MmsAlternateAccessSelection::SelectAccess(MmsAccessSelection::parse(data, 1)?)),
+        }
+    }
+}
+
+impl MmsAccessSelection {
+    // The choice is tagged from 0 when followed by further alternate access and from 1 otherwise.
+    pub(crate) fn to_ber(&self, first_tag: u32) -> BerObject<'_> {
+        match &self {
+            MmsAccessSelection::Component(name) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(first_tag), Length::Definite(0)), BerObjectContent::VisibleString(name.as_str())),
+            MmsAccessSelection::Index(index) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(first_tag + 1), Length::Definite(0)), BerObjectContent::Integer(index)),
+            MmsAccessSelection::IndexRange { low_index, number_of_elements } => BerObject::from_header_and_content(
+                Header::new(Class::ContextSpecific, true, Tag::from(first_tag + 2), Length::Definite(0)),
+                BerObjectContent::Sequence(vec![
+                    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(0), Length::Definite(0)), BerObjectContent::Integer(low_index)),
+                    BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(1), Length::Definite(0)), BerObjectContent::Integer(number_of_elements)),
+                ]),
+            ),
+            MmsAccessSelection::AllElements => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(first_tag + 3), Length::Definite(0)), BerObjectContent::Null),
+        }
+    }
+
+    pub(crate) fn parse(data: &Any<'_>, first_tag: u32) -> Result<MmsAccessSelection, MmsError> {
+        if data.header.class() != Class::ContextSpecific {
+            return Err(MmsError::ProtocolError(format!("Unknown Access Selection: {:?}", data.header.raw_tag())));
+        }
+        match data.header.tag().0.checked_sub(first_tag) {
+            Some(0) => Ok(MmsAccessSelection::Component(process_mms_string(data, "Failed to parse Access Selection Component")?)),
+            Some(1) => Ok(MmsAccessSelection::Index(process_integer_content(data, "Failed to parse Access Selection Index")?)),
+            Some(2) => {
+                let mut low_index = None;
+                let mut number_of_elements = None;
+                for item in process_constructed_data(data.data).map_err(to_mms_error("Failed to parse Access Selection Index Range"))? {
+                    match item.header.raw_tag() {
+                        Some([128]) => low_index = Some(process_integer_content(&item, "Failed to parse Access Selection Low Index")?),
+                        Some([129]) => number_of_elements = Some(process_integer_content(&item, "Failed to parse Access Selection Number Of Elements")?),
+                        x => warn!("Unknown Index Range item {:?}", x),
+                    }
+                }
+                Ok(MmsAccessSelection::IndexRange { low_index: expect_value("Index Range", "Low Index", low_index)?, number_of_elements: expect_value("Index Range", "Number Of Elements", number_of_elements)? })
+            }
+            Some(3) => Ok(MmsAccessSelection::AllElements),
+            _ => Err(MmsError::ProtocolError(format!("Unknown Access Selection: {:?}", data.header.raw_tag()))),
+        }
     }
 }
 
@@ -152,20 +276,68 @@ impl Default for MmsRequestInformation {
                 ParameterSupportOption::Str1,
                 ParameterSupportOption::Str2,
                 ParameterSupportOption::Vnam,
-                // ParameterSupportOption::Valt,
+                ParameterSupportOption::Valt,
                 ParameterSupportOption::Vlis,
             ],
             services_supported_calling: vec![
+                ServiceSupportOption::Status,
                 ServiceSupportOption::GetNameList,
                 ServiceSupportOption::Identify,
+                ServiceSupportOption::Rename,
                 ServiceSupportOption::Read,
                 ServiceSupportOption::Write,
                 ServiceSupportOption::GetVariableAccessAttributes,
                 ServiceSupportOption::GetNamedVariableListAttribute,
                 ServiceSupportOption::DefineNamedVariableList,
                 ServiceSupportOption::DeleteNamedVariableList,
+                ServiceSupportOption::DefineNamedType,
+                ServiceSupportOption::GetNamedTypeAttributes,
+                ServiceSupportOption::DeleteNamedType,
+                ServiceSupportOption::TakeControl,
+                ServiceSupportOption::RelinquishControl,
+                ServiceSupportOption::DefineSemaphore,
+                ServiceSupportOption::DeleteSemaphore,
+                ServiceSupportOption::ReportSemaphoreStatus,
+                ServiceSupportOption::InitiateDownloadSequence,
+                ServiceSupportOption::DownloadSegment,
+                ServiceSupportOption::TerminateDownloadSequence,
+                ServiceSupportOption::InitiateUploadSequence,
+                ServiceSupportOption::UploadSegment,
+                ServiceSupportOption::TerminateUploadSequence,
+                ServiceSupportOption::DeleteDomain,
+                ServiceSupportOption::GetDomainAttributes,
+                ServiceSupportOption::CreateProgramInvocation,
+                ServiceSupportOption::DeleteProgramInvocation,
+                ServiceSupportOption::Start,
+                ServiceSupportOption::Stop,
+                ServiceSupportOption::Resume,
+                ServiceSupportOption::Reset,
+                ServiceSupportOption::Kill,
+                ServiceSupportOption::GetProgramInvocationAttributes,
+                ServiceSupportOption::ObtainFile,
+                ServiceSupportOption::DefineEventCondition,
+                ServiceSupportOption::GetEventConditionAttributes,
+                ServiceSupportOption::AlterEventConditionMonitoring,
+                ServiceSupportOption::DefineEventEnrollment,
+                ServiceSupportOption::AcknowledgeEventNotification,
+                ServiceSupportOption::ReadJournal,
+                ServiceSupportOption::WriteJournal,
+                ServiceSupportOption::InitializeJournal,
+                ServiceSupportOption::ReportJournalStatus,
+                ServiceSupportOption::CreateJournal,
+                ServiceSupportOption::DeleteJournal,
+                ServiceSupportOption::GetCapabilityList,
+                ServiceSupportOption::FileOpen,
+                ServiceSupportOption::FileRead,
+                ServiceSupportOption::FileClose,
+                ServiceSupportOption::FileRename,
+                ServiceSupportOption::FileDelete,
+                ServiceSupportOption::FileDirectory,
+                ServiceSupportOption::UnsolicitedStatus,
                 ServiceSupportOption::InformationReport,
+                ServiceSupportOption::EventNotification,
                 ServiceSupportOption::Conclude,
+                ServiceSupportOption::Cancel,
             ],
         }
     }
@@ -203,13 +375,14 @@ impl MmsData {
             MmsData::OctetString(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(9), Length::Definite(0)), BerObjectContent::OctetString(&object_data)),
             MmsData::VisibleString(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(10), Length::Definite(0)), BerObjectContent::VisibleString(&object_data)),
             MmsData::GeneralizedTime(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(11), Length::Definite(0)), BerObjectContent::VisibleString(&object_data)),
-            MmsData::BinaryTime(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(12), Length::Definite(0)), BerObjectContent::OctetString(&object_data)),
+            MmsData::BinaryTime(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(12), Length::Definite(0)), BerObjectContent::OctetString(object_data.data())),
             MmsData::Bcd(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(13), Length::Definite(0)), BerObjectContent::Integer(&object_data)),
             MmsData::BooleanArray(paddibg, object_data) => {
                 BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(14), Length::Definite(0)), BerObjectContent::BitString(*paddibg, BitStringObject { data: &object_data }))
             }
             MmsData::ObjectId(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(15), Length::Definite(0)), BerObjectContent::OID(object_data.to_owned())),
             MmsData::MmsString(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(16), Length::Definite(0)), BerObjectContent::VisibleString(&object_data)),
+            MmsData::UtcTime(object_data) => BerObject::from_header_and_content(Header::new(Class::ContextSpecific, false, Tag::from(17), Length::Definite(0)), BerObjectContent::OctetString(object_data.data())),
         };
         Ok(payload)
     }
@@ -238,7 +411,9 @@ impl MmsData {
             Some([137]) => Ok(MmsData::OctetString(data.data.to_owned())),
             Some([138]) => Ok(MmsData::VisibleString(String::from_utf8(data.data.to_vec()).map_err(to_mms_error("Illegal characters found in MMS Data Visible String"))?)),
             // Some([139]) => Ok(MmsData::GeneralizedTime(process_generalised_time_content(data, format!("Failed to parse GenerlizedTime on {}", pdu).as_str())?)),
+            Some([140]) => Ok(MmsData::BinaryTime(MmsBinaryTime::parse(data.data)?)),
             Some([144]) => Ok(MmsData::MmsString(String::from_utf8(data.data.to_owned()).map_err(to_mms_error("Failed to parse MMS String"))?)),
+            Some([145]) => Ok(MmsData::UtcTime(MmsUtcTime::parse(data.data)?)),
             x => Err(MmsError::ProtocolError(format!("Unsupported MMS Data type {:?} on {}", x, pdu))),
         }
     }
@@ -257,9 +432,14 @@ impl MmsTypeSpecification {
 
     pub(crate) fn parse(pdu: &str, data: &Any<'_>) -> Result<MmsTypeSpecification, MmsError> {
         let (_, item) = parse_ber_any(data.data).map_err(to_mms_error("Failed to parse MmsTypeSpecification"))?;
+        MmsTypeSpecification::parse_untagged(pdu, &item)
+    }
+
+    /// Parses a type specification that is not wrapped in an explicit tag.
+    pub(crate) fn parse_untagged(pdu: &str, item: &Any<'_>) -> Result<MmsTypeSpecification, MmsError> {
         match item.header.raw_tag() {
             Some([160]) => Ok(MmsTypeSpecification::ObjectName(MmsObjectName::parse(pdu, item.data)?)),
-            Some(_) => Ok(MmsTypeSpecification::TypeDescription(MmsTypeDescription::parse(pdu, data)?)),
+            Some(_) => Ok(MmsTypeSpecification::TypeDescription(MmsTypeDescription::parse_untagged(pdu, item)?)),
             x => Err(MmsError::ProtocolError(format!("Unsupported MmsTypeSpecification {:?} on {}", x, pdu))),
         }
     }
@@ -357,12 +537,17 @@ impl MmsTypeDescription {
             MmsTypeDescription::Bcd(length) => BerObject::from_header_and_content(BerObjectHeader::new(Class::ContextSpecific, false, Tag::from(13), Length::Definite(0)), BerObjectContent::Integer(length)),
             MmsTypeDescription::ObjId => BerObject::from_header_and_content(BerObjectHeader::new(Class::ContextSpecific, false, Tag::from(15), Length::Definite(0)), BerObjectContent::Null),
             MmsTypeDescription::MmsString(length) => BerObject::from_header_and_content(BerObjectHeader::new(Class::ContextSpecific, false, Tag::from(16), Length::Definite(0)), BerObjectContent::Integer(length)),
+            MmsTypeDescription::UtcTime => BerObject::from_header_and_content(BerObjectHeader::new(Class::ContextSpecific, false, Tag::from(17), Length::Definite(0)), BerObjectContent::Null),
         })
     }
 
     pub(crate) fn parse(pdu: &str, data: &Any<'_>) -> Result<MmsTypeDescription, MmsError> {
         let (_, description) = parse_ber_any(data.data).map_err(to_mms_error("Failed to parse Mms Type Description"))?;
+        MmsTypeDescription::parse_untagged(pdu, &description)
+    }
 
+    /// Parses a type description that is not wrapped in an explicit tag.
+    pub(crate) fn parse_untagged(pdu: &str, description: &Any<'_>) -> Result<MmsTypeDescription, MmsError> {
         Ok(match description.header.raw_tag() {
             Some([161]) => {
                 let mut packed = None;
@@ -408,9 +593,9 @@ impl MmsTypeDescription {
                 MmsTypeDescription::Structure { packed, components: type_descriptions }
             }
             Some([131]) => MmsTypeDescription::Boolean,
-            Some([132]) => MmsTypeDescription::BitString(process_integer_content(&description, "Failed to parse Mms Type Description BitString")?),
-            Some([133]) => MmsTypeDescription::Integer(process_integer_content(&description, "Failed to parse Mms Type Description Integer")?),
-            Some([134]) => MmsTypeDescription::Unsigned(process_integer_content(&description, "Failed to parse Mms Type Description Unsigned")?),
+            Some([132]) => MmsTypeDescription::BitString(process_integer_content(description, "Failed to parse Mms Type Description BitString")?),
+            Some([133]) => MmsTypeDescription::Integer(process_integer_content(description, "Failed to parse Mms Type Description Integer")?),
+            Some([134]) => MmsTypeDescription::Unsigned(process_integer_content(description, "Failed to parse Mms Type Description Unsigned")?),
             Some([167]) => {
                 let npm_object_items = process_constructed_data(description.data).map_err(to_mms_error("Failed to parse Mms Type Description FloatingPoint".into()))?;
                 let mut npm_object_items_iter = npm_object_items.iter();
@@ -421,14 +606,15 @@ impl MmsTypeDescription {
 
                 MmsTypeDescription::FloatingPoint { format_width, exponent_width }
             }
-            Some([137]) => MmsTypeDescription::OctetString(process_integer_content(&description, "Failed to parse Mms Type Description OctetString")?),
-            Some([138]) => MmsTypeDescription::VisibleString(process_integer_content(&description, "Failed to parse Mms Type Description VisibleString")?),
+            Some([137]) => MmsTypeDescription::OctetString(process_integer_content(description, "Failed to parse Mms Type Description OctetString")?),
+            Some([138]) => MmsTypeDescription::VisibleString(process_integer_content(description, "Failed to parse Mms Type Description VisibleString")?),
             Some([139]) => MmsTypeDescription::GeneralizedTime,
-            Some([140]) => MmsTypeDescription::BinaryTime(process_mms_boolean_content(&description, "Failed to parse Mms Type Description BinaryTime")?),
-            Some([141]) => MmsTypeDescription::Bcd(process_integer_content(&description, "Failed to parse Mms Type Description BCD")?),
+            Some([140]) => MmsTypeDescription::BinaryTime(process_mms_boolean_content(description, "Failed to parse Mms Type Description BinaryTime")?),
+            Some([141]) => MmsTypeDescription::Bcd(process_integer_content(description, "Failed to parse Mms Type Description BCD")?),
             // There is no 142
             Some([143]) => MmsTypeDescription::ObjId,
-            Some([144]) => MmsTypeDescription::MmsString(process_integer_content(&description, "Failed to parse Mms Type Description MMS String")?),
+            Some([144]) => MmsTypeDescription::MmsString(process_integer_content(description, "Failed to parse Mms Type Description MMS String")?),
+            Some([145]) => MmsTypeDescription::UtcTime,
             x => return Err(MmsError::ProtocolError(format!("Unsupported MmsTypeDescription {:?} on {}", x, pdu))),
         })
     }
@@ -448,7 +634,7 @@ impl<T: OsiSingleValueAcseInitiator, R: OsiSingleValueAcseReader, W: OsiSingleVa
 }
 
 impl<T: OsiSingleValueAcseInitiator, R: OsiSingleValueAcseReader, W: OsiSingleValueAcseWriter> MmsInitiator for RustyMmsInitiator<T, R, W> {
-    async fn initiate(self) -> Result<impl MmsConnection, MmsError> {
+    async fn initiate(self) -> Result<(impl MmsConnection, AcseResponseInformation), MmsError> {
         let pdu = InitiateRequestPdu::new(
             self.options.local_detail_calling,
             self.options.proposed_max_serv_outstanding_calling,
@@ -462,14 +648,14 @@ impl<T: OsiSingleValueAcseInitiator, R: OsiSingleValueAcseReader, W: OsiSingleVa
         );
         let request_data = pdu.serialise()?;
 
-        // TODO Figure out what to do with these.
-        let (acse_connection, _response, user_data) =
+        let (acse_connection, acse_response, user_data) =
             self.acse_initiator.initiate(Oid::from(&[1, 0, 9506, 2, 1]).map_err(to_mms_error("Failed to create MMS OID. This is a bug."))?.to_owned(), request_data).await.map_err(to_mms_error("Failed to initiate MMS connection"))?;
-        let _response = InitiateResponsePdu::parse(user_data)?;
+        let response = InitiateResponsePdu::parse(user_data)?;
+        let services_supported = response.init_response_details().services_supported_calling.options.clone();
 
         let (acse_reader, acse_writer) = acse_connection.split().await.map_err(|e| MmsError::ProtocolError(format!("Failed to initiate MMS connection: {:?}", e)))?;
 
-        Ok(RustyMmsConnection::<R, W>::new(acse_reader, acse_writer))
+        Ok((RustyMmsConnection::<R, W>::new(acse_reader, acse_writer, services_supported), acse_response))
     }
 }
 
@@ -531,110 +717,238 @@ impl<T: OsiSingleValueAcseResponder, R: OsiSingleValueAcseReader, W: OsiSingleVa
                         ParameterSupportOption::Str1,
                         ParameterSupportOption::Str2,
                         ParameterSupportOption::Vnam,
-                        // ParameterSupportOption::Valt, Optional, not implemented for now.
+                        ParameterSupportOption::Valt,
                         ParameterSupportOption::Vlis,
                     ],
                 },
                 services_supported_calling: ServiceSupportOptions {
                     options: vec![
+                        ServiceSupportOption::Status,
                         ServiceSupportOption::GetNameList,
                         ServiceSupportOption::Identify,
+                        ServiceSupportOption::Rename,
                         ServiceSupportOption::Read,
                         ServiceSupportOption::Write,
                         ServiceSupportOption::GetVariableAccessAttributes,
                         ServiceSupportOption::GetNamedVariableListAttribute,
                         ServiceSupportOption::DefineNamedVariableList,
                         ServiceSupportOption::DeleteNamedVariableList,
+                        ServiceSupportOption::DefineNamedType,
+                        ServiceSupportOption::GetNamedTypeAttributes,
+                        ServiceSupportOption::DeleteNamedType,
+                        ServiceSupportOption::TakeControl,
+                        ServiceSupportOption::RelinquishControl,
+                        ServiceSupportOption::DefineSemaphore,
+                        ServiceSupportOption::DeleteSemaphore,
+                        ServiceSupportOption::ReportSemaphoreStatus,
+                        ServiceSupportOption::InitiateDownloadSequence,
+                        ServiceSupportOption::DownloadSegment,
+                        ServiceSupportOption::TerminateDownloadSequence,
+                        ServiceSupportOption::InitiateUploadSequence,
+                        ServiceSupportOption::UploadSegment,
+                        ServiceSupportOption::TerminateUploadSequence,
+                        ServiceSupportOption::DeleteDomain,
+                        ServiceSupportOption::GetDomainAttributes,
+                        ServiceSupportOption::CreateProgramInvocation,
+                        ServiceSupportOption::DeleteProgramInvocation,
+                        ServiceSupportOption::Start,
+                        ServiceSupportOption::Stop,
+                        ServiceSupportOption::Resume,
+                        ServiceSupportOption::Reset,
+                        ServiceSupportOption::Kill,
+                        ServiceSupportOption::GetProgramInvocationAttributes,
+                        ServiceSupportOption::ObtainFile,
+                        ServiceSupportOption::DefineEventCondition,
+                        ServiceSupportOption::GetEventConditionAttributes,
+                        ServiceSupportOption::AlterEventConditionMonitoring,
+                        ServiceSupportOption::DefineEventEnrollment,
+                        ServiceSupportOption::AcknowledgeEventNotification,
+                        ServiceSupportOption::ReadJournal,
+                        ServiceSupportOption::WriteJournal,
+                        ServiceSupportOption::InitializeJournal,
+                        ServiceSupportOption::ReportJournalStatus,
+                        ServiceSupportOption::CreateJournal,
+                        ServiceSupportOption::DeleteJournal,
+                        ServiceSupportOption::GetCapabilityList,
+                        ServiceSupportOption::FileOpen,
+                        ServiceSupportOption::FileRead,
+                        ServiceSupportOption::FileClose,
+                        ServiceSupportOption::FileRename,
+                        ServiceSupportOption::FileDelete,
+                        ServiceSupportOption::FileDirectory,
+                        ServiceSupportOption::UnsolicitedStatus,
                         ServiceSupportOption::InformationReport,
+                        ServiceSupportOption::EventNotification,
                         ServiceSupportOption::Conclude,
+                        ServiceSupportOption::Cancel,
                     ],
                 },
             },
         );
         let acse_connection = self.acse_responder.accept(repsonse.serialise()?).await.map_err(|e| MmsError::ProtocolError(format!("Failed to initiate MMS connection: {:?}", e)))?;
         let (acse_reader, acse_writer) = acse_connection.split().await.map_err(|e| MmsError::ProtocolError(format!("Failed to initiate MMS connection: {:?}", e)))?;
-        Ok(RustyMmsConnection::<R, W>::new(acse_reader, acse_writer))
+        Ok(RustyMmsConnection::<R, W>::new(acse_reader, acse_writer, self.mms_request_information.services_supported_calling))
     }
 }
 
 pub struct RustyMmsConnection<R: OsiSingleValueAcseReader, W: OsiSingleValueAcseWriter> {
     acse_reader: R,
     acse_writer: W,
+    services_supported: Vec<ServiceSupportOption>,
 }
 
 impl<R: OsiSingleValueAcseReader, W: OsiSingleValueAcseWriter> RustyMmsConnection<R, W> {
-    pub fn new(acse_reader: impl OsiSingleValueAcseReader, acse_writer: impl OsiSingleValueAcseWriter) -> RustyMmsConnection<impl OsiSingleValueAcseReader, impl OsiSingleValueAcseWriter> {
-        RustyMmsConnection { acse_reader, acse_writer }
+    pub fn new(acse_reader: impl OsiSingleValueAcseReader, acse_writer: impl OsiSingleValueAcseWriter, services_supported: Vec<ServiceSupportOption>) -> RustyMmsConnection<impl OsiSingleValueAcseReader, impl OsiSingleValueAcseWriter> {
+        RustyMmsConnection { acse_reader, acse_writer, services_supported }
     }
 }
 
 impl<R: OsiSingleValueAcseReader, W: OsiSingleValueAcseWriter> MmsConnection for RustyMmsConnection<R, W> {
+    fn services_supported(&self) -> &[ServiceSupportOption] {
+        &self.services_supported
+    }
+
     async fn split(self) -> Result<(impl MmsReader, impl MmsWriter), MmsError> {
-        Ok((RustyMmsReader::new(self.acse_reader), RustyMmsWriter::new(self.acse_writer)))
+        // The reader shares the writer so it can reject PDUs it cannot process.
+        let acse_writer = Arc::new(Mutex::new(Some(self.acse_writer)));
+        Ok((RustyMmsReader::new(self.acse_reader, acse_writer.clone()), RustyMmsWriter::new(acse_writer)))
     }
 }
 
-pub struct RustyMmsReader<R: OsiSingleValueAcseReader> {
+pub struct RustyMmsReader<R: OsiSingleValueAcseReader, W: OsiSingleValueAcseWriter> {
     acse_reader: R,
+    acse_writer: Arc<Mutex<Option<W>>>,
 }
 
-impl<R: OsiSingleValueAcseReader> RustyMmsReader<R> {
-    fn new(acse_reader: R) -> Self {
-        RustyMmsReader { acse_reader }
+impl<R: OsiSingleValueAcseReader, W: OsiSingleValueAcseWriter> RustyMmsReader<R, W> {
+    fn new(acse_reader: R, acse_writer: Arc<Mutex<Option<W>>>) -> Self {
+        RustyMmsReader { acse_reader, acse_writer }
+    }
+
+    /// Rejects a PDU that could not be processed. A rejected answer to a local request is returned so the request can be failed.
+    async fn reject(&mut self, data: &[u8], error: MmsError) -> Result<Option<MmsRecvResult>, MmsError> {
+        let Some((original_invocation_id, reject_reason)) = reject_reason_for_pdu(data) else {
+            warn!("Dropping MMS Reject PDU that could not be processed: {}", error);
+            return Ok(None);
+        };
+        warn!("Rejecting MMS PDU that could not be processed ({:?}): {}", reject_reason, error);
+
+        let mut buffer = VecDeque::from(vec![ber_object_to_vec(&reject_to_ber(&original_invocation_id, &reject_reason))?]);
+        // Nothing can be sent once the association has been released.
+        if let Some(acse_writer) = self.acse_writer.lock().await.as_mut() {
+            while !buffer.is_empty() {
+                acse_writer.send(&mut buffer).await?;
+            }
+            acse_writer.send(&mut buffer).await?;
+        }
+        match (original_invocation_id, reject_reason) {
+            (Some(original_invocation_id), reject_reason @ (MmsRejectReason::ConfirmedResponsePdu(_) | MmsRejectReason::ConfirmedErrorPdu(_))) => Ok(Some(MmsRecvResult::ResponseRejected { original_invocation_id, reject_reason })),
+            _ => Ok(None),
+        }
     }
 }
 
-impl<R: OsiSingleValueAcseReader> MmsReader for RustyMmsReader<R> {
+fn parse_mms_pdu(data: &[u8]) -> Result<MmsMessage, MmsError> {
+    let (_, message) = parse_ber_any(data).map_err(to_mms_error("Failed to parse MMS message"))?;
+    match message.header.raw_tag() {
+        Some([139]) => parse_conclude_request(&message),
+        Some([140]) => parse_conclude_response(&message),
+        Some([160]) => parse_confirmed_request(message),
+        Some([161]) => parse_confirmed_response(message),
+        Some([162]) => parse_confirmed_error(message),
+        Some([163]) => parse_unconfirmed(message),
+        Some([164]) => parse_reject(message),
+        Some([133]) => parse_cancel_request(&message),
+        Some([134]) => parse_cancel_response(&message),
+        Some([167]) => parse_cancel_error(message),
+        x => Err(MmsError::ProtocolError(format!("Failed to parse unknown MMS PDU: {:?}", x))),
+    }
+}
+
+impl<R: OsiSingleValueAcseReader, W: OsiSingleValueAcseWriter> MmsReader for RustyMmsReader<R, W> {
     async fn recv(&mut self) -> Result<MmsRecvResult, MmsError> {
         loop {
             let result = self.acse_reader.recv().await?;
             match result {
                 AcseRecvResult::Closed => return Ok(MmsRecvResult::Closed),
-                AcseRecvResult::Data(data) => {
-                    let (_, message) = parse_ber_any(&data).map_err(to_mms_error("Failed to parse MMS message"))?;
-                    match message.header.raw_tag() {
-                        Some([139]) => return Ok(MmsRecvResult::Message(parse_conclude_request(&message)?)),
-                        Some([140]) => return Ok(MmsRecvResult::Message(parse_conclude_response(&message)?)),
-                        Some([160]) => return Ok(MmsRecvResult::Message(parse_confirmed_request(message)?)),
-                        Some([161]) => return Ok(MmsRecvResult::Message(parse_confirmed_response(message)?)),
-                        Some([163]) => return Ok(MmsRecvResult::Message(parse_unconfirmed(message)?)),
-                        x => warn!("Failed to parse unknown MMS PDU: {:?}", x),
+                AcseRecvResult::ReleaseRequest(_, _) => return Ok(MmsRecvResult::ReleaseRequest),
+                AcseRecvResult::ReleaseResponse(result, _, _) => {
+                    // The association ends once the release is accepted.
+                    if result == ReleaseResult::Affirmative {
+                        self.acse_writer.lock().await.take();
                     }
+                    return Ok(MmsRecvResult::ReleaseResponse(result));
                 }
+                AcseRecvResult::Data(data) => match parse_mms_pdu(&data) {
+                    Ok(message) => return Ok(MmsRecvResult::Message(message)),
+                    Err(e) => {
+                        if let Some(result) = self.reject(&data, e).await? {
+                            return Ok(result);
+                        }
+                    }
+                },
             };
         }
     }
 }
 
 pub struct RustyMmsWriter<W: OsiSingleValueAcseWriter> {
-    acse_writer: W,
+    // The writer is dropped when the association is released.
+    acse_writer: Arc<Mutex<Option<W>>>,
     buffer: VecDeque<Vec<u8>>,
 }
 
 impl<R: OsiSingleValueAcseWriter> RustyMmsWriter<R> {
-    fn new(acse_writer: R) -> Self {
+    fn new(acse_writer: Arc<Mutex<Option<R>>>) -> Self {
         RustyMmsWriter { acse_writer, buffer: VecDeque::new() }
     }
+
+    async fn take_writer(&mut self) -> Result<R, MmsError> {
+        self.acse_writer.lock().await.take().ok_or_else(association_released)
+    }
+}
+
+fn association_released() -> MmsError {
+    MmsError::ProtocolError("The association has already been released.".into())
 }
 
 impl<W: OsiSingleValueAcseWriter> MmsWriter for RustyMmsWriter<W> {
     async fn send(&mut self, messages: &mut VecDeque<MmsMessage>) -> Result<(), MmsError> {
         while let Some(message) = messages.pop_front() {
             let data = match message {
-                MmsMessage::ConcludeRequest { request: _ } => conclude_request_to_ber().to_vec(),
-                MmsMessage::ConcludeResponse { request: _ } => conclude_response_to_ber().to_vec(),
-                MmsMessage::Unconfirmed { unconfirmed_service } => unconfirmed_to_ber(&unconfirmed_service)?.to_vec(),
-                MmsMessage::ConfirmedRequest { invocation_id, request } => confirmed_request_to_ber(&invocation_id, &request)?.to_vec(),
-                MmsMessage::ConfirmedResponse { invocation_id, response } => confirmed_response_to_ber(&invocation_id, &response)?.to_vec(),
-            }
-            .map_err(to_mms_error("Failed to serialise message"))?;
+                MmsMessage::ConcludeRequest { request: _ } => ber_object_to_vec(&conclude_request_to_ber())?,
+                MmsMessage::ConcludeResponse { request: _ } => ber_object_to_vec(&conclude_response_to_ber())?,
+                MmsMessage::Unconfirmed { unconfirmed_service } => ber_object_to_vec(&unconfirmed_to_ber(&unconfirmed_service)?)?,
+                MmsMessage::ConfirmedRequest { invocation_id, request } => ber_object_to_vec(&confirmed_request_to_ber(&invocation_id, &request)?)?,
+                MmsMessage::ConfirmedResponse { invocation_id, response } => ber_object_to_vec(&confirmed_response_to_ber(&invocation_id, &response)?)?,
+                MmsMessage::ConfirmedError { invocation_id, service_error } => ber_object_to_vec(&confirmed_error_to_ber(&invocation_id, &service_error))?,
+                MmsMessage::Reject { original_invocation_id, reject_reason } => ber_object_to_vec(&reject_to_ber(&original_invocation_id, &reject_reason))?,
+                MmsMessage::CancelRequest { invocation_id } => ber_object_to_vec(&cancel_request_to_ber(&invocation_id))?,
+                MmsMessage::CancelResponse { invocation_id } => ber_object_to_vec(&cancel_response_to_ber(&invocation_id))?,
+                MmsMessage::CancelError { invocation_id, service_error } => ber_object_to_vec(&cancel_error_to_ber(&invocation_id, &service_error))?,
+            };
             self.buffer.push_back(data);
         }
 
+        let mut acse_writer = self.acse_writer.lock().await;
+        let acse_writer = acse_writer.as_mut().ok_or_else(association_released)?;
         while !self.buffer.is_empty() {
-            self.acse_writer.send(&mut self.buffer).await?;
+            acse_writer.send(&mut self.buffer).await?;
+        }
+        acse_writer.send(&mut self.buffer).await?;
+        Ok(())
+    }
+
+    async fn release(&mut self) -> Result<(), MmsError> {
+        self.acse_writer.lock().await.as_mut().ok_or_else(association_released)?.release(Some(ReleaseRequestReason::Normal), None).await?;
+        Ok(())
+    }
+
+    async fn release_response(&mut self, result: ReleaseResult) -> Result<(), MmsError> {
+        match result {
+            ReleaseResult::Affirmative => self.take_writer().await?.release_response(result, Some(ReleaseResponseReason::Normal), None).await?,
+            ReleaseResult::Negative => self.acse_writer.lock().await.as_mut().ok_or_else(association_released)?.release_response(result, Some(ReleaseResponseReason::NotFinished), None).await?,
         }
-        self.acse_writer.send(&mut self.buffer).await?;
         Ok(())
     }
 }
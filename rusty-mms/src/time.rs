@@ -0,0 +1,195 @@
+use chrono::{DateTime, Days, NaiveDate, NaiveTime, Timelike, Utc};
+
+use crate::MmsError;
+
+const FRACTION_SCALE: u64 = 1 << 24;
+const NANOS_PER_SECOND: u64 = 1_000_000_000;
+const MILLIS_PER_DAY: u32 = 86_400_000;
+
+/// The time accuracy value used when the number of accurate fraction bits is not known.
+pub const MMS_TIME_ACCURACY_UNSPECIFIED: u8 = 31;
+
+/// The quality octet carried on an MMS UtcTime.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct MmsTimeQuality {
+    pub leap_seconds_known: bool,
+    pub clock_failure: bool,
+    pub clock_not_synchronized: bool,
+    pub time_accuracy: u8, /* Number of accurate bits in the fraction. 0 - 24, or 31 if unspecified. */
+}
+
+impl MmsTimeQuality {
+    pub(crate) fn from_octet(value: u8) -> Self {
+        MmsTimeQuality { leap_seconds_known: value & 0x80 != 0, clock_failure: value & 0x40 != 0, clock_not_synchronized: value & 0x20 != 0, time_accuracy: value & 0x1f }
+    }
+
+    pub(crate) fn to_octet(self) -> u8 {
+        (if self.leap_seconds_known { 0x80 } else { 0 }) | (if self.clock_failure { 0x40 } else { 0 }) | (if self.clock_not_synchronized { 0x20 } else { 0 }) | (self.time_accuracy & 0x1f)
+    }
+}
+
+/// An MMS UtcTime. Held in its encoded form: four octets of seconds since 1970-01-01, a three octet binary fraction of a second and the time quality octet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsUtcTime {
+    data: [u8; 8],
+}
+
+impl MmsUtcTime {
+    /// The fraction is the 24 bit binary fraction of a second. Higher bits are discarded.
+    pub fn new(seconds: u32, fraction: u32, time_quality: MmsTimeQuality) -> Self {
+        let mut data = [0; 8];
+        data[0..4].copy_from_slice(&seconds.to_be_bytes());
+        data[4..7].copy_from_slice(&fraction.to_be_bytes()[1..4]);
+        data[7] = time_quality.to_octet();
+        MmsUtcTime { data }
+    }
+
+    pub fn seconds(&self) -> u32 {
+        u32::from_be_bytes([self.data[0], self.data[1], self.data[2], self.data[3]])
+    }
+
+    pub fn fraction(&self) -> u32 {
+        u32::from_be_bytes([0, self.data[4], self.data[5], self.data[6]])
+    }
+
+    pub fn time_quality(&self) -> MmsTimeQuality {
+        MmsTimeQuality::from_octet(self.data[7])
+    }
+
+    pub fn from_datetime(value: &DateTime<Utc>, time_quality: MmsTimeQuality) -> Result<Self, MmsError> {
+        let seconds = u32::try_from(value.timestamp()).map_err(|_| MmsError::ProtocolError(format!("Time cannot be represented as an MMS UtcTime: {}", value)))?;
+        // Chrono represents a leap second as a nanosecond value past one second. UtcTime has no way to carry this.
+        let nanos = (value.nanosecond() as u64).min(NANOS_PER_SECOND - 1);
+        Ok(MmsUtcTime::new(seconds, (nanos * FRACTION_SCALE / NANOS_PER_SECOND) as u32, time_quality))
+    }
+
+    pub fn to_datetime(&self) -> Result<DateTime<Utc>, MmsError> {
+        let nanos = (self.fraction() as u64 * NANOS_PER_SECOND / FRACTION_SCALE) as u32;
+        DateTime::from_timestamp(self.seconds() as i64, nanos).ok_or_else(|| MmsError::ProtocolError(format!("MMS UtcTime is out of range: {:?}", self.data)))
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub(crate) fn parse(data: &[u8]) -> Result<Self, MmsError> {
+        Ok(MmsUtcTime { data: data.try_into().map_err(|_| MmsError::ProtocolError(format!("MMS UtcTime must be 8 octets but was {}", data.len())))? })
+    }
+}
+
+/// An MMS BinaryTime (TimeOfDay). Held in its encoded form: four octets of milliseconds since midnight optionally followed by two octets of days since 1984-01-01.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MmsBinaryTime {
+    data: Vec<u8>,
+}
+
+impl MmsBinaryTime {
+    pub fn time_of_day(milliseconds: u32) -> Self {
+        MmsBinaryTime { data: milliseconds.to_be_bytes().to_vec() }
+    }
+
+    pub fn with_date(milliseconds: u32, days: u16) -> Self {
+        let mut data = milliseconds.to_be_bytes().to_vec();
+        data.extend_from_slice(&days.to_be_bytes());
+        MmsBinaryTime { data }
+    }
+
+    pub fn milliseconds(&self) -> u32 {
+        u32::from_be_bytes([self.data[0], self.data[1], self.data[2], self.data[3]])
+    }
+
+    /// Days since 1984-01-01. This is only present when the BinaryTime includes the date.
+    pub fn days(&self) -> Option<u16> {
+        match self.data.len() {
+            6 => Some(u16::from_be_bytes([self.data[4], self.data[5]])),
+            _ => None,
+        }
+    }
+
+    pub fn from_datetime(value: &DateTime<Utc>, include_date: bool) -> Result<Self, MmsError> {
+        let milliseconds = (value.num_seconds_from_midnight() * 1000 + (value.nanosecond() / 1_000_000).min(999)).min(MILLIS_PER_DAY - 1);
+        if !include_date {
+            return Ok(MmsBinaryTime::time_of_day(milliseconds));
+        }
+
+        let days = value.date_naive().signed_duration_since(binary_time_epoch()?).num_days();
+        let days = u16::try_from(days).map_err(|_| MmsError::ProtocolError(format!("Time cannot be represented as an MMS BinaryTime: {}", value)))?;
+        Ok(MmsBinaryTime::with_date(milliseconds, days))
+    }
+
+    /// Fails if the BinaryTime does not include the date.
+    pub fn to_datetime(&self) -> Result<DateTime<Utc>, MmsError> {
+        let days = self.days().ok_or_else(|| MmsError::ProtocolError("MMS BinaryTime does not include a date".into()))?;
+        let date = binary_time_epoch()?.checked_add_days(Days::new(days as u64)).ok_or_else(|| MmsError::ProtocolError(format!("MMS BinaryTime is out of range: {:?}", self.data)))?;
+        Ok(date.and_time(self.to_naive_time()?).and_utc())
+    }
+
+    pub fn to_naive_time(&self) -> Result<NaiveTime, MmsError> {
+        let milliseconds = self.milliseconds();
+        NaiveTime::from_num_seconds_from_midnight_opt(milliseconds / 1000, (milliseconds % 1000) * 1_000_000).ok_or_else(|| MmsError::ProtocolError(format!("MMS BinaryTime time of day is out of range: {}", milliseconds)))
+    }
+
+    pub(crate) fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub(crate) fn parse(data: &[u8]) -> Result<Self, MmsError> {
+        match data.len() {
+            4 | 6 => Ok(MmsBinaryTime { data: data.to_vec() }),
+            x => Err(MmsError::ProtocolError(format!("MMS BinaryTime must be 4 or 6 octets but was {}", x))),
+        }
+    }
+}
+
+fn binary_time_epoch() -> Result<NaiveDate, MmsError> {
+    NaiveDate::from_ymd_opt(1984, 1, 1).ok_or_else(|| MmsError::InternalError("Failed to create the MMS BinaryTime epoch. This is a bug.".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use chrono::TimeZone;
+
+    use super::*;
+
+    #[test]
+    fn test_utc_time() -> Result<(), anyhow::Error> {
+        let time_quality = MmsTimeQuality { leap_seconds_known: true, clock_failure: false, clock_not_synchronized: true, time_accuracy: 10 };
+        let utc_time = MmsUtcTime::new(0x01020304, 0x800000, time_quality);
+        assert_eq!(utc_time.data(), &[1, 2, 3, 4, 0x80, 0, 0, 0xaa]);
+        assert_eq!(utc_time.seconds(), 0x01020304);
+        assert_eq!(utc_time.fraction(), 0x800000);
+        assert_eq!(utc_time.time_quality(), time_quality);
+        assert_eq!(MmsUtcTime::parse(&[1, 2, 3, 4, 0x80, 0, 0, 0xaa])?, utc_time);
+        assert!(MmsUtcTime::parse(&[1, 2, 3, 4]).is_err());
+
+        let time = Utc.with_ymd_and_hms(2024, 2, 29, 12, 30, 15).unwrap().with_nanosecond(500_000_000).unwrap();
+        let utc_time = MmsUtcTime::from_datetime(&time, MmsTimeQuality::default())?;
+        assert_eq!(utc_time.seconds(), 1709209815);
+        assert_eq!(utc_time.fraction(), 0x800000);
+        assert_eq!(utc_time.to_datetime()?, time);
+
+        assert!(MmsUtcTime::from_datetime(&Utc.with_ymd_and_hms(1969, 12, 31, 23, 59, 59).unwrap(), MmsTimeQuality::default()).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_binary_time() -> Result<(), anyhow::Error> {
+        let time = Utc.with_ymd_and_hms(1984, 1, 3, 0, 0, 1).unwrap().with_nanosecond(250_000_000).unwrap();
+
+        let binary_time = MmsBinaryTime::from_datetime(&time, true)?;
+        assert_eq!(binary_time.data(), &[0, 0, 0x04, 0xe2, 0, 2]);
+        assert_eq!(binary_time.milliseconds(), 1250);
+        assert_eq!(binary_time.days(), Some(2));
+        assert_eq!(binary_time.to_datetime()?, time);
+
+        let binary_time = MmsBinaryTime::from_datetime(&time, false)?;
+        assert_eq!(binary_time.data(), &[0, 0, 0x04, 0xe2]);
+        assert_eq!(binary_time.days(), None);
+        assert_eq!(binary_time.to_naive_time()?, time.time());
+        assert!(binary_time.to_datetime().is_err());
+
+        assert!(MmsBinaryTime::from_datetime(&Utc.with_ymd_and_hms(1983, 12, 31, 0, 0, 0).unwrap(), true).is_err());
+        assert!(MmsBinaryTime::parse(&[0, 0, 0]).is_err());
+        Ok(())
+    }
+}